@@ -26,6 +26,13 @@ pub struct Cli {
 	#[allow(missing_docs)]
 	#[clap(flatten)]
 	pub run: sc_cli::RunCmd,
+
+	/// Index the events deposited by contracts and serve them via `contracts_getEvents`.
+	///
+	/// Only the events of recent blocks are kept in memory. Blocks imported during the major
+	/// sync are not indexed.
+	#[clap(long)]
+	pub enable_contract_events_index: bool,
 }
 
 /// Possible subcommands of the main binary.
@@ -48,6 +48,11 @@ type FullGrandpaBlockImport =
 /// The transaction pool type defintion.
 pub type TransactionPool = sc_transaction_pool::FullPool<Block, FullClient>;
 
+/// The number of most recent blocks whose contract events are served by `contracts_getEvents`.
+///
+/// This is one day worth of blocks.
+const CONTRACT_EVENTS_INDEX_BLOCKS: u32 = 14_400;
+
 /// Fetch the nonce of the given `account` from the chain state.
 ///
 /// Note: Should only be used for tests.
@@ -94,6 +99,7 @@ pub fn create_extrinsic(
 		frame_system::CheckNonce::<node_runtime::Runtime>::from(nonce),
 		frame_system::CheckWeight::<node_runtime::Runtime>::new(),
 		pallet_asset_tx_payment::ChargeAssetTxPayment::<node_runtime::Runtime>::from(tip, None),
+		pallet_recovery::TrackActivity::<node_runtime::Runtime>::new(),
 	);
 
 	let raw_payload = node_runtime::SignedPayload::from_raw(
@@ -108,6 +114,7 @@ pub fn create_extrinsic(
 			(),
 			(),
 			(),
+			(),
 		),
 	);
 	let signature = raw_payload.using_encoded(|e| sender.sign(e));
@@ -121,8 +128,11 @@ pub fn create_extrinsic(
 }
 
 /// Creates a new partial node.
+///
+/// The contract events index is only created if `enable_contract_events_index` is set.
 pub fn new_partial(
 	config: &Configuration,
+	enable_contract_events_index: bool,
 ) -> Result<
 	sc_service::PartialComponents<
 		FullClient,
@@ -140,7 +150,7 @@ pub fn new_partial(
 				grandpa::LinkHalf<Block, FullClient, FullSelectChain>,
 				sc_consensus_babe::BabeLink<Block>,
 			),
-			grandpa::SharedVoterState,
+			(grandpa::SharedVoterState, Option<Arc<node_rpc::ContractEventsIndex<Block>>>),
 			Option<Telemetry>,
 		),
 	>,
@@ -236,7 +246,9 @@ pub fn new_partial(
 		let justification_stream = grandpa_link.justification_stream();
 		let shared_authority_set = grandpa_link.shared_authority_set().clone();
 		let shared_voter_state = grandpa::SharedVoterState::empty();
-		let rpc_setup = shared_voter_state.clone();
+		let contract_events = enable_contract_events_index
+			.then(|| Arc::new(node_rpc::ContractEventsIndex::new(CONTRACT_EVENTS_INDEX_BLOCKS)));
+		let rpc_setup = (shared_voter_state.clone(), contract_events.clone());
 
 		let finality_proof_provider = grandpa::FinalityProofProvider::new_for_service(
 			backend.clone(),
@@ -259,6 +271,7 @@ pub fn new_partial(
 				select_chain: select_chain.clone(),
 				chain_spec: chain_spec.cloned_box(),
 				deny_unsafe,
+				contract_events: contract_events.clone(),
 				babe: node_rpc::BabeDeps {
 					babe_config: babe_config.clone(),
 					shared_epoch_changes: shared_epoch_changes.clone(),
@@ -308,6 +321,7 @@ pub struct NewFullBase {
 /// Creates a full service from the configuration.
 pub fn new_full_base(
 	mut config: Configuration,
+	enable_contract_events_index: bool,
 	with_startup_data: impl FnOnce(
 		&sc_consensus_babe::BabeBlockImport<Block, FullClient, FullGrandpaBlockImport>,
 		&sc_consensus_babe::BabeLink<Block>,
@@ -322,9 +336,9 @@ pub fn new_full_base(
 		select_chain,
 		transaction_pool,
 		other: (rpc_extensions_builder, import_setup, rpc_setup, mut telemetry),
-	} = new_partial(&config)?;
+	} = new_partial(&config, enable_contract_events_index)?;
 
-	let shared_voter_state = rpc_setup;
+	let (shared_voter_state, contract_events) = rpc_setup;
 	let auth_disc_publish_non_global_ips = config.network.allow_non_globals_in_dht;
 	let grandpa_protocol_name = grandpa::protocol_standard_name(
 		&client.block_hash(0).ok().flatten().expect("Genesis block exists; qed"),
@@ -361,6 +375,20 @@ pub fn new_full_base(
 		);
 	}
 
+	if let Some(contract_events) = contract_events {
+		task_manager.spawn_handle().spawn(
+			"contracts-events-index",
+			None,
+			node_rpc::index_contract_events::<
+				_,
+				_,
+				node_primitives::AccountId,
+				node_primitives::Balance,
+				node_primitives::Hash,
+			>(client.clone(), contract_events),
+		);
+	}
+
 	let role = config.role.clone();
 	let force_authoring = config.force_authoring;
 	let backoff_authoring_blocks =
@@ -529,8 +557,12 @@ pub fn new_full_base(
 }
 
 /// Builds a new service for a full client.
-pub fn new_full(config: Configuration) -> Result<TaskManager, ServiceError> {
-	new_full_base(config, |_, _| ()).map(|NewFullBase { task_manager, .. }| task_manager)
+pub fn new_full(
+	config: Configuration,
+	enable_contract_events_index: bool,
+) -> Result<TaskManager, ServiceError> {
+	new_full_base(config, enable_contract_events_index, |_, _| ())
+		.map(|NewFullBase { task_manager, .. }| task_manager)
 }
 
 #[cfg(test)]
@@ -597,6 +629,7 @@ mod tests {
 				let NewFullBase { task_manager, client, network, transaction_pool, .. } =
 					new_full_base(
 						config,
+						false,
 						|block_import: &sc_consensus_babe::BabeBlockImport<Block, _, _>,
 						 babe_link: &sc_consensus_babe::BabeLink<Block>| {
 							setup_handles = Some((block_import.clone(), babe_link.clone()));
@@ -738,6 +771,7 @@ mod tests {
 				let check_nonce = frame_system::CheckNonce::from(index);
 				let check_weight = frame_system::CheckWeight::new();
 				let tx_payment = pallet_asset_tx_payment::ChargeAssetTxPayment::from(0, None);
+				let track_activity = pallet_recovery::TrackActivity::new();
 				let extra = (
 					check_non_zero_sender,
 					check_spec_version,
@@ -747,11 +781,22 @@ mod tests {
 					check_nonce,
 					check_weight,
 					tx_payment,
+					track_activity,
 				);
 				let raw_payload = SignedPayload::from_raw(
 					function,
 					extra,
-					((), spec_version, transaction_version, genesis_hash, genesis_hash, (), (), ()),
+					(
+						(),
+						spec_version,
+						transaction_version,
+						genesis_hash,
+						genesis_hash,
+						(),
+						(),
+						(),
+						(),
+					),
 				);
 				let signature = raw_payload.using_encoded(|payload| signer.sign(payload));
 				let (function, extra, _) = raw_payload.deconstruct();
@@ -771,7 +816,7 @@ mod tests {
 			crate::chain_spec::tests::integration_test_config_with_two_authorities(),
 			|config| {
 				let NewFullBase { task_manager, client, network, transaction_pool, .. } =
-					new_full_base(config, |_, _| ())?;
+					new_full_base(config, false, |_, _| ())?;
 				Ok(sc_service_test::TestNetComponents::new(
 					task_manager,
 					client,
@@ -27,9 +27,9 @@ use frame_election_provider_support::onchain;
 use frame_support::{
 	construct_runtime, parameter_types,
 	traits::{
-		ConstU128, ConstU16, ConstU32, Currency, EnsureOneOf, EqualPrivilegeOnly, Everything,
-		Imbalance, InstanceFilter, KeyOwnerProofSystem, LockIdentifier, Nothing, OnUnbalanced,
-		U128CurrencyToVote,
+		ConstU128, ConstU16, ConstU32, Contains, Currency, EnsureOneOf, EqualPrivilegeOnly,
+		Imbalance, InsideBoth, InstanceFilter, KeyOwnerProofSystem, LockIdentifier, Nothing,
+		OnUnbalanced, U128CurrencyToVote,
 	},
 	weights::{
 		constants::{BlockExecutionWeight, ExtrinsicBaseWeight, RocksDbWeight, WEIGHT_PER_SECOND},
@@ -39,7 +39,7 @@ use frame_support::{
 };
 use frame_system::{
 	limits::{BlockLength, BlockWeights},
-	EnsureRoot,
+	EnsureRoot, EnsureSignedBy,
 };
 pub use node_primitives::{AccountId, Signature};
 use node_primitives::{AccountIndex, Balance, BlockNumber, Hash, Index, Moment};
@@ -198,7 +198,7 @@ parameter_types! {
 const_assert!(NORMAL_DISPATCH_RATIO.deconstruct() >= AVERAGE_ON_INITIALIZE_RATIO.deconstruct());
 
 impl frame_system::Config for Runtime {
-	type BaseCallFilter = Everything;
+	type BaseCallFilter = InsideBoth<SafeMode, TxPause>;
 	type BlockWeights = RuntimeBlockWeights;
 	type BlockLength = RuntimeBlockLength;
 	type DbWeight = RocksDbWeight;
@@ -238,6 +238,7 @@ parameter_types! {
 	pub const DepositBase: Balance = deposit(1, 88);
 	// Additional storage item size of 32 bytes.
 	pub const DepositFactor: Balance = deposit(0, 32);
+	pub const ManagedProposalLifetime: BlockNumber = 7 * DAYS;
 }
 
 impl pallet_multisig::Config for Runtime {
@@ -247,6 +248,7 @@ impl pallet_multisig::Config for Runtime {
 	type DepositBase = DepositBase;
 	type DepositFactor = DepositFactor;
 	type MaxSignatories = ConstU16<100>;
+	type ManagedProposalLifetime = ManagedProposalLifetime;
 	type WeightInfo = pallet_multisig::weights::SubstrateWeight<Runtime>;
 }
 
@@ -292,6 +294,7 @@ impl InstanceFilter<Call> for ProxyType {
 				c,
 				Call::Balances(..) |
 					Call::Assets(..) | Call::Uniques(..) |
+					Call::NftFractionalization(..) |
 					Call::Vesting(pallet_vesting::Call::vested_transfer { .. }) |
 					Call::Indices(pallet_indices::Call::transfer { .. })
 			),
@@ -329,6 +332,7 @@ impl pallet_proxy::Config for Runtime {
 	type CallHasher = BlakeTwo256;
 	type AnnouncementDepositBase = AnnouncementDepositBase;
 	type AnnouncementDepositFactor = AnnouncementDepositFactor;
+	type MaxAllowedCalls = ConstU32<16>;
 }
 
 parameter_types! {
@@ -524,7 +528,7 @@ parameter_types! {
 	pub const BondingDuration: sp_staking::EraIndex = 24 * 28;
 	pub const SlashDeferDuration: sp_staking::EraIndex = 24 * 7; // 1/4 the bonding duration.
 	pub const RewardCurve: &'static PiecewiseLinear<'static> = &REWARD_CURVE;
-	pub const MaxNominatorRewardedPerValidator: u32 = 256;
+	pub const MaxExposurePageSize: u32 = 256;
 	pub const OffendingValidatorsThreshold: Perbill = Perbill::from_percent(17);
 	pub OffchainRepeat: BlockNumber = 5;
 }
@@ -555,7 +559,7 @@ impl pallet_staking::Config for Runtime {
 	type SessionInterface = Self;
 	type EraPayout = pallet_staking::ConvertCurve<RewardCurve>;
 	type NextNewSession = Session;
-	type MaxNominatorRewardedPerValidator = MaxNominatorRewardedPerValidator;
+	type MaxExposurePageSize = MaxExposurePageSize;
 	type OffendingValidatorsThreshold = OffendingValidatorsThreshold;
 	type ElectionProvider = ElectionProviderMultiPhase;
 	type GenesisElectionProvider = onchain::OnChainSequentialPhragmen<Self>;
@@ -622,6 +626,15 @@ impl pallet_election_provider_multi_phase::BenchmarkingConfig for ElectionProvid
 /// miner of election provider multi phase.
 pub const MINER_MAX_ITERATIONS: u32 = 10;
 
+parameter_types! {
+	/// The algorithms that the OCW election miner runs to improve the solution of its `Solver`.
+	pub MinerAlgorithms: Vec<pallet_election_provider_multi_phase::unsigned::MinerAlgorithm> = vec![
+		pallet_election_provider_multi_phase::unsigned::MinerAlgorithm::PhragMMS {
+			balancing: MINER_MAX_ITERATIONS,
+		},
+	];
+}
+
 /// A source of random balance for NposSolver, which is meant to be run by the OCW election miner.
 pub struct OffchainRandomBalancing;
 impl frame_support::pallet_prelude::Get<Option<(usize, sp_npos_elections::ExtendedBalance)>>
@@ -678,6 +691,7 @@ impl pallet_election_provider_multi_phase::Config for Runtime {
 		pallet_election_provider_multi_phase::SolutionAccuracyOf<Self>,
 		OffchainRandomBalancing,
 	>;
+	type MinerAlgorithms = MinerAlgorithms;
 	type WeightInfo = pallet_election_provider_multi_phase::weights::SubstrateWeight<Self>;
 	type ForceOrigin = EnsureRootOrHalfCouncil;
 	type BenchmarkingConfig = ElectionProviderBenchmarkConfig;
@@ -685,6 +699,8 @@ impl pallet_election_provider_multi_phase::Config for Runtime {
 	// To ensure we respect memory limits when using the BagsList this must be set to a number of
 	// voters we know can fit into a single vec allocation.
 	type VoterSnapshotPerBlock = ConstU32<10_000>;
+	// The snapshot and the election result both fit in a single page.
+	type Pages = ConstU32<1>;
 }
 
 parameter_types! {
@@ -1010,6 +1026,7 @@ where
 			frame_system::CheckNonce::<Runtime>::from(nonce),
 			frame_system::CheckWeight::<Runtime>::new(),
 			pallet_asset_tx_payment::ChargeAssetTxPayment::<Runtime>::from(tip, None),
+			pallet_recovery::TrackActivity::<Runtime>::new(),
 		);
 		let raw_payload = SignedPayload::new(call, extra)
 			.map_err(|e| {
@@ -1112,6 +1129,7 @@ parameter_types! {
 	pub const FriendDepositFactor: Balance = 50 * CENTS;
 	pub const MaxFriends: u16 = 9;
 	pub const RecoveryDeposit: Balance = 5 * DOLLARS;
+	pub const InheritanceDeposit: Balance = 5 * DOLLARS;
 }
 
 impl pallet_recovery::Config for Runtime {
@@ -1122,6 +1140,8 @@ impl pallet_recovery::Config for Runtime {
 	type FriendDepositFactor = FriendDepositFactor;
 	type MaxFriends = MaxFriends;
 	type RecoveryDeposit = RecoveryDeposit;
+	type MaxFriendGroups = ConstU16<3>;
+	type InheritanceDeposit = InheritanceDeposit;
 }
 
 parameter_types! {
@@ -1273,9 +1293,116 @@ impl pallet_uniques::Config for Runtime {
 	type StringLimit = StringLimit;
 	type KeyLimit = KeyLimit;
 	type ValueLimit = ValueLimit;
+	type MaxAttributesPerCall = ConstU32<10>;
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as traits::Verify>::Signer;
+	#[cfg(feature = "runtime-benchmarks")]
+	type Helper = ();
 	type WeightInfo = pallet_uniques::weights::SubstrateWeight<Runtime>;
 }
 
+parameter_types! {
+	pub const NftFractionalizationPalletId: PalletId = PalletId(*b"fraction");
+}
+
+impl pallet_nft_fractionalization::Config for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type Deposit = AssetDeposit;
+	type PalletId = NftFractionalizationPalletId;
+	type AssetId = <Self as pallet_assets::Config>::AssetId;
+	type AssetBalance = <Self as pallet_assets::Config>::Balance;
+	type Assets = Assets;
+	type NftClassId = <Self as pallet_uniques::Config>::ClassId;
+	type NftInstanceId = <Self as pallet_uniques::Config>::InstanceId;
+	type Nfts = Uniques;
+	#[cfg(feature = "runtime-benchmarks")]
+	type BenchmarkHelper = ();
+	type WeightInfo = pallet_nft_fractionalization::weights::SubstrateWeight<Runtime>;
+}
+
+parameter_types! {
+	pub const MerkleClaimsUnsignedPriority: TransactionPriority = TransactionPriority::max_value();
+}
+
+impl pallet_merkle_claims::Config for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type CreateOrigin = EnsureSignedBy<TechnicalMembership, AccountId>;
+	type VestingSchedule = Vesting;
+	type BlockNumberToBalance = ConvertInto;
+	type OnUnclaimed = Treasury;
+	type OffchainSignature = Signature;
+	type OffchainPublic = <Signature as traits::Verify>::Signer;
+	type MaxProofLength = ConstU32<32>;
+	type RemoveClaimsLimit = ConstU32<1000>;
+	type UnsignedPriority = MerkleClaimsUnsignedPriority;
+	#[cfg(feature = "runtime-benchmarks")]
+	type Helper = ();
+	type WeightInfo = pallet_merkle_claims::weights::SubstrateWeight<Runtime>;
+}
+
+/// The pallets whose calls can't be paused by `TxPause`.
+pub struct UnpausablePallets;
+impl Contains<pallet_tx_pause::FullNameOf<Runtime>> for UnpausablePallets {
+	fn contains(full_name: &pallet_tx_pause::FullNameOf<Runtime>) -> bool {
+		matches!(
+			&full_name.0[..],
+			b"System" | b"Timestamp" | b"Authorship" | b"Babe" | b"Grandpa" | b"Sudo" | b"SafeMode"
+		)
+	}
+}
+
+impl pallet_tx_pause::Config for Runtime {
+	type Event = Event;
+	type PauseOrigin = EnsureRootOrHalfCouncil;
+	type UnpauseOrigin = EnsureRootOrHalfCouncil;
+	type WhitelistedCalls = UnpausablePallets;
+	type MaxNameLen = ConstU32<256>;
+	type WeightInfo = pallet_tx_pause::weights::SubstrateWeight<Runtime>;
+}
+
+/// The calls which can be dispatched while `SafeMode` is entered.
+pub struct SafeModeWhitelistedCalls;
+impl Contains<Call> for SafeModeWhitelistedCalls {
+	fn contains(call: &Call) -> bool {
+		matches!(
+			call,
+			Call::System(_) |
+				Call::Timestamp(_) |
+				Call::Authorship(_) |
+				Call::Babe(_) |
+				Call::Grandpa(_) |
+				Call::Sudo(_) |
+				Call::Council(_) |
+				Call::TechnicalCommittee(_) |
+				Call::TxPause(_)
+		)
+	}
+}
+
+parameter_types! {
+	pub const SafeModeEnterDuration: BlockNumber = 4 * HOURS;
+	pub const SafeModeExtendDuration: BlockNumber = 2 * HOURS;
+	pub const SafeModeEnterDeposit: Balance = 2_000 * DOLLARS;
+	pub const SafeModeReleaseDelay: BlockNumber = 2 * DAYS;
+}
+
+impl pallet_safe_mode::Config for Runtime {
+	type Event = Event;
+	type Currency = Balances;
+	type WhitelistedCalls = SafeModeWhitelistedCalls;
+	type EnterDuration = SafeModeEnterDuration;
+	type ExtendDuration = SafeModeExtendDuration;
+	type EnterDeposit = SafeModeEnterDeposit;
+	type ReleaseDelay = SafeModeReleaseDelay;
+	type ForceEnterOrigin = EnsureRootOrHalfCouncil;
+	type ForceExtendOrigin = EnsureRootOrHalfCouncil;
+	type ForceExitOrigin = EnsureRootOrHalfCouncil;
+	type ForceDepositOrigin = EnsureRootOrHalfCouncil;
+	type WeightInfo = pallet_safe_mode::weights::SubstrateWeight<Runtime>;
+}
+
 impl pallet_transaction_storage::Config for Runtime {
 	type Event = Event;
 	type Currency = Balances;
@@ -1333,9 +1460,13 @@ construct_runtime!(
 		Lottery: pallet_lottery,
 		Gilt: pallet_gilt,
 		Uniques: pallet_uniques,
+		NftFractionalization: pallet_nft_fractionalization,
 		TransactionStorage: pallet_transaction_storage,
 		BagsList: pallet_bags_list,
 		ChildBounties: pallet_child_bounties,
+		MerkleClaims: pallet_merkle_claims,
+		TxPause: pallet_tx_pause,
+		SafeMode: pallet_safe_mode,
 	}
 );
 
@@ -1363,6 +1494,7 @@ pub type SignedExtra = (
 	frame_system::CheckNonce<Runtime>,
 	frame_system::CheckWeight<Runtime>,
 	pallet_asset_tx_payment::ChargeAssetTxPayment<Runtime>,
+	pallet_recovery::TrackActivity<Runtime>,
 );
 /// Unchecked extrinsic type as expected by this runtime.
 pub type UncheckedExtrinsic = generic::UncheckedExtrinsic<Address, Call, Signature, SignedExtra>;
@@ -1416,11 +1548,14 @@ mod benches {
 		[pallet_indices, Indices]
 		[pallet_lottery, Lottery]
 		[pallet_membership, TechnicalMembership]
+		[pallet_merkle_claims, MerkleClaims]
 		[pallet_mmr, Mmr]
 		[pallet_multisig, Multisig]
+		[pallet_nft_fractionalization, NftFractionalization]
 		[pallet_offences, OffencesBench::<Runtime>]
 		[pallet_preimage, Preimage]
 		[pallet_proxy, Proxy]
+		[pallet_safe_mode, SafeMode]
 		[pallet_scheduler, Scheduler]
 		[pallet_session, SessionBench::<Runtime>]
 		[pallet_staking, Staking]
@@ -1429,6 +1564,7 @@ mod benches {
 		[pallet_tips, Tips]
 		[pallet_transaction_storage, TransactionStorage]
 		[pallet_treasury, Treasury]
+		[pallet_tx_pause, TxPause]
 		[pallet_uniques, Uniques]
 		[pallet_utility, Utility]
 		[pallet_vesting, Vesting]
@@ -1603,8 +1739,18 @@ impl_runtime_apis! {
 			gas_limit: u64,
 			storage_deposit_limit: Option<Balance>,
 			input_data: Vec<u8>,
-		) -> pallet_contracts_primitives::ContractExecResult<Balance> {
-			Contracts::bare_call(origin, dest, value, gas_limit, storage_deposit_limit, input_data, true)
+		) -> pallet_contracts_primitives::ContractExecResult<AccountId, Balance> {
+			Contracts::bare_call(
+				origin,
+				dest,
+				value,
+				gas_limit,
+				storage_deposit_limit,
+				input_data,
+				true,
+				true,
+				true,
+			)
 		}
 
 		fn instantiate(
@@ -1617,7 +1763,18 @@ impl_runtime_apis! {
 			salt: Vec<u8>,
 		) -> pallet_contracts_primitives::ContractInstantiateResult<AccountId, Balance>
 		{
-			Contracts::bare_instantiate(origin, value, gas_limit, storage_deposit_limit, code, data, salt, true)
+			Contracts::bare_instantiate(
+				origin,
+				value,
+				gas_limit,
+				storage_deposit_limit,
+				code,
+				data,
+				salt,
+				true,
+				true,
+				true,
+			)
 		}
 
 		fn upload_code(
@@ -1635,6 +1792,22 @@ impl_runtime_apis! {
 		) -> pallet_contracts_primitives::GetStorageResult {
 			Contracts::get_storage(address, key)
 		}
+
+		fn get_storage_keys(
+			address: AccountId,
+			start_key: Option<[u8; 32]>,
+			limit: u32,
+		) -> pallet_contracts_primitives::GetStorageKeysResult {
+			Contracts::get_storage_keys(address, start_key, limit)
+		}
+
+		fn contract_events() -> Vec<pallet_contracts_primitives::ContractEvent<AccountId, Hash>> {
+			Contracts::contract_events()
+		}
+
+		fn predict_address(deployer: AccountId, code_hash: Hash, salt: Vec<u8>) -> AccountId {
+			Contracts::contract_address(&deployer, &code_hash, &salt)
+		}
 	}
 
 	impl pallet_transaction_payment_rpc_runtime_api::TransactionPaymentApi<
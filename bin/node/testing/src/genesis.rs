@@ -91,6 +91,7 @@ pub fn config_endowed(code: Option<&[u8]>, extra_endowed: Vec<AccountId>) -> Gen
 		assets: Default::default(),
 		gilt: Default::default(),
 		transaction_storage: Default::default(),
+		merkle_claims: Default::default(),
 		transaction_payment: Default::default(),
 	}
 }
@@ -29,6 +29,13 @@
 //! is used to allow the target to approve (claim) the swap. If the swap is not
 //! claimed within a specified duration of time, the sender may cancel it.
 //!
+//! The swap is locked by the hash of the proof, which may be computed with any of the
+//! [`HashAlgorithm`]s. This allows swapping with chains which only support SHA-256 (e.g. Bitcoin)
+//! or Keccak-256 (e.g. Ethereum) hash locks.
+//!
+//! Swapped funds are defined by the [`SwapAction`] of the runtime. [`BalanceSwapAction`] swaps
+//! native balances and [`FungiblesSwapAction`] swaps `fungibles` assets.
+//!
 //! ## Interface
 //!
 //! ### Dispatchable Functions
@@ -36,6 +43,8 @@
 //! * [`create_swap`](Call::create_swap) - called by a sender to register a new atomic swap
 //! * [`claim_swap`](Call::claim_swap) - called by the target to approve a swap
 //! * [`cancel_swap`](Call::cancel_swap) - may be called by a sender after a specified duration
+//! * [`refund_swap`](Call::refund_swap) - may be called by anyone after a specified duration to
+//!   return the funds to the sender
 
 // Ensure we're `no_std` when compiling for Wasm.
 #![cfg_attr(not(feature = "std"), no_std)]
@@ -45,12 +54,15 @@ mod tests;
 use codec::{Decode, Encode};
 use frame_support::{
 	dispatch::DispatchResult,
-	traits::{BalanceStatus, Currency, Get, ReservableCurrency},
+	traits::{
+		tokens::fungibles::{Inspect as FungiblesInspect, MutateHold as FungiblesMutateHold},
+		BalanceStatus, Currency, Get, ReservableCurrency,
+	},
 	weights::Weight,
 	RuntimeDebugNoBound,
 };
 use scale_info::TypeInfo;
-use sp_io::hashing::blake2_256;
+use sp_io::hashing::{blake2_256, keccak_256, sha2_256};
 use sp_runtime::RuntimeDebug;
 use sp_std::{
 	marker::PhantomData,
@@ -73,6 +85,32 @@ pub struct PendingSwap<T: Config> {
 /// Hashed proof type.
 pub type HashedProof = [u8; 32];
 
+/// Hash functions which may be used to compute the [`HashedProof`] of a swap.
+#[derive(Clone, Copy, Eq, PartialEq, RuntimeDebug)]
+pub enum HashAlgorithm {
+	/// BLAKE2b with a 256 bit output.
+	Blake2_256,
+	/// SHA-256, as used by Bitcoin hash locks.
+	Sha2_256,
+	/// Keccak-256, as used by Ethereum hash locks.
+	Keccak256,
+}
+
+impl HashAlgorithm {
+	/// All supported hash algorithms, in the order a proof is checked against them.
+	pub const ALL: [HashAlgorithm; 3] =
+		[HashAlgorithm::Blake2_256, HashAlgorithm::Sha2_256, HashAlgorithm::Keccak256];
+
+	/// Hash the given `proof` with this algorithm.
+	pub fn hash(&self, proof: &[u8]) -> HashedProof {
+		match self {
+			HashAlgorithm::Blake2_256 => blake2_256(proof),
+			HashAlgorithm::Sha2_256 => sha2_256(proof),
+			HashAlgorithm::Keccak256 => keccak_256(proof),
+		}
+	}
+}
+
 /// Definition of a pending atomic swap action. It contains the following three phrases:
 ///
 /// - **Reserve**: reserve the resources needed for a swap. This is to make sure that **Claim**
@@ -151,6 +189,59 @@ where
 	}
 }
 
+/// A swap action that only allows transferring an amount of a `fungibles` asset.
+#[derive(Clone, RuntimeDebug, Eq, PartialEq, Encode, Decode, TypeInfo)]
+#[scale_info(skip_type_params(F))]
+pub struct FungiblesSwapAction<AccountId, F: FungiblesMutateHold<AccountId>> {
+	asset: <F as FungiblesInspect<AccountId>>::AssetId,
+	value: <F as FungiblesInspect<AccountId>>::Balance,
+	_marker: PhantomData<F>,
+}
+
+impl<AccountId, F> FungiblesSwapAction<AccountId, F>
+where
+	F: FungiblesMutateHold<AccountId>,
+{
+	/// Create a new swap action value of an asset.
+	pub fn new(
+		asset: <F as FungiblesInspect<AccountId>>::AssetId,
+		value: <F as FungiblesInspect<AccountId>>::Balance,
+	) -> Self {
+		Self { asset, value, _marker: PhantomData }
+	}
+
+	/// The asset of the swap.
+	pub fn asset(&self) -> <F as FungiblesInspect<AccountId>>::AssetId {
+		self.asset
+	}
+
+	/// The amount of the asset of the swap.
+	pub fn value(&self) -> <F as FungiblesInspect<AccountId>>::Balance {
+		self.value
+	}
+}
+
+impl<T: Config, AccountId, F> SwapAction<AccountId, T> for FungiblesSwapAction<AccountId, F>
+where
+	F: FungiblesMutateHold<AccountId>,
+{
+	fn reserve(&self, source: &AccountId) -> DispatchResult {
+		F::hold(self.asset, source, self.value)
+	}
+
+	fn claim(&self, source: &AccountId, target: &AccountId) -> bool {
+		F::transfer_held(self.asset, source, target, self.value, false, false).is_ok()
+	}
+
+	fn weight(&self) -> Weight {
+		T::DbWeight::get().reads_writes(2, 2)
+	}
+
+	fn cancel(&self, source: &AccountId) {
+		let _ = F::release(self.asset, source, self.value, true);
+	}
+}
+
 pub use pallet::*;
 
 #[frame_support::pallet]
@@ -240,7 +331,8 @@ pub mod pallet {
 		/// The dispatch origin for this call must be _Signed_.
 		///
 		/// - `target`: Receiver of the atomic swap.
-		/// - `hashed_proof`: The blake2_256 hash of the secret proof.
+		/// - `hashed_proof`: The hash of the secret proof, computed with any of the
+		///   [`HashAlgorithm`]s.
 		/// - `balance`: Funds to be sent from origin.
 		/// - `duration`: Locked duration of the atomic swap. For safety reasons, it is recommended
 		///   that the revealer uses a shorter duration than the counterparty, to prevent the
@@ -277,15 +369,17 @@ pub mod pallet {
 		///
 		/// The dispatch origin for this call must be _Signed_.
 		///
-		/// - `proof`: Revealed proof of the claim.
+		/// - `proof`: Revealed proof of the claim. It is hashed with each of the [`HashAlgorithm`]s
+		///   and claims the first swap to the origin found under one of the hashes.
 		/// - `action`: Action defined in the swap, it must match the entry in blockchain. Otherwise
 		///   the operation fails. This is used for weight calculation.
-		#[pallet::weight(
-			T::DbWeight::get().reads_writes(1, 1)
+		#[pallet::weight({
+			let algorithms = HashAlgorithm::ALL.len() as Weight;
+			T::DbWeight::get().reads_writes(algorithms, 1)
 				.saturating_add(40_000_000)
-				.saturating_add((proof.len() as Weight).saturating_mul(100))
+				.saturating_add((proof.len() as Weight).saturating_mul(100 * algorithms))
 				.saturating_add(action.weight())
-		)]
+		})]
 		pub fn claim_swap(
 			origin: OriginFor<T>,
 			proof: Vec<u8>,
@@ -294,10 +388,13 @@ pub mod pallet {
 			ensure!(proof.len() <= T::ProofLimit::get() as usize, Error::<T>::ProofTooLarge);
 
 			let target = ensure_signed(origin)?;
-			let hashed_proof = blake2_256(&proof);
-
-			let swap =
-				PendingSwaps::<T>::get(&target, hashed_proof).ok_or(Error::<T>::InvalidProof)?;
+			let (hashed_proof, swap) = HashAlgorithm::ALL
+				.iter()
+				.find_map(|algorithm| {
+					let hashed_proof = algorithm.hash(&proof);
+					PendingSwaps::<T>::get(&target, hashed_proof).map(|swap| (hashed_proof, swap))
+				})
+				.ok_or(Error::<T>::InvalidProof)?;
 			ensure!(swap.action == action, Error::<T>::ClaimActionMismatch);
 
 			let succeeded = swap.action.claim(&swap.source, &target);
@@ -327,8 +424,42 @@ pub mod pallet {
 		) -> DispatchResult {
 			let source = ensure_signed(origin)?;
 
+			Self::do_cancel_swap(target, hashed_proof, Some(source))
+		}
+
+		/// Refund an atomic swap to its source. Only possible after the originally set duration
+		/// has passed.
+		///
+		/// The dispatch origin for this call must be _Signed_, but may be any account, so that the
+		/// funds of a timed out swap can be returned without a transaction from its source.
+		///
+		/// - `target`: Target of the original atomic swap.
+		/// - `hashed_proof`: Hashed proof of the original atomic swap.
+		#[pallet::weight(T::DbWeight::get().reads_writes(1, 1).saturating_add(40_000_000))]
+		pub fn refund_swap(
+			origin: OriginFor<T>,
+			target: T::AccountId,
+			hashed_proof: HashedProof,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			Self::do_cancel_swap(target, hashed_proof, None)
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Cancel the swap to `target` locked by `hashed_proof` once its duration has passed and
+		/// return the reserved funds to its source. If `maybe_source` is given, it must match the
+		/// source of the swap.
+		fn do_cancel_swap(
+			target: T::AccountId,
+			hashed_proof: HashedProof,
+			maybe_source: Option<T::AccountId>,
+		) -> DispatchResult {
 			let swap = PendingSwaps::<T>::get(&target, hashed_proof).ok_or(Error::<T>::NotExist)?;
-			ensure!(swap.source == source, Error::<T>::SourceMismatch);
+			if let Some(source) = maybe_source {
+				ensure!(swap.source == source, Error::<T>::SourceMismatch);
+			}
 			ensure!(
 				frame_system::Pallet::<T>::block_number() >= swap.end_block,
 				Error::<T>::DurationNotPassed,
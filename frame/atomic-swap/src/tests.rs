@@ -4,13 +4,17 @@ use super::*;
 use crate as pallet_atomic_swap;
 
 use frame_support::{
-	parameter_types,
-	traits::{ConstU32, ConstU64},
+	assert_noop, assert_ok, parameter_types,
+	traits::{
+		tokens::{fungible, fungibles, DepositConsequence, WithdrawConsequence},
+		ConstU32, ConstU64,
+	},
 };
 use sp_core::H256;
 use sp_runtime::{
 	testing::Header,
 	traits::{BlakeTwo256, IdentityLookup},
+	DispatchError,
 };
 
 type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
@@ -145,3 +149,190 @@ fn two_party_successful_swap() {
 		assert_eq!(Balances::free_balance(B), 200 + 50);
 	});
 }
+
+#[test]
+fn claim_swap_with_sha2_and_keccak_hash_locks() {
+	new_test_ext().execute_with(|| {
+		let proof: [u8; 2] = [4, 2];
+
+		assert_ok!(AtomicSwap::create_swap(
+			Origin::signed(A),
+			B,
+			sha2_256(&proof),
+			BalanceSwapAction::new(50),
+			1000,
+		));
+		assert_ok!(AtomicSwap::create_swap(
+			Origin::signed(B),
+			A,
+			keccak_256(&proof),
+			BalanceSwapAction::new(75),
+			1000,
+		));
+
+		// A proof which doesn't match any hash lock is rejected.
+		assert_noop!(
+			AtomicSwap::claim_swap(Origin::signed(B), vec![4, 3], BalanceSwapAction::new(50)),
+			Error::<Test>::InvalidProof
+		);
+
+		assert_ok!(AtomicSwap::claim_swap(
+			Origin::signed(B),
+			proof.to_vec(),
+			BalanceSwapAction::new(50)
+		));
+		assert_ok!(AtomicSwap::claim_swap(
+			Origin::signed(A),
+			proof.to_vec(),
+			BalanceSwapAction::new(75)
+		));
+
+		assert_eq!(Balances::free_balance(A), 100 - 50 + 75);
+		assert_eq!(Balances::free_balance(B), 200 + 50 - 75);
+		assert!(PendingSwaps::<Test>::get(B, sha2_256(&proof)).is_none());
+		assert!(PendingSwaps::<Test>::get(A, keccak_256(&proof)).is_none());
+	});
+}
+
+#[test]
+fn refund_swap_by_anyone_after_duration() {
+	new_test_ext().execute_with(|| {
+		System::set_block_number(1);
+		let hashed_proof = blake2_256(&[4, 2]);
+
+		assert_ok!(AtomicSwap::create_swap(
+			Origin::signed(A),
+			B,
+			hashed_proof,
+			BalanceSwapAction::new(50),
+			10,
+		));
+		assert_eq!(Balances::free_balance(A), 100 - 50);
+
+		assert_noop!(
+			AtomicSwap::refund_swap(Origin::signed(3), B, hashed_proof),
+			Error::<Test>::DurationNotPassed
+		);
+		// Only the source may cancel a swap.
+		System::set_block_number(11);
+		assert_noop!(
+			AtomicSwap::cancel_swap(Origin::signed(3), B, hashed_proof),
+			Error::<Test>::SourceMismatch
+		);
+
+		assert_ok!(AtomicSwap::refund_swap(Origin::signed(3), B, hashed_proof));
+		assert_eq!(Balances::free_balance(A), 100);
+		assert_eq!(Balances::reserved_balance(A), 0);
+		assert!(PendingSwaps::<Test>::get(B, hashed_proof).is_none());
+		System::assert_last_event(
+			crate::Event::SwapCancelled { account: B, proof: hashed_proof }.into(),
+		);
+
+		assert_noop!(
+			AtomicSwap::refund_swap(Origin::signed(3), B, hashed_proof),
+			Error::<Test>::NotExist
+		);
+	});
+}
+
+/// Exposes `Balances` through the `fungibles` traits. The asset id is ignored.
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub struct NativeAsset;
+
+impl fungibles::Inspect<u64> for NativeAsset {
+	type AssetId = u32;
+	type Balance = u64;
+
+	fn total_issuance(_: u32) -> u64 {
+		<Balances as fungible::Inspect<u64>>::total_issuance()
+	}
+	fn minimum_balance(_: u32) -> u64 {
+		<Balances as fungible::Inspect<u64>>::minimum_balance()
+	}
+	fn balance(_: u32, who: &u64) -> u64 {
+		<Balances as fungible::Inspect<u64>>::balance(who)
+	}
+	fn reducible_balance(_: u32, who: &u64, keep_alive: bool) -> u64 {
+		<Balances as fungible::Inspect<u64>>::reducible_balance(who, keep_alive)
+	}
+	fn can_deposit(_: u32, who: &u64, amount: u64) -> DepositConsequence {
+		<Balances as fungible::Inspect<u64>>::can_deposit(who, amount)
+	}
+	fn can_withdraw(_: u32, who: &u64, amount: u64) -> WithdrawConsequence<u64> {
+		<Balances as fungible::Inspect<u64>>::can_withdraw(who, amount)
+	}
+}
+
+impl fungibles::Transfer<u64> for NativeAsset {
+	fn transfer(
+		_: u32,
+		source: &u64,
+		dest: &u64,
+		amount: u64,
+		keep_alive: bool,
+	) -> Result<u64, DispatchError> {
+		<Balances as fungible::Transfer<u64>>::transfer(source, dest, amount, keep_alive)
+	}
+}
+
+impl fungibles::InspectHold<u64> for NativeAsset {
+	fn balance_on_hold(_: u32, who: &u64) -> u64 {
+		<Balances as fungible::InspectHold<u64>>::balance_on_hold(who)
+	}
+	fn can_hold(_: u32, who: &u64, amount: u64) -> bool {
+		<Balances as fungible::InspectHold<u64>>::can_hold(who, amount)
+	}
+}
+
+impl fungibles::MutateHold<u64> for NativeAsset {
+	fn hold(_: u32, who: &u64, amount: u64) -> DispatchResult {
+		<Balances as fungible::MutateHold<u64>>::hold(who, amount)
+	}
+	fn release(_: u32, who: &u64, amount: u64, best_effort: bool) -> Result<u64, DispatchError> {
+		<Balances as fungible::MutateHold<u64>>::release(who, amount, best_effort)
+	}
+	fn transfer_held(
+		_: u32,
+		source: &u64,
+		dest: &u64,
+		amount: u64,
+		best_effort: bool,
+		on_hold: bool,
+	) -> Result<u64, DispatchError> {
+		<Balances as fungible::MutateHold<u64>>::transfer_held(
+			source,
+			dest,
+			amount,
+			best_effort,
+			on_hold,
+		)
+	}
+}
+
+#[test]
+fn fungibles_swap_action_works() {
+	new_test_ext().execute_with(|| {
+		let action = FungiblesSwapAction::<u64, NativeAsset>::new(0, 50);
+
+		assert_ok!(SwapAction::<u64, Test>::reserve(&action, &A));
+		assert_eq!(Balances::free_balance(A), 100 - 50);
+		assert_eq!(Balances::reserved_balance(A), 50);
+
+		assert!(SwapAction::<u64, Test>::claim(&action, &A, &B));
+		assert_eq!(Balances::free_balance(A), 100 - 50);
+		assert_eq!(Balances::reserved_balance(A), 0);
+		assert_eq!(Balances::free_balance(B), 200 + 50);
+
+		// Nothing is held anymore, so the action can't be claimed twice.
+		assert!(!SwapAction::<u64, Test>::claim(&action, &A, &B));
+
+		assert_ok!(SwapAction::<u64, Test>::reserve(&action, &B));
+		SwapAction::<u64, Test>::cancel(&action, &B);
+		assert_eq!(Balances::free_balance(B), 200 + 50);
+		assert_eq!(Balances::reserved_balance(B), 0);
+
+		// Holding more than the free balance fails.
+		let action = FungiblesSwapAction::<u64, NativeAsset>::new(0, 1000);
+		assert!(SwapAction::<u64, Test>::reserve(&action, &A).is_err());
+	});
+}
@@ -165,6 +165,10 @@ pub mod pallet {
 	#[pallet::storage]
 	pub(crate) type ListBags<T: Config> = StorageMap<_, Twox64Concat, VoteWeight, list::Bag<T>>;
 
+	/// Whether the order of the list is locked, see [`SortedListProvider::lock`].
+	#[pallet::storage]
+	pub(crate) type Locked<T: Config> = StorageValue<_, bool, ValueQuery>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(crate) fn deposit_event)]
 	pub enum Event<T: Config> {
@@ -181,6 +185,8 @@ pub mod pallet {
 		IdNotFound,
 		/// An Id does not have a greater vote weight than another Id.
 		NotHeavier,
+		/// The order of the list is locked.
+		Locked,
 	}
 
 	#[pallet::call]
@@ -191,11 +197,12 @@ pub mod pallet {
 		///
 		/// Anyone can call this function about any potentially dislocated account.
 		///
-		/// Will never return an error; if `dislocated` does not exist or doesn't need a rebag, then
-		/// it is a noop and fees are still collected from `origin`.
+		/// Only fails if the order of the list is locked; if `dislocated` does not exist or doesn't
+		/// need a rebag, then it is a noop and fees are still collected from `origin`.
 		#[pallet::weight(T::WeightInfo::rebag_non_terminal().max(T::WeightInfo::rebag_terminal()))]
 		pub fn rebag(origin: OriginFor<T>, dislocated: T::AccountId) -> DispatchResult {
 			ensure_signed(origin)?;
+			ensure!(!Locked::<T>::get(), Error::<T>::Locked);
 			let current_weight = T::VoteWeightProvider::vote_weight(&dislocated);
 			let _ = Pallet::<T>::do_rebag(&dislocated, current_weight);
 			Ok(())
@@ -208,10 +215,12 @@ pub mod pallet {
 		///
 		/// Only works if
 		/// - both nodes are within the same bag,
-		/// - and `origin` has a greater `VoteWeight` than `lighter`.
+		/// - and `origin` has a greater `VoteWeight` than `lighter`,
+		/// - and the order of the list is not locked.
 		#[pallet::weight(T::WeightInfo::put_in_front_of())]
 		pub fn put_in_front_of(origin: OriginFor<T>, lighter: T::AccountId) -> DispatchResult {
 			let heavier = ensure_signed(origin)?;
+			ensure!(!Locked::<T>::get(), Error::<T>::Locked);
 			List::<T>::put_in_front_of(&lighter, &heavier).map_err(Into::into)
 		}
 	}
@@ -260,6 +269,13 @@ impl<T: Config> SortedListProvider<T::AccountId> for Pallet<T> {
 		Box::new(List::<T>::iter().map(|n| n.id().clone()))
 	}
 
+	fn iter_from(
+		start: &T::AccountId,
+	) -> Result<Box<dyn Iterator<Item = T::AccountId>>, Self::Error> {
+		let iter = List::<T>::iter_from(start)?;
+		Ok(Box::new(iter.map(|n| n.id().clone())))
+	}
+
 	fn count() -> u32 {
 		ListNodes::<T>::count()
 	}
@@ -273,13 +289,24 @@ impl<T: Config> SortedListProvider<T::AccountId> for Pallet<T> {
 	}
 
 	fn on_update(id: &T::AccountId, new_weight: VoteWeight) {
-		Pallet::<T>::do_rebag(id, new_weight);
+		// while locked, the id keeps its position and can be rebagged once unlocked.
+		if !Locked::<T>::get() {
+			Pallet::<T>::do_rebag(id, new_weight);
+		}
 	}
 
 	fn on_remove(id: &T::AccountId) {
 		List::<T>::remove(id)
 	}
 
+	fn lock() {
+		Locked::<T>::put(true);
+	}
+
+	fn unlock() {
+		Locked::<T>::kill();
+	}
+
 	fn unsafe_regenerate(
 		all: impl IntoIterator<Item = T::AccountId>,
 		weight_of: Box<dyn Fn(&T::AccountId) -> VoteWeight>,
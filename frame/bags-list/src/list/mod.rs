@@ -41,6 +41,8 @@ use sp_std::{
 pub enum Error {
 	/// A duplicate id has been detected.
 	Duplicate,
+	/// The given id does not exist in the list.
+	NodeNotFound,
 }
 
 #[cfg(test)]
@@ -239,6 +241,27 @@ impl<T: Config> List<T> {
 		iter.filter_map(Bag::get).flat_map(|bag| bag.iter())
 	}
 
+	/// Same as [`Self::iter`], but starts from the node right after `start`.
+	///
+	/// The remainder of the bag of `start` is iterated first, followed by all the bags lower than
+	/// it. Returns an error if `start` is not in the list.
+	pub(crate) fn iter_from(start: &T::AccountId) -> Result<impl Iterator<Item = Node<T>>, Error> {
+		let start_node = Node::<T>::get(start).ok_or(Error::NodeNotFound)?;
+		let start_bag_upper = start_node.bag_upper;
+		let rest_of_bag = sp_std::iter::successors(start_node.next(), |prev| prev.next());
+
+		let thresholds = T::BagThresholds::get();
+		let idx = thresholds.partition_point(|&threshold| start_bag_upper > threshold);
+		let lower_bags = thresholds[..idx]
+			.iter()
+			.rev()
+			.copied()
+			.filter_map(Bag::get)
+			.flat_map(|bag| bag.iter());
+
+		Ok(rest_of_bag.chain(lower_bags))
+	}
+
 	/// Insert several ids into the appropriate bags in the list. Continues with insertions
 	/// if duplicates are detected.
 	///
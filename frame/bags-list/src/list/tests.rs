@@ -211,6 +211,46 @@ mod list {
 			})
 	}
 
+	#[test]
+	fn iter_from_works() {
+		ExtBuilder::default()
+			.add_ids(vec![(5, 2_000), (6, 2_000)])
+			.build_and_execute(|| {
+				// given
+				assert_eq!(
+					List::<Runtime>::get_bags(),
+					vec![(10, vec![1]), (1_000, vec![2, 3, 4]), (2_000, vec![5, 6])]
+				);
+
+				// when starting from the middle of a bag
+				let iteration = List::<Runtime>::iter_from(&2)
+					.unwrap()
+					.map(|node| *node.id())
+					.collect::<Vec<_>>();
+
+				// then the rest of the bag and all lower bags are iterated
+				assert_eq!(iteration, vec![3, 4, 1]);
+
+				// when starting from the tail of a bag
+				let iteration = List::<Runtime>::iter_from(&6)
+					.unwrap()
+					.map(|node| *node.id())
+					.collect::<Vec<_>>();
+
+				// then
+				assert_eq!(iteration, vec![2, 3, 4, 1]);
+
+				// when starting from the last node
+				assert_eq!(List::<Runtime>::iter_from(&1).unwrap().count(), 0);
+
+				// when starting from a non-existent node
+				assert_eq!(
+					List::<Runtime>::iter_from(&42).map(|_| ()).unwrap_err(),
+					Error::NodeNotFound
+				);
+			})
+	}
+
 	#[test]
 	fn insert_works() {
 		ExtBuilder::default().build_and_execute(|| {
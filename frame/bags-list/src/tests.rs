@@ -582,6 +582,51 @@ mod sorted_list_provider {
 		});
 	}
 
+	#[test]
+	fn lock_keeps_positions() {
+		ExtBuilder::default().build_and_execute(|| {
+			// given
+			assert_eq!(List::<Runtime>::get_bags(), vec![(10, vec![1]), (1_000, vec![2, 3, 4])]);
+
+			// when the list is locked
+			BagsList::lock();
+
+			// then an update does not move the id,
+			BagsList::on_update(&2, 10);
+			assert_eq!(List::<Runtime>::get_bags(), vec![(10, vec![1]), (1_000, vec![2, 3, 4])]);
+
+			// the ids cannot be moved by their owners,
+			StakingMock::set_vote_weight_of(&2, 10);
+			assert_noop!(
+				BagsList::rebag(Origin::signed(0), 2),
+				crate::pallet::Error::<Runtime>::Locked
+			);
+			StakingMock::set_vote_weight_of(&4, 2_000);
+			assert_noop!(
+				BagsList::put_in_front_of(Origin::signed(4), 3),
+				crate::pallet::Error::<Runtime>::Locked
+			);
+
+			// but ids can still be inserted and removed.
+			assert_ok!(BagsList::on_insert(42, 20));
+			BagsList::on_remove(&3);
+			assert_eq!(
+				List::<Runtime>::get_bags(),
+				vec![(10, vec![1]), (20, vec![42]), (1_000, vec![2, 4])]
+			);
+
+			// when the list is unlocked
+			BagsList::unlock();
+
+			// then the ids can be moved again.
+			assert_ok!(BagsList::rebag(Origin::signed(0), 2));
+			assert_eq!(
+				List::<Runtime>::get_bags(),
+				vec![(10, vec![1, 2]), (20, vec![42]), (1_000, vec![4])]
+			);
+		});
+	}
+
 	#[test]
 	fn on_remove_works() {
 		let ensure_left = |id, counter| {
@@ -43,11 +43,12 @@ use sp_rpc::number::NumberOrHex;
 	feature = "std",
 	serde(
 		rename_all = "camelCase",
-		bound(serialize = "R: Serialize, Balance: Copy + Into<NumberOrHex>"),
-		bound(deserialize = "R: Deserialize<'de>, Balance: TryFrom<NumberOrHex>")
+		bound(serialize = "R: Serialize, AccountId: Serialize, Balance: Copy + Into<NumberOrHex>"),
+		bound(deserialize = "R: Deserialize<'de>, AccountId: Deserialize<'de>, \
+				Balance: TryFrom<NumberOrHex>")
 	)
 )]
-pub struct ContractResult<R, Balance> {
+pub struct ContractResult<R, AccountId, Balance> {
 	/// How much gas was consumed during execution.
 	pub gas_consumed: u64,
 	/// How much gas is required as gas limit in order to execute this call.
@@ -82,17 +83,82 @@ pub struct ContractResult<R, Balance> {
 	/// RPC calls.
 	#[cfg_attr(feature = "std", serde(with = "as_string"))]
 	pub debug_message: Vec<u8>,
+	/// The events that were emitted during execution.
+	///
+	/// Each event is a SCALE encoded `frame_system::EventRecord` of the runtime. This is only
+	/// collected when explicitly requested by the code that calls into the contract. Otherwise
+	/// it is `None`.
+	///
+	/// # Note
+	///
+	/// The events are never collected during on-chain execution. They are reserved for
+	/// RPC calls.
+	pub events: Option<Vec<Bytes>>,
+	/// A tree of all the calls and instantiations that happened during execution.
+	///
+	/// The root of the tree is the call or instantiation that was requested. It is only
+	/// collected when explicitly requested by the code that calls into the contract. Otherwise
+	/// it is `None`.
+	///
+	/// # Note
+	///
+	/// The trace is never collected during on-chain execution. It is reserved for RPC calls.
+	pub call_trace: Option<CallTrace<AccountId, Balance>>,
 	/// The execution result of the wasm code.
 	pub result: R,
 }
 
 /// Result type of a `bare_call` call.
-pub type ContractExecResult<Balance> =
-	ContractResult<Result<ExecReturnValue, DispatchError>, Balance>;
+pub type ContractExecResult<AccountId, Balance> =
+	ContractResult<Result<ExecReturnValue, DispatchError>, AccountId, Balance>;
 
 /// Result type of a `bare_instantiate` call.
 pub type ContractInstantiateResult<AccountId, Balance> =
-	ContractResult<Result<InstantiateReturnValue<AccountId>, DispatchError>, Balance>;
+	ContractResult<Result<InstantiateReturnValue<AccountId>, DispatchError>, AccountId, Balance>;
+
+/// [`ContractResult`] as returned by version 1 of the contracts runtime API.
+///
+/// It lacks the events and the call trace, which are `None` when converted into a
+/// [`ContractResult`].
+#[derive(Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+pub struct ContractResultV1<R, Balance> {
+	/// See [`ContractResult::gas_consumed`].
+	pub gas_consumed: u64,
+	/// See [`ContractResult::gas_required`].
+	pub gas_required: u64,
+	/// See [`ContractResult::storage_deposit`].
+	pub storage_deposit: StorageDeposit<Balance>,
+	/// See [`ContractResult::debug_message`].
+	pub debug_message: Vec<u8>,
+	/// See [`ContractResult::result`].
+	pub result: R,
+}
+
+impl<R, AccountId, Balance> From<ContractResultV1<R, Balance>>
+	for ContractResult<R, AccountId, Balance>
+{
+	fn from(result: ContractResultV1<R, Balance>) -> Self {
+		let ContractResultV1 { gas_consumed, gas_required, storage_deposit, debug_message, result } =
+			result;
+		Self {
+			gas_consumed,
+			gas_required,
+			storage_deposit,
+			debug_message,
+			events: None,
+			call_trace: None,
+			result,
+		}
+	}
+}
+
+/// Result type of a `bare_call` call in version 1 of the contracts runtime API.
+pub type ContractExecResultV1<Balance> =
+	ContractResultV1<Result<ExecReturnValue, DispatchError>, Balance>;
+
+/// Result type of a `bare_instantiate` call in version 1 of the contracts runtime API.
+pub type ContractInstantiateResultV1<AccountId, Balance> =
+	ContractResultV1<Result<InstantiateReturnValue<AccountId>, DispatchError>, Balance>;
 
 /// Result type of a `bare_code_upload` call.
 pub type CodeUploadResult<CodeHash, Balance> =
@@ -101,6 +167,22 @@ pub type CodeUploadResult<CodeHash, Balance> =
 /// Result type of a `get_storage` call.
 pub type GetStorageResult = Result<Option<Vec<u8>>, ContractAccessError>;
 
+/// The result of querying the storage keys of a contract.
+///
+/// The keys are the `blake2_256` hashes of the keys supplied by the contract.
+pub type GetStorageKeysResult = Result<Vec<[u8; 32]>, ContractAccessError>;
+
+/// An event that was deposited by a contract via `seal_deposit_event`.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug)]
+pub struct ContractEvent<AccountId, Hash> {
+	/// The contract that emitted the event.
+	pub contract: AccountId,
+	/// The topics the event was deposited with.
+	pub topics: Vec<Hash>,
+	/// Data supplied by the contract.
+	pub data: Vec<u8>,
+}
+
 /// The possible errors that can happen querying the storage of a contract.
 #[derive(Eq, PartialEq, Encode, Decode, RuntimeDebug)]
 pub enum ContractAccessError {
@@ -120,7 +202,7 @@ bitflags! {
 }
 
 /// Output of a contract call or instantiation which ran to completion.
-#[derive(PartialEq, Eq, Encode, Decode, RuntimeDebug)]
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
 #[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
 pub struct ExecReturnValue {
@@ -148,6 +230,60 @@ pub struct InstantiateReturnValue<AccountId> {
 	pub account_id: AccountId,
 }
 
+/// The kind of frame a [`CallTrace`] was recorded for.
+#[derive(Clone, Copy, PartialEq, Eq, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(feature = "std", serde(rename_all = "camelCase"))]
+pub enum CallKind {
+	/// A regular call into a contract.
+	Call,
+	/// A call which executes foreign code in the context of the calling contract.
+	DelegateCall,
+	/// The instantiation of a new contract.
+	Instantiate,
+}
+
+/// A single contract call or instantiation which was executed.
+///
+/// Nested calls and instantiations issued by the executing contract are contained in
+/// [`Self::calls`] which makes this type a tree of all the frames of an execution.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+#[cfg_attr(
+	feature = "std",
+	serde(
+		rename_all = "camelCase",
+		bound(serialize = "AccountId: Serialize, Balance: Copy + Into<NumberOrHex>"),
+		bound(deserialize = "AccountId: Deserialize<'de>, Balance: TryFrom<NumberOrHex>")
+	)
+)]
+pub struct CallTrace<AccountId, Balance> {
+	/// Whether this frame was a call, delegate call or instantiation.
+	pub kind: CallKind,
+	/// The account which issued the call or instantiation.
+	///
+	/// For a delegate call this is the caller of the contract that issued it.
+	pub caller: AccountId,
+	/// The contract which was called or instantiated.
+	///
+	/// For a delegate call this is the contract in whose context the foreign code was executed.
+	pub contract: AccountId,
+	/// The value that was transferred to [`Self::contract`].
+	#[cfg_attr(feature = "std", serde(with = "as_hex"))]
+	pub value: Balance,
+	/// The input data passed to the contract.
+	pub input: Bytes,
+	/// How much gas was consumed by this frame including all of its nested frames.
+	pub gas_consumed: u64,
+	/// The names of the host functions invoked by this frame in the order they were called.
+	#[cfg_attr(feature = "std", serde(with = "as_strings"))]
+	pub host_functions: Vec<Vec<u8>>,
+	/// The output of the contract or the error which caused this frame to fail.
+	pub result: Result<ExecReturnValue, DispatchError>,
+	/// The calls and instantiations issued by this frame.
+	pub calls: Vec<CallTrace<AccountId, Balance>>,
+}
+
 /// The result of succesfully uploading a contract.
 #[derive(PartialEq, Eq, Encode, Decode, RuntimeDebug)]
 #[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
@@ -310,6 +446,33 @@ mod as_string {
 	}
 }
 
+#[cfg(feature = "std")]
+mod as_strings {
+	use super::*;
+	use serde::{ser::Error, Deserializer, Serializer};
+
+	pub fn serialize<S: Serializer>(
+		strings: &Vec<Vec<u8>>,
+		serializer: S,
+	) -> Result<S::Ok, S::Error> {
+		strings
+			.iter()
+			.map(|bytes| std::str::from_utf8(bytes))
+			.collect::<Result<Vec<_>, _>>()
+			.map_err(|e| S::Error::custom(format!("Name contains invalid UTF8: {}", e)))?
+			.serialize(serializer)
+	}
+
+	pub fn deserialize<'de, D: Deserializer<'de>>(
+		deserializer: D,
+	) -> Result<Vec<Vec<u8>>, D::Error> {
+		Ok(Vec::<String>::deserialize(deserializer)?
+			.into_iter()
+			.map(String::into_bytes)
+			.collect())
+	}
+}
+
 #[cfg(feature = "std")]
 mod as_hex {
 	use super::*;
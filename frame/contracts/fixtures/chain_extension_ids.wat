@@ -0,0 +1,38 @@
+;; Call the chain extension addressed by the func_id in the first four bytes of the input
+;; and pass the remaining input through.
+(module
+	(import "seal0" "seal_call_chain_extension"
+		(func $seal_call_chain_extension (param i32 i32 i32 i32 i32) (result i32))
+	)
+	(import "seal0" "seal_input" (func $seal_input (param i32 i32)))
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "env" "memory" (memory 16 16))
+
+	;; [0, 4) len of input buffer
+	(data (i32.const 0) "\08")
+
+	;; [4, 12) buffer for input
+
+	;; [12, 16) len of output buffer
+	(data (i32.const 12) "\04")
+
+	;; [16, inf) buffer for output
+
+	(func (export "deploy"))
+
+	(func (export "call")
+		(call $seal_input (i32.const 4) (i32.const 0))
+
+		(drop
+			(call $seal_call_chain_extension
+				(i32.load (i32.const 4))							;; func_id
+				(i32.const 8)										;; input_ptr
+				(i32.sub (i32.load (i32.const 0)) (i32.const 4))	;; input_len
+				(i32.const 16)										;; output_ptr
+				(i32.const 12)										;; output_len_ptr
+			)
+		)
+
+		(call $seal_return (i32.const 0) (i32.const 16) (i32.load (i32.const 12)))
+	)
+)
@@ -0,0 +1,40 @@
+;; Delegate calls into the code hash passed as input and asserts that the call succeeded.
+(module
+	(import "seal0" "seal_input" (func $seal_input (param i32 i32)))
+	(import "__unstable__" "seal_delegate_call" (func $seal_delegate_call (param i32 i32 i32 i32 i32 i32) (result i32)))
+	(import "env" "memory" (memory 1 1))
+
+	;; [0, 32) buffer where the input is copied
+
+	;; [32, 36) size of the input buffer
+	(data (i32.const 32) "\20")
+
+	(func $assert (param i32)
+		(block $ok
+			(br_if $ok
+				(get_local 0)
+			)
+			(unreachable)
+		)
+	)
+
+	(func (export "call")
+		;; Receive the code hash to delegate to.
+		(call $seal_input (i32.const 0) (i32.const 32))
+		(call $assert
+			(i32.eq
+				(call $seal_delegate_call
+					(i32.const 0)			;; Set no call flags
+					(i32.const 0)			;; Pointer to the code hash
+					(i32.const 0)			;; Input is ignored
+					(i32.const 0)			;; Length of the input
+					(i32.const 4294967295)	;; u32 max sentinel value: do not copy output
+					(i32.const 0)			;; Length is ignored in this case
+				)
+				(i32.const 0) ;; ReturnCode::Success
+			)
+		)
+	)
+
+	(func (export "deploy"))
+)
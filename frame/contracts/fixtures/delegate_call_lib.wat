@@ -0,0 +1,61 @@
+;; Stores the context it is executed in: `value_transferred` at key 0x01..,
+;; `caller` at key 0x02.. and `address` at key 0x03...
+(module
+	(import "seal0" "seal_set_storage" (func $seal_set_storage (param i32 i32 i32)))
+	(import "seal0" "seal_value_transferred" (func $seal_value_transferred (param i32 i32)))
+	(import "seal0" "seal_caller" (func $seal_caller (param i32 i32)))
+	(import "seal0" "seal_address" (func $seal_address (param i32 i32)))
+	(import "env" "memory" (memory 1 1))
+
+	;; [0, 32) storage key 0x01..
+	(data (i32.const 0)
+		"\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01"
+		"\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01"
+	)
+
+	;; [32, 64) storage key 0x02..
+	(data (i32.const 32)
+		"\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02"
+		"\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02"
+	)
+
+	;; [64, 96) storage key 0x03..
+	(data (i32.const 64)
+		"\03\03\03\03\03\03\03\03\03\03\03\03\03\03\03\03"
+		"\03\03\03\03\03\03\03\03\03\03\03\03\03\03\03\03"
+	)
+
+	;; [96, 100) size of the output buffer
+	(data (i32.const 96) "\20")
+
+	;; [100, 132) output buffer
+
+	(func (export "call")
+		(call $seal_value_transferred (i32.const 100) (i32.const 96))
+		(call $seal_set_storage
+			(i32.const 0)				;; Pointer to storage key
+			(i32.const 100)				;; Pointer to value
+			(i32.load (i32.const 96))	;; Size of value
+		)
+
+		;; Reset the output buffer size.
+		(i32.store (i32.const 96) (i32.const 32))
+		(call $seal_caller (i32.const 100) (i32.const 96))
+		(call $seal_set_storage
+			(i32.const 32)				;; Pointer to storage key
+			(i32.const 100)				;; Pointer to value
+			(i32.load (i32.const 96))	;; Size of value
+		)
+
+		;; Reset the output buffer size.
+		(i32.store (i32.const 96) (i32.const 32))
+		(call $seal_address (i32.const 100) (i32.const 96))
+		(call $seal_set_storage
+			(i32.const 64)				;; Pointer to storage key
+			(i32.const 100)				;; Pointer to value
+			(i32.load (i32.const 96))	;; Size of value
+		)
+	)
+
+	(func (export "deploy"))
+)
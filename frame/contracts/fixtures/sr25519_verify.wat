@@ -0,0 +1,37 @@
+;; This contract:
+;; 1) Reads signature, public key and message from the input
+;; 2) Calls sr25519_verify
+;; 3) Returns the result code of the verification
+(module
+	(import "__unstable__" "seal_sr25519_verify" (func $seal_sr25519_verify (param i32 i32 i32 i32) (result i32)))
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "seal0" "seal_input" (func $seal_input (param i32 i32)))
+	(import "env" "memory" (memory 1 1))
+
+	(func (export "deploy"))
+
+	;; [4, 8) len of signature + public key + message - 64 + 32 + 11 = 107 bytes
+	(data (i32.const 4) "\6b")
+
+	;; Memory layout during `call`
+	;; [10, 74) signature
+	;; [74, 106) public key
+	;; [106, 117) message
+	;; [0, 4) result code
+	(func (export "call")
+		;; Read signature, public key and message - 107 bytes
+		(call $seal_input (i32.const 10) (i32.const 4))
+		(i32.store
+			(i32.const 0)
+			(call $seal_sr25519_verify
+				(i32.const 10) ;; signature_ptr
+				(i32.const 74) ;; pub_key_ptr
+				(i32.const 11) ;; message_len
+				(i32.const 106) ;; message_ptr
+			)
+		)
+
+		;; exit with success and return the result code
+		(call $seal_return (i32.const 0) (i32.const 0) (i32.const 4))
+	)
+)
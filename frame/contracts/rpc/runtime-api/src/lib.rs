@@ -25,12 +25,14 @@
 
 use codec::Codec;
 use pallet_contracts_primitives::{
-	Code, CodeUploadResult, ContractExecResult, ContractInstantiateResult, GetStorageResult,
+	Code, CodeUploadResult, ContractEvent, ContractExecResult, ContractExecResultV1,
+	ContractInstantiateResult, ContractInstantiateResultV1, GetStorageKeysResult, GetStorageResult,
 };
 use sp_std::vec::Vec;
 
 sp_api::decl_runtime_apis! {
 	/// The API to interact with contracts without using executive.
+	#[api_version(5)]
 	pub trait ContractsApi<AccountId, Balance, BlockNumber, Hash> where
 		AccountId: Codec,
 		Balance: Codec,
@@ -38,6 +40,19 @@ sp_api::decl_runtime_apis! {
 		Hash: Codec,
 	{
 		/// Perform a call from a specified account to a given contract.
+		#[changed_in(2)]
+		fn call(
+			origin: AccountId,
+			dest: AccountId,
+			value: Balance,
+			gas_limit: u64,
+			storage_deposit_limit: Option<Balance>,
+			input_data: Vec<u8>,
+		) -> ContractExecResultV1<Balance>;
+
+		/// Perform a call from a specified account to a given contract.
+		///
+		/// The result contains the emitted events and a trace of all nested calls.
 		///
 		/// See `pallet_contracts::Pallet::call`.
 		fn call(
@@ -47,10 +62,24 @@ sp_api::decl_runtime_apis! {
 			gas_limit: u64,
 			storage_deposit_limit: Option<Balance>,
 			input_data: Vec<u8>,
-		) -> ContractExecResult<Balance>;
+		) -> ContractExecResult<AccountId, Balance>;
+
+		/// Instantiate a new contract.
+		#[changed_in(2)]
+		fn instantiate(
+			origin: AccountId,
+			value: Balance,
+			gas_limit: u64,
+			storage_deposit_limit: Option<Balance>,
+			code: Code<Hash>,
+			data: Vec<u8>,
+			salt: Vec<u8>,
+		) -> ContractInstantiateResultV1<AccountId, Balance>;
 
 		/// Instantiate a new contract.
 		///
+		/// The result contains the emitted events and a trace of all nested calls.
+		///
 		/// See `pallet_contracts::Pallet::instantiate`.
 		fn instantiate(
 			origin: AccountId,
@@ -81,5 +110,31 @@ sp_api::decl_runtime_apis! {
 			address: AccountId,
 			key: [u8; 32],
 		) -> GetStorageResult;
+
+		/// Query the hashed storage keys of a given contract.
+		///
+		/// Returns up to `limit` keys that follow `start_key`. If the account specified by the
+		/// address doesn't exist, or doesn't have a contract then `Err` is returned.
+		///
+		/// See `pallet_contracts::Pallet::get_storage_keys`.
+		fn get_storage_keys(
+			address: AccountId,
+			start_key: Option<[u8; 32]>,
+			limit: u32,
+		) -> GetStorageKeysResult;
+
+		/// Returns all events that were deposited by contracts in this block.
+		///
+		/// See `pallet_contracts::Pallet::contract_events`.
+		fn contract_events() -> Vec<ContractEvent<AccountId, Hash>>;
+
+		/// Returns the address a contract would be instantiated at.
+		///
+		/// See `pallet_contracts::Pallet::contract_address`.
+		fn predict_address(
+			deployer: AccountId,
+			code_hash: Hash,
+			salt: Vec<u8>,
+		) -> AccountId;
 	}
 }
@@ -0,0 +1,333 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! An optional node side index of the events deposited by contracts.
+//!
+//! The index is fed by block import and allows to look up the events of a contract by topic
+//! within a range of blocks. It only covers blocks that were imported while the index was
+//! running and only keeps the events of a bounded number of the most recent blocks. Blocks
+//! imported during the major sync or from a file do not trigger import notifications and are
+//! hence not indexed.
+
+use crate::ContractsRuntimeApi;
+use codec::{Codec, Encode};
+use futures::{future::ready, StreamExt};
+use parking_lot::RwLock;
+use sc_client_api::BlockchainEvents;
+use sp_api::ProvideRuntimeApi;
+use sp_runtime::{
+	generic::BlockId,
+	traits::{Block as BlockT, Header as HeaderT, NumberFor, Saturating},
+};
+use std::{
+	collections::{BTreeMap, BTreeSet, HashMap},
+	sync::Arc,
+};
+
+const LOG_TARGET: &str = "contracts-events-index";
+
+/// The position of an event in the index: block number, block hash and index of the event.
+type EventLocation<Block> = (NumberFor<Block>, <Block as BlockT>::Hash, u32);
+
+/// An event deposited by a contract.
+///
+/// The contract and the topics are kept SCALE encoded so that the index does not need to
+/// know about the concrete types used by the runtime.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct IndexedEvent {
+	/// The encoded address of the contract that emitted the event.
+	pub contract: Vec<u8>,
+	/// The encoded topics the event was deposited with.
+	pub topics: Vec<Vec<u8>>,
+	/// Data supplied by the contract.
+	pub data: Vec<u8>,
+}
+
+/// An event returned by [`ContractEventsIndex::query`].
+pub struct IndexedEventRecord<Block: BlockT> {
+	/// The number of the block the event was deposited in.
+	pub block_number: NumberFor<Block>,
+	/// The hash of the block the event was deposited in.
+	pub block_hash: Block::Hash,
+	/// The index of the event among all contract events of the block.
+	pub event_index: u32,
+	/// The event itself.
+	pub event: IndexedEvent,
+}
+
+struct Inner<Block: BlockT> {
+	/// The events of each indexed block.
+	///
+	/// There can be multiple blocks with the same number because of forks.
+	blocks: BTreeMap<NumberFor<Block>, HashMap<Block::Hash, Vec<IndexedEvent>>>,
+	/// The locations of all events by emitting contract.
+	by_contract: HashMap<Vec<u8>, BTreeSet<EventLocation<Block>>>,
+	/// The locations of all events by emitting contract and topic.
+	by_topic: HashMap<(Vec<u8>, Vec<u8>), BTreeSet<EventLocation<Block>>>,
+}
+
+/// An in-memory index of the events deposited by contracts.
+pub struct ContractEventsIndex<Block: BlockT> {
+	/// The number of most recent blocks whose events are kept.
+	max_blocks: u32,
+	inner: RwLock<Inner<Block>>,
+}
+
+impl<Block: BlockT> ContractEventsIndex<Block> {
+	/// Create an empty index which keeps the events of the `max_blocks` most recent blocks.
+	pub fn new(max_blocks: u32) -> Self {
+		Self {
+			max_blocks,
+			inner: RwLock::new(Inner {
+				blocks: Default::default(),
+				by_contract: Default::default(),
+				by_topic: Default::default(),
+			}),
+		}
+	}
+
+	/// Add the events of a block to the index.
+	///
+	/// Blocks that fall out of the retention window are removed from the index.
+	pub fn insert_block(
+		&self,
+		number: NumberFor<Block>,
+		hash: Block::Hash,
+		events: Vec<IndexedEvent>,
+	) {
+		let mut inner = self.inner.write();
+		for (index, event) in events.iter().enumerate() {
+			let location = (number, hash, index as u32);
+			inner.by_contract.entry(event.contract.clone()).or_default().insert(location);
+			for topic in &event.topics {
+				inner
+					.by_topic
+					.entry((event.contract.clone(), topic.clone()))
+					.or_default()
+					.insert(location);
+			}
+		}
+		inner.blocks.entry(number).or_default().insert(hash, events);
+
+		let latest = inner.blocks.keys().next_back().copied().unwrap_or(number);
+		while let Some(first) = inner.blocks.keys().next().copied() {
+			if first.saturating_add(self.max_blocks.into()) > latest {
+				break
+			}
+			let removed = inner.blocks.remove(&first).unwrap_or_default();
+			for (hash, events) in removed {
+				for (index, event) in events.into_iter().enumerate() {
+					let location = (first, hash, index as u32);
+					remove_location(&mut inner.by_contract, event.contract.clone(), &location);
+					for topic in event.topics {
+						remove_location(
+							&mut inner.by_topic,
+							(event.contract.clone(), topic),
+							&location,
+						);
+					}
+				}
+			}
+		}
+	}
+
+	/// Returns the events of `contract` that were deposited with all of the given `topics`
+	/// within the blocks `from` to `to` (inclusive).
+	///
+	/// Only events of blocks for which `is_canonical` returns `true` are returned. The events
+	/// are ordered by block number and event index and at most `limit` events are returned.
+	pub fn query(
+		&self,
+		contract: &[u8],
+		topics: &[Vec<u8>],
+		from: NumberFor<Block>,
+		to: NumberFor<Block>,
+		limit: usize,
+		is_canonical: impl Fn(NumberFor<Block>, &Block::Hash) -> bool,
+	) -> Vec<IndexedEventRecord<Block>> {
+		let inner = self.inner.read();
+		let locations = match topics.first() {
+			Some(topic) => inner.by_topic.get(&(contract.to_vec(), topic.clone())),
+			None => inner.by_contract.get(contract),
+		};
+		let locations = match locations {
+			Some(locations) => locations,
+			None => return Vec::new(),
+		};
+		locations
+			.iter()
+			.skip_while(|(number, _, _)| *number < from)
+			.take_while(|(number, _, _)| *number <= to)
+			.filter(|(number, hash, _)| is_canonical(*number, hash))
+			.filter_map(|(number, hash, index)| {
+				let event = inner.blocks.get(number)?.get(hash)?.get(*index as usize)?;
+				if !topics.iter().all(|topic| event.topics.contains(topic)) {
+					return None
+				}
+				Some(IndexedEventRecord {
+					block_number: *number,
+					block_hash: *hash,
+					event_index: *index,
+					event: event.clone(),
+				})
+			})
+			.take(limit)
+			.collect()
+	}
+}
+
+fn remove_location<K: Eq + std::hash::Hash, L: Ord>(
+	map: &mut HashMap<K, BTreeSet<L>>,
+	key: K,
+	location: &L,
+) {
+	if let Some(locations) = map.get_mut(&key) {
+		locations.remove(location);
+		if locations.is_empty() {
+			map.remove(&key);
+		}
+	}
+}
+
+/// Feed the contract events of every imported block into `index`.
+///
+/// The events are queried through the [`ContractsRuntimeApi`]. This future only completes
+/// when the import notification stream of the client ends and is meant to be spawned as
+/// a task of the node.
+pub async fn index_contract_events<C, Block, AccountId, Balance, Hash>(
+	client: Arc<C>,
+	index: Arc<ContractEventsIndex<Block>>,
+) where
+	Block: BlockT,
+	C: ProvideRuntimeApi<Block> + BlockchainEvents<Block> + Send + Sync + 'static,
+	C::Api: ContractsRuntimeApi<Block, AccountId, Balance, NumberFor<Block>, Hash>,
+	AccountId: Codec,
+	Balance: Codec,
+	Hash: Codec,
+{
+	client
+		.import_notification_stream()
+		.for_each(move |notification| {
+			let number = *notification.header.number();
+			let at = BlockId::hash(notification.hash);
+			match client.runtime_api().contract_events(&at) {
+				Ok(events) => {
+					let events = events
+						.into_iter()
+						.map(|event| IndexedEvent {
+							contract: event.contract.encode(),
+							topics: event.topics.iter().map(Encode::encode).collect(),
+							data: event.data,
+						})
+						.collect();
+					index.insert_block(number, notification.hash, events);
+				},
+				Err(err) => log::debug!(
+					target: LOG_TARGET,
+					"Failed to query the contract events of block {:?}: {:?}",
+					notification.hash,
+					err,
+				),
+			}
+			ready(())
+		})
+		.await
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use sp_core::H256;
+	use sp_runtime::testing::{Block as RawBlock, ExtrinsicWrapper};
+
+	type Block = RawBlock<ExtrinsicWrapper<u64>>;
+
+	fn event(contract: u8, topics: &[u8]) -> IndexedEvent {
+		IndexedEvent {
+			contract: vec![contract],
+			topics: topics.iter().map(|topic| vec![*topic]).collect(),
+			data: vec![],
+		}
+	}
+
+	fn query(
+		index: &ContractEventsIndex<Block>,
+		contract: u8,
+		topics: &[u8],
+		from: u64,
+		to: u64,
+	) -> Vec<(u64, u32)> {
+		let topics: Vec<_> = topics.iter().map(|topic| vec![*topic]).collect();
+		index
+			.query(&[contract], &topics, from, to, usize::MAX, |_, hash| {
+				*hash != H256::repeat_byte(0xff)
+			})
+			.into_iter()
+			.map(|record| (record.block_number, record.event_index))
+			.collect()
+	}
+
+	#[test]
+	fn query_filters_by_contract_topics_and_range() {
+		let index = ContractEventsIndex::<Block>::new(100);
+		index.insert_block(1, H256::repeat_byte(1), vec![event(1, &[1, 2]), event(2, &[1])]);
+		index.insert_block(2, H256::repeat_byte(2), vec![event(1, &[2])]);
+		index.insert_block(3, H256::repeat_byte(3), vec![event(2, &[]), event(1, &[1])]);
+
+		assert_eq!(query(&index, 1, &[], 0, 10), vec![(1, 0), (2, 0), (3, 1)]);
+		assert_eq!(query(&index, 1, &[1], 0, 10), vec![(1, 0), (3, 1)]);
+		assert_eq!(query(&index, 1, &[1, 2], 0, 10), vec![(1, 0)]);
+		assert_eq!(query(&index, 1, &[], 2, 3), vec![(2, 0), (3, 1)]);
+		assert_eq!(query(&index, 2, &[], 0, 10), vec![(1, 1), (3, 0)]);
+		assert_eq!(query(&index, 3, &[], 0, 10), vec![]);
+	}
+
+	#[test]
+	fn query_skips_non_canonical_blocks() {
+		let index = ContractEventsIndex::<Block>::new(100);
+		index.insert_block(1, H256::repeat_byte(1), vec![event(1, &[])]);
+		index.insert_block(1, H256::repeat_byte(0xff), vec![event(1, &[])]);
+
+		let records =
+			index.query(&[1], &[], 0, 10, usize::MAX, |_, hash| *hash != H256::repeat_byte(0xff));
+		assert_eq!(records.len(), 1);
+		assert_eq!(records[0].block_hash, H256::repeat_byte(1));
+	}
+
+	#[test]
+	fn query_returns_at_most_limit_events() {
+		let index = ContractEventsIndex::<Block>::new(100);
+		for number in 1..=5 {
+			index.insert_block(number, H256::repeat_byte(number as u8), vec![event(1, &[1])]);
+		}
+
+		let records = index.query(&[1], &[], 0, 10, 2, |_, _| true);
+		let numbers: Vec<_> = records.into_iter().map(|record| record.block_number).collect();
+		assert_eq!(numbers, vec![1, 2]);
+	}
+
+	#[test]
+	fn old_blocks_are_pruned() {
+		let index = ContractEventsIndex::<Block>::new(2);
+		for number in 1..=5 {
+			index.insert_block(number, H256::repeat_byte(number as u8), vec![event(1, &[1])]);
+		}
+
+		assert_eq!(query(&index, 1, &[], 0, 10), vec![(4, 0), (5, 0)]);
+		assert_eq!(query(&index, 1, &[1], 0, 10), vec![(4, 0), (5, 0)]);
+	}
+}
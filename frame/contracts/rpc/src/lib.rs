@@ -17,28 +17,32 @@
 
 //! Node-specific RPC methods for interaction with contracts.
 
+mod events;
+
 use std::sync::Arc;
 
-use codec::Codec;
+use codec::{Codec, Decode, Encode};
 use jsonrpc_core::{Error, ErrorCode, Result};
 use jsonrpc_derive::rpc;
 use pallet_contracts_primitives::{
 	Code, CodeUploadResult, ContractExecResult, ContractInstantiateResult,
 };
 use serde::{Deserialize, Serialize};
-use sp_api::ProvideRuntimeApi;
+use sp_api::{ApiExt, ProvideRuntimeApi};
 use sp_blockchain::HeaderBackend;
 use sp_core::{Bytes, H256};
 use sp_rpc::number::NumberOrHex;
 use sp_runtime::{
 	generic::BlockId,
-	traits::{Block as BlockT, Header as HeaderT},
+	traits::{AtLeast32BitUnsigned, Block as BlockT, Header as HeaderT},
 };
 
+pub use events::{index_contract_events, ContractEventsIndex, IndexedEvent, IndexedEventRecord};
 pub use pallet_contracts_rpc_runtime_api::ContractsApi as ContractsRuntimeApi;
 
 const RUNTIME_ERROR: i64 = 1;
 const CONTRACT_DOESNT_EXIST: i64 = 2;
+const EVENTS_INDEX_DISABLED: i64 = 3;
 
 pub type Weight = u64;
 
@@ -56,6 +60,15 @@ const GAS_PER_SECOND: Weight = 1_000_000_000_000;
 /// This puts a ceiling on the weight limit that is supplied to the rpc as an argument.
 const GAS_LIMIT: Weight = 5 * GAS_PER_SECOND;
 
+/// The maximum number of storage keys that can be requested by a single `getStorageKeys` call.
+const STORAGE_KEYS_LIMIT: u32 = 1000;
+
+/// The maximum number of blocks that can be searched by a single `getEvents` call.
+const EVENTS_BLOCKS_LIMIT: u32 = 1000;
+
+/// The maximum number of events that can be returned by a single `getEvents` call.
+const EVENTS_LIMIT: usize = 1000;
+
 /// A private newtype for converting `ContractAccessError` into an RPC error.
 struct ContractAccessError(pallet_contracts_primitives::ContractAccessError);
 impl From<ContractAccessError> for Error {
@@ -108,6 +121,25 @@ pub struct CodeUploadRequest<AccountId> {
 	storage_deposit_limit: Option<NumberOrHex>,
 }
 
+/// An event deposited by a contract as returned by the `contracts_getEvents` RPC.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+#[serde(deny_unknown_fields)]
+pub struct ContractEventRecord<BlockHash, BlockNumber, AccountId, Hash> {
+	/// The hash of the block the event was deposited in.
+	pub block_hash: BlockHash,
+	/// The number of the block the event was deposited in.
+	pub block_number: BlockNumber,
+	/// The index of the event among all contract events of the block.
+	pub event_index: u32,
+	/// The contract that emitted the event.
+	pub contract: AccountId,
+	/// The topics the event was deposited with.
+	pub topics: Vec<Hash>,
+	/// Data supplied by the contract.
+	pub data: Bytes,
+}
+
 /// Contracts RPC methods.
 #[rpc]
 pub trait ContractsApi<BlockHash, BlockNumber, AccountId, Balance, Hash>
@@ -120,20 +152,22 @@ where
 	/// won't change any state. Nonetheless, the calling state-changing contracts is still possible.
 	///
 	/// This method is useful for calling getter-like methods on contracts or to dry-run a
-	/// a contract call in order to determine the `gas_limit`.
+	/// a contract call in order to determine the `gas_limit`. The result also contains the
+	/// SCALE encoded events emitted by the call and a trace of all nested calls.
 	#[rpc(name = "contracts_call")]
 	fn call(
 		&self,
 		call_request: CallRequest<AccountId>,
 		at: Option<BlockHash>,
-	) -> Result<ContractExecResult<Balance>>;
+	) -> Result<ContractExecResult<AccountId, Balance>>;
 
 	/// Instantiate a new contract.
 	///
 	/// This instantiate is performed locally without submitting any transactions. Thus the contract
 	/// is not actually created.
 	///
-	/// This method is useful for UIs to dry-run contract instantiations.
+	/// This method is useful for UIs to dry-run contract instantiations. The result also contains
+	/// the SCALE encoded events emitted by the instantiation and a trace of all nested calls.
 	#[rpc(name = "contracts_instantiate")]
 	fn instantiate(
 		&self,
@@ -163,18 +197,72 @@ where
 		key: H256,
 		at: Option<BlockHash>,
 	) -> Result<Option<Bytes>>;
+
+	/// Returns up to `count` hashed storage keys of the contract given by `address` that
+	/// follow `start_key`.
+	///
+	/// The keys are the `blake2_256` hashes of the keys used by the contract. This is meant
+	/// for debugging and inspecting the storage of a contract.
+	#[rpc(name = "contracts_getStorageKeys")]
+	fn get_storage_keys(
+		&self,
+		address: AccountId,
+		start_key: Option<H256>,
+		count: u32,
+		at: Option<BlockHash>,
+	) -> Result<Vec<H256>>;
+
+	/// Returns the events deposited by the contract given by `address` within the blocks
+	/// `from` to `to` (inclusive) which carry all of the supplied `topics`.
+	///
+	/// This requires the node to run the contract events index. Only the events of recent
+	/// blocks that were imported while the index was running are returned. Blocks imported
+	/// during the major sync are not indexed.
+	///
+	/// At most `EVENTS_BLOCKS_LIMIT` blocks can be searched at once. A query matching more
+	/// than `EVENTS_LIMIT` events is rejected and needs to be split into smaller ranges.
+	#[rpc(name = "contracts_getEvents")]
+	fn get_events(
+		&self,
+		address: AccountId,
+		topics: Vec<Hash>,
+		from: BlockNumber,
+		to: BlockNumber,
+	) -> Result<Vec<ContractEventRecord<BlockHash, BlockNumber, AccountId, Hash>>>;
+
+	/// Returns the address a contract instantiated by `deployer` from `code_hash` with the
+	/// given `salt` would be deployed at.
+	///
+	/// The address does not depend on the constructor input or the endowment and can thus be
+	/// known before the contract is instantiated.
+	#[rpc(name = "contracts_predictAddress")]
+	fn predict_address(
+		&self,
+		deployer: AccountId,
+		code_hash: Hash,
+		salt: Bytes,
+		at: Option<BlockHash>,
+	) -> Result<AccountId>;
 }
 
 /// An implementation of contract specific RPC methods.
-pub struct Contracts<C, B> {
+pub struct Contracts<C, B: BlockT> {
 	client: Arc<C>,
-	_marker: std::marker::PhantomData<B>,
+	events_index: Option<Arc<ContractEventsIndex<B>>>,
 }
 
-impl<C, B> Contracts<C, B> {
+impl<C, B: BlockT> Contracts<C, B> {
 	/// Create new `Contracts` with the given reference to the client.
 	pub fn new(client: Arc<C>) -> Self {
-		Contracts { client, _marker: Default::default() }
+		Contracts { client, events_index: None }
+	}
+
+	/// Serve `contracts_getEvents` from the given index.
+	///
+	/// The index needs to be fed by [`index_contract_events`].
+	pub fn with_events_index(mut self, events_index: Arc<ContractEventsIndex<B>>) -> Self {
+		self.events_index = Some(events_index);
+		self
 	}
 }
 impl<C, Block, AccountId, Balance, Hash>
@@ -203,7 +291,7 @@ where
 		&self,
 		call_request: CallRequest<AccountId>,
 		at: Option<<Block as BlockT>::Hash>,
-	) -> Result<ContractExecResult<Balance>> {
+	) -> Result<ContractExecResult<AccountId, Balance>> {
 		let api = self.client.runtime_api();
 		let at = BlockId::hash(at.unwrap_or_else(||
 			// If the block hash is not supplied assume the best block.
@@ -218,8 +306,41 @@ where
 			storage_deposit_limit.map(|l| decode_hex(l, "balance")).transpose()?;
 		limit_gas(gas_limit)?;
 
-		api.call(&at, origin, dest, value, gas_limit, storage_deposit_limit, input_data.to_vec())
+		let api_version = api
+			.api_version::<dyn ContractsRuntimeApi<
+				Block,
+				AccountId,
+				Balance,
+				<<Block as BlockT>::Header as HeaderT>::Number,
+				Hash,
+			>>(&at)
+			.map_err(runtime_error_into_rpc_err)?;
+
+		if api_version.map_or(false, |version| version < 2) {
+			#[allow(deprecated)] // old `call` without events and call trace
+			api.call_before_version_2(
+				&at,
+				origin,
+				dest,
+				value,
+				gas_limit,
+				storage_deposit_limit,
+				input_data.to_vec(),
+			)
+			.map(Into::into)
+			.map_err(runtime_error_into_rpc_err)
+		} else {
+			api.call(
+				&at,
+				origin,
+				dest,
+				value,
+				gas_limit,
+				storage_deposit_limit,
+				input_data.to_vec(),
+			)
 			.map_err(runtime_error_into_rpc_err)
+		}
 	}
 
 	fn instantiate(
@@ -248,17 +369,43 @@ where
 			storage_deposit_limit.map(|l| decode_hex(l, "balance")).transpose()?;
 		limit_gas(gas_limit)?;
 
-		api.instantiate(
-			&at,
-			origin,
-			value,
-			gas_limit,
-			storage_deposit_limit,
-			code,
-			data.to_vec(),
-			salt.to_vec(),
-		)
-		.map_err(runtime_error_into_rpc_err)
+		let api_version = api
+			.api_version::<dyn ContractsRuntimeApi<
+				Block,
+				AccountId,
+				Balance,
+				<<Block as BlockT>::Header as HeaderT>::Number,
+				Hash,
+			>>(&at)
+			.map_err(runtime_error_into_rpc_err)?;
+
+		if api_version.map_or(false, |version| version < 2) {
+			#[allow(deprecated)] // old `instantiate` without events and call trace
+			api.instantiate_before_version_2(
+				&at,
+				origin,
+				value,
+				gas_limit,
+				storage_deposit_limit,
+				code,
+				data.to_vec(),
+				salt.to_vec(),
+			)
+			.map(Into::into)
+			.map_err(runtime_error_into_rpc_err)
+		} else {
+			api.instantiate(
+				&at,
+				origin,
+				value,
+				gas_limit,
+				storage_deposit_limit,
+				code,
+				data.to_vec(),
+				salt.to_vec(),
+			)
+			.map_err(runtime_error_into_rpc_err)
+		}
 	}
 
 	fn upload_code(
@@ -299,6 +446,106 @@ where
 
 		Ok(result)
 	}
+
+	fn get_storage_keys(
+		&self,
+		address: AccountId,
+		start_key: Option<H256>,
+		count: u32,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<Vec<H256>> {
+		limit_storage_keys(count)?;
+
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		let result = api
+			.get_storage_keys(&at, address, start_key.map(Into::into), count)
+			.map_err(runtime_error_into_rpc_err)?
+			.map_err(ContractAccessError)?
+			.into_iter()
+			.map(H256)
+			.collect();
+
+		Ok(result)
+	}
+
+	fn get_events(
+		&self,
+		address: AccountId,
+		topics: Vec<Hash>,
+		from: <<Block as BlockT>::Header as HeaderT>::Number,
+		to: <<Block as BlockT>::Header as HeaderT>::Number,
+	) -> Result<
+		Vec<
+			ContractEventRecord<
+				<Block as BlockT>::Hash,
+				<<Block as BlockT>::Header as HeaderT>::Number,
+				AccountId,
+				Hash,
+			>,
+		>,
+	> {
+		let index = self.events_index.as_ref().ok_or_else(|| Error {
+			code: ErrorCode::ServerError(EVENTS_INDEX_DISABLED),
+			message: "The contract events index is not enabled on this node.".into(),
+			data: None,
+		})?;
+		limit_events_blocks(from, to)?;
+
+		let topics: Vec<_> = topics.iter().map(Encode::encode).collect();
+		let is_canonical = |number, hash: &<Block as BlockT>::Hash| {
+			self.client.hash(number).ok().flatten().as_ref() == Some(hash)
+		};
+		// Query one more event than allowed in order to detect that the limit is exceeded.
+		let records =
+			index.query(&address.encode(), &topics, from, to, EVENTS_LIMIT + 1, is_canonical);
+		if records.len() > EVENTS_LIMIT {
+			return Err(Error {
+				code: ErrorCode::InvalidParams,
+				message: format!(
+					"Requested blocks contain more than the maximum allowed number of events: {}",
+					EVENTS_LIMIT
+				),
+				data: None,
+			})
+		}
+		records
+			.into_iter()
+			.map(|record| {
+				let IndexedEvent { contract, topics, data } = record.event;
+				Ok(ContractEventRecord {
+					block_hash: record.block_hash,
+					block_number: record.block_number,
+					event_index: record.event_index,
+					contract: decode_encoded(&contract)?,
+					topics: topics
+						.iter()
+						.map(|topic| decode_encoded(topic))
+						.collect::<Result<_>>()?,
+					data: Bytes(data),
+				})
+			})
+			.collect()
+	}
+
+	fn predict_address(
+		&self,
+		deployer: AccountId,
+		code_hash: Hash,
+		salt: Bytes,
+		at: Option<<Block as BlockT>::Hash>,
+	) -> Result<AccountId> {
+		let api = self.client.runtime_api();
+		let at = BlockId::hash(at.unwrap_or_else(||
+			// If the block hash is not supplied assume the best block.
+			self.client.info().best_hash));
+
+		api.predict_address(&at, deployer, code_hash, salt.to_vec())
+			.map_err(runtime_error_into_rpc_err)
+	}
 }
 
 /// Converts a runtime trap into an RPC error.
@@ -310,6 +557,11 @@ fn runtime_error_into_rpc_err(err: impl std::fmt::Debug) -> Error {
 	}
 }
 
+/// Decodes a value that was SCALE encoded by the contract events index.
+fn decode_encoded<T: Decode>(mut encoded: &[u8]) -> Result<T> {
+	T::decode(&mut encoded).map_err(runtime_error_into_rpc_err)
+}
+
 fn decode_hex<H: std::fmt::Debug + Copy, T: TryFrom<H>>(from: H, name: &str) -> Result<T> {
 	from.try_into().map_err(|_| Error {
 		code: ErrorCode::InvalidParams,
@@ -333,6 +585,36 @@ fn limit_gas(gas_limit: Weight) -> Result<()> {
 	}
 }
 
+fn limit_storage_keys(count: u32) -> Result<()> {
+	if count > STORAGE_KEYS_LIMIT {
+		Err(Error {
+			code: ErrorCode::InvalidParams,
+			message: format!(
+				"Requested number of storage keys is greater than maximum allowed: {} > {}",
+				count, STORAGE_KEYS_LIMIT
+			),
+			data: None,
+		})
+	} else {
+		Ok(())
+	}
+}
+
+fn limit_events_blocks<N: AtLeast32BitUnsigned + std::fmt::Display>(from: N, to: N) -> Result<()> {
+	if to.saturating_sub(from) >= EVENTS_BLOCKS_LIMIT.into() {
+		Err(Error {
+			code: ErrorCode::InvalidParams,
+			message: format!(
+				"Requested block range {}..={} exceeds the maximum allowed number of blocks: {}",
+				from, to, EVENTS_BLOCKS_LIMIT
+			),
+			data: None,
+		})
+	} else {
+		Ok(())
+	}
+}
+
 #[cfg(test)]
 mod tests {
 	use super::*;
@@ -411,10 +693,28 @@ mod tests {
 		assert_eq!(req.storage_deposit_limit.map(|l| l.into_u256()), Some(5000.into()));
 	}
 
+	#[test]
+	fn contract_event_record_should_serialize_deserialize_properly() {
+		let expected = r#"{
+			"blockHash": "0x0101010101010101010101010101010101010101010101010101010101010101",
+			"blockNumber": 7,
+			"eventIndex": 2,
+			"contract": "5CiPPseXPECbkjWCa6MnjNokrgYjMqmKndv2rSnekmSK2DjL",
+			"topics": ["0x0202020202020202020202020202020202020202020202020202020202020202"],
+			"data": "0x8c97db39"
+		}"#;
+		let record: ContractEventRecord<H256, u32, String, H256> =
+			serde_json::from_str(expected).unwrap();
+		assert_eq!(record.block_number, 7);
+		assert_eq!(record.topics, vec![H256::repeat_byte(2)]);
+		let actual = serde_json::to_string(&record).unwrap();
+		assert_eq!(actual, trim(expected).as_str());
+	}
+
 	#[test]
 	fn call_result_should_serialize_deserialize_properly() {
 		fn test(expected: &str) {
-			let res: ContractExecResult<u32> = serde_json::from_str(expected).unwrap();
+			let res: ContractExecResult<String, u32> = serde_json::from_str(expected).unwrap();
 			let actual = serde_json::to_string(&res).unwrap();
 			assert_eq!(actual, trim(expected).as_str());
 		}
@@ -424,6 +724,35 @@ mod tests {
 			"gasRequired": 8000,
 			"storageDeposit": {"charge": 42000},
 			"debugMessage": "HelloWorld",
+			"events": ["0x0001", "0x0203"],
+			"callTrace": {
+				"kind": "call",
+				"caller": "5CiPP",
+				"contract": "5DRak",
+				"value": 0,
+				"input": "0x8c97db39",
+				"gasConsumed": 5000,
+				"hostFunctions": ["seal_input", "seal_call", "seal_return"],
+				"result": {
+					"Ok": {
+						"flags": 5,
+						"data": "0x1234"
+					}
+				},
+				"calls": [{
+					"kind": "delegateCall",
+					"caller": "5CiPP",
+					"contract": "5DRak",
+					"value": 0,
+					"input": "0x",
+					"gasConsumed": 1000,
+					"hostFunctions": [],
+					"result": {
+						"Err": "BadOrigin"
+					},
+					"calls": []
+				}]
+			},
 			"result": {
 			  "Ok": {
 				"flags": 5,
@@ -438,6 +767,8 @@ mod tests {
 			"gasRequired": 5200,
 			"storageDeposit": {"refund": 12000},
 			"debugMessage": "HelloWorld",
+			"events": null,
+			"callTrace": null,
 			"result": {
 			  "Err": "BadOrigin"
 			}
@@ -459,6 +790,8 @@ mod tests {
 			"gasRequired": 8000,
 			"storageDeposit": {"refund": 12000},
 			"debugMessage": "HelloWorld",
+			"events": [],
+			"callTrace": null,
 			"result": {
 			   "Ok": {
 				  "result": {
@@ -476,6 +809,8 @@ mod tests {
 			"gasRequired": 5200,
 			"storageDeposit": {"charge": 0},
 			"debugMessage": "HelloWorld",
+			"events": null,
+			"callTrace": null,
 			"result": {
 			  "Err": "BadOrigin"
 			}
@@ -29,6 +29,19 @@
 //! required for this endeavour are defined or re-exported in this module. There is an
 //! implementation on `()` which can be used to signal that no chain extension is available.
 //!
+//! # Using multiple chain extensions
+//!
+//! Chain extensions written by different parties can be combined by putting them into a tuple
+//! which is then passed to [`Config::ChainExtension`] like `type ChainExtension = (ExtA, ExtB)`.
+//! Only extensions implementing [`RegisteredChainExtension`] can be put into a tuple.
+//!
+//! The upper 16 bits of the `func_id` passed to `seal_call_chain_extension` select the
+//! extension by its [`RegisteredChainExtension::ID`]. The lower 16 bits are left to the
+//! extension to determine which of its functions to call. The whole `func_id` is passed
+//! through to the selected extension. Calling an extension that is not part of the tuple or
+//! that is not [`enabled`](ChainExtension::enabled) fails with
+//! [`NoChainExtension`](Error::NoChainExtension).
+//!
 //! # Security
 //!
 //! The chain author alone is responsible for the security of the chain extension.
@@ -112,20 +125,64 @@ pub trait ChainExtension<C: Config> {
 	}
 }
 
-/// Implementation that indicates that no chain extension is available.
-impl<C: Config> ChainExtension<C> for () {
-	fn call<E>(_func_id: u32, mut _env: Environment<E, InitState>) -> Result<RetVal>
+/// A [`ChainExtension`] that can be composed with other extensions using a tuple.
+///
+/// An extension that implements this trait can be put in a tuple in order to have multiple
+/// extensions available. The tuple implementation routes requests based on the two most
+/// significant bytes of the `func_id` passed to `call`. Tuples of up to ten extensions are
+/// supported.
+pub trait RegisteredChainExtension<C: Config>: ChainExtension<C> {
+	/// The identifier claimed by this extension.
+	///
+	/// It needs to be unique among all extensions used by a runtime.
+	const ID: u16;
+}
+
+/// Returns the id of the extension that `func_id` is addressed to.
+///
+/// This is the value of the upper 16 bits of `func_id`.
+pub fn ext_id(func_id: u32) -> u16 {
+	(func_id >> 16) as u16
+}
+
+/// Returns the function of the extension that `func_id` refers to.
+///
+/// This is the value of the lower 16 bits of `func_id`.
+pub fn ext_func_id(func_id: u32) -> u16 {
+	(func_id & 0x0000FFFF) as u16
+}
+
+/// Routes calls to the registered chain extension whose `ID` matches [`ext_id`].
+///
+/// The implementation on `()` indicates that no chain extension is available.
+#[impl_trait_for_tuples::impl_for_tuples(10)]
+#[tuple_types_custom_trait_bound(RegisteredChainExtension<C>)]
+impl<C: Config> ChainExtension<C> for Tuple {
+	fn call<E>(func_id: u32, env: Environment<E, InitState>) -> Result<RetVal>
 	where
 		E: Ext<T = C>,
 		<E::T as SysConfig>::AccountId: UncheckedFrom<<E::T as SysConfig>::Hash> + AsRef<[u8]>,
 	{
-		// Never called since [`Self::enabled()`] is set to `false`. Because we want to
-		// avoid panics at all costs we supply a sensible error value here instead
-		// of an `unimplemented!`.
+		for_tuples!(
+			#(
+				if (Tuple::ID == ext_id(func_id)) && Tuple::enabled() {
+					return Tuple::call(func_id, env)
+				}
+			)*
+		);
+		// Because we want to avoid panics at all costs we return an error instead of
+		// an `unimplemented!` when no enabled extension claims the id.
 		Err(Error::<E::T>::NoChainExtension.into())
 	}
 
 	fn enabled() -> bool {
+		for_tuples!(
+			#(
+				if Tuple::enabled() {
+					return true
+				}
+			)*
+		);
 		false
 	}
 }
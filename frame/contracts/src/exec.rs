@@ -28,9 +28,13 @@ use frame_support::{
 	weights::Weight,
 };
 use frame_system::RawOrigin;
-use pallet_contracts_primitives::ExecReturnValue;
+use pallet_contracts_primitives::{CallKind, CallTrace, ExecReturnValue, ReturnFlags};
 use smallvec::{Array, SmallVec};
-use sp_core::crypto::UncheckedFrom;
+use sp_core::{
+	crypto::UncheckedFrom,
+	sr25519::{Public as SR25519Public, Signature as SR25519Signature},
+	Bytes,
+};
 use sp_io::crypto::secp256k1_ecdsa_recover_compressed;
 use sp_runtime::traits::Convert;
 use sp_std::{marker::PhantomData, mem, prelude::*};
@@ -41,6 +45,7 @@ pub type SeedOf<T> = <T as frame_system::Config>::Hash;
 pub type BlockNumberOf<T> = <T as frame_system::Config>::BlockNumber;
 pub type StorageKey = [u8; 32];
 pub type ExecResult = Result<ExecReturnValue, ExecError>;
+pub type CallTraceOf<T> = CallTrace<AccountIdOf<T>, BalanceOf<T>>;
 
 /// A type that represents a topic of an event. At the moment a hash is used.
 pub type TopicOf<T> = <T as frame_system::Config>::Hash;
@@ -104,6 +109,18 @@ pub trait Ext: sealing::Sealed {
 		allows_reentry: bool,
 	) -> Result<ExecReturnValue, ExecError>;
 
+	/// Execute code in the current frame.
+	///
+	/// The code at `code_hash` is run on behalf of the executing contract: It uses its storage,
+	/// balance and storage deposit. The `caller` and `value_transferred` seen by the code are the
+	/// ones of the current frame. No value is transferred and no reentrancy check is performed
+	/// as no other contract is entered.
+	fn delegate_call(
+		&mut self,
+		code_hash: CodeHash<Self::T>,
+		input_data: Vec<u8>,
+	) -> Result<ExecReturnValue, ExecError>;
+
 	/// Instantiate a contract from the given code.
 	///
 	/// Returns the original code size of the called contract.
@@ -146,6 +163,12 @@ pub trait Ext: sealing::Sealed {
 	/// was deleted.
 	fn get_storage_size(&mut self, key: &StorageKey) -> Option<u32>;
 
+	/// Returns the storage entry of the executing account that follows `hashed_key`.
+	///
+	/// Entries are ordered by the `blake2_256` hash of their key which is returned together
+	/// with the value. Returns `None` if there is no entry after `hashed_key`.
+	fn next_storage_entry(&mut self, hashed_key: &[u8; 32]) -> Option<([u8; 32], Vec<u8>)>;
+
 	/// Sets the storage entry by the given key to the specified value. If `value` is `None` then
 	/// the storage entry is deleted.
 	fn set_storage(
@@ -158,6 +181,14 @@ pub trait Ext: sealing::Sealed {
 	/// Returns a reference to the account id of the caller.
 	fn caller(&self) -> &AccountIdOf<Self::T>;
 
+	/// Returns the code hash of the contract for the given `address`.
+	///
+	/// Returns `None` if the `address` does not belong to a contract.
+	fn code_hash(&self, address: &AccountIdOf<Self::T>) -> Option<CodeHash<Self::T>>;
+
+	/// Returns the code hash of the contract being executed.
+	fn own_code_hash(&mut self) -> &CodeHash<Self::T>;
+
 	/// Returns a reference to the account id of the current contract.
 	fn address(&self) -> &AccountIdOf<Self::T>;
 
@@ -208,12 +239,58 @@ pub trait Ext: sealing::Sealed {
 	/// Returns `true` if debug message recording is enabled. Otherwise `false` is returned.
 	fn append_debug_buffer(&mut self, msg: &str) -> bool;
 
+	/// Returns `true` if call tracing is enabled.
+	///
+	/// Call tracing is always disabled when the code is executing on-chain.
+	fn call_tracing_enabled(&self) -> bool;
+
+	/// Record that the executing contract invoked the host function `name`.
+	///
+	/// This is a no-op if call tracing is disabled which is always the case when the code is
+	/// executing on-chain.
+	fn trace_host_fn(&mut self, name: &'static str);
+
 	/// Call some dispatchable and return the result.
 	fn call_runtime(&self, call: <Self::T as Config>::Call) -> DispatchResultWithPostInfo;
 
 	/// Recovers ECDSA compressed public key based on signature and message hash.
 	fn ecdsa_recover(&self, signature: &[u8; 65], message_hash: &[u8; 32]) -> Result<[u8; 33], ()>;
 
+	/// Verify a sr25519 signature.
+	fn sr25519_verify(&self, signature: &[u8; 64], message: &[u8], pub_key: &[u8; 32]) -> bool;
+
+	/// Returns the Ethereum address derived from the given ECDSA compressed public key.
+	///
+	/// Fails if the key is not a valid compressed secp256k1 public key.
+	fn ecdsa_to_eth_address(&self, pk: &[u8; 33]) -> Result<[u8; 20], ()>;
+
+	/// Replace the code of the executing contract with the code at `hash`.
+	///
+	/// The currently running code is not affected: The new code is only used for calls
+	/// that enter the contract after this one. The refcount of the new code is incremented
+	/// and the one of the old code decremented.
+	///
+	/// The storage deposit of the contract stays the same because the size of its
+	/// [`ContractInfo`] does not change. The deposit for the code itself is held by
+	/// its uploader.
+	///
+	/// # Errors
+	///
+	/// [`Error::CodeNotFound`] is returned if no code exists at `hash`.
+	fn set_code_hash(&mut self, hash: CodeHash<Self::T>) -> Result<(), DispatchError>;
+
+	/// Returns the number of times the currently executing contract exists on the call stack in
+	/// addition to the calling instance. A value of 0 means no reentrancy.
+	///
+	/// Delegate calls are not counted as separate entrances.
+	fn reentrance_count(&self) -> u32;
+
+	/// Returns the number of times the specified contract exists on the call stack.
+	///
+	/// Delegate calls are not counted as separate entrances. A value of 0 means that the
+	/// contract does not exist on the call stack.
+	fn account_reentrance_count(&self, account_id: &AccountIdOf<Self::T>) -> u32;
+
 	/// Tests sometimes need to modify and inspect the contract info directly.
 	#[cfg(test)]
 	fn contract_info(&mut self) -> &mut ContractInfo<Self::T>;
@@ -243,6 +320,13 @@ pub trait Executable<T: Config>: Sized {
 		gas_meter: &mut GasMeter<T>,
 	) -> Result<Self, DispatchError>;
 
+	/// Increment the refcount of a code in-storage by one.
+	///
+	/// # Errors
+	///
+	/// [`Error::CodeNotFound`] is returned if the specified `code_hash` does not exist.
+	fn add_user(code_hash: CodeHash<T>) -> Result<(), DispatchError>;
+
 	/// Decrement the refcount by one if the code exists.
 	///
 	/// # Note
@@ -310,6 +394,8 @@ pub struct Stack<'a, T: Config, E> {
 	/// All the bytes added to this field should be valid UTF-8. The buffer has no defined
 	/// structure and is intended to be shown to users as-is for debugging purposes.
 	debug_message: Option<&'a mut Vec<u8>>,
+	/// Records a [`CallTrace`] for every frame that is executed.
+	call_tracer: Option<&'a mut CallTracer<T>>,
 	/// No executable is held by the struct but influences its behaviour.
 	_phantom: PhantomData<E>,
 }
@@ -338,6 +424,89 @@ pub struct Frame<T: Config> {
 	nested_storage: storage::meter::NestedMeter<T>,
 	/// If `false` the contract enabled its defense against reentrance attacks.
 	allows_reentry: bool,
+	/// If `Some` the frame executes a delegate call and this is the caller of the frame
+	/// that issued it.
+	delegate_caller: Option<T::AccountId>,
+}
+
+/// Records the [`CallTrace`] of every frame executed by a [`Stack`].
+///
+/// The trace of a nested frame is attached to the trace of the frame that created it. This
+/// results in a tree whose root is the first frame of the call stack.
+pub struct CallTracer<T: Config> {
+	/// Traces of the frames that are still executing. The last one belongs to the top frame.
+	pending: Vec<CallTraceOf<T>>,
+	/// The trace of the first frame. Only set after it finished executing.
+	root: Option<CallTraceOf<T>>,
+}
+
+impl<T: Config> Default for CallTracer<T> {
+	fn default() -> Self {
+		Self { pending: Vec::new(), root: None }
+	}
+}
+
+impl<T: Config> CallTracer<T> {
+	/// Consume the tracer and return the trace of the first frame.
+	///
+	/// Returns `None` if the first frame was never executed. For example, because the
+	/// called contract does not exist.
+	pub fn into_trace(self) -> Option<CallTraceOf<T>> {
+		self.root
+	}
+
+	/// Start the trace of a new top frame.
+	fn enter(
+		&mut self,
+		kind: CallKind,
+		caller: AccountIdOf<T>,
+		contract: AccountIdOf<T>,
+		value: BalanceOf<T>,
+		input: Vec<u8>,
+	) {
+		self.pending.push(CallTrace {
+			kind,
+			caller,
+			contract,
+			value,
+			input: Bytes(input),
+			gas_consumed: 0,
+			host_functions: Vec::new(),
+			// Replaced with the actual result once the frame finished executing.
+			result: Ok(ExecReturnValue { flags: ReturnFlags::empty(), data: Bytes(Vec::new()) }),
+			calls: Vec::new(),
+		});
+	}
+
+	/// Record a host function invocation of the top frame.
+	fn host_fn(&mut self, name: &str) {
+		if let Some(trace) = self.pending.last_mut() {
+			trace.host_functions.push(name.as_bytes().to_vec());
+		}
+	}
+
+	/// Finish the trace of the top frame and attach it to its parent.
+	fn exit(&mut self, gas_consumed: Weight, result: &ExecResult) {
+		let mut trace = match self.pending.pop() {
+			Some(trace) => trace,
+			None => return,
+		};
+		trace.gas_consumed = gas_consumed;
+		trace.result = result.as_ref().map(Clone::clone).map_err(|e| e.error.clone());
+		if let Some(parent) = self.pending.last_mut() {
+			parent.calls.push(trace);
+		} else {
+			self.root = Some(trace);
+		}
+	}
+}
+
+/// Used in a delegate call frame arguments in order to override the executable and caller.
+struct DelegatedCall<T: Config, E> {
+	/// The executable which is run instead of the contracts own `executable`.
+	executable: E,
+	/// The account id of the caller contract.
+	caller: T::AccountId,
 }
 
 /// Parameter passed in when creating a new `Frame`.
@@ -349,6 +518,10 @@ enum FrameArgs<'a, T: Config, E> {
 		dest: T::AccountId,
 		/// If `None` the contract info needs to be reloaded from storage.
 		cached_info: Option<ContractInfo<T>>,
+		/// This frame was created by `seal_delegate_call` and hence uses different code than
+		/// what is stored at [`Self::Call::dest`]. Its caller ([`Frame::delegate_caller`]) is the
+		/// account which called the caller contract
+		delegated_call: Option<DelegatedCall<T, E>>,
 	},
 	Instantiate {
 		/// The contract or signed origin which instantiates the new contract.
@@ -487,8 +660,9 @@ where
 	///
 	/// # Note
 	///
-	/// `debug_message` should only ever be set to `Some` when executing as an RPC because
-	/// it adds allocations and could be abused to drive the runtime into an OOM panic.
+	/// `debug_message` and `call_tracer` should only ever be set to `Some` when executing as
+	/// an RPC because they add allocations and could be abused to drive the runtime into an
+	/// OOM panic.
 	///
 	/// # Return Value
 	///
@@ -502,15 +676,17 @@ where
 		value: BalanceOf<T>,
 		input_data: Vec<u8>,
 		debug_message: Option<&'a mut Vec<u8>>,
+		call_tracer: Option<&'a mut CallTracer<T>>,
 	) -> Result<ExecReturnValue, ExecError> {
 		let (mut stack, executable) = Self::new(
-			FrameArgs::Call { dest, cached_info: None },
+			FrameArgs::Call { dest, cached_info: None, delegated_call: None },
 			origin,
 			gas_meter,
 			storage_meter,
 			schedule,
 			value,
 			debug_message,
+			call_tracer,
 		)?;
 		stack.run(executable, input_data)
 	}
@@ -519,8 +695,9 @@ where
 	///
 	/// # Note
 	///
-	/// `debug_message` should only ever be set to `Some` when executing as an RPC because
-	/// it adds allocations and could be abused to drive the runtime into an OOM panic.
+	/// `debug_message` and `call_tracer` should only ever be set to `Some` when executing as
+	/// an RPC because they add allocations and could be abused to drive the runtime into an
+	/// OOM panic.
 	///
 	/// # Return Value
 	///
@@ -535,6 +712,7 @@ where
 		input_data: Vec<u8>,
 		salt: &[u8],
 		debug_message: Option<&'a mut Vec<u8>>,
+		call_tracer: Option<&'a mut CallTracer<T>>,
 	) -> Result<(T::AccountId, ExecReturnValue), ExecError> {
 		let (mut stack, executable) = Self::new(
 			FrameArgs::Instantiate {
@@ -549,6 +727,7 @@ where
 			schedule,
 			value,
 			debug_message,
+			call_tracer,
 		)?;
 		let account_id = stack.top_frame().account_id.clone();
 		stack.run(executable, input_data).map(|ret| (account_id, ret))
@@ -563,6 +742,7 @@ where
 		schedule: &'a Schedule<T>,
 		value: BalanceOf<T>,
 		debug_message: Option<&'a mut Vec<u8>>,
+		call_tracer: Option<&'a mut CallTracer<T>>,
 	) -> Result<(Self, E), ExecError> {
 		let (first_frame, executable, account_counter) =
 			Self::new_frame(args, value, gas_meter, storage_meter, 0, &schedule)?;
@@ -577,6 +757,7 @@ where
 			first_frame,
 			frames: Default::default(),
 			debug_message,
+			call_tracer,
 			_phantom: Default::default(),
 		};
 
@@ -595,31 +776,43 @@ where
 		gas_limit: Weight,
 		schedule: &Schedule<T>,
 	) -> Result<(Frame<T>, E, Option<u64>), ExecError> {
-		let (account_id, contract_info, executable, entry_point, account_counter) = match frame_args
-		{
-			FrameArgs::Call { dest, cached_info } => {
-				let contract = if let Some(contract) = cached_info {
-					contract
-				} else {
-					<ContractInfoOf<T>>::get(&dest).ok_or(<Error<T>>::ContractNotFound)?
-				};
-
-				let executable = E::from_storage(contract.code_hash, schedule, gas_meter)?;
-
-				(dest, contract, executable, ExportedFunction::Call, None)
-			},
-			FrameArgs::Instantiate { sender, trie_seed, executable, salt } => {
-				let account_id =
-					<Contracts<T>>::contract_address(&sender, executable.code_hash(), &salt);
-				let trie_id = Storage::<T>::generate_trie_id(&account_id, trie_seed);
-				let contract = Storage::<T>::new_contract(
-					&account_id,
-					trie_id,
-					executable.code_hash().clone(),
-				)?;
-				(account_id, contract, executable, ExportedFunction::Constructor, Some(trie_seed))
-			},
-		};
+		let (account_id, contract_info, executable, delegate_caller, entry_point, account_counter) =
+			match frame_args {
+				FrameArgs::Call { dest, cached_info, delegated_call } => {
+					let contract = if let Some(contract) = cached_info {
+						contract
+					} else {
+						<ContractInfoOf<T>>::get(&dest).ok_or(<Error<T>>::ContractNotFound)?
+					};
+
+					let (executable, delegate_caller) =
+						if let Some(DelegatedCall { executable, caller }) = delegated_call {
+							(executable, Some(caller))
+						} else {
+							(E::from_storage(contract.code_hash, schedule, gas_meter)?, None)
+						};
+
+					(dest, contract, executable, delegate_caller, ExportedFunction::Call, None)
+				},
+				FrameArgs::Instantiate { sender, trie_seed, executable, salt } => {
+					let account_id =
+						<Contracts<T>>::contract_address(&sender, executable.code_hash(), &salt);
+					let trie_id = Storage::<T>::generate_trie_id(&account_id, trie_seed);
+					let contract = Storage::<T>::new_contract(
+						&account_id,
+						trie_id,
+						executable.code_hash().clone(),
+					)?;
+					(
+						account_id,
+						contract,
+						executable,
+						None,
+						ExportedFunction::Constructor,
+						Some(trie_seed),
+					)
+				},
+			};
 
 		let frame = Frame {
 			value_transferred,
@@ -629,6 +822,7 @@ where
 			nested_gas: gas_meter.nested(gas_limit)?,
 			nested_storage: storage_meter.nested(),
 			allows_reentry: true,
+			delegate_caller,
 		};
 
 		Ok((frame, executable, account_counter))
@@ -676,6 +870,19 @@ where
 	/// This can be either a call or an instantiate.
 	fn run(&mut self, executable: E, input_data: Vec<u8>) -> Result<ExecReturnValue, ExecError> {
 		let entry_point = self.top_frame().entry_point;
+		if self.call_tracer.is_some() {
+			let frame = self.top_frame();
+			let kind = match (entry_point, &frame.delegate_caller) {
+				(ExportedFunction::Constructor, _) => CallKind::Instantiate,
+				(ExportedFunction::Call, Some(_)) => CallKind::DelegateCall,
+				(ExportedFunction::Call, None) => CallKind::Call,
+			};
+			let (caller, contract) = (self.caller().clone(), frame.account_id.clone());
+			let value = frame.value_transferred;
+			if let Some(tracer) = self.call_tracer.as_mut() {
+				tracer.enter(kind, caller, contract, value, input_data.clone());
+			}
+		}
 		let do_transaction = || {
 			// We need to charge the storage deposit before the initial transfer so that
 			// it can create the account in case the initial transfer is < ed.
@@ -728,6 +935,10 @@ where
 				_ => TransactionOutcome::Rollback((false, output)),
 			}
 		});
+		let gas_consumed = self.top_frame().nested_gas.gas_consumed();
+		if let Some(tracer) = self.call_tracer.as_mut() {
+			tracer.exit(gas_consumed, &output);
+		}
 		self.pop_frame(success);
 		output
 	}
@@ -839,6 +1050,12 @@ where
 		let frame = self.top_frame();
 		let value = frame.value_transferred;
 
+		// A delegate call passes on the value of the frame that issued it. The value was
+		// already transferred when that frame was entered.
+		if frame.delegate_caller.is_some() {
+			return Ok(())
+		}
+
 		Self::transfer(ExistenceRequirement::KeepAlive, self.caller(), &frame.account_id, value)
 	}
 
@@ -927,8 +1144,11 @@ where
 					CachedContract::Cached(contract) => Some(contract.clone()),
 					_ => None,
 				});
-			let executable =
-				self.push_frame(FrameArgs::Call { dest: to, cached_info }, value, gas_limit)?;
+			let executable = self.push_frame(
+				FrameArgs::Call { dest: to, cached_info, delegated_call: None },
+				value,
+				gas_limit,
+			)?;
 			self.run(executable, input_data)
 		};
 
@@ -941,6 +1161,28 @@ where
 		result
 	}
 
+	fn delegate_call(
+		&mut self,
+		code_hash: CodeHash<Self::T>,
+		input_data: Vec<u8>,
+	) -> Result<ExecReturnValue, ExecError> {
+		let executable = E::from_storage(code_hash, &self.schedule, self.gas_meter())?;
+		let top_frame = self.top_frame_mut();
+		let contract_info = top_frame.contract_info().clone();
+		let account_id = top_frame.account_id.clone();
+		let value = top_frame.value_transferred;
+		let executable = self.push_frame(
+			FrameArgs::Call {
+				dest: account_id,
+				cached_info: Some(contract_info),
+				delegated_call: Some(DelegatedCall { executable, caller: self.caller().clone() }),
+			},
+			value,
+			0,
+		)?;
+		self.run(executable, input_data)
+	}
+
 	fn instantiate(
 		&mut self,
 		gas_limit: Weight,
@@ -1000,6 +1242,10 @@ where
 		Storage::<T>::size(&self.top_frame_mut().contract_info().trie_id, key)
 	}
 
+	fn next_storage_entry(&mut self, hashed_key: &[u8; 32]) -> Option<([u8; 32], Vec<u8>)> {
+		Storage::<T>::next_entry(&self.top_frame_mut().contract_info().trie_id, Some(hashed_key))
+	}
+
 	fn set_storage(
 		&mut self,
 		key: StorageKey,
@@ -1021,7 +1267,19 @@ where
 	}
 
 	fn caller(&self) -> &T::AccountId {
-		self.frames().nth(1).map(|f| &f.account_id).unwrap_or(&self.origin)
+		if let Some(caller) = &self.top_frame().delegate_caller {
+			caller
+		} else {
+			self.frames().nth(1).map(|f| &f.account_id).unwrap_or(&self.origin)
+		}
+	}
+
+	fn code_hash(&self, address: &T::AccountId) -> Option<CodeHash<Self::T>> {
+		<ContractInfoOf<T>>::get(address).map(|contract| contract.code_hash)
+	}
+
+	fn own_code_hash(&mut self) -> &CodeHash<Self::T> {
+		&self.top_frame_mut().contract_info().code_hash
 	}
 
 	fn balance(&self) -> BalanceOf<T> {
@@ -1082,6 +1340,16 @@ where
 		}
 	}
 
+	fn call_tracing_enabled(&self) -> bool {
+		self.call_tracer.is_some()
+	}
+
+	fn trace_host_fn(&mut self, name: &'static str) {
+		if let Some(tracer) = &mut self.call_tracer {
+			tracer.host_fn(name);
+		}
+	}
+
 	fn call_runtime(&self, call: <Self::T as Config>::Call) -> DispatchResultWithPostInfo {
 		let mut origin: T::Origin = RawOrigin::Signed(self.address().clone()).into();
 		origin.add_filter(T::CallFilter::contains);
@@ -1092,6 +1360,48 @@ where
 		secp256k1_ecdsa_recover_compressed(&signature, &message_hash).map_err(|_| ())
 	}
 
+	fn sr25519_verify(&self, signature: &[u8; 64], message: &[u8], pub_key: &[u8; 32]) -> bool {
+		sp_io::crypto::sr25519_verify(
+			&SR25519Signature(*signature),
+			message,
+			&SR25519Public(*pub_key),
+		)
+	}
+
+	fn ecdsa_to_eth_address(&self, pk: &[u8; 33]) -> Result<[u8; 20], ()> {
+		let pub_key = libsecp256k1::PublicKey::parse_compressed(pk).map_err(|_| ())?;
+		// The first byte of the uncompressed key is the `0x04` tag which is not hashed.
+		let hash = sp_io::hashing::keccak_256(&pub_key.serialize()[1..]);
+		let mut address = [0; 20];
+		address.copy_from_slice(&hash[12..]);
+		Ok(address)
+	}
+
+	fn set_code_hash(&mut self, hash: CodeHash<Self::T>) -> Result<(), DispatchError> {
+		E::add_user(hash)?;
+		let top_frame = self.top_frame_mut();
+		let prev_hash = top_frame.contract_info().code_hash;
+		E::remove_user(prev_hash)?;
+		top_frame.contract_info().code_hash = hash;
+		Contracts::<Self::T>::deposit_event(Event::ContractCodeUpdated {
+			contract: top_frame.account_id.clone(),
+			new_code_hash: hash,
+			old_code_hash: prev_hash,
+		});
+		Ok(())
+	}
+
+	fn reentrance_count(&self) -> u32 {
+		let id: &AccountIdOf<Self::T> = &self.top_frame().account_id;
+		self.account_reentrance_count(id).saturating_sub(1)
+	}
+
+	fn account_reentrance_count(&self, account_id: &AccountIdOf<Self::T>) -> u32 {
+		self.frames()
+			.filter(|f| f.delegate_caller.is_none() && &f.account_id == account_id)
+			.count() as u32
+	}
+
 	#[cfg(test)]
 	fn contract_info(&mut self) -> &mut ContractInfo<Self::T> {
 		self.top_frame_mut().contract_info()
@@ -1207,15 +1517,14 @@ mod tests {
 			})
 		}
 
-		fn increment_refcount(code_hash: CodeHash<Test>) {
+		fn increment_refcount(code_hash: CodeHash<Test>) -> Result<(), DispatchError> {
 			LOADER.with(|loader| {
 				let mut loader = loader.borrow_mut();
-				loader
-					.map
-					.entry(code_hash)
-					.and_modify(|executable| executable.refcount += 1)
-					.or_insert_with(|| panic!("code_hash does not exist"));
-			});
+				let executable =
+					loader.map.get_mut(&code_hash).ok_or(Error::<Test>::CodeNotFound)?;
+				executable.refcount += 1;
+				Ok(())
+			})
 		}
 
 		fn decrement_refcount(code_hash: CodeHash<Test>) {
@@ -1251,6 +1560,10 @@ mod tests {
 			})
 		}
 
+		fn add_user(code_hash: CodeHash<Test>) -> Result<(), DispatchError> {
+			MockLoader::increment_refcount(code_hash)
+		}
+
 		fn remove_user(code_hash: CodeHash<Test>) -> Result<(), DispatchError> {
 			MockLoader::decrement_refcount(code_hash);
 			Ok(())
@@ -1263,7 +1576,7 @@ mod tests {
 			input_data: Vec<u8>,
 		) -> ExecResult {
 			if let &Constructor = function {
-				MockLoader::increment_refcount(self.code_hash);
+				MockLoader::increment_refcount(self.code_hash).unwrap();
 			}
 			if function == &self.func_type {
 				(self.func)(MockCtx { ext, input_data }, &self)
@@ -1317,6 +1630,7 @@ mod tests {
 					value,
 					vec![],
 					None,
+					None,
 				),
 				Ok(_)
 			);
@@ -1370,6 +1684,7 @@ mod tests {
 				55,
 				vec![],
 				None,
+				None,
 			)
 			.unwrap();
 
@@ -1421,6 +1736,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 			);
 
 			let output = result.unwrap();
@@ -1453,6 +1769,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 			);
 
 			let output = result.unwrap();
@@ -1461,6 +1778,57 @@ mod tests {
 		});
 	}
 
+	#[test]
+	fn delegate_call() {
+		let value = 55;
+		let success_ch = MockLoader::insert(Call, move |ctx, _| {
+			// The delegated code sees the context of the frame that issued the delegate call.
+			assert_eq!(ctx.ext.value_transferred(), value);
+			assert_eq!(ctx.ext.caller(), &ALICE);
+			assert_eq!(ctx.ext.address(), &BOB);
+			assert_eq!(ctx.input_data, vec![1, 2, 3]);
+			ctx.ext.set_storage([1; 32], Some(vec![42]), false).unwrap();
+			exec_success()
+		});
+
+		let delegate_ch = MockLoader::insert(Call, move |ctx, _| {
+			assert_eq!(ctx.ext.value_transferred(), value);
+			let ret = ctx.ext.delegate_call(success_ch, vec![1, 2, 3])?;
+			assert!(!ret.did_revert());
+			// Changes to the storage are visible to the caller.
+			assert_eq!(ctx.ext.get_storage(&[1; 32]), Some(vec![42]));
+			// Delegate calls to non existing code fail.
+			assert_err!(
+				ctx.ext.delegate_call(<Test as frame_system::Config>::Hash::zero(), vec![]),
+				<Error<Test>>::CodeNotFound,
+			);
+			exec_success()
+		});
+
+		ExtBuilder::default().build().execute_with(|| {
+			let schedule = <Test as Config>::Schedule::get();
+			place_contract(&BOB, delegate_ch);
+			set_balance(&ALICE, <Test as Config>::Currency::minimum_balance() * 1000);
+			let bob_balance = get_balance(&BOB);
+			let mut storage_meter = storage::meter::Meter::new(&ALICE, None, value).unwrap();
+
+			let result = MockStack::run_call(
+				ALICE,
+				BOB,
+				&mut GasMeter::<Test>::new(GAS_LIMIT),
+				&mut storage_meter,
+				&schedule,
+				value,
+				vec![],
+				None,
+				None,
+			);
+			assert_matches!(result, Ok(_));
+			// The value is only transferred once.
+			assert_eq!(get_balance(&BOB), bob_balance + value);
+		});
+	}
+
 	#[test]
 	fn input_data_to_call() {
 		let input_data_ch = MockLoader::insert(Call, |ctx, _| {
@@ -1483,6 +1851,7 @@ mod tests {
 				0,
 				vec![1, 2, 3, 4],
 				None,
+				None,
 			);
 			assert_matches!(result, Ok(_));
 		});
@@ -1516,6 +1885,7 @@ mod tests {
 				vec![1, 2, 3, 4],
 				&[],
 				None,
+				None,
 			);
 			assert_matches!(result, Ok(_));
 		});
@@ -1564,6 +1934,7 @@ mod tests {
 				value,
 				vec![],
 				None,
+				None,
 			);
 
 			assert_matches!(result, Ok(_));
@@ -1611,6 +1982,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 			);
 
 			assert_matches!(result, Ok(_));
@@ -1650,6 +2022,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 			);
 
 			assert_matches!(result, Ok(_));
@@ -1678,6 +2051,7 @@ mod tests {
 					vec![],
 					&[],
 					None,
+					None,
 				),
 				Err(_)
 			);
@@ -1711,6 +2085,7 @@ mod tests {
 					vec![],
 					&[],
 					None,
+					None,
 				),
 				Ok((address, ref output)) if output.data == Bytes(vec![80, 65, 83, 83]) => address
 			);
@@ -1755,6 +2130,7 @@ mod tests {
 					vec![],
 					&[],
 					None,
+					None,
 				),
 				Ok((address, ref output)) if output.data == Bytes(vec![70, 65, 73, 76]) => address
 			);
@@ -1809,6 +2185,7 @@ mod tests {
 					min_balance * 10,
 					vec![],
 					None,
+					None,
 				),
 				Ok(_)
 			);
@@ -1871,6 +2248,7 @@ mod tests {
 					0,
 					vec![],
 					None,
+					None,
 				),
 				Ok(_)
 			);
@@ -1907,6 +2285,7 @@ mod tests {
 					vec![],
 					&[],
 					None,
+					None,
 				),
 				Err(Error::<Test>::TerminatedInConstructor.into())
 			);
@@ -1956,6 +2335,7 @@ mod tests {
 				0,
 				vec![0],
 				None,
+				None,
 			);
 			assert_matches!(result, Ok(_));
 		});
@@ -1991,6 +2371,7 @@ mod tests {
 				vec![],
 				&[],
 				None,
+				None,
 			);
 			assert_matches!(result, Ok(_));
 		});
@@ -2022,6 +2403,7 @@ mod tests {
 				0,
 				vec![],
 				Some(&mut debug_buffer),
+				None,
 			)
 			.unwrap();
 		});
@@ -2055,6 +2437,7 @@ mod tests {
 				0,
 				vec![],
 				Some(&mut debug_buffer),
+				None,
 			);
 			assert!(result.is_err());
 		});
@@ -2062,6 +2445,57 @@ mod tests {
 		assert_eq!(&String::from_utf8(debug_buffer).unwrap(), "This is a testMore text");
 	}
 
+	#[test]
+	fn call_tracer_records_nested_calls() {
+		let charlie_ch = MockLoader::insert(Call, |ctx, _| {
+			ctx.ext.trace_host_fn("seal_caller");
+			exec_trapped()
+		});
+		let bob_ch = MockLoader::insert(Call, |ctx, _| {
+			ctx.ext.trace_host_fn("seal_call");
+			assert!(ctx.ext.call(0, CHARLIE, 0, vec![2], true).is_err());
+			exec_success()
+		});
+
+		let mut call_tracer = CallTracer::default();
+
+		ExtBuilder::default().build().execute_with(|| {
+			let schedule = <Test as Config>::Schedule::get();
+			place_contract(&BOB, bob_ch);
+			place_contract(&CHARLIE, charlie_ch);
+			let mut storage_meter = storage::meter::Meter::new(&ALICE, Some(0), 0).unwrap();
+			let result = MockStack::run_call(
+				ALICE,
+				BOB,
+				&mut GasMeter::<Test>::new(GAS_LIMIT),
+				&mut storage_meter,
+				&schedule,
+				0,
+				vec![1],
+				None,
+				Some(&mut call_tracer),
+			);
+			assert_matches!(result, Ok(_));
+		});
+
+		let trace = call_tracer.into_trace().unwrap();
+		assert_eq!(trace.kind, CallKind::Call);
+		assert_eq!((trace.caller, trace.contract), (ALICE, BOB));
+		assert_eq!(trace.input, Bytes(vec![1]));
+		assert_eq!(trace.host_functions, vec![b"seal_call".to_vec()]);
+		assert_eq!(trace.result, exec_success().map_err(|e| e.error));
+		assert_eq!(trace.calls.len(), 1);
+
+		let nested = &trace.calls[0];
+		assert_eq!(nested.kind, CallKind::Call);
+		assert_eq!((&nested.caller, &nested.contract), (&BOB, &CHARLIE));
+		assert_eq!(nested.input, Bytes(vec![2]));
+		assert_eq!(nested.host_functions, vec![b"seal_caller".to_vec()]);
+		assert_eq!(nested.result, Err(<Error<Test>>::ContractTrapped.into()));
+		assert!(nested.calls.is_empty());
+		assert!(trace.gas_consumed >= nested.gas_consumed);
+	}
+
 	#[test]
 	fn call_reentry_direct_recursion() {
 		// call the contract passed as input with disabled reentry
@@ -2088,6 +2522,7 @@ mod tests {
 				0,
 				CHARLIE.encode(),
 				None,
+				None,
 			));
 
 			// Calling into oneself fails
@@ -2101,6 +2536,7 @@ mod tests {
 					0,
 					BOB.encode(),
 					None,
+					None,
 				)
 				.map_err(|e| e.error),
 				<Error<Test>>::ReentranceDenied,
@@ -2139,6 +2575,7 @@ mod tests {
 					0,
 					vec![0],
 					None,
+					None,
 				)
 				.map_err(|e| e.error),
 				<Error<Test>>::ReentranceDenied,
@@ -2173,6 +2610,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 			)
 			.unwrap();
 
@@ -2244,6 +2682,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 			)
 			.unwrap();
 
@@ -2326,6 +2765,7 @@ mod tests {
 				vec![],
 				&[],
 				None,
+				None,
 			)
 			.ok();
 			assert_eq!(<AccountCounter<Test>>::get(), 0);
@@ -2340,6 +2780,7 @@ mod tests {
 				vec![],
 				&[],
 				None,
+				None,
 			));
 			assert_eq!(<AccountCounter<Test>>::get(), 1);
 
@@ -2353,6 +2794,7 @@ mod tests {
 				vec![],
 				&[],
 				None,
+				None,
 			));
 			assert_eq!(<AccountCounter<Test>>::get(), 2);
 
@@ -2366,6 +2808,7 @@ mod tests {
 				vec![],
 				&[],
 				None,
+				None,
 			));
 			assert_eq!(<AccountCounter<Test>>::get(), 4);
 		});
@@ -2427,6 +2870,7 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
 			));
 		});
 	}
@@ -2462,7 +2906,154 @@ mod tests {
 				0,
 				vec![],
 				None,
+				None,
+			));
+		});
+	}
+
+	#[test]
+	fn set_code_hash_works() {
+		let new_code_hash = MockLoader::insert(Call, |_, _| exec_success());
+		let code_hash = MockLoader::insert(Call, move |ctx, executable| {
+			let old_code_hash = *executable.code_hash();
+			assert_eq!(ctx.ext.own_code_hash(), &old_code_hash);
+			assert_err!(
+				ctx.ext.set_code_hash(<Test as frame_system::Config>::Hash::repeat_byte(0xff)),
+				<Error<Test>>::CodeNotFound,
+			);
+			assert_ok!(ctx.ext.set_code_hash(new_code_hash));
+			assert_eq!(ctx.ext.own_code_hash(), &new_code_hash);
+			exec_success()
+		});
+
+		ExtBuilder::default().build().execute_with(|| {
+			let schedule = <Test as Config>::Schedule::get();
+			let mut gas_meter = GasMeter::<Test>::new(GAS_LIMIT);
+			set_balance(&ALICE, <Test as Config>::Currency::minimum_balance() * 10);
+			place_contract(&BOB, code_hash);
+			let mut storage_meter = storage::meter::Meter::new(&ALICE, Some(0), 0).unwrap();
+			System::reset_events();
+			assert_ok!(MockStack::run_call(
+				ALICE,
+				BOB,
+				&mut gas_meter,
+				&mut storage_meter,
+				&schedule,
+				0,
+				vec![],
+				None,
+				None,
 			));
+
+			assert_eq!(Storage::<Test>::code_hash(&BOB), Some(new_code_hash));
+			assert_eq!(
+				&events(),
+				&[Event::ContractCodeUpdated {
+					contract: BOB,
+					new_code_hash,
+					old_code_hash: code_hash,
+				}]
+			);
+			LOADER.with(|loader| {
+				let loader = loader.borrow();
+				assert_eq!(loader.map.get(&new_code_hash).map(|e| e.refcount), Some(2));
+				assert!(!loader.map.contains_key(&code_hash));
+			});
+		});
+	}
+
+	#[test]
+	fn code_hash_returns_proper_values() {
+		let code_hash = MockLoader::insert(Call, |ctx, executable| {
+			// We know that BOB is a contract.
+			assert_eq!(ctx.ext.code_hash(&BOB), Some(*executable.code_hash()));
+			// CHARLIE is a plain account without code.
+			assert_eq!(ctx.ext.code_hash(&CHARLIE), None);
+			exec_success()
+		});
+
+		ExtBuilder::default().build().execute_with(|| {
+			let schedule = <Test as Config>::Schedule::get();
+			let mut gas_meter = GasMeter::<Test>::new(GAS_LIMIT);
+			set_balance(&ALICE, <Test as Config>::Currency::minimum_balance() * 10);
+			place_contract(&BOB, code_hash);
+			let mut storage_meter = storage::meter::Meter::new(&ALICE, Some(0), 0).unwrap();
+			assert_ok!(MockStack::run_call(
+				ALICE,
+				BOB,
+				&mut gas_meter,
+				&mut storage_meter,
+				&schedule,
+				0,
+				vec![],
+				None,
+				None,
+			));
+		});
+	}
+
+	#[test]
+	fn reentrance_count_works_with_call() {
+		let code_hash = MockLoader::insert(Call, |ctx, _| {
+			let count = ctx.ext.reentrance_count();
+			assert_eq!(count, u32::decode(&mut &ctx.input_data[..]).unwrap());
+			assert_eq!(ctx.ext.account_reentrance_count(&BOB), count + 1);
+			assert_eq!(ctx.ext.account_reentrance_count(&CHARLIE), 0);
+			// Reenter twice.
+			if count < 2 {
+				ctx.ext.call(0, BOB, 0, (count + 1).encode(), true)?;
+			}
+			exec_success()
+		});
+
+		ExtBuilder::default().build().execute_with(|| {
+			let schedule = <Test as Config>::Schedule::get();
+			place_contract(&BOB, code_hash);
+			let mut storage_meter = storage::meter::Meter::new(&ALICE, Some(0), 0).unwrap();
+			let result = MockStack::run_call(
+				ALICE,
+				BOB,
+				&mut GasMeter::<Test>::new(GAS_LIMIT),
+				&mut storage_meter,
+				&schedule,
+				0,
+				0u32.encode(),
+				None,
+				None,
+			);
+			assert_matches!(result, Ok(_));
+		});
+	}
+
+	#[test]
+	fn reentrance_count_works_with_delegated_call() {
+		let lib_hash = MockLoader::insert(Call, |ctx, _| {
+			// The delegate call is not counted as a separate entrance.
+			assert_eq!(ctx.ext.reentrance_count(), 0);
+			assert_eq!(ctx.ext.account_reentrance_count(&BOB), 1);
+			exec_success()
+		});
+		let code_hash = MockLoader::insert(Call, move |ctx, _| {
+			ctx.ext.delegate_call(lib_hash, vec![])?;
+			exec_success()
+		});
+
+		ExtBuilder::default().build().execute_with(|| {
+			let schedule = <Test as Config>::Schedule::get();
+			place_contract(&BOB, code_hash);
+			let mut storage_meter = storage::meter::Meter::new(&ALICE, Some(0), 0).unwrap();
+			let result = MockStack::run_call(
+				ALICE,
+				BOB,
+				&mut GasMeter::<Test>::new(GAS_LIMIT),
+				&mut storage_meter,
+				&schedule,
+				0,
+				vec![],
+				None,
+				None,
+			);
+			assert_matches!(result, Ok(_));
 		});
 	}
 }
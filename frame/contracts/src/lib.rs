@@ -104,7 +104,7 @@ pub use crate::{
 	schedule::{HostFnWeights, InstructionWeights, Limits, Schedule},
 };
 use crate::{
-	exec::{AccountIdOf, ExecError, Executable, Stack as ExecStack},
+	exec::{AccountIdOf, CallTracer, ExecError, Executable, Stack as ExecStack},
 	gas::GasMeter,
 	storage::{meter::Meter as StorageMeter, ContractInfo, DeletedContract, Storage},
 	wasm::{OwnerInfo, PrefabWasmModule},
@@ -112,16 +112,16 @@ use crate::{
 };
 use codec::{Encode, HasCompact};
 use frame_support::{
-	dispatch::Dispatchable,
+	dispatch::{Dispatchable, WithPostDispatchInfo},
 	ensure,
 	traits::{Contains, Currency, Get, Randomness, ReservableCurrency, StorageVersion, Time},
 	weights::{GetDispatchInfo, Pays, PostDispatchInfo, Weight},
 };
 use frame_system::Pallet as System;
 use pallet_contracts_primitives::{
-	Code, CodeUploadResult, CodeUploadReturnValue, ContractAccessError, ContractExecResult,
-	ContractInstantiateResult, ExecReturnValue, GetStorageResult, InstantiateReturnValue,
-	StorageDeposit,
+	Code, CodeUploadResult, CodeUploadReturnValue, ContractAccessError, ContractEvent,
+	ContractExecResult, ContractInstantiateResult, ExecReturnValue, GetStorageKeysResult,
+	GetStorageResult, InstantiateReturnValue, StorageDeposit,
 };
 use scale_info::TypeInfo;
 use sp_core::{crypto::UncheckedFrom, Bytes};
@@ -330,6 +330,12 @@ pub mod pallet {
 			Storage::<T>::process_deletion_queue_batch(weight_limit)
 				.saturating_add(T::WeightInfo::on_initialize())
 		}
+
+		fn on_idle(_block: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			// Bring code that is outdated after a schedule change up to date. Calls never
+			// reinstrument code and keep using the outdated instrumentation until then.
+			Self::reinstrument_outdated_code(remaining_weight)
+		}
 	}
 
 	#[pallet::call]
@@ -374,6 +380,7 @@ pub mod pallet {
 				storage_deposit_limit.map(Into::into),
 				data,
 				None,
+				None,
 			);
 			if let Ok(retval) = &output.result {
 				if retval.did_revert() {
@@ -437,6 +444,7 @@ pub mod pallet {
 				data,
 				salt,
 				None,
+				None,
 			);
 			if let Ok(retval) = &output.result {
 				if retval.1.did_revert() {
@@ -477,6 +485,7 @@ pub mod pallet {
 				data,
 				salt,
 				None,
+				None,
 			);
 			if let Ok(retval) = &output.result {
 				if retval.1.did_revert() {
@@ -529,6 +538,44 @@ pub mod pallet {
 			// we waive the fee because removing unused code is beneficial
 			Ok(Pays::No.into())
 		}
+
+		/// Instantiates a contract from a previously deployed wasm binary unless the address
+		/// it would be instantiated at is already taken.
+		///
+		/// This function is identical to [`Self::instantiate`] except that it checks whether
+		/// a contract exists at the address derived from the origin, `code_hash` and `salt`
+		/// before anything is executed. If this is the case it fails with
+		/// [`Error::DuplicateContract`] and only the check is charged. The address can be
+		/// predicted using [`Pallet::contract_address`].
+		#[pallet::weight(
+			T::WeightInfo::instantiate(salt.len() as u32 / 1024).saturating_add(*gas_limit)
+		)]
+		pub fn try_instantiate(
+			origin: OriginFor<T>,
+			#[pallet::compact] value: BalanceOf<T>,
+			#[pallet::compact] gas_limit: Weight,
+			storage_deposit_limit: Option<<BalanceOf<T> as codec::HasCompact>::Type>,
+			code_hash: CodeHash<T>,
+			data: Vec<u8>,
+			salt: Vec<u8>,
+		) -> DispatchResultWithPostInfo {
+			let deployer = ensure_signed(origin.clone())?;
+			let address = Self::contract_address(&deployer, &code_hash, &salt);
+			if <ContractInfoOf<T>>::contains_key(&address) {
+				return Err(<Error<T>>::DuplicateContract.with_weight(
+					T::WeightInfo::try_instantiate_duplicate(salt.len() as u32 / 1024),
+				))
+			}
+			Self::instantiate(
+				origin,
+				value,
+				gas_limit,
+				storage_deposit_limit,
+				code_hash,
+				data,
+				salt,
+			)
+		}
 	}
 
 	#[pallet::event]
@@ -564,6 +611,20 @@ pub mod pallet {
 
 		/// A code with the specified hash was removed.
 		CodeRemoved { code_hash: T::Hash },
+
+		/// A contract's code was updated.
+		///
+		/// # Note
+		///
+		/// The only way for a contract to change its code is by calling `seal_set_code_hash`.
+		ContractCodeUpdated {
+			/// The contract that has been updated.
+			contract: T::AccountId,
+			/// New code hash that was set for the contract.
+			new_code_hash: T::Hash,
+			/// Previous code hash of the contract.
+			old_code_hash: T::Hash,
+		},
 	}
 
 	#[pallet::error]
@@ -641,6 +702,8 @@ pub mod pallet {
 		/// A more detailed error can be found on the node console if debug messages are enabled
 		/// or in the debug buffer which is returned to RPC clients.
 		CodeRejected,
+		/// Invalid combination of flags supplied to `seal_call` or `seal_delegate_call`.
+		InvalidCallFlags,
 	}
 
 	/// A mapping from an original code hash to the original code, untouched by instrumentation.
@@ -673,6 +736,14 @@ pub mod pallet {
 	/// stored in said trie. Therefore this operation is performed lazily in `on_initialize`.
 	#[pallet::storage]
 	pub(crate) type DeletionQueue<T: Config> = StorageValue<_, Vec<DeletedContract>, ValueQuery>;
+
+	/// Progress of the lazy reinstrumentation of [`CodeStorage`] performed in `on_idle`.
+	///
+	/// Holds the instruction weights version the stored code is being reinstrumented to
+	/// together with the raw storage key of the last visited code. A `None` key signals that
+	/// all code was reinstrumented to this version.
+	#[pallet::storage]
+	pub(crate) type ReinstrumentCursor<T: Config> = StorageValue<_, (u32, Option<Vec<u8>>)>;
 }
 
 /// Return type of the private [`Pallet::internal_call`] function.
@@ -702,9 +773,12 @@ where
 	///
 	/// # Note
 	///
-	/// `debug` should only ever be set to `true` when executing as an RPC because
-	/// it adds allocations and could be abused to drive the runtime into an OOM panic.
-	/// If set to `true` it returns additional human readable debugging information.
+	/// `debug`, `collect_events` and `collect_trace` should only ever be set to `true` when
+	/// executing as an RPC because they add allocations and could be abused to drive the
+	/// runtime into an OOM panic.
+	/// If `debug` is set to `true` it returns additional human readable debugging information.
+	/// If `collect_events` is set to `true` it returns the events emitted during execution.
+	/// If `collect_trace` is set to `true` it returns a tree of all the executed calls.
 	///
 	/// It returns the execution result and the amount of used weight.
 	pub fn bare_call(
@@ -715,8 +789,12 @@ where
 		storage_deposit_limit: Option<BalanceOf<T>>,
 		data: Vec<u8>,
 		debug: bool,
-	) -> ContractExecResult<BalanceOf<T>> {
+		collect_events: bool,
+		collect_trace: bool,
+	) -> ContractExecResult<T::AccountId, BalanceOf<T>> {
 		let mut debug_message = if debug { Some(Vec::new()) } else { None };
+		let mut call_tracer = if collect_trace { Some(CallTracer::default()) } else { None };
+		let first_event = System::<T>::event_count();
 		let output = Self::internal_call(
 			origin,
 			dest,
@@ -725,6 +803,7 @@ where
 			storage_deposit_limit,
 			data,
 			debug_message.as_mut(),
+			call_tracer.as_mut(),
 		);
 		ContractExecResult {
 			result: output.result.map_err(|r| r.error),
@@ -732,6 +811,8 @@ where
 			gas_required: output.gas_meter.gas_required(),
 			storage_deposit: output.storage_deposit,
 			debug_message: debug_message.unwrap_or_default(),
+			events: if collect_events { Some(Self::events_since(first_event)) } else { None },
+			call_trace: call_tracer.and_then(CallTracer::into_trace),
 		}
 	}
 
@@ -744,9 +825,12 @@ where
 	///
 	/// # Note
 	///
-	/// `debug` should only ever be set to `true` when executing as an RPC because
-	/// it adds allocations and could be abused to drive the runtime into an OOM panic.
-	/// If set to `true` it returns additional human readable debugging information.
+	/// `debug`, `collect_events` and `collect_trace` should only ever be set to `true` when
+	/// executing as an RPC because they add allocations and could be abused to drive the
+	/// runtime into an OOM panic.
+	/// If `debug` is set to `true` it returns additional human readable debugging information.
+	/// If `collect_events` is set to `true` it returns the events emitted during execution.
+	/// If `collect_trace` is set to `true` it returns a tree of all the executed calls.
 	pub fn bare_instantiate(
 		origin: T::AccountId,
 		value: BalanceOf<T>,
@@ -756,8 +840,12 @@ where
 		data: Vec<u8>,
 		salt: Vec<u8>,
 		debug: bool,
+		collect_events: bool,
+		collect_trace: bool,
 	) -> ContractInstantiateResult<T::AccountId, BalanceOf<T>> {
 		let mut debug_message = if debug { Some(Vec::new()) } else { None };
+		let mut call_tracer = if collect_trace { Some(CallTracer::default()) } else { None };
+		let first_event = System::<T>::event_count();
 		let output = Self::internal_instantiate(
 			origin,
 			value,
@@ -767,6 +855,7 @@ where
 			data,
 			salt,
 			debug_message.as_mut(),
+			call_tracer.as_mut(),
 		);
 		ContractInstantiateResult {
 			result: output
@@ -777,6 +866,8 @@ where
 			gas_required: output.gas_meter.gas_required(),
 			storage_deposit: output.storage_deposit,
 			debug_message: debug_message.unwrap_or_default(),
+			events: if collect_events { Some(Self::events_since(first_event)) } else { None },
+			call_trace: call_tracer.and_then(CallTracer::into_trace),
 		}
 	}
 
@@ -810,6 +901,39 @@ where
 		Ok(maybe_value)
 	}
 
+	/// Query the hashed storage keys of a contract.
+	///
+	/// Returns up to `limit` keys which follow `start_key` in the contract's storage. Keys are
+	/// the `blake2_256` hashes of the keys used by the contract and are returned in ascending
+	/// order. Supply the last returned key as `start_key` to query the next page.
+	pub fn get_storage_keys(
+		address: T::AccountId,
+		start_key: Option<[u8; 32]>,
+		limit: u32,
+	) -> GetStorageKeysResult {
+		let contract_info =
+			ContractInfoOf::<T>::get(&address).ok_or(ContractAccessError::DoesntExist)?;
+
+		Ok(Storage::<T>::hashed_keys(&contract_info.trie_id, start_key, limit))
+	}
+
+	/// Returns all events deposited by contracts in the current block.
+	///
+	/// This reads all the events of the current block. Hence it must not be used on-chain.
+	pub fn contract_events() -> Vec<ContractEvent<T::AccountId, T::Hash>>
+	where
+		<T as frame_system::Config>::Event: TryInto<Event<T>>,
+	{
+		System::<T>::read_events_no_consensus()
+			.into_iter()
+			.filter_map(|record| match record.event.try_into() {
+				Ok(Event::ContractEmitted { contract, data }) =>
+					Some(ContractEvent { contract, topics: record.topics, data }),
+				_ => None,
+			})
+			.collect()
+	}
+
 	/// Determine the address of a contract.
 	///
 	/// This is the address generation function used by contract instantiation. See
@@ -822,6 +946,17 @@ where
 		T::AddressGenerator::generate_address(deploying_address, code_hash, salt)
 	}
 
+	/// Returns the SCALE encoded records of all events deposited after `first`.
+	///
+	/// This reads all the events of the current block. Hence it must not be used on-chain.
+	fn events_since(first: u32) -> Vec<Bytes> {
+		System::<T>::read_events_no_consensus()
+			.into_iter()
+			.skip(first as usize)
+			.map(|record| Bytes(record.encode()))
+			.collect()
+	}
+
 	/// Store code for benchmarks which does not check nor instrument the code.
 	#[cfg(feature = "runtime-benchmarks")]
 	fn store_code_raw(
@@ -833,6 +968,18 @@ where
 		Ok(())
 	}
 
+	/// Reinstrument code that was instrumented with an outdated schedule.
+	///
+	/// This is called from `on_idle` but can also be used by runtime migrations in order to
+	/// reinstrument code eagerly after a schedule change. Code is visited in storage order
+	/// and processing resumes where the previous call stopped. Returns the weight consumed
+	/// which never exceeds `weight_limit`.
+	///
+	/// Code that is called before it was visited is executed with its outdated instrumentation.
+	pub fn reinstrument_outdated_code(weight_limit: Weight) -> Weight {
+		self::wasm::reinstrument_outdated::<T>(weight_limit)
+	}
+
 	/// This exists so that benchmarks can determine the weight of running an instrumentation.
 	#[cfg(feature = "runtime-benchmarks")]
 	fn reinstrument_module(
@@ -853,6 +1000,7 @@ where
 		storage_deposit_limit: Option<BalanceOf<T>>,
 		data: Vec<u8>,
 		debug_message: Option<&mut Vec<u8>>,
+		call_tracer: Option<&mut CallTracer<T>>,
 	) -> InternalCallOutput<T> {
 		let mut gas_meter = GasMeter::new(gas_limit);
 		let mut storage_meter = match StorageMeter::new(&origin, storage_deposit_limit, value) {
@@ -874,6 +1022,7 @@ where
 			value,
 			data,
 			debug_message,
+			call_tracer,
 		);
 		InternalCallOutput { result, gas_meter, storage_deposit: storage_meter.into_deposit() }
 	}
@@ -890,6 +1039,7 @@ where
 		data: Vec<u8>,
 		salt: Vec<u8>,
 		mut debug_message: Option<&mut Vec<u8>>,
+		call_tracer: Option<&mut CallTracer<T>>,
 	) -> InternalInstantiateOutput<T> {
 		let mut storage_deposit = Default::default();
 		let mut gas_meter = GasMeter::new(gas_limit);
@@ -936,6 +1086,7 @@ where
 				data,
 				&salt,
 				debug_message,
+				call_tracer,
 			);
 			storage_deposit = storage_meter
 				.into_deposit()
@@ -187,7 +187,9 @@ pub struct InstructionWeights<T: Config> {
 	/// reason is that changes to instruction weights require a re-instrumentation
 	/// in order to apply the changes to an already deployed code. The re-instrumentation
 	/// is triggered by comparing the version of the current schedule with the version the code was
-	/// instrumented with. Changes usually happen when pallet_contracts is re-benchmarked.
+	/// instrumented with. It is performed in `on_idle` and never when the code is called, so
+	/// deployed code is metered with its previous instruction weights until it was reinstrumented.
+	/// Changes usually happen when pallet_contracts is re-benchmarked.
 	///
 	/// Changes to other parts of the schedule should not increment the version in
 	/// order to avoid unnecessary re-instrumentations.
@@ -340,6 +342,12 @@ pub struct HostFnWeights<T: Config> {
 	/// Weight per byte of an item received via `seal_get_storage`.
 	pub get_storage_per_byte: Weight,
 
+	/// Weight of calling `seal_next_key`.
+	pub next_key: Weight,
+
+	/// Weight per byte of an item received via `seal_next_key`.
+	pub next_key_per_byte: Weight,
+
 	/// Weight of calling `seal_take_storage`.
 	pub take_storage: Weight,
 
@@ -352,6 +360,9 @@ pub struct HostFnWeights<T: Config> {
 	/// Weight of calling `seal_call`.
 	pub call: Weight,
 
+	/// Weight of calling `seal_delegate_call`.
+	pub delegate_call: Weight,
+
 	/// Weight surcharge that is claimed if `seal_call` does a balance transfer.
 	pub call_transfer_surcharge: Weight,
 
@@ -400,6 +411,30 @@ pub struct HostFnWeights<T: Config> {
 	/// Weight of calling `seal_ecdsa_recover`.
 	pub ecdsa_recover: Weight,
 
+	/// Weight of calling `seal_sr25519_verify`.
+	pub sr25519_verify: Weight,
+
+	/// Weight per byte of the message verified by `seal_sr25519_verify`.
+	pub sr25519_verify_per_byte: Weight,
+
+	/// Weight of calling `seal_ecdsa_to_eth_address`.
+	pub ecdsa_to_eth_address: Weight,
+
+	/// Weight of calling `seal_set_code_hash`.
+	pub set_code_hash: Weight,
+
+	/// Weight of calling `seal_code_hash`.
+	pub code_hash: Weight,
+
+	/// Weight of calling `seal_own_code_hash`.
+	pub own_code_hash: Weight,
+
+	/// Weight of calling `seal_reentrance_count`.
+	pub reentrance_count: Weight,
+
+	/// Weight of calling `seal_account_reentrance_count`.
+	pub account_reentrance_count: Weight,
+
 	/// The type parameter is used in the default implementation.
 	#[codec(skip)]
 	pub _phantom: PhantomData<T>,
@@ -603,10 +638,13 @@ impl<T: Config> Default for HostFnWeights<T> {
 			contains_storage_per_byte: cost_byte_batched!(seal_contains_storage_per_kb),
 			get_storage: cost_batched!(seal_get_storage),
 			get_storage_per_byte: cost_byte_batched!(seal_get_storage_per_kb),
+			next_key: cost_batched!(seal_next_key),
+			next_key_per_byte: cost_byte_batched!(seal_next_key_per_kb),
 			take_storage: cost_batched!(seal_take_storage),
 			take_storage_per_byte: cost_byte_batched!(seal_take_storage_per_kb),
 			transfer: cost_batched!(seal_transfer),
 			call: cost_batched!(seal_call),
+			delegate_call: cost_batched!(seal_delegate_call),
 			call_transfer_surcharge: cost_batched_args!(
 				seal_call_per_transfer_input_output_kb,
 				1,
@@ -653,6 +691,14 @@ impl<T: Config> Default for HostFnWeights<T> {
 			hash_blake2_128: cost_batched!(seal_hash_blake2_128),
 			hash_blake2_128_per_byte: cost_byte_batched!(seal_hash_blake2_128_per_kb),
 			ecdsa_recover: cost_batched!(seal_ecdsa_recover),
+			sr25519_verify: cost_batched!(seal_sr25519_verify),
+			sr25519_verify_per_byte: cost_byte_batched!(seal_sr25519_verify_per_kb),
+			ecdsa_to_eth_address: cost_batched!(seal_ecdsa_to_eth_address),
+			set_code_hash: cost_batched!(seal_set_code_hash),
+			code_hash: cost_batched!(seal_code_hash),
+			own_code_hash: cost_batched!(seal_own_code_hash),
+			reentrance_count: cost_batched!(seal_reentrance_count),
+			account_reentrance_count: cost_batched!(seal_account_reentrance_count),
 			_phantom: PhantomData,
 		}
 	}
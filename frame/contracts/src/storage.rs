@@ -137,6 +137,51 @@ where
 		child::len(&child_trie_info(trie_id), &blake2_256(key))
 	}
 
+	/// Returns the storage entry that follows `hashed_key` in the contract's child trie.
+	///
+	/// Storage keys are hashed before they are written to the child trie. Therefore the
+	/// iteration happens in the order of the hashed keys and the hashed key of the entry is
+	/// returned alongside its value. Passing `None` returns the first entry.
+	pub fn next_entry(
+		trie_id: &TrieId,
+		hashed_key: Option<&[u8; 32]>,
+	) -> Option<([u8; 32], Vec<u8>)> {
+		let child_trie_info = child_trie_info(trie_id);
+		let next_key = sp_io::default_child_storage::next_key(
+			child_trie_info.storage_key(),
+			hashed_key.map(|key| &key[..]).unwrap_or(&[]),
+		)?;
+		let value = child::get_raw(&child_trie_info, &next_key)?;
+		Some((next_key.try_into().ok()?, value))
+	}
+
+	/// Returns up to `limit` hashed storage keys of a contract that follow `hashed_key`.
+	///
+	/// See [`Self::next_entry`] for the ordering of the keys.
+	pub fn hashed_keys(
+		trie_id: &TrieId,
+		hashed_key: Option<[u8; 32]>,
+		limit: u32,
+	) -> Vec<[u8; 32]> {
+		let child_trie_info = child_trie_info(trie_id);
+		let mut keys = Vec::new();
+		let mut previous = hashed_key.map(|key| key.to_vec()).unwrap_or_default();
+		while keys.len() < limit as usize {
+			let next_key = match sp_io::default_child_storage::next_key(
+				child_trie_info.storage_key(),
+				&previous,
+			) {
+				Some(key) => key,
+				None => break,
+			};
+			if let Ok(key) = next_key.as_slice().try_into() {
+				keys.push(key);
+			}
+			previous = next_key;
+		}
+		keys
+	}
+
 	/// Update a storage entry into a contract's kv storage.
 	///
 	/// If the `new_value` is `None` then the kv pair is removed. If `take` is true
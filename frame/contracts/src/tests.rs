@@ -17,8 +17,8 @@
 
 use crate::{
 	chain_extension::{
-		ChainExtension, Environment, Ext, InitState, Result as ExtensionResult, RetVal,
-		ReturnFlags, SysConfig, UncheckedFrom,
+		ChainExtension, Environment, Ext, InitState, RegisteredChainExtension,
+		Result as ExtensionResult, RetVal, ReturnFlags, SysConfig, UncheckedFrom,
 	},
 	exec::Frame,
 	storage::Storage,
@@ -28,27 +28,30 @@ use crate::{
 	Schedule,
 };
 use assert_matches::assert_matches;
-use codec::Encode;
+use codec::{Decode, Encode};
 use frame_support::{
 	assert_err, assert_err_ignore_postinfo, assert_noop, assert_ok,
 	dispatch::DispatchErrorWithPostInfo,
 	parameter_types,
 	storage::child,
 	traits::{
-		BalanceStatus, ConstU32, ConstU64, Contains, Currency, OnInitialize, ReservableCurrency,
+		BalanceStatus, ConstU32, ConstU64, Contains, Currency, OnIdle, OnInitialize,
+		ReservableCurrency,
 	},
 	weights::{constants::WEIGHT_PER_SECOND, DispatchClass, PostDispatchInfo, Weight},
 };
 use frame_system::{self as system, EventRecord, Phase};
+use pallet_contracts_primitives::{CallKind, ContractAccessError};
 use pretty_assertions::assert_eq;
 use sp_core::Bytes;
 use sp_io::hashing::blake2_256;
+use sp_keystore::{testing::KeyStore, KeystoreExt};
 use sp_runtime::{
 	testing::{Header, H256},
 	traits::{BlakeTwo256, Convert, Hash, IdentityLookup},
 	AccountId32,
 };
-use std::cell::RefCell;
+use std::{cell::RefCell, sync::Arc};
 
 use crate as pallet_contracts;
 
@@ -179,6 +182,54 @@ impl ChainExtension<Test> for TestExtension {
 	}
 }
 
+impl RegisteredChainExtension<Test> for TestExtension {
+	const ID: u16 = 0;
+}
+
+/// A chain extension which always reverts the calling contract.
+///
+/// It shares the enablement of [`TestExtension`].
+pub struct RevertingExtension;
+
+impl ChainExtension<Test> for RevertingExtension {
+	fn call<E>(_func_id: u32, _env: Environment<E, InitState>) -> ExtensionResult<RetVal>
+	where
+		E: Ext<T = Test>,
+		<E::T as SysConfig>::AccountId: UncheckedFrom<<E::T as SysConfig>::Hash> + AsRef<[u8]>,
+	{
+		Ok(RetVal::Diverging { flags: ReturnFlags::REVERT, data: vec![0x4B, 0x1D] })
+	}
+
+	fn enabled() -> bool {
+		TEST_EXTENSION.with(|e| e.borrow().enabled)
+	}
+}
+
+impl RegisteredChainExtension<Test> for RevertingExtension {
+	const ID: u16 = 1;
+}
+
+/// A chain extension which is never enabled.
+pub struct DisabledExtension;
+
+impl ChainExtension<Test> for DisabledExtension {
+	fn call<E>(_func_id: u32, _env: Environment<E, InitState>) -> ExtensionResult<RetVal>
+	where
+		E: Ext<T = Test>,
+		<E::T as SysConfig>::AccountId: UncheckedFrom<<E::T as SysConfig>::Hash> + AsRef<[u8]>,
+	{
+		panic!("Disabled chain extensions are never called")
+	}
+
+	fn enabled() -> bool {
+		false
+	}
+}
+
+impl RegisteredChainExtension<Test> for DisabledExtension {
+	const ID: u16 = 2;
+}
+
 parameter_types! {
 	pub BlockWeights: frame_system::limits::BlockWeights =
 		frame_system::limits::BlockWeights::simple_max(2 * WEIGHT_PER_SECOND);
@@ -280,7 +331,7 @@ impl Config for Test {
 	type CallStack = [Frame<Self>; 31];
 	type WeightPrice = Self;
 	type WeightInfo = ();
-	type ChainExtension = TestExtension;
+	type ChainExtension = (TestExtension, DisabledExtension, RevertingExtension);
 	type DeletionQueueDepth = ConstU32<1024>;
 	type DeletionWeightLimit = DeletionWeightLimit;
 	type Schedule = MySchedule;
@@ -322,6 +373,7 @@ impl ExtBuilder {
 			.assimilate_storage(&mut t)
 			.unwrap();
 		let mut ext = sp_io::TestExternalities::new(t);
+		ext.register_extension(KeystoreExt(Arc::new(KeyStore::new())));
 		ext.execute_with(|| System::set_block_number(1));
 		ext
 	}
@@ -455,6 +507,63 @@ fn instantiate_and_call_and_deposit_event() {
 	});
 }
 
+#[test]
+fn try_instantiate_fails_fast_on_existing_address() {
+	let (wasm, code_hash) = compile_module::<Test>("return_from_start_fn").unwrap();
+
+	ExtBuilder::default().existential_deposit(500).build().execute_with(|| {
+		let _ = Balances::deposit_creating(&ALICE, 1_000_000);
+		assert_ok!(Contracts::upload_code(Origin::signed(ALICE), wasm, None));
+
+		let addr = Contracts::contract_address(&ALICE, &code_hash, &[1, 2, 3]);
+		assert_ok!(Contracts::try_instantiate(
+			Origin::signed(ALICE),
+			100,
+			GAS_LIMIT,
+			None,
+			code_hash,
+			vec![],
+			vec![1, 2, 3],
+		));
+		assert!(ContractInfoOf::<Test>::contains_key(&addr));
+		let balance = Balances::free_balance(&ALICE);
+
+		// Deploying to the same address again fails before anything is executed and only
+		// the existence check is charged.
+		let result = Contracts::try_instantiate(
+			Origin::signed(ALICE),
+			100,
+			GAS_LIMIT,
+			None,
+			code_hash,
+			vec![],
+			vec![1, 2, 3],
+		);
+		assert_err_ignore_postinfo!(result, <Error<Test>>::DuplicateContract);
+		assert_eq!(
+			result.unwrap_err().post_info.actual_weight,
+			Some(<Test as Config>::WeightInfo::try_instantiate_duplicate(0)),
+		);
+		assert_eq!(Balances::free_balance(&ALICE), balance);
+
+		// A different salt yields a different address.
+		assert_ok!(Contracts::try_instantiate(
+			Origin::signed(ALICE),
+			100,
+			GAS_LIMIT,
+			None,
+			code_hash,
+			vec![],
+			vec![4, 5, 6],
+		));
+		assert!(ContractInfoOf::<Test>::contains_key(&Contracts::contract_address(
+			&ALICE,
+			&code_hash,
+			&[4, 5, 6],
+		)));
+	});
+}
+
 #[test]
 fn deposit_event_max_value_limit() {
 	let (wasm, code_hash) = compile_module::<Test>("event_size").unwrap();
@@ -581,6 +690,52 @@ fn storage_max_value_limit() {
 	});
 }
 
+#[test]
+fn get_storage_keys_works() {
+	let (wasm, code_hash) = compile_module::<Test>("dummy").unwrap();
+	ExtBuilder::default().existential_deposit(50).build().execute_with(|| {
+		let min_balance = <Test as Config>::Currency::minimum_balance();
+		let _ = Balances::deposit_creating(&ALICE, 1000 * min_balance);
+
+		// Querying an address without a contract fails.
+		assert_eq!(
+			Contracts::get_storage_keys(BOB, None, 10),
+			Err(ContractAccessError::DoesntExist),
+		);
+
+		assert_ok!(Contracts::instantiate_with_code(
+			Origin::signed(ALICE),
+			min_balance * 100,
+			GAS_LIMIT,
+			None,
+			wasm,
+			vec![],
+			vec![],
+		));
+		let addr = Contracts::contract_address(&ALICE, &code_hash, &[]);
+		let info = <ContractInfoOf<Test>>::get(&addr).unwrap();
+		assert_eq!(Contracts::get_storage_keys(addr.clone(), None, 10), Ok(vec![]));
+
+		// Put some values into the contract's child trie.
+		let keys: Vec<_> = (0u32..5).map(|i| blake2_256(&i.encode())).collect();
+		for key in &keys {
+			Storage::<Test>::write(&info.trie_id, key, Some(vec![1]), None, false).unwrap();
+		}
+		let mut hashed_keys: Vec<_> = keys.iter().map(|key| blake2_256(key)).collect();
+		hashed_keys.sort();
+
+		// All keys are returned in the order of their hashes.
+		assert_eq!(Contracts::get_storage_keys(addr.clone(), None, 10), Ok(hashed_keys.clone()));
+
+		// Keys can be queried page by page.
+		let first_page = Contracts::get_storage_keys(addr.clone(), None, 3).unwrap();
+		assert_eq!(first_page, hashed_keys[..3].to_vec());
+		let second_page =
+			Contracts::get_storage_keys(addr.clone(), first_page.last().cloned(), 3).unwrap();
+		assert_eq!(second_page, hashed_keys[3..].to_vec());
+	});
+}
+
 #[test]
 fn deploy_and_call_other_contract() {
 	let (caller_wasm, caller_code_hash) = compile_module::<Test>("caller_contract").unwrap();
@@ -1093,10 +1248,19 @@ fn crypto_hashes() {
 			// We offset data in the contract tables by 1.
 			let mut params = vec![(n + 1) as u8];
 			params.extend_from_slice(input);
-			let result =
-				<Pallet<Test>>::bare_call(ALICE, addr.clone(), 0, GAS_LIMIT, None, params, false)
-					.result
-					.unwrap();
+			let result = <Pallet<Test>>::bare_call(
+				ALICE,
+				addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				params,
+				false,
+				false,
+				false,
+			)
+			.result
+			.unwrap();
 			assert!(!result.did_revert());
 			let expected = hash_fn(input.as_ref());
 			assert_eq!(&result.data[..*expected_size], &*expected);
@@ -1124,9 +1288,19 @@ fn transfer_return_code() {
 
 		// Contract has only the minimal balance so any transfer will fail.
 		Balances::make_free_balance_be(&addr, min_balance);
-		let result = Contracts::bare_call(ALICE, addr.clone(), 0, GAS_LIMIT, None, vec![], false)
-			.result
-			.unwrap();
+		let result = Contracts::bare_call(
+			ALICE,
+			addr.clone(),
+			0,
+			GAS_LIMIT,
+			None,
+			vec![],
+			false,
+			false,
+			false,
+		)
+		.result
+		.unwrap();
 		assert_return_code!(result, RuntimeReturnCode::TransferFailed);
 
 		// Contract has enough total balance in order to not go below the min balance
@@ -1134,9 +1308,10 @@ fn transfer_return_code() {
 		// the transfer still fails.
 		Balances::make_free_balance_be(&addr, min_balance + 100);
 		Balances::reserve(&addr, min_balance + 100).unwrap();
-		let result = Contracts::bare_call(ALICE, addr, 0, GAS_LIMIT, None, vec![], false)
-			.result
-			.unwrap();
+		let result =
+			Contracts::bare_call(ALICE, addr, 0, GAS_LIMIT, None, vec![], false, false, false)
+				.result
+				.unwrap();
 		assert_return_code!(result, RuntimeReturnCode::TransferFailed);
 	});
 }
@@ -1171,6 +1346,8 @@ fn call_return_code() {
 			None,
 			AsRef::<[u8]>::as_ref(&DJANGO).to_vec(),
 			false,
+			false,
+			false,
 		)
 		.result
 		.unwrap();
@@ -1201,6 +1378,8 @@ fn call_return_code() {
 				.cloned()
 				.collect(),
 			false,
+			false,
+			false,
 		)
 		.result
 		.unwrap();
@@ -1223,6 +1402,8 @@ fn call_return_code() {
 				.cloned()
 				.collect(),
 			false,
+			false,
+			false,
 		)
 		.result
 		.unwrap();
@@ -1242,6 +1423,8 @@ fn call_return_code() {
 				.cloned()
 				.collect(),
 			false,
+			false,
+			false,
 		)
 		.result
 		.unwrap();
@@ -1260,6 +1443,8 @@ fn call_return_code() {
 				.cloned()
 				.collect(),
 			false,
+			false,
+			false,
 		)
 		.result
 		.unwrap();
@@ -1308,6 +1493,8 @@ fn instantiate_return_code() {
 			None,
 			callee_hash.clone(),
 			false,
+			false,
+			false,
 		)
 		.result
 		.unwrap();
@@ -1326,6 +1513,8 @@ fn instantiate_return_code() {
 			None,
 			callee_hash.clone(),
 			false,
+			false,
+			false,
 		)
 		.result
 		.unwrap();
@@ -1333,10 +1522,19 @@ fn instantiate_return_code() {
 
 		// Contract has enough balance but the passed code hash is invalid
 		Balances::make_free_balance_be(&addr, min_balance + 10_000);
-		let result =
-			Contracts::bare_call(ALICE, addr.clone(), 0, GAS_LIMIT, None, vec![0; 33], false)
-				.result
-				.unwrap();
+		let result = Contracts::bare_call(
+			ALICE,
+			addr.clone(),
+			0,
+			GAS_LIMIT,
+			None,
+			vec![0; 33],
+			false,
+			false,
+			false,
+		)
+		.result
+		.unwrap();
 		assert_return_code!(result, RuntimeReturnCode::CodeNotFound);
 
 		// Contract has enough balance but callee reverts because "1" is passed.
@@ -1348,6 +1546,8 @@ fn instantiate_return_code() {
 			None,
 			callee_hash.iter().chain(&1u32.to_le_bytes()).cloned().collect(),
 			false,
+			false,
+			false,
 		)
 		.result
 		.unwrap();
@@ -1362,6 +1562,8 @@ fn instantiate_return_code() {
 			None,
 			callee_hash.iter().chain(&2u32.to_le_bytes()).cloned().collect(),
 			false,
+			false,
+			false,
 		)
 		.result
 		.unwrap();
@@ -1437,34 +1639,104 @@ fn chain_extension_works() {
 		// func_id.
 
 		// 0 = read input buffer and pass it through as output
-		let result =
-			Contracts::bare_call(ALICE, addr.clone(), 0, GAS_LIMIT, None, vec![0, 99], false);
+		let result = Contracts::bare_call(
+			ALICE,
+			addr.clone(),
+			0,
+			GAS_LIMIT,
+			None,
+			vec![0, 99],
+			false,
+			false,
+			false,
+		);
 		let gas_consumed = result.gas_consumed;
 		assert_eq!(TestExtension::last_seen_buffer(), vec![0, 99]);
 		assert_eq!(result.result.unwrap().data, Bytes(vec![0, 99]));
 
 		// 1 = treat inputs as integer primitives and store the supplied integers
-		Contracts::bare_call(ALICE, addr.clone(), 0, GAS_LIMIT, None, vec![1], false)
+		Contracts::bare_call(ALICE, addr.clone(), 0, GAS_LIMIT, None, vec![1], false, false, false)
 			.result
 			.unwrap();
 		// those values passed in the fixture
 		assert_eq!(TestExtension::last_seen_inputs(), (4, 1, 16, 12));
 
 		// 2 = charge some extra weight (amount supplied in second byte)
-		let result =
-			Contracts::bare_call(ALICE, addr.clone(), 0, GAS_LIMIT, None, vec![2, 42], false);
+		let result = Contracts::bare_call(
+			ALICE,
+			addr.clone(),
+			0,
+			GAS_LIMIT,
+			None,
+			vec![2, 42],
+			false,
+			false,
+			false,
+		);
 		assert_ok!(result.result);
 		assert_eq!(result.gas_consumed, gas_consumed + 42);
 
 		// 3 = diverging chain extension call that sets flags to 0x1 and returns a fixed buffer
-		let result = Contracts::bare_call(ALICE, addr.clone(), 0, GAS_LIMIT, None, vec![3], false)
-			.result
-			.unwrap();
+		let result = Contracts::bare_call(
+			ALICE,
+			addr.clone(),
+			0,
+			GAS_LIMIT,
+			None,
+			vec![3],
+			false,
+			false,
+			false,
+		)
+		.result
+		.unwrap();
 		assert_eq!(result.flags, ReturnFlags::REVERT);
 		assert_eq!(result.data, Bytes(vec![42, 99]));
 	});
 }
 
+#[test]
+fn chain_extension_registry_routes_by_id() {
+	let (code, hash) = compile_module::<Test>("chain_extension_ids").unwrap();
+	ExtBuilder::default().existential_deposit(50).build().execute_with(|| {
+		let min_balance = <Test as Config>::Currency::minimum_balance();
+		let _ = Balances::deposit_creating(&ALICE, 1000 * min_balance);
+		assert_ok!(Contracts::instantiate_with_code(
+			Origin::signed(ALICE),
+			min_balance * 100,
+			GAS_LIMIT,
+			None,
+			code,
+			vec![],
+			vec![],
+		));
+		let addr = Contracts::contract_address(&ALICE, &hash, &[]);
+		let call = |ext_id: u16, func_id: u16, input: &[u8]| {
+			let func_id = (u32::from(ext_id) << 16) | u32::from(func_id);
+			let mut data = func_id.to_le_bytes().to_vec();
+			data.extend_from_slice(input);
+			Contracts::bare_call(ALICE, addr.clone(), 0, GAS_LIMIT, None, data, false, false, false)
+				.result
+		};
+
+		// 0 = TestExtension which passes through the input
+		let result = call(0, 0, &[0, 99]).unwrap();
+		assert_eq!(result.data, Bytes(vec![0, 99]));
+		assert_eq!(TestExtension::last_seen_buffer(), vec![0, 99]);
+
+		// 1 = RevertingExtension
+		let result = call(1, 0, &[]).unwrap();
+		assert_eq!(result.flags, ReturnFlags::REVERT);
+		assert_eq!(result.data, Bytes(vec![0x4B, 0x1D]));
+
+		// 2 = DisabledExtension is part of the tuple but never called
+		assert_err!(call(2, 0, &[]), <Error<Test>>::NoChainExtension);
+
+		// 3 = no extension claimed this id
+		assert_err!(call(3, 0, &[]), <Error<Test>>::NoChainExtension);
+	});
+}
+
 #[test]
 fn lazy_removal_works() {
 	let (code, hash) = compile_module::<Test>("self_destruct").unwrap();
@@ -1914,13 +2186,12 @@ fn refcounter() {
 }
 
 #[test]
-fn reinstrument_does_charge() {
+fn call_does_not_reinstrument() {
 	let (wasm, code_hash) = compile_module::<Test>("return_with_data").unwrap();
 	ExtBuilder::default().existential_deposit(50).build().execute_with(|| {
 		let _ = Balances::deposit_creating(&ALICE, 1_000_000);
 		let min_balance = <Test as Config>::Currency::minimum_balance();
 		let zero = 0u32.to_le_bytes().encode();
-		let code_len = wasm.len() as u32;
 
 		assert_ok!(Contracts::instantiate_with_code(
 			Origin::signed(ALICE),
@@ -1936,12 +2207,30 @@ fn reinstrument_does_charge() {
 
 		// Call the contract two times without reinstrument
 
-		let result0 =
-			Contracts::bare_call(ALICE, addr.clone(), 0, GAS_LIMIT, None, zero.clone(), false);
+		let result0 = Contracts::bare_call(
+			ALICE,
+			addr.clone(),
+			0,
+			GAS_LIMIT,
+			None,
+			zero.clone(),
+			false,
+			false,
+			false,
+		);
 		assert!(!result0.result.unwrap().did_revert());
 
-		let result1 =
-			Contracts::bare_call(ALICE, addr.clone(), 0, GAS_LIMIT, None, zero.clone(), false);
+		let result1 = Contracts::bare_call(
+			ALICE,
+			addr.clone(),
+			0,
+			GAS_LIMIT,
+			None,
+			zero.clone(),
+			false,
+			false,
+			false,
+		);
 		assert!(!result1.result.unwrap().did_revert());
 
 		// They should match because both where called with the same schedule.
@@ -1952,36 +2241,121 @@ fn reinstrument_does_charge() {
 		crate::CodeStorage::mutate(&code_hash, |code: &mut Option<PrefabWasmModule<Test>>| {
 			code.as_mut().unwrap().decrement_version();
 		});
+		let outdated = crate::CodeStorage::<Test>::get(&code_hash).unwrap().encode();
 
-		// This call should trigger reinstrumentation
-		let result2 =
-			Contracts::bare_call(ALICE, addr.clone(), 0, GAS_LIMIT, None, zero.clone(), false);
-		assert!(!result2.result.unwrap().did_revert());
-		assert!(result2.gas_consumed > result1.gas_consumed);
-		assert_eq!(
-			result2.gas_consumed,
-			result1.gas_consumed + <Test as Config>::WeightInfo::reinstrument(code_len / 1024),
+		// This call runs the outdated code as it is cached.
+		let result2 = Contracts::bare_call(
+			ALICE,
+			addr.clone(),
+			0,
+			GAS_LIMIT,
+			None,
+			zero.clone(),
+			false,
+			false,
+			false,
 		);
+		assert!(!result2.result.unwrap().did_revert());
+		assert_eq!(result2.gas_consumed, result1.gas_consumed);
+
+		// The code is left for `on_idle` to reinstrument.
+		assert_eq!(crate::CodeStorage::<Test>::get(&code_hash).unwrap().encode(), outdated);
 	});
 }
 
 #[test]
-fn debug_message_works() {
-	let (wasm, code_hash) = compile_module::<Test>("debug_message_works").unwrap();
-
+fn reinstrument_on_idle_spares_callers() {
+	let (wasm, code_hash) = compile_module::<Test>("return_with_data").unwrap();
+	let (dummy_wasm, dummy_code_hash) = compile_module::<Test>("dummy").unwrap();
 	ExtBuilder::default().existential_deposit(50).build().execute_with(|| {
 		let _ = Balances::deposit_creating(&ALICE, 1_000_000);
+		let min_balance = <Test as Config>::Currency::minimum_balance();
+		let zero = 0u32.to_le_bytes().encode();
+		let schedule = <Test as Config>::Schedule::get();
+		let version = schedule.instruction_weights.version;
+
+		assert_ok!(Contracts::upload_code(Origin::signed(ALICE), dummy_wasm, None));
 		assert_ok!(Contracts::instantiate_with_code(
 			Origin::signed(ALICE),
-			30_000,
+			min_balance * 100,
 			GAS_LIMIT,
 			None,
 			wasm,
+			zero.clone(),
+			vec![],
+		));
+		let addr = Contracts::contract_address(&ALICE, &code_hash, &[]);
+		let call = || {
+			let result = Contracts::bare_call(
+				ALICE,
+				addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				zero.clone(),
+				false,
+				false,
+				false,
+			);
+			assert!(!result.result.unwrap().did_revert());
+			result.gas_consumed
+		};
+		let gas_before = call();
+
+		// Nothing is outdated: the whole storage is visited and marked as done.
+		Contracts::on_idle(0, Weight::MAX);
+		assert_eq!(crate::ReinstrumentCursor::<Test>::get(), Some((version, None)));
+
+		// Pretend that the schedule changed by outdating both codes.
+		for hash in [code_hash, dummy_code_hash] {
+			crate::CodeStorage::mutate(&hash, |code: &mut Option<PrefabWasmModule<Test>>| {
+				code.as_mut().unwrap().decrement_version();
+			});
+		}
+		crate::ReinstrumentCursor::<Test>::kill();
+
+		// Not enough weight to visit a single code.
+		let max_weight_per_code =
+			<Test as Config>::WeightInfo::reinstrument_code(schedule.limits.code_len / 1024);
+		let weight_used = Contracts::on_idle(0, max_weight_per_code);
+		assert_eq!(weight_used, <Test as frame_system::Config>::DbWeight::get().reads(1));
+		assert_eq!(crate::ReinstrumentCursor::<Test>::get(), None);
+
+		// Only enough weight to visit one of the codes.
+		let weight_limit = <Test as frame_system::Config>::DbWeight::get()
+			.reads_writes(1, 1)
+			.saturating_add(max_weight_per_code);
+		let weight_used = Contracts::on_idle(0, weight_limit);
+		assert!(weight_used <= weight_limit);
+		assert_matches!(crate::ReinstrumentCursor::<Test>::get(), Some((v, Some(_))) if v == version);
+
+		// The second run continues where the first one stopped and finishes.
+		Contracts::on_idle(0, Weight::MAX);
+		assert_eq!(crate::ReinstrumentCursor::<Test>::get(), Some((version, None)));
+
+		// The caller is not charged for any reinstrumentation.
+		assert_eq!(call(), gas_before);
+	});
+}
+
+#[test]
+fn debug_message_works() {
+	let (wasm, code_hash) = compile_module::<Test>("debug_message_works").unwrap();
+
+	ExtBuilder::default().existential_deposit(50).build().execute_with(|| {
+		let _ = Balances::deposit_creating(&ALICE, 1_000_000);
+		assert_ok!(Contracts::instantiate_with_code(
+			Origin::signed(ALICE),
+			30_000,
+			GAS_LIMIT,
+			None,
+			wasm,
+			vec![],
 			vec![],
-			vec![],
 		),);
 		let addr = Contracts::contract_address(&ALICE, &code_hash, &[]);
-		let result = Contracts::bare_call(ALICE, addr, 0, GAS_LIMIT, None, vec![], true);
+		let result =
+			Contracts::bare_call(ALICE, addr, 0, GAS_LIMIT, None, vec![], true, false, false);
 
 		assert_matches!(result.result, Ok(_));
 		assert_eq!(std::str::from_utf8(&result.debug_message).unwrap(), "Hello World!");
@@ -2005,7 +2379,17 @@ fn debug_message_logging_disabled() {
 		),);
 		let addr = Contracts::contract_address(&ALICE, &code_hash, &[]);
 		// disable logging by passing `false`
-		let result = Contracts::bare_call(ALICE, addr.clone(), 0, GAS_LIMIT, None, vec![], false);
+		let result = Contracts::bare_call(
+			ALICE,
+			addr.clone(),
+			0,
+			GAS_LIMIT,
+			None,
+			vec![],
+			false,
+			false,
+			false,
+		);
 		assert_matches!(result.result, Ok(_));
 		// the dispatchables always run without debugging
 		assert_ok!(Contracts::call(Origin::signed(ALICE), addr, 0, GAS_LIMIT, None, vec![]));
@@ -2029,7 +2413,8 @@ fn debug_message_invalid_utf8() {
 			vec![],
 		),);
 		let addr = Contracts::contract_address(&ALICE, &code_hash, &[]);
-		let result = Contracts::bare_call(ALICE, addr, 0, GAS_LIMIT, None, vec![], true);
+		let result =
+			Contracts::bare_call(ALICE, addr, 0, GAS_LIMIT, None, vec![], true, false, false);
 		assert_err!(result.result, <Error<Test>>::DebugMessageInvalidUTF8);
 	});
 }
@@ -2080,6 +2465,8 @@ fn gas_estimation_nested_call_fixed_limit() {
 			None,
 			input.clone(),
 			false,
+			false,
+			false,
 		);
 		assert_ok!(&result.result);
 
@@ -2096,6 +2483,8 @@ fn gas_estimation_nested_call_fixed_limit() {
 				Some(result.storage_deposit.charge_or_zero()),
 				input,
 				false,
+				false,
+				false,
 			)
 			.result
 		);
@@ -2151,6 +2540,8 @@ fn gas_estimation_call_runtime() {
 			None,
 			call.encode(),
 			false,
+			false,
+			false,
 		);
 		assert_ok!(&result.result);
 
@@ -2166,6 +2557,8 @@ fn gas_estimation_call_runtime() {
 				None,
 				call.encode(),
 				false,
+				false,
+				false,
 			)
 			.result
 		);
@@ -2215,15 +2608,260 @@ fn ecdsa_recover() {
 		params.extend_from_slice(&signature);
 		params.extend_from_slice(&message_hash);
 		assert!(params.len() == 65 + 32);
-		let result =
-			<Pallet<Test>>::bare_call(ALICE, addr.clone(), 0, GAS_LIMIT, None, params, false)
-				.result
-				.unwrap();
+		let result = <Pallet<Test>>::bare_call(
+			ALICE,
+			addr.clone(),
+			0,
+			GAS_LIMIT,
+			None,
+			params,
+			false,
+			false,
+			false,
+		)
+		.result
+		.unwrap();
 		assert!(!result.did_revert());
 		assert_eq!(result.data.as_ref(), &EXPECTED_COMPRESSED_PUBLIC_KEY);
 	})
 }
 
+#[test]
+#[cfg(feature = "unstable-interface")]
+fn ecdsa_to_eth_address() {
+	let (wasm, code_hash) = compile_module::<Test>("ecdsa_to_eth_address").unwrap();
+
+	ExtBuilder::default().existential_deposit(50).build().execute_with(|| {
+		let _ = Balances::deposit_creating(&ALICE, 1_000_000);
+
+		// Instantiate the ecdsa_to_eth_address contract.
+		assert_ok!(Contracts::instantiate_with_code(
+			Origin::signed(ALICE),
+			100_000,
+			GAS_LIMIT,
+			None,
+			wasm,
+			vec![],
+			vec![],
+		));
+		let addr = Contracts::contract_address(&ALICE, &code_hash, &[]);
+
+		// Compressed public key of the secret key `1`.
+		#[rustfmt::skip]
+		let pub_key: [u8; 33] = [
+			  2, 121, 190, 102, 126, 249, 220, 187, 172, 85, 160,  98, 149, 206, 135, 11,
+			  7,   2, 155, 252, 219,  45, 206,  40, 217, 89, 242, 129,  91,  22, 248, 23,
+			152,
+		];
+		// Ethereum address `0x7E5F4552091A69125d5DfCb7b8C2659029395Bdf`.
+		#[rustfmt::skip]
+		const EXPECTED_ETH_ADDRESS: [u8; 20] = [
+			126,  95,  69,  82,   9,  26, 105,  18,  93,  93,
+			252, 183, 184, 194, 101, 144,  41,  57,  91, 223,
+		];
+		let result = <Pallet<Test>>::bare_call(
+			ALICE,
+			addr.clone(),
+			0,
+			GAS_LIMIT,
+			None,
+			pub_key.to_vec(),
+			false,
+			false,
+			false,
+		)
+		.result
+		.unwrap();
+		assert!(!result.did_revert());
+		assert_eq!(result.data.as_ref(), &EXPECTED_ETH_ADDRESS);
+	})
+}
+
+#[test]
+#[cfg(feature = "unstable-interface")]
+fn sr25519_verify() {
+	let (wasm, code_hash) = compile_module::<Test>("sr25519_verify").unwrap();
+
+	ExtBuilder::default().existential_deposit(50).build().execute_with(|| {
+		let _ = Balances::deposit_creating(&ALICE, 1_000_000);
+
+		// Instantiate the sr25519_verify contract.
+		assert_ok!(Contracts::instantiate_with_code(
+			Origin::signed(ALICE),
+			100_000,
+			GAS_LIMIT,
+			None,
+			wasm,
+			vec![],
+			vec![],
+		));
+		let addr = Contracts::contract_address(&ALICE, &code_hash, &[]);
+
+		let key_type = sp_core::crypto::KeyTypeId(*b"code");
+		let pub_key = sp_io::crypto::sr25519_generate(key_type, None);
+		let message = b"hello world";
+		let signature = sp_io::crypto::sr25519_sign(key_type, &pub_key, message).unwrap();
+
+		let call = |message: &[u8; 11]| {
+			let mut params = vec![];
+			params.extend_from_slice(signature.as_ref());
+			params.extend_from_slice(pub_key.as_ref());
+			params.extend_from_slice(message);
+			assert!(params.len() == 64 + 32 + 11);
+			<Pallet<Test>>::bare_call(
+				ALICE,
+				addr.clone(),
+				0,
+				GAS_LIMIT,
+				None,
+				params,
+				false,
+				false,
+				false,
+			)
+			.result
+			.unwrap()
+		};
+
+		// verification should succeed for the signed message
+		let result = call(message);
+		assert!(!result.did_revert());
+		assert_eq!(result.data, Bytes((RuntimeReturnCode::Success as u32).encode()));
+
+		// verification should fail for a different message
+		let result = call(b"hello worlD");
+		assert!(!result.did_revert());
+		assert_eq!(result.data, Bytes((RuntimeReturnCode::Sr25519VerifyFailed as u32).encode()));
+	})
+}
+
+#[test]
+#[cfg(feature = "unstable-interface")]
+fn delegate_call() {
+	let (caller_wasm, caller_code_hash) = compile_module::<Test>("delegate_call").unwrap();
+	let (lib_wasm, lib_code_hash) = compile_module::<Test>("delegate_call_lib").unwrap();
+
+	ExtBuilder::default().existential_deposit(500).build().execute_with(|| {
+		let _ = Balances::deposit_creating(&ALICE, 1_000_000);
+
+		// Instantiate the 'caller'
+		assert_ok!(Contracts::instantiate_with_code(
+			Origin::signed(ALICE),
+			300_000,
+			GAS_LIMIT,
+			None,
+			caller_wasm,
+			vec![],
+			vec![],
+		));
+		let caller_addr = Contracts::contract_address(&ALICE, &caller_code_hash, &[]);
+
+		// Only upload the library: It is never instantiated.
+		assert_ok!(Contracts::upload_code(Origin::signed(ALICE), lib_wasm, None));
+
+		let caller_balance = Balances::free_balance(&caller_addr);
+		assert_ok!(Contracts::call(
+			Origin::signed(ALICE),
+			caller_addr.clone(),
+			1337,
+			GAS_LIMIT,
+			None,
+			lib_code_hash.encode(),
+		));
+
+		// The value was transferred only once: to the calling contract.
+		assert_eq!(Balances::free_balance(&caller_addr), caller_balance + 1337);
+
+		// The library code was executed in the context of the calling contract.
+		let storage = |key| Contracts::get_storage(caller_addr.clone(), [key; 32]).unwrap();
+		assert_eq!(storage(1), Some(1337u64.encode()));
+		assert_eq!(storage(2), Some(ALICE.encode()));
+		assert_eq!(storage(3), Some(caller_addr.encode()));
+	});
+}
+
+#[test]
+#[cfg(feature = "unstable-interface")]
+fn set_code_hash() {
+	let (wasm, code_hash) = compile_module::<Test>("set_code_hash").unwrap();
+	let (new_wasm, new_code_hash) = compile_module::<Test>("new_set_code_hash_contract").unwrap();
+
+	let contract_addr = Contracts::contract_address(&ALICE, &code_hash, &[]);
+
+	ExtBuilder::default().existential_deposit(100).build().execute_with(|| {
+		let _ = Balances::deposit_creating(&ALICE, 1_000_000);
+
+		// Instantiate the 'caller'
+		assert_ok!(Contracts::instantiate_with_code(
+			Origin::signed(ALICE),
+			300_000,
+			GAS_LIMIT,
+			None,
+			wasm,
+			vec![],
+			vec![],
+		));
+		// upload new code
+		assert_ok!(Contracts::upload_code(Origin::signed(ALICE), new_wasm, None));
+		assert_refcount!(&code_hash, 1);
+		assert_refcount!(&new_code_hash, 0);
+		let deposit = ContractInfoOf::<Test>::get(&contract_addr).unwrap().storage_deposit;
+
+		System::reset_events();
+
+		// First call sets new code_hash and returns 1
+		let result = Contracts::bare_call(
+			ALICE,
+			contract_addr.clone(),
+			0,
+			GAS_LIMIT,
+			None,
+			new_code_hash.as_ref().to_vec(),
+			true,
+			false,
+			false,
+		)
+		.result
+		.unwrap();
+		assert_return_code!(result, 1);
+
+		// Second calls new contract code that returns 2
+		let result = Contracts::bare_call(
+			ALICE,
+			contract_addr.clone(),
+			0,
+			GAS_LIMIT,
+			None,
+			vec![],
+			true,
+			false,
+			false,
+		)
+		.result
+		.unwrap();
+		assert_return_code!(result, 2);
+
+		// The refcount moved to the new code and the storage deposit is unchanged.
+		assert_refcount!(&code_hash, 0);
+		assert_refcount!(&new_code_hash, 1);
+		assert_eq!(ContractInfoOf::<Test>::get(&contract_addr).unwrap().storage_deposit, deposit);
+
+		// Checking for the last event only
+		assert_eq!(
+			System::events().pop().unwrap(),
+			EventRecord {
+				phase: Phase::Initialization,
+				event: Event::Contracts(crate::Event::ContractCodeUpdated {
+					contract: contract_addr.clone(),
+					new_code_hash,
+					old_code_hash: code_hash,
+				}),
+				topics: vec![],
+			},
+		);
+	});
+}
+
 #[test]
 fn upload_code_works() {
 	let (wasm, code_hash) = compile_module::<Test>("dummy").unwrap();
@@ -2920,6 +3558,8 @@ fn contract_reverted() {
 			input.clone(),
 			vec![],
 			false,
+			false,
+			false,
 		)
 		.result
 		.unwrap();
@@ -2937,6 +3577,8 @@ fn contract_reverted() {
 			ReturnFlags::empty().bits().encode(),
 			vec![],
 			false,
+			false,
+			false,
 		)
 		.result
 		.unwrap()
@@ -2949,9 +3591,19 @@ fn contract_reverted() {
 		);
 
 		// Calling directly: revert leads to success but the flags indicate the error
-		let result = Contracts::bare_call(ALICE, addr.clone(), 0, GAS_LIMIT, None, input, false)
-			.result
-			.unwrap();
+		let result = Contracts::bare_call(
+			ALICE,
+			addr.clone(),
+			0,
+			GAS_LIMIT,
+			None,
+			input,
+			false,
+			false,
+			false,
+		)
+		.result
+		.unwrap();
 		assert_eq!(result.flags, flags);
 		assert_eq!(result.data.0, buffer);
 	});
@@ -2977,6 +3629,8 @@ fn code_rejected_error_works() {
 			vec![],
 			vec![],
 			true,
+			false,
+			false,
 		);
 		assert_err!(result.result, <Error<Test>>::CodeRejected);
 		assert_eq!(
@@ -2985,3 +3639,92 @@ fn code_rejected_error_works() {
 		);
 	});
 }
+
+#[test]
+fn bare_instantiate_collects_events_and_call_trace() {
+	let (wasm, code_hash) = compile_module::<Test>("dummy").unwrap();
+	ExtBuilder::default().existential_deposit(100).build().execute_with(|| {
+		let _ = Balances::deposit_creating(&ALICE, 1_000_000);
+		let addr = Contracts::contract_address(&ALICE, &code_hash, &[]);
+		let events_before = System::events().len();
+
+		let result = Contracts::bare_instantiate(
+			ALICE,
+			30_000,
+			GAS_LIMIT,
+			None,
+			Code::Upload(Bytes(wasm)),
+			vec![],
+			vec![],
+			false,
+			true,
+			true,
+		);
+		assert_eq!(result.result.unwrap().account_id, addr);
+
+		// Only the events emitted by the instantiation are collected.
+		let events = result
+			.events
+			.unwrap()
+			.into_iter()
+			.map(|record| EventRecord::<Event, H256>::decode(&mut &record[..]).unwrap())
+			.collect::<Vec<_>>();
+		assert_eq!(&events[..], &System::events()[events_before..]);
+		assert!(events.iter().any(|record| record.event ==
+			Event::Contracts(crate::Event::Instantiated {
+				deployer: ALICE,
+				contract: addr.clone()
+			})));
+
+		let trace = result.call_trace.unwrap();
+		assert_eq!(trace.kind, CallKind::Instantiate);
+		assert_eq!((&trace.caller, &trace.contract), (&ALICE, &addr));
+		assert_eq!(trace.value, 30_000);
+		assert!(trace.gas_consumed > 0);
+		assert!(trace.host_functions.is_empty());
+		assert!(trace.result.is_ok());
+		assert!(trace.calls.is_empty());
+
+		// Nothing is collected unless requested.
+		let result =
+			Contracts::bare_call(ALICE, addr, 0, GAS_LIMIT, None, vec![], false, false, false);
+		assert_ok!(result.result);
+		assert_eq!(result.events, None);
+		assert_eq!(result.call_trace, None);
+	});
+}
+
+#[test]
+#[cfg(feature = "unstable-interface")]
+fn reentrance_count_works_with_call() {
+	let (wasm, code_hash) = compile_module::<Test>("reentrance_count_call").unwrap();
+
+	ExtBuilder::default().existential_deposit(100).build().execute_with(|| {
+		let _ = Balances::deposit_creating(&ALICE, 1_000_000);
+
+		assert_ok!(Contracts::instantiate_with_code(
+			Origin::signed(ALICE),
+			300_000,
+			GAS_LIMIT,
+			None,
+			wasm,
+			vec![],
+			vec![],
+		));
+		let contract_addr = Contracts::contract_address(&ALICE, &code_hash, &[]);
+
+		// The fixture reenters itself and asserts the count matches the input on every level.
+		let result = Contracts::bare_call(
+			ALICE,
+			contract_addr,
+			0,
+			GAS_LIMIT,
+			None,
+			0u32.encode(),
+			true,
+			false,
+			false,
+		);
+		assert_ok!(result.result);
+	});
+}
@@ -20,25 +20,30 @@
 //! - In order to run contract code we need to instrument it with gas metering.
 //! To do that we need to provide the schedule which will supply exact gas costs values.
 //! We cache this code in the storage saving the schedule version.
-//! - Before running contract code we check if the cached code has the schedule version that
-//! is equal to the current saved schedule.
-//! If it is equal then run the code, if it isn't reinstrument with the current schedule.
+//! - Contract code is always run as it is cached, even if it was instrumented with an older
+//! schedule than the current one. Hence callers never pay for a reinstrumentation, and outdated
+//! code keeps being metered with the instruction weights it was instrumented with.
+//! - Outdated code is reinstrumented lazily in `on_idle` using the spare block weight, or eagerly
+//! by a runtime migration.
+//! - Reinstrumentation goes through the same module checks as an upload and hence enforces the
+//! memory limits of the current schedule. Code that violates them is left as is. The code size
+//! limit is only checked when code is uploaded.
 //! - When we update the schedule we want it to have strictly greater version than the current saved
 //!   one:
 //! this guarantees that every instrumented contract code in cache cannot have the version equal to
-//! the current one. Thus, before executing a contract it should be reinstrument with new schedule.
+//! the current one. Thus, every contract code is picked up by the reinstrumentation.
 
 use crate::{
 	gas::{GasMeter, Token},
 	wasm::{prepare, PrefabWasmModule},
 	weights::WeightInfo,
-	CodeHash, CodeStorage, Config, Error, Event, OwnerInfoOf, Pallet, PristineCode, Schedule,
-	Weight,
+	CodeHash, CodeStorage, Config, Error, Event, OwnerInfoOf, Pallet, PristineCode,
+	ReinstrumentCursor, Schedule, Weight,
 };
 use frame_support::{
 	dispatch::{DispatchError, DispatchResult},
 	ensure,
-	traits::ReservableCurrency,
+	traits::{Get, ReservableCurrency},
 };
 use sp_core::crypto::UncheckedFrom;
 use sp_runtime::traits::BadOrigin;
@@ -117,6 +122,22 @@ pub fn decrement_refcount<T: Config>(code_hash: CodeHash<T>) -> Result<(), Dispa
 	Ok(())
 }
 
+/// Increment the refcount of a code in-storage by one.
+///
+/// # Errors
+///
+/// [`Error::CodeNotFound`] is returned if the specified `code_hash` does not exist.
+pub fn increment_refcount<T: Config>(code_hash: CodeHash<T>) -> Result<(), DispatchError> {
+	<OwnerInfoOf<T>>::mutate(code_hash, |existing| {
+		if let Some(info) = existing {
+			info.refcount = info.refcount.saturating_add(1);
+			Ok(())
+		} else {
+			Err(Error::<T>::CodeNotFound.into())
+		}
+	})
+}
+
 /// Try to remove code together with all associated information.
 pub fn try_remove<T: Config>(origin: &T::AccountId, code_hash: CodeHash<T>) -> DispatchResult {
 	<OwnerInfoOf<T>>::try_mutate_exists(&code_hash, |existing| {
@@ -137,9 +158,9 @@ pub fn try_remove<T: Config>(origin: &T::AccountId, code_hash: CodeHash<T>) -> D
 
 /// Load code with the given code hash.
 ///
-/// If the module was instrumented with a lower version of schedule than
-/// the current one given as an argument, then this function will perform
-/// re-instrumentation and update the cache in the storage.
+/// The module is returned as it is cached, even if it was instrumented with a lower version of
+/// schedule than the current one. It is brought up to date by [`reinstrument_outdated`] rather
+/// than at the expense of the caller.
 pub fn load<T: Config>(
 	code_hash: CodeHash<T>,
 	schedule: &Schedule<T>,
@@ -155,14 +176,6 @@ where
 	gas_meter.adjust_gas(charged, CodeToken::Load(prefab_module.code.len() as u32));
 	prefab_module.code_hash = code_hash;
 
-	if prefab_module.instruction_weights_version < schedule.instruction_weights.version {
-		// The instruction weights have changed.
-		// We need to re-instrument the code with the new instruction weights.
-		let charged = gas_meter.charge(CodeToken::Reinstrument(schedule.limits.code_len))?;
-		let code_size = reinstrument(&mut prefab_module, schedule)?;
-		gas_meter.adjust_gas(charged, CodeToken::Reinstrument(code_size));
-	}
-
 	Ok(prefab_module)
 }
 
@@ -182,12 +195,73 @@ pub fn reinstrument<T: Config>(
 	Ok(original_code_len as u32)
 }
 
+/// Reinstrument outdated code in storage until `weight_limit` is exhausted.
+///
+/// Code is visited in storage order. The position of the last visited code is persisted in
+/// [`ReinstrumentCursor`] so that the next call continues where this one stopped. A change
+/// of the instruction weights version restarts the process. Returns the consumed weight.
+pub fn reinstrument_outdated<T: Config>(weight_limit: Weight) -> Weight
+where
+	T::AccountId: UncheckedFrom<T::Hash> + AsRef<[u8]>,
+{
+	let schedule = T::Schedule::get();
+	let version = schedule.instruction_weights.version;
+	let mut weight_used = T::DbWeight::get().reads(1);
+
+	let start_key = match <ReinstrumentCursor<T>>::get() {
+		// All code was already reinstrumented to the current version.
+		Some((cursor_version, None)) if cursor_version == version => return weight_used,
+		Some((cursor_version, Some(key))) if cursor_version == version => Some(key),
+		_ => None,
+	};
+
+	// We only know the size of a code after loading it. Therefore we need to assume
+	// that every code has the maximum size before we visit it.
+	let max_weight_per_code = T::WeightInfo::reinstrument_code(schedule.limits.code_len / 1024);
+	weight_used = weight_used.saturating_add(T::DbWeight::get().writes(1));
+	if weight_used.saturating_add(max_weight_per_code) > weight_limit {
+		return T::DbWeight::get().reads(1)
+	}
+
+	let mut iter = match start_key {
+		Some(key) => <CodeStorage<T>>::iter_from(key),
+		None => <CodeStorage<T>>::iter(),
+	};
+	let mut finished = false;
+	while weight_used.saturating_add(max_weight_per_code) <= weight_limit {
+		let (code_hash, mut prefab_module) = match iter.next() {
+			Some(entry) => entry,
+			None => {
+				finished = true;
+				break
+			},
+		};
+		if prefab_module.instruction_weights_version >= version {
+			// Checking an up to date code still requires it to be loaded. We overestimate
+			// here in order to not need a separate benchmark for this case.
+			let code_len = prefab_module.code.len() as u32;
+			weight_used =
+				weight_used.saturating_add(T::WeightInfo::reinstrument_code(code_len / 1024));
+			continue
+		}
+		prefab_module.code_hash = code_hash;
+		// A code that cannot be reinstrumented anymore is left as is. It keeps being
+		// executed with its outdated instrumentation.
+		let weight = reinstrument(&mut prefab_module, &schedule)
+			.map(|code_len| T::WeightInfo::reinstrument_code(code_len / 1024))
+			.unwrap_or(max_weight_per_code);
+		weight_used = weight_used.saturating_add(weight);
+	}
+
+	let cursor = if finished { None } else { Some(iter.last_raw_key().to_vec()) };
+	<ReinstrumentCursor<T>>::put((version, cursor));
+	weight_used
+}
+
 /// Costs for operations that are related to code handling.
 #[cfg_attr(test, derive(Debug, PartialEq, Eq))]
 #[derive(Clone, Copy)]
 enum CodeToken {
-	/// Weight for reinstrumenting a contract contract of the supplied size in bytes.
-	Reinstrument(u32),
 	/// Weight for loading a contract per byte.
 	Load(u32),
 }
@@ -201,7 +275,6 @@ impl<T: Config> Token<T> for CodeToken {
 		// point because when charging the general weight for calling the contract we not know the
 		// size of the contract.
 		match *self {
-			Reinstrument(len) => T::WeightInfo::reinstrument(len / 1024),
 			Load(len) => T::WeightInfo::call_with_code_kb(len / 1024)
 				.saturating_sub(T::WeightInfo::call_with_code_kb(0)),
 		}
@@ -144,6 +144,11 @@ macro_rules! define_func {
 			#[allow(unused)]
 			let mut args = args.iter();
 
+			// `gas` is injected by the instrumentation and not called by the contract itself.
+			if stringify!($name) != "gas" {
+				$ctx.trace_host_fn(stringify!($name));
+			}
+
 			unmarshall_then_body_then_marshall!(
 				args,
 				$ctx,
@@ -26,7 +26,10 @@ mod runtime;
 
 #[cfg(feature = "runtime-benchmarks")]
 pub use self::code_cache::reinstrument;
-pub use self::runtime::{ReturnCode, Runtime, RuntimeCosts};
+pub use self::{
+	code_cache::reinstrument_outdated,
+	runtime::{ReturnCode, Runtime, RuntimeCosts},
+};
 use crate::{
 	exec::{ExecResult, Executable, ExportedFunction, Ext},
 	gas::GasMeter,
@@ -45,11 +48,10 @@ pub use tests::MockExt;
 ///
 /// # Note
 ///
-/// This data structure is mostly immutable once created and stored. The exceptions that
-/// can be changed by calling a contract are `instruction_weights_version` and `code`.
-/// `instruction_weights_version` and `code` change when a contract with an outdated instrumentation
-/// is called. Therefore one must be careful when holding any in-memory representation of this
-/// type while calling into a contract as those fields can get out of date.
+/// This data structure is mostly immutable once created and stored. The exceptions are
+/// `instruction_weights_version` and `code` which change when a contract with an outdated
+/// instrumentation is reinstrumented in `on_idle`. Therefore one must be careful when holding any
+/// in-memory representation of this type as those fields can get out of date.
 #[derive(Clone, Encode, Decode, scale_info::TypeInfo)]
 #[scale_info(skip_type_params(T))]
 pub struct PrefabWasmModule<T: Config> {
@@ -172,7 +174,7 @@ where
 	}
 
 	/// Decrement instruction_weights_version by 1. Panics if it is already 0.
-	#[cfg(test)]
+	#[cfg(any(test, feature = "runtime-benchmarks"))]
 	pub fn decrement_version(&mut self) {
 		self.instruction_weights_version = self.instruction_weights_version.checked_sub(1).unwrap();
 	}
@@ -198,6 +200,10 @@ where
 		code_cache::load(code_hash, schedule, gas_meter)
 	}
 
+	fn add_user(code_hash: CodeHash<T>) -> Result<(), DispatchError> {
+		code_cache::increment_refcount::<T>(code_hash)
+	}
+
 	fn remove_user(code_hash: CodeHash<T>) -> Result<(), DispatchError> {
 		code_cache::decrement_refcount::<T>(code_hash)
 	}
@@ -267,6 +273,7 @@ mod tests {
 	use pallet_contracts_primitives::{ExecReturnValue, ReturnFlags};
 	use pretty_assertions::assert_eq;
 	use sp_core::{Bytes, H256};
+	use sp_io::hashing::blake2_256;
 	use sp_runtime::DispatchError;
 	use std::{
 		borrow::BorrowMut,
@@ -302,11 +309,18 @@ mod tests {
 		allows_reentry: bool,
 	}
 
+	#[derive(Debug, PartialEq, Eq)]
+	struct DelegateCallEntry {
+		code_hash: H256,
+		data: Vec<u8>,
+	}
+
 	pub struct MockExt {
 		storage: HashMap<StorageKey, Vec<u8>>,
 		instantiates: Vec<InstantiateEntry>,
 		terminations: Vec<TerminationEntry>,
 		calls: Vec<CallEntry>,
+		delegate_calls: Vec<DelegateCallEntry>,
 		transfers: Vec<TransferEntry>,
 		// (topics, data)
 		events: Vec<(Vec<H256>, Vec<u8>)>,
@@ -315,6 +329,9 @@ mod tests {
 		gas_meter: GasMeter<Test>,
 		debug_buffer: Vec<u8>,
 		ecdsa_recover: RefCell<Vec<([u8; 65], [u8; 32])>>,
+		sr25519_verify: RefCell<Vec<([u8; 64], Vec<u8>, [u8; 32])>>,
+		code_hashes: Vec<CodeHash<Test>>,
+		host_fns: Vec<&'static str>,
 	}
 
 	/// The call is mocked and just returns this hardcoded value.
@@ -329,6 +346,7 @@ mod tests {
 				instantiates: Default::default(),
 				terminations: Default::default(),
 				calls: Default::default(),
+				delegate_calls: Default::default(),
 				transfers: Default::default(),
 				events: Default::default(),
 				runtime_calls: Default::default(),
@@ -336,6 +354,9 @@ mod tests {
 				gas_meter: GasMeter::new(10_000_000_000),
 				debug_buffer: Default::default(),
 				ecdsa_recover: Default::default(),
+				sr25519_verify: Default::default(),
+				code_hashes: Default::default(),
+				host_fns: Default::default(),
 			}
 		}
 	}
@@ -354,6 +375,14 @@ mod tests {
 			self.calls.push(CallEntry { to, value, data, allows_reentry });
 			Ok(ExecReturnValue { flags: ReturnFlags::empty(), data: call_return_data() })
 		}
+		fn delegate_call(
+			&mut self,
+			code_hash: CodeHash<Self::T>,
+			data: Vec<u8>,
+		) -> Result<ExecReturnValue, ExecError> {
+			self.delegate_calls.push(DelegateCallEntry { code_hash, data });
+			Ok(ExecReturnValue { flags: ReturnFlags::empty(), data: call_return_data() })
+		}
 		fn instantiate(
 			&mut self,
 			gas_limit: Weight,
@@ -388,6 +417,13 @@ mod tests {
 		fn get_storage_size(&mut self, key: &StorageKey) -> Option<u32> {
 			self.storage.get(key).map(|val| val.len() as u32)
 		}
+		fn next_storage_entry(&mut self, hashed_key: &[u8; 32]) -> Option<([u8; 32], Vec<u8>)> {
+			self.storage
+				.iter()
+				.map(|(key, value)| (blake2_256(key), value.clone()))
+				.filter(|(key, _)| key > hashed_key)
+				.min_by_key(|(key, _)| *key)
+		}
 		fn set_storage(
 			&mut self,
 			key: StorageKey,
@@ -409,6 +445,13 @@ mod tests {
 		fn caller(&self) -> &AccountIdOf<Self::T> {
 			&ALICE
 		}
+		fn code_hash(&self, _address: &AccountIdOf<Self::T>) -> Option<CodeHash<Self::T>> {
+			Some(H256::from_slice(&[0x11; 32]))
+		}
+		fn own_code_hash(&mut self) -> &CodeHash<Self::T> {
+			const HASH: H256 = H256([0x10; 32]);
+			&HASH
+		}
 		fn address(&self) -> &AccountIdOf<Self::T> {
 			&BOB
 		}
@@ -449,6 +492,12 @@ mod tests {
 			self.debug_buffer.extend(msg.as_bytes());
 			true
 		}
+		fn call_tracing_enabled(&self) -> bool {
+			true
+		}
+		fn trace_host_fn(&mut self, name: &'static str) {
+			self.host_fns.push(name);
+		}
 		fn call_runtime(&self, call: <Self::T as Config>::Call) -> DispatchResultWithPostInfo {
 			self.runtime_calls.borrow_mut().push(call);
 			Ok(Default::default())
@@ -461,6 +510,23 @@ mod tests {
 			self.ecdsa_recover.borrow_mut().push((signature.clone(), message_hash.clone()));
 			Ok([3; 33])
 		}
+		fn sr25519_verify(&self, signature: &[u8; 64], message: &[u8], pub_key: &[u8; 32]) -> bool {
+			self.sr25519_verify.borrow_mut().push((*signature, message.to_vec(), *pub_key));
+			true
+		}
+		fn ecdsa_to_eth_address(&self, _pk: &[u8; 33]) -> Result<[u8; 20], ()> {
+			Ok([2u8; 20])
+		}
+		fn set_code_hash(&mut self, hash: CodeHash<Self::T>) -> Result<(), DispatchError> {
+			self.code_hashes.push(hash);
+			Ok(())
+		}
+		fn reentrance_count(&self) -> u32 {
+			12
+		}
+		fn account_reentrance_count(&self, _account_id: &AccountIdOf<Self::T>) -> u32 {
+			12
+		}
 		fn contract_info(&mut self) -> &mut crate::ContractInfo<Self::T> {
 			unimplemented!()
 		}
@@ -516,6 +582,14 @@ mod tests {
 		assert_eq!(&mock_ext.transfers, &[TransferEntry { to: ALICE, value: 153 }]);
 	}
 
+	#[test]
+	fn host_fns_are_traced() {
+		let mut mock_ext = MockExt::default();
+		assert_ok!(execute(CODE_TRANSFER, vec![], &mut mock_ext));
+
+		assert_eq!(&mock_ext.host_fns, &["seal_transfer"]);
+	}
+
 	const CODE_CALL: &str = r#"
 (module
 	;; seal_call(
@@ -729,6 +803,55 @@ mod tests {
 		);
 	}
 
+	#[cfg(feature = "unstable-interface")]
+	const CODE_DELEGATE_CALL: &str = r#"
+(module
+	;; seal_delegate_call(
+	;;    flags: u32,
+	;;    code_hash_ptr: u32,
+	;;    input_data_ptr: u32,
+	;;    input_data_len: u32,
+	;;    output_ptr: u32,
+	;;    output_len_ptr: u32
+	;;) -> u32
+	(import "__unstable__" "seal_delegate_call" (func $seal_delegate_call (param i32 i32 i32 i32 i32 i32) (result i32)))
+	(import "env" "memory" (memory 1 1))
+	(func (export "call")
+		(drop
+			(call $seal_delegate_call
+				(i32.const 0) ;; No flags are set
+				(i32.const 4)  ;; Pointer to "callee" code_hash.
+				(i32.const 36) ;; Pointer to input data buffer address
+				(i32.const 4)  ;; Length of input data buffer
+				(i32.const 4294967295) ;; u32 max value is the sentinel value: do not copy output
+				(i32.const 0) ;; Length is ignored in this case
+			)
+		)
+	)
+	(func (export "deploy"))
+
+	;; Callee code_hash
+	(data (i32.const 4)
+		"\11\11\11\11\11\11\11\11\11\11\11\11\11\11\11\11"
+		"\11\11\11\11\11\11\11\11\11\11\11\11\11\11\11\11"
+	)
+
+	(data (i32.const 36) "\01\02\03\04")
+)
+"#;
+
+	#[test]
+	#[cfg(feature = "unstable-interface")]
+	fn contract_delegate_call() {
+		let mut mock_ext = MockExt::default();
+		assert_ok!(execute(CODE_DELEGATE_CALL, vec![], &mut mock_ext));
+
+		assert_eq!(
+			&mock_ext.delegate_calls,
+			&[DelegateCallEntry { code_hash: H256::repeat_byte(0x11), data: vec![1, 2, 3, 4] }]
+		);
+	}
+
 	const CODE_INSTANTIATE: &str = r#"
 (module
 	;; seal_instantiate(
@@ -938,6 +1061,311 @@ mod tests {
 		assert_eq!(mock_ext.ecdsa_recover.into_inner(), [([1; 65], [1; 32])]);
 	}
 
+	#[test]
+	#[cfg(feature = "unstable-interface")]
+	fn contract_sr25519() {
+		const CODE_SR25519: &str = r#"
+(module
+	(import "__unstable__" "seal_sr25519_verify" (func $seal_sr25519_verify (param i32 i32 i32 i32) (result i32)))
+	(import "env" "memory" (memory 1 1))
+	(func $assert (param i32)
+		(block $ok
+			(br_if $ok
+				(get_local 0)
+			)
+			(unreachable)
+		)
+	)
+	(func (export "call")
+		(call $assert
+			(i32.eqz
+				(call $seal_sr25519_verify
+					(i32.const 0)   ;; Pointer to signature.
+					(i32.const 64)  ;; Pointer to public key.
+					(i32.const 16)  ;; Length of message.
+					(i32.const 96)  ;; Pointer to message.
+				)
+			)
+		)
+	)
+	(func (export "deploy"))
+
+	;; Signature (64 bytes)
+	(data (i32.const 0)
+		"\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01"
+		"\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01"
+		"\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01"
+		"\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01\01"
+	)
+
+	;;  public key (32 bytes)
+	(data (i32.const 64)
+		"\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02"
+		"\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02\02"
+	)
+
+	;;  message (16 bytes)
+	(data (i32.const 96)
+		"\03\03\03\03\03\03\03\03\03\03\03\03\03\03\03\03"
+	)
+)
+"#;
+
+		let mut mock_ext = MockExt::default();
+		assert_ok!(execute(&CODE_SR25519, vec![], &mut mock_ext));
+		assert_eq!(mock_ext.sr25519_verify.into_inner(), [([1; 64], [3; 16].to_vec(), [2; 32])]);
+	}
+
+	#[test]
+	#[cfg(feature = "unstable-interface")]
+	fn contract_ecdsa_to_eth_address() {
+		const CODE_ECDSA_TO_ETH_ADDRESS: &str = r#"
+(module
+	(import "__unstable__" "seal_ecdsa_to_eth_address" (func $seal_ecdsa_to_eth_address (param i32 i32) (result i32)))
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "env" "memory" (memory 1 1))
+
+	(func (export "call")
+		;; fill the buffer with the eth address.
+		(drop
+			(call $seal_ecdsa_to_eth_address
+				(i32.const 0)  ;; Pointer to the compressed public key.
+				(i32.const 33) ;; Pointer to the output buffer.
+			)
+		)
+
+		;; Return the contents of the buffer
+		(call $seal_return
+			(i32.const 0)
+			(i32.const 33)
+			(i32.const 20)
+		)
+
+		;; seal_return doesn't return, so this is effectively unreachable.
+		(unreachable)
+	)
+	(func (export "deploy"))
+)
+"#;
+
+		let output = execute(CODE_ECDSA_TO_ETH_ADDRESS, vec![], MockExt::default()).unwrap();
+		assert_eq!(
+			output,
+			ExecReturnValue { flags: ReturnFlags::empty(), data: Bytes([0x02; 20].to_vec()) }
+		);
+	}
+
+	#[test]
+	#[cfg(feature = "unstable-interface")]
+	fn reentrance_count_works() {
+		const CODE: &str = r#"
+(module
+	(import "__unstable__" "seal_reentrance_count" (func $seal_reentrance_count (result i32)))
+	(import "env" "memory" (memory 1 1))
+	(func $assert (param i32)
+		(block $ok
+			(br_if $ok
+				(get_local 0)
+			)
+			(unreachable)
+		)
+	)
+	(func (export "call")
+		(local $return_val i32)
+		(set_local $return_val
+			(call $seal_reentrance_count)
+		)
+		(call $assert
+			(i32.eq (get_local $return_val) (i32.const 12))
+		)
+	)
+
+	(func (export "deploy"))
+)
+"#;
+
+		let mut mock_ext = MockExt::default();
+		assert_ok!(execute(CODE, vec![], &mut mock_ext));
+	}
+
+	#[test]
+	#[cfg(feature = "unstable-interface")]
+	fn account_reentrance_count_works() {
+		const CODE: &str = r#"
+(module
+	(import "__unstable__" "seal_account_reentrance_count" (func $seal_account_reentrance_count (param i32) (result i32)))
+	(import "env" "memory" (memory 1 1))
+	(func $assert (param i32)
+		(block $ok
+			(br_if $ok
+				(get_local 0)
+			)
+			(unreachable)
+		)
+	)
+	(func (export "call")
+		(local $return_val i32)
+		(set_local $return_val
+			(call $seal_account_reentrance_count (i32.const 0))
+		)
+		(call $assert
+			(i32.eq (get_local $return_val) (i32.const 12))
+		)
+	)
+
+	(func (export "deploy"))
+)
+"#;
+
+		let mut mock_ext = MockExt::default();
+		assert_ok!(execute(CODE, vec![], &mut mock_ext));
+	}
+
+	#[test]
+	#[cfg(feature = "unstable-interface")]
+	fn contract_set_code_hash() {
+		const CODE: &str = r#"
+(module
+	(import "__unstable__" "seal_set_code_hash" (func $seal_set_code_hash (param i32) (result i32)))
+	(import "env" "memory" (memory 1 1))
+	(func $assert (param i32)
+		(block $ok
+			(br_if $ok
+				(get_local 0)
+			)
+			(unreachable)
+		)
+	)
+	(func (export "call")
+		(call $assert
+			(i32.eq
+				(call $seal_set_code_hash
+					(i32.const 0) ;; Pointer to the new code hash.
+				)
+				(i32.const 0) ;; ReturnCode::Success
+			)
+		)
+	)
+	(func (export "deploy"))
+	(data (i32.const 0)
+		"\11\11\11\11\11\11\11\11\11\11\11\11\11\11\11\11"
+		"\11\11\11\11\11\11\11\11\11\11\11\11\11\11\11\11"
+	)
+)
+"#;
+
+		let mut mock_ext = MockExt::default();
+		assert_ok!(execute(CODE, vec![], &mut mock_ext));
+
+		assert_eq!(mock_ext.code_hashes.pop().unwrap(), H256::from_slice(&[17u8; 32]));
+	}
+
+	#[test]
+	#[cfg(feature = "unstable-interface")]
+	fn contract_code_hash() {
+		const CODE: &str = r#"
+(module
+	(import "__unstable__" "seal_code_hash" (func $seal_code_hash (param i32 i32 i32) (result i32)))
+	(import "env" "memory" (memory 1 1))
+
+	;; size of our buffer is 32 bytes
+	(data (i32.const 32) "\20")
+
+	(func $assert (param i32)
+		(block $ok
+			(br_if $ok
+				(get_local 0)
+			)
+			(unreachable)
+		)
+	)
+
+	(func (export "call")
+		;; fill the buffer with the code hash.
+		(call $assert
+			(i32.eq
+				(call $seal_code_hash
+					(i32.const 0) ;; input: address_ptr (before call)
+					(i32.const 0) ;; output: code_hash_ptr (after call)
+					(i32.const 32) ;; same 32 bytes length for input and output
+				)
+				(i32.const 0) ;; ReturnCode::Success
+			)
+		)
+
+		;; assert size == 32
+		(call $assert
+			(i32.eq
+				(i32.load (i32.const 32))
+				(i32.const 32)
+			)
+		)
+
+		;; assert that the first 8 bytes are the beginning of "1111111..."
+		(call $assert
+			(i64.eq
+				(i64.load (i32.const 0))
+				(i64.const 0x1111111111111111)
+			)
+		)
+	)
+
+	(func (export "deploy"))
+)
+"#;
+		assert_ok!(execute(CODE, vec![], MockExt::default()));
+	}
+
+	#[test]
+	#[cfg(feature = "unstable-interface")]
+	fn contract_own_code_hash() {
+		const CODE: &str = r#"
+(module
+	(import "__unstable__" "seal_own_code_hash" (func $seal_own_code_hash (param i32 i32)))
+	(import "env" "memory" (memory 1 1))
+
+	;; size of our buffer is 32 bytes
+	(data (i32.const 32) "\20")
+
+	(func $assert (param i32)
+		(block $ok
+			(br_if $ok
+				(get_local 0)
+			)
+			(unreachable)
+		)
+	)
+
+	(func (export "call")
+		;; fill the buffer with the code hash.
+		(call $seal_own_code_hash
+			(i32.const 0)  ;; output: code_hash_ptr
+			(i32.const 32) ;; 32 bytes length of code_hash output
+		)
+
+		;; assert size == 32
+		(call $assert
+			(i32.eq
+				(i32.load (i32.const 32))
+				(i32.const 32)
+			)
+		)
+
+		;; assert that the first 8 bytes are the beginning of "1010101..."
+		(call $assert
+			(i64.eq
+				(i64.load (i32.const 0))
+				(i64.const 0x1010101010101010)
+			)
+		)
+	)
+
+	(func (export "deploy"))
+)
+"#;
+		assert_ok!(execute(CODE, vec![], MockExt::default()));
+	}
+
 	const CODE_GET_STORAGE: &str = r#"
 (module
 	(import "seal0" "seal_get_storage" (func $seal_get_storage (param i32 i32 i32) (result i32)))
@@ -1015,6 +1443,76 @@ mod tests {
 		);
 	}
 
+	#[test]
+	#[cfg(feature = "unstable-interface")]
+	fn next_key_iterates_storage() {
+		const CODE_NEXT_KEY: &str = r#"
+(module
+	(import "seal0" "seal_input" (func $seal_input (param i32 i32)))
+	(import "__unstable__" "seal_next_key" (func $seal_next_key (param i32 i32 i32) (result i32)))
+	(import "seal0" "seal_return" (func $seal_return (param i32 i32 i32)))
+	(import "env" "memory" (memory 1 1))
+
+	;; [0, 4) size of the input buffer = 32 bytes
+	(data (i32.const 0) "\20")
+
+	;; [4, 36) input buffer where the hashed key is copied
+
+	;; [36, 40) size of the output buffer = 4k in little endian
+	(data (i32.const 36) "\00\10")
+
+	;; [40, inf) output buffer where the entry is copied
+
+	(func (export "call")
+		(local $return_code i32)
+		(call $seal_input (i32.const 4) (i32.const 0))
+		(set_local $return_code
+			(call $seal_next_key
+				(i32.const 4)		;; Pointer to the hashed key
+				(i32.const 40)		;; Pointer to the output buffer
+				(i32.const 36)		;; Pointer to the size of the buffer
+			)
+		)
+		(if (get_local $return_code)
+			(then
+				;; Return the return code if no entry was found.
+				(i32.store (i32.const 40) (get_local $return_code))
+				(i32.store (i32.const 36) (i32.const 4))
+			)
+		)
+		;; Return the contents of the buffer
+		(call $seal_return
+			(i32.const 0)
+			(i32.const 40)
+			(i32.load (i32.const 36))
+		)
+	)
+
+	(func (export "deploy"))
+)
+"#;
+		let mut mock_ext = MockExt::default();
+		mock_ext.storage.insert([0x11; 32], vec![0x01]);
+		mock_ext.storage.insert([0x22; 32], vec![0x02, 0x02]);
+		let mut entries = vec![
+			(blake2_256(&[0x11; 32]), vec![0x01]),
+			(blake2_256(&[0x22; 32]), vec![0x02, 0x02]),
+		];
+		entries.sort();
+
+		// Iterating from the zero key yields the entries in the order of their hashed keys.
+		let mut key = [0u8; 32];
+		for (hashed_key, value) in entries {
+			let output = execute(CODE_NEXT_KEY, key.to_vec(), &mut mock_ext).unwrap();
+			assert_eq!(output.data.0, [&hashed_key[..], &value[..]].concat());
+			key = hashed_key;
+		}
+
+		// There is no entry after the last one.
+		let output = execute(CODE_NEXT_KEY, key.to_vec(), &mut mock_ext).unwrap();
+		assert_eq!(output.data.0, (ReturnCode::KeyNotFound as u32).encode());
+	}
+
 	/// calls `seal_caller` and compares the result with the constant 42.
 	const CODE_CALLER: &str = r#"
 (module
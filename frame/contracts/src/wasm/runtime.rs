@@ -74,6 +74,9 @@ pub enum ReturnCode {
 	/// ECDSA pubkey recovery failed. Most probably wrong recovery id or signature.
 	#[cfg(feature = "unstable-interface")]
 	EcdsaRecoverFailed = 11,
+	/// sr25519 signature verification failed.
+	#[cfg(feature = "unstable-interface")]
+	Sr25519VerifyFailed = 12,
 }
 
 impl ConvertibleToWasm for ReturnCode {
@@ -177,6 +180,9 @@ pub enum RuntimeCosts {
 	ContainsStorage(u32),
 	/// Weight of calling `seal_get_storage` with the specified size in storage.
 	GetStorage(u32),
+	/// Weight of calling `seal_next_key` with the specified size of the returned item.
+	#[cfg(feature = "unstable-interface")]
+	NextKey(u32),
 	/// Weight of calling `seal_take_storage` for the given size.
 	#[cfg(feature = "unstable-interface")]
 	TakeStorage(u32),
@@ -184,6 +190,9 @@ pub enum RuntimeCosts {
 	Transfer,
 	/// Weight of calling `seal_call` for the given input size.
 	CallBase(u32),
+	/// Weight of calling `seal_delegate_call` for the given input size.
+	#[cfg(feature = "unstable-interface")]
+	DelegateCallBase(u32),
 	/// Weight of the transfer performed during a call.
 	CallSurchargeTransfer,
 	/// Weight of output received through `seal_call` for the given size.
@@ -205,6 +214,12 @@ pub enum RuntimeCosts {
 	/// Weight of calling `seal_ecdsa_recover`.
 	#[cfg(feature = "unstable-interface")]
 	EcdsaRecovery,
+	/// Weight of calling `seal_sr25519_verify` for the given input size.
+	#[cfg(feature = "unstable-interface")]
+	Sr25519Verify(u32),
+	/// Weight of calling `seal_ecdsa_to_eth_address`.
+	#[cfg(feature = "unstable-interface")]
+	EcdsaToEthAddress,
 	/// Weight charged by a chain extension through `seal_call_chain_extension`.
 	ChainExtension(u64),
 	/// Weight charged for copying data from the sandbox.
@@ -213,6 +228,21 @@ pub enum RuntimeCosts {
 	/// Weight charged for calling into the runtime.
 	#[cfg(feature = "unstable-interface")]
 	CallRuntime(Weight),
+	/// Weight of calling `seal_set_code_hash`.
+	#[cfg(feature = "unstable-interface")]
+	SetCodeHash,
+	/// Weight of calling `seal_code_hash`.
+	#[cfg(feature = "unstable-interface")]
+	CodeHash,
+	/// Weight of calling `seal_own_code_hash`.
+	#[cfg(feature = "unstable-interface")]
+	OwnCodeHash,
+	/// Weight of calling `seal_reentrance_count`.
+	#[cfg(feature = "unstable-interface")]
+	ReentranceCount,
+	/// Weight of calling `seal_account_reentrance_count`.
+	#[cfg(feature = "unstable-interface")]
+	AccountReentranceCount,
 }
 
 impl RuntimeCosts {
@@ -257,12 +287,17 @@ impl RuntimeCosts {
 			GetStorage(len) =>
 				s.get_storage.saturating_add(s.get_storage_per_byte.saturating_mul(len.into())),
 			#[cfg(feature = "unstable-interface")]
+			NextKey(len) => s.next_key.saturating_add(s.next_key_per_byte.saturating_mul(len.into())),
+			#[cfg(feature = "unstable-interface")]
 			TakeStorage(len) => s
 				.take_storage
 				.saturating_add(s.take_storage_per_byte.saturating_mul(len.into())),
 			Transfer => s.transfer,
 			CallBase(len) =>
 				s.call.saturating_add(s.call_per_input_byte.saturating_mul(len.into())),
+			#[cfg(feature = "unstable-interface")]
+			DelegateCallBase(len) =>
+				s.delegate_call.saturating_add(s.call_per_input_byte.saturating_mul(len.into())),
 			CallSurchargeTransfer => s.call_transfer_surcharge,
 			CallCopyOut(len) => s.call_per_output_byte.saturating_mul(len.into()),
 			InstantiateBase { input_data_len, salt_len } => s
@@ -284,11 +319,27 @@ impl RuntimeCosts {
 				.saturating_add(s.hash_blake2_128_per_byte.saturating_mul(len.into())),
 			#[cfg(feature = "unstable-interface")]
 			EcdsaRecovery => s.ecdsa_recover,
+			#[cfg(feature = "unstable-interface")]
+			Sr25519Verify(len) => s
+				.sr25519_verify
+				.saturating_add(s.sr25519_verify_per_byte.saturating_mul(len.into())),
+			#[cfg(feature = "unstable-interface")]
+			EcdsaToEthAddress => s.ecdsa_to_eth_address,
 			ChainExtension(amount) => amount,
 			#[cfg(feature = "unstable-interface")]
 			CopyIn(len) => s.return_per_byte.saturating_mul(len.into()),
 			#[cfg(feature = "unstable-interface")]
 			CallRuntime(weight) => weight,
+			#[cfg(feature = "unstable-interface")]
+			SetCodeHash => s.set_code_hash,
+			#[cfg(feature = "unstable-interface")]
+			CodeHash => s.code_hash,
+			#[cfg(feature = "unstable-interface")]
+			OwnCodeHash => s.own_code_hash,
+			#[cfg(feature = "unstable-interface")]
+			ReentranceCount => s.reentrance_count,
+			#[cfg(feature = "unstable-interface")]
+			AccountReentranceCount => s.account_reentrance_count,
 		};
 		RuntimeToken {
 			#[cfg(test)]
@@ -357,6 +408,25 @@ bitflags! {
 	}
 }
 
+/// The kind of call that should be performed by `Runtime::call`.
+enum CallType {
+	/// Execute another instantiated contract.
+	Call { callee_ptr: u32, value_ptr: u32, gas: u64 },
+	/// Execute deployed code in the context (storage, account ID, value) of the caller contract.
+	#[cfg(feature = "unstable-interface")]
+	DelegateCall { code_hash_ptr: u32 },
+}
+
+impl CallType {
+	fn cost(&self, input_data_len: u32) -> RuntimeCosts {
+		match self {
+			CallType::Call { .. } => RuntimeCosts::CallBase(input_data_len),
+			#[cfg(feature = "unstable-interface")]
+			CallType::DelegateCall { .. } => RuntimeCosts::DelegateCallBase(input_data_len),
+		}
+	}
+}
+
 /// This is only appropriate when writing out data of constant size that does not depend on user
 /// input. In this case the costs for this copy was already charged as part of the token at
 /// the beginning of the API entry point.
@@ -370,6 +440,7 @@ pub struct Runtime<'a, E: Ext + 'a> {
 	input_data: Option<Vec<u8>>,
 	memory: sp_sandbox::default_executor::Memory,
 	trap_reason: Option<TrapReason>,
+	trace_host_fns: bool,
 }
 
 impl<'a, E> Runtime<'a, E>
@@ -383,7 +454,8 @@ where
 		input_data: Vec<u8>,
 		memory: sp_sandbox::default_executor::Memory,
 	) -> Self {
-		Runtime { ext, input_data: Some(input_data), memory, trap_reason: None }
+		let trace_host_fns = ext.call_tracing_enabled();
+		Runtime { ext, input_data: Some(input_data), memory, trap_reason: None, trace_host_fns }
 	}
 
 	/// Converts the sandbox result and the runtime state into the execution outcome.
@@ -435,6 +507,16 @@ where
 		self.ext
 	}
 
+	/// Record that the contract invoked the host function `name`.
+	///
+	/// Whether call tracing is enabled is only queried once per execution, such that host
+	/// functions don't pay for tracing when it is disabled.
+	pub fn trace_host_fn(&mut self, name: &'static str) {
+		if self.trace_host_fns {
+			self.ext.trace_host_fn(name);
+		}
+	}
+
 	/// Store the reason for a host function triggered trap.
 	///
 	/// This is called by the `define_env` macro in order to store any error returned by
@@ -683,18 +765,13 @@ where
 	fn call(
 		&mut self,
 		flags: CallFlags,
-		callee_ptr: u32,
-		gas: u64,
-		value_ptr: u32,
+		call_type: CallType,
 		input_data_ptr: u32,
 		input_data_len: u32,
 		output_ptr: u32,
 		output_len_ptr: u32,
 	) -> Result<ReturnCode, TrapReason> {
-		self.charge_gas(RuntimeCosts::CallBase(input_data_len))?;
-		let callee: <<E as Ext>::T as frame_system::Config>::AccountId =
-			self.read_sandbox_memory_as(callee_ptr)?;
-		let value: BalanceOf<<E as Ext>::T> = self.read_sandbox_memory_as(value_ptr)?;
+		self.charge_gas(call_type.cost(input_data_len))?;
 		let input_data = if flags.contains(CallFlags::CLONE_INPUT) {
 			self.input_data.as_ref().ok_or_else(|| Error::<E::T>::InputForwarded)?.clone()
 		} else if flags.contains(CallFlags::FORWARD_INPUT) {
@@ -702,12 +779,34 @@ where
 		} else {
 			self.read_sandbox_memory(input_data_ptr, input_data_len)?
 		};
-		if value > 0u32.into() {
-			self.charge_gas(RuntimeCosts::CallSurchargeTransfer)?;
-		}
-		let ext = &mut self.ext;
-		let call_outcome =
-			ext.call(gas, callee, value, input_data, flags.contains(CallFlags::ALLOW_REENTRY));
+
+		let call_outcome = match call_type {
+			CallType::Call { callee_ptr, value_ptr, gas } => {
+				let callee: <<E as Ext>::T as frame_system::Config>::AccountId =
+					self.read_sandbox_memory_as(callee_ptr)?;
+				let value: BalanceOf<<E as Ext>::T> = self.read_sandbox_memory_as(value_ptr)?;
+				if value > 0u32.into() {
+					self.charge_gas(RuntimeCosts::CallSurchargeTransfer)?;
+				}
+				self.ext.call(
+					gas,
+					callee,
+					value,
+					input_data,
+					flags.contains(CallFlags::ALLOW_REENTRY),
+				)
+			},
+			#[cfg(feature = "unstable-interface")]
+			CallType::DelegateCall { code_hash_ptr } => {
+				// Reentrancy protection does not apply as no other contract is entered.
+				if flags.contains(CallFlags::ALLOW_REENTRY) {
+					return Err(Error::<E::T>::InvalidCallFlags.into())
+				}
+				let code_hash: CodeHash<<E as Ext>::T> =
+					self.read_sandbox_memory_as(code_hash_ptr)?;
+				self.ext.delegate_call(code_hash, input_data)
+			},
+		};
 
 		// `TAIL_CALL` only matters on an `OK` result. Otherwise the call stack comes to
 		// a halt anyways without anymore code being executed.
@@ -868,6 +967,44 @@ define_env!(Env, <E: Ext>,
 		}
 	},
 
+	// Retrieve the storage entry that follows the given hashed key.
+	//
+	// Storage keys are hashed with `blake2_256` before they are written to storage. This
+	// function iterates the storage of the executing contract in the order of those hashes.
+	// Supply 32 zero bytes as key in order to retrieve the first entry and the hashed key of
+	// the previously returned entry to retrieve the next one.
+	//
+	// # Parameters
+	//
+	// - `key_ptr`: pointer into the linear memory where the 32 byte hashed key is placed.
+	// - `out_ptr`: pointer to the linear memory where the entry is written to. It consists of
+	//   the 32 byte hashed key of the entry followed by its value.
+	// - `out_len_ptr`: in-out pointer into linear memory where the buffer length
+	//   is read from and the entry length is written to.
+	//
+	// # Errors
+	//
+	// `ReturnCode::KeyNotFound` if there is no entry after the supplied key.
+	//
+	// # Unstable
+	//
+	// This function is unstable and subject to change (or removal) in the future. Do not
+	// deploy a contract using it to a production chain.
+	[__unstable__] seal_next_key(ctx, key_ptr: u32, out_ptr: u32, out_len_ptr: u32) -> ReturnCode => {
+		let charged = ctx.charge_gas(RuntimeCosts::NextKey(ctx.ext.max_value_size()))?;
+		let mut hashed_key = [0u8; 32];
+		ctx.read_sandbox_memory_into_buf(key_ptr, &mut hashed_key)?;
+		if let Some((next_key, value)) = ctx.ext.next_storage_entry(&hashed_key) {
+			ctx.adjust_gas(charged, RuntimeCosts::NextKey(value.len() as u32));
+			let entry = [&next_key[..], &value[..]].concat();
+			ctx.write_sandbox_output(out_ptr, out_len_ptr, &entry, false, already_charged)?;
+			Ok(ReturnCode::Success)
+		} else {
+			ctx.adjust_gas(charged, RuntimeCosts::NextKey(0));
+			Ok(ReturnCode::KeyNotFound)
+		}
+	},
+
 	// Checks whether there is a value stored under the given key.
 	//
 	// # Parameters
@@ -980,9 +1117,7 @@ define_env!(Env, <E: Ext>,
 	) -> ReturnCode => {
 		ctx.call(
 			CallFlags::ALLOW_REENTRY,
-			callee_ptr,
-			gas,
-			value_ptr,
+			CallType::Call { callee_ptr, value_ptr, gas },
 			input_data_ptr,
 			input_data_len,
 			output_ptr,
@@ -1032,9 +1167,57 @@ define_env!(Env, <E: Ext>,
 	) -> ReturnCode => {
 		ctx.call(
 			CallFlags::from_bits(flags).ok_or_else(|| "used reserved bit in CallFlags")?,
-			callee_ptr,
-			gas,
-			value_ptr,
+			CallType::Call { callee_ptr, value_ptr, gas },
+			input_data_ptr,
+			input_data_len,
+			output_ptr,
+			output_len_ptr,
+		)
+	},
+
+	// Execute code in the context (storage, caller, value) of the current contract.
+	//
+	// Reentrancy protection is always disabled since the callee is allowed
+	// to modify the callers storage. This makes going through a reentrancy attack
+	// unnecessary for the callee when it wants to exploit the caller.
+	//
+	// # Parameters
+	//
+	// - flags: See [`CallFlags`] for a documentation of the supported flags.
+	//   `ALLOW_REENTRY` is not supported and traps when set.
+	// - code_hash_ptr: a pointer to the hash of the code that should be executed.
+	//   Should be decodable as a `T::Hash`. Traps otherwise.
+	// - input_data_ptr: a pointer to a buffer to be used as input data to the callee.
+	// - input_data_len: length of the input data buffer.
+	// - output_ptr: a pointer where the output buffer is copied to.
+	// - output_len_ptr: in-out pointer to where the length of the buffer is read from
+	//   and the actual length is written to.
+	//
+	// # Errors
+	//
+	// An error means that the call wasn't successful and no output buffer is returned unless
+	// stated otherwise.
+	//
+	// `ReturnCode::CalleeReverted`: Output buffer is returned.
+	// `ReturnCode::CalleeTrapped`
+	// `ReturnCode::CodeNotFound`
+	//
+	// # Unstable
+	//
+	// This function is unstable and subject to change (or removal) in the future. Do not
+	// deploy a contract using it to a production chain.
+	[__unstable__] seal_delegate_call(
+		ctx,
+		flags: u32,
+		code_hash_ptr: u32,
+		input_data_ptr: u32,
+		input_data_len: u32,
+		output_ptr: u32,
+		output_len_ptr: u32
+	) -> ReturnCode => {
+		ctx.call(
+			CallFlags::from_bits(flags).ok_or_else(|| "used reserved bit in CallFlags")?,
+			CallType::DelegateCall { code_hash_ptr },
 			input_data_ptr,
 			input_data_len,
 			output_ptr,
@@ -1835,4 +2018,204 @@ define_env!(Env, <E: Ext>,
 			Err(_) => Ok(ReturnCode::EcdsaRecoverFailed),
 		}
 	},
+
+	// Verify a sr25519 signature.
+	//
+	// # Parameters
+	//
+	// - `signature_ptr`: the pointer into the linear memory where the signature is placed.
+	//					  Should be decodable as a 64 bytes. Traps otherwise.
+	// - `pub_key_ptr`: the pointer into the linear memory where the public key is placed.
+	//					Should be decodable as a 32 bytes. Traps otherwise.
+	// - `message_len`: the length of the message payload.
+	// - `message_ptr`: the pointer into the linear memory where the message is placed.
+	//
+	// # Errors
+	//
+	// `ReturnCode::Sr25519VerifyFailed`
+	[__unstable__] seal_sr25519_verify(
+		ctx,
+		signature_ptr: u32,
+		pub_key_ptr: u32,
+		message_len: u32,
+		message_ptr: u32
+	) -> ReturnCode => {
+		ctx.charge_gas(RuntimeCosts::Sr25519Verify(message_len))?;
+
+		let mut signature: [u8; 64] = [0; 64];
+		ctx.read_sandbox_memory_into_buf(signature_ptr, &mut signature)?;
+		let mut pub_key: [u8; 32] = [0; 32];
+		ctx.read_sandbox_memory_into_buf(pub_key_ptr, &mut pub_key)?;
+		let message = ctx.read_sandbox_memory(message_ptr, message_len)?;
+
+		if ctx.ext.sr25519_verify(&signature, &message, &pub_key) {
+			Ok(ReturnCode::Success)
+		} else {
+			Ok(ReturnCode::Sr25519VerifyFailed)
+		}
+	},
+
+	// Calculates the Ethereum address from the ECDSA compressed public key and stores
+	// it into the supplied buffer.
+	//
+	// # Parameters
+	//
+	// - `key_ptr`: a pointer to the ECDSA compressed public key. Should be decodable as a 33 bytes
+	//				value. Traps otherwise.
+	// - `out_ptr`: the pointer into the linear memory where the output data is placed. The
+	//				buffer should be 20 bytes. Traps otherwise.
+	//				The function will write the result directly into this buffer.
+	//
+	// # Errors
+	//
+	// If the public key is not a valid compressed secp256k1 key
+	// `ReturnCode::EcdsaRecoverFailed` is returned.
+	[__unstable__] seal_ecdsa_to_eth_address(ctx, key_ptr: u32, out_ptr: u32) -> ReturnCode => {
+		ctx.charge_gas(RuntimeCosts::EcdsaToEthAddress)?;
+
+		let mut compressed_key: [u8; 33] = [0; 33];
+		ctx.read_sandbox_memory_into_buf(key_ptr, &mut compressed_key)?;
+
+		match ctx.ext.ecdsa_to_eth_address(&compressed_key) {
+			Ok(eth_address) => {
+				ctx.write_sandbox_memory(out_ptr, eth_address.as_ref())?;
+				Ok(ReturnCode::Success)
+			},
+			Err(_) => Ok(ReturnCode::EcdsaRecoverFailed),
+		}
+	},
+
+	// Replace the contract code at the specified address with new code.
+	//
+	// # Note
+	//
+	// There are a couple of important considerations which must be taken into account when
+	// using this API:
+	//
+	// 1. The storage at the code address will remain untouched. This means that contract
+	// developers must ensure that the storage layout of the new code is compatible with that of
+	// the old code.
+	//
+	// 2. Contracts using this API can't be assumed as having deterministic addresses. Said another
+	// way, when using this API you lose the guarantee that an address always identifies a specific
+	// code hash.
+	//
+	// 3. The currently executing code is not replaced. The new code is used by all calls that
+	// enter the contract after this one.
+	//
+	// # Parameters
+	//
+	// - `code_hash_ptr`: A pointer to the buffer that contains the new code hash.
+	//
+	// # Errors
+	//
+	// `ReturnCode::CodeNotFound`
+	//
+	// # Unstable
+	//
+	// This function is unstable and subject to change (or removal) in the future. Do not
+	// deploy a contract using it to a production chain.
+	[__unstable__] seal_set_code_hash(ctx, code_hash_ptr: u32) -> ReturnCode => {
+		ctx.charge_gas(RuntimeCosts::SetCodeHash)?;
+		let code_hash: CodeHash<<E as Ext>::T> = ctx.read_sandbox_memory_as(code_hash_ptr)?;
+		match ctx.ext.set_code_hash(code_hash) {
+			Ok(()) => Ok(ReturnCode::Success),
+			Err(err) => {
+				let code = Runtime::<E>::err_into_return_code(err)?;
+				Ok(code)
+			}
+		}
+	},
+
+	// Retrieve the code hash for a specified contract address.
+	//
+	// # Parameters
+	//
+	// - `account_ptr`: a pointer to the address in question.
+	//   Should be decodable as an `T::AccountId`. Traps otherwise.
+	// - `out_ptr`: pointer to the linear memory where the returning value is written to.
+	// - `out_len_ptr`: in-out pointer into linear memory where the buffer length
+	//   is read from and the value length is written to.
+	//
+	// # Errors
+	//
+	// `ReturnCode::KeyNotFound`
+	//
+	// # Unstable
+	//
+	// This function is unstable and subject to change (or removal) in the future. Do not
+	// deploy a contract using it to a production chain.
+	[__unstable__] seal_code_hash(ctx, account_ptr: u32, out_ptr: u32, out_len_ptr: u32) -> ReturnCode => {
+		ctx.charge_gas(RuntimeCosts::CodeHash)?;
+		let address: <<E as Ext>::T as frame_system::Config>::AccountId =
+			ctx.read_sandbox_memory_as(account_ptr)?;
+		if let Some(value) = ctx.ext.code_hash(&address) {
+			ctx.write_sandbox_output(
+				out_ptr, out_len_ptr, &value.encode(), false, already_charged
+			)?;
+			Ok(ReturnCode::Success)
+		} else {
+			Ok(ReturnCode::KeyNotFound)
+		}
+	},
+
+	// Retrieve the code hash of the currently executing contract.
+	//
+	// # Parameters
+	//
+	// - `out_ptr`: pointer to the linear memory where the returning value is written to.
+	// - `out_len_ptr`: in-out pointer into linear memory where the buffer length
+	//   is read from and the value length is written to.
+	//
+	// # Unstable
+	//
+	// This function is unstable and subject to change (or removal) in the future. Do not
+	// deploy a contract using it to a production chain.
+	[__unstable__] seal_own_code_hash(ctx, out_ptr: u32, out_len_ptr: u32) => {
+		ctx.charge_gas(RuntimeCosts::OwnCodeHash)?;
+		let code_hash_encoded = &ctx.ext.own_code_hash().encode();
+		Ok(ctx.write_sandbox_output(
+			out_ptr, out_len_ptr, code_hash_encoded, false, already_charged
+		)?)
+	},
+
+	// Returns the number of times the currently executing contract exists on the call stack in
+	// addition to the calling instance.
+	//
+	// # Return Value
+	//
+	// Returns 0 when there is no reentrancy. Delegate calls are not counted as separate
+	// entrances.
+	//
+	// # Unstable
+	//
+	// This function is unstable and subject to change (or removal) in the future. Do not
+	// deploy a contract using it to a production chain.
+	[__unstable__] seal_reentrance_count(ctx) -> u32 => {
+		ctx.charge_gas(RuntimeCosts::ReentranceCount)?;
+		Ok(ctx.ext.reentrance_count())
+	},
+
+	// Returns the number of times specified contract exists on the call stack. Delegate calls are
+	// not counted as separate entrances.
+	//
+	// # Parameters
+	//
+	// - `account_ptr`: a pointer to the contract address.
+	//   Should be decodable as an `T::AccountId`. Traps otherwise.
+	//
+	// # Return Value
+	//
+	// Returns 0 when the contract does not exist on the call stack.
+	//
+	// # Unstable
+	//
+	// This function is unstable and subject to change (or removal) in the future. Do not
+	// deploy a contract using it to a production chain.
+	[__unstable__] seal_account_reentrance_count(ctx, account_ptr: u32) -> u32 => {
+		ctx.charge_gas(RuntimeCosts::AccountReentranceCount)?;
+		let account_id: <<E as Ext>::T as frame_system::Config>::AccountId =
+			ctx.read_sandbox_memory_as(account_ptr)?;
+		Ok(ctx.ext.account_reentrance_count(&account_id))
+	},
 );
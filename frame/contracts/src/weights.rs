@@ -48,9 +48,11 @@ pub trait WeightInfo {
 	fn on_initialize_per_trie_key(k: u32, ) -> Weight;
 	fn on_initialize_per_queue_item(q: u32, ) -> Weight;
 	fn reinstrument(c: u32, ) -> Weight;
+	fn reinstrument_code(c: u32, ) -> Weight;
 	fn call_with_code_kb(c: u32, ) -> Weight;
 	fn instantiate_with_code(c: u32, s: u32, ) -> Weight;
 	fn instantiate(s: u32, ) -> Weight;
+	fn try_instantiate_duplicate(s: u32, ) -> Weight;
 	fn call() -> Weight;
 	fn upload_code(c: u32, ) -> Weight;
 	fn remove_code() -> Weight;
@@ -80,12 +82,15 @@ pub trait WeightInfo {
 	fn seal_clear_storage_per_kb(n: u32, ) -> Weight;
 	fn seal_get_storage(r: u32, ) -> Weight;
 	fn seal_get_storage_per_kb(n: u32, ) -> Weight;
+	fn seal_next_key(r: u32, ) -> Weight;
+	fn seal_next_key_per_kb(n: u32, ) -> Weight;
 	fn seal_contains_storage(r: u32, ) -> Weight;
 	fn seal_contains_storage_per_kb(n: u32, ) -> Weight;
 	fn seal_take_storage(r: u32, ) -> Weight;
 	fn seal_take_storage_per_kb(n: u32, ) -> Weight;
 	fn seal_transfer(r: u32, ) -> Weight;
 	fn seal_call(r: u32, ) -> Weight;
+	fn seal_delegate_call(r: u32, ) -> Weight;
 	fn seal_call_per_transfer_input_output_kb(t: u32, i: u32, o: u32, ) -> Weight;
 	fn seal_instantiate(r: u32, ) -> Weight;
 	fn seal_instantiate_per_input_output_salt_kb(i: u32, o: u32, s: u32, ) -> Weight;
@@ -98,6 +103,14 @@ pub trait WeightInfo {
 	fn seal_hash_blake2_128(r: u32, ) -> Weight;
 	fn seal_hash_blake2_128_per_kb(n: u32, ) -> Weight;
 	fn seal_ecdsa_recover(r: u32, ) -> Weight;
+	fn seal_sr25519_verify(r: u32, ) -> Weight;
+	fn seal_sr25519_verify_per_kb(n: u32, ) -> Weight;
+	fn seal_ecdsa_to_eth_address(r: u32, ) -> Weight;
+	fn seal_set_code_hash(r: u32, ) -> Weight;
+	fn seal_code_hash(r: u32, ) -> Weight;
+	fn seal_own_code_hash(r: u32, ) -> Weight;
+	fn seal_reentrance_count(r: u32, ) -> Weight;
+	fn seal_account_reentrance_count(r: u32, ) -> Weight;
 	fn instr_i64const(r: u32, ) -> Weight;
 	fn instr_i64load(r: u32, ) -> Weight;
 	fn instr_i64store(r: u32, ) -> Weight;
@@ -185,6 +198,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Storage: Contracts ReinstrumentCursor (r:1 w:1)
+	// Storage: Contracts CodeStorage (r:2 w:1)
+	// Storage: Contracts PristineCode (r:1 w:0)
+	// NOTE: this weight is a placeholder, based on `reinstrument` plus the extra storage
+	// accesses, until it is benchmarked.
+	fn reinstrument_code(c: u32, ) -> Weight {
+		(17_413_000 as Weight)
+			.saturating_add((64_495_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
 	// Storage: Contracts ContractInfoOf (r:1 w:1)
 	// Storage: Contracts CodeStorage (r:1 w:0)
 	// Storage: Timestamp Now (r:1 w:0)
@@ -225,6 +249,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(6 as Weight))
 			.saturating_add(T::DbWeight::get().writes(5 as Weight))
 	}
+	// Storage: Contracts ContractInfoOf (r:1 w:0)
+	// NOTE: this weight is a placeholder, covering the address derivation from the salt and
+	// a single storage read, until it is benchmarked.
+	fn try_instantiate_duplicate(s: u32, ) -> Weight {
+		(21_350_000 as Weight)
+			.saturating_add((1_024_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+	}
 	// Storage: Contracts ContractInfoOf (r:1 w:1)
 	// Storage: Contracts CodeStorage (r:1 w:0)
 	// Storage: Timestamp Now (r:1 w:0)
@@ -534,6 +566,23 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
 	// Storage: Skipped Metadata (r:0 w:0)
+	// NOTE: the weights of `seal_next_key` and `seal_next_key_per_kb` are placeholders, based on
+	// `seal_get_storage` and `seal_get_storage_per_kb`, until they are benchmarked.
+	fn seal_next_key(r: u32, ) -> Weight {
+		(112_328_000 as Weight)
+			.saturating_add((323_031_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads((100 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Skipped Metadata (r:0 w:0)
+	fn seal_next_key_per_kb(n: u32, ) -> Weight {
+		(556_303_000 as Weight)
+			.saturating_add((64_170_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(104 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Skipped Metadata (r:0 w:0)
 	fn seal_contains_storage(r: u32, ) -> Weight {
 		(106_572_000 as Weight)
 			// Standard Error: 691_000
@@ -595,6 +644,18 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().writes((100 as Weight).saturating_mul(r as Weight)))
 	}
 	// Storage: System Account (r:1 w:0)
+	// Storage: Contracts ContractInfoOf (r:1 w:1)
+	// Storage: Contracts CodeStorage (r:2 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// NOTE: this weight is a placeholder until it is benchmarked.
+	fn seal_delegate_call(r: u32, ) -> Weight {
+		(0 as Weight)
+			.saturating_add((17_872_629_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads((100 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: System Account (r:1 w:0)
 	// Storage: Contracts ContractInfoOf (r:101 w:101)
 	// Storage: Contracts CodeStorage (r:2 w:0)
 	// Storage: Timestamp Now (r:1 w:0)
@@ -742,6 +803,96 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// Storage: System Account (r:1 w:0)
+	// Storage: Contracts ContractInfoOf (r:1 w:1)
+	// Storage: Contracts CodeStorage (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// NOTE: the weights of `seal_sr25519_verify`, `seal_sr25519_verify_per_kb` and
+	// `seal_ecdsa_to_eth_address` are placeholders until they are benchmarked.
+	fn seal_sr25519_verify(r: u32, ) -> Weight {
+		(203_412_000 as Weight)
+			.saturating_add((13_916_312_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: System Account (r:1 w:0)
+	// Storage: Contracts ContractInfoOf (r:1 w:1)
+	// Storage: Contracts CodeStorage (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	fn seal_sr25519_verify_per_kb(n: u32, ) -> Weight {
+		(915_621_000 as Weight)
+			.saturating_add((108_264_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: System Account (r:1 w:0)
+	// Storage: Contracts ContractInfoOf (r:1 w:1)
+	// Storage: Contracts CodeStorage (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	fn seal_ecdsa_to_eth_address(r: u32, ) -> Weight {
+		(209_536_000 as Weight)
+			.saturating_add((3_783_437_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: System Account (r:1 w:0)
+	// Storage: Contracts ContractInfoOf (r:1 w:1)
+	// Storage: Contracts CodeStorage (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Contracts OwnerInfoOf (r:2 w:2)
+	// NOTE: the weights of `seal_set_code_hash`, `seal_code_hash` and `seal_own_code_hash` are
+	// placeholders until they are benchmarked.
+	fn seal_set_code_hash(r: u32, ) -> Weight {
+		(0 as Weight)
+			.saturating_add((96_207_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads((200 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes((200 as Weight).saturating_mul(r as Weight)))
+	}
+	// Storage: System Account (r:1 w:0)
+	// Storage: Contracts ContractInfoOf (r:1 w:1)
+	// Storage: Contracts CodeStorage (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Contracts ContractInfoOf (r:100 w:0)
+	fn seal_code_hash(r: u32, ) -> Weight {
+		(155_714_000 as Weight)
+			.saturating_add((1_238_415_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().reads((100 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: System Account (r:1 w:0)
+	// Storage: Contracts ContractInfoOf (r:1 w:1)
+	// Storage: Contracts CodeStorage (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	fn seal_own_code_hash(r: u32, ) -> Weight {
+		(213_426_000 as Weight)
+			.saturating_add((3_947_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: System Account (r:1 w:0)
+	// Storage: Contracts ContractInfoOf (r:1 w:1)
+	// Storage: Contracts CodeStorage (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// NOTE: the reentrance count weights are placeholders until they are benchmarked.
+	fn seal_reentrance_count(r: u32, ) -> Weight {
+		(206_587_000 as Weight)
+			.saturating_add((1_643_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: System Account (r:1 w:0)
+	// Storage: Contracts ContractInfoOf (r:1 w:1)
+	// Storage: Contracts CodeStorage (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	fn seal_account_reentrance_count(r: u32, ) -> Weight {
+		(209_818_000 as Weight)
+			.saturating_add((4_127_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(T::DbWeight::get().reads(4 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
 	fn instr_i64const(r: u32, ) -> Weight {
 		(74_569_000 as Weight)
 			// Standard Error: 1_000
@@ -1032,6 +1183,17 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	// Storage: Contracts ReinstrumentCursor (r:1 w:1)
+	// Storage: Contracts CodeStorage (r:2 w:1)
+	// Storage: Contracts PristineCode (r:1 w:0)
+	// NOTE: this weight is a placeholder, based on `reinstrument` plus the extra storage
+	// accesses, until it is benchmarked.
+	fn reinstrument_code(c: u32, ) -> Weight {
+		(17_413_000 as Weight)
+			.saturating_add((64_495_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
 	// Storage: Contracts ContractInfoOf (r:1 w:1)
 	// Storage: Contracts CodeStorage (r:1 w:0)
 	// Storage: Timestamp Now (r:1 w:0)
@@ -1072,6 +1234,14 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
 	}
+	// Storage: Contracts ContractInfoOf (r:1 w:0)
+	// NOTE: this weight is a placeholder, covering the address derivation from the salt and
+	// a single storage read, until it is benchmarked.
+	fn try_instantiate_duplicate(s: u32, ) -> Weight {
+		(21_350_000 as Weight)
+			.saturating_add((1_024_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+	}
 	// Storage: Contracts ContractInfoOf (r:1 w:1)
 	// Storage: Contracts CodeStorage (r:1 w:0)
 	// Storage: Timestamp Now (r:1 w:0)
@@ -1381,6 +1551,23 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
 	// Storage: Skipped Metadata (r:0 w:0)
+	// NOTE: the weights of `seal_next_key` and `seal_next_key_per_kb` are placeholders, based on
+	// `seal_get_storage` and `seal_get_storage_per_kb`, until they are benchmarked.
+	fn seal_next_key(r: u32, ) -> Weight {
+		(112_328_000 as Weight)
+			.saturating_add((323_031_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((100 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Skipped Metadata (r:0 w:0)
+	fn seal_next_key_per_kb(n: u32, ) -> Weight {
+		(556_303_000 as Weight)
+			.saturating_add((64_170_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(104 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Skipped Metadata (r:0 w:0)
 	fn seal_contains_storage(r: u32, ) -> Weight {
 		(106_572_000 as Weight)
 			// Standard Error: 691_000
@@ -1442,6 +1629,18 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().writes((100 as Weight).saturating_mul(r as Weight)))
 	}
 	// Storage: System Account (r:1 w:0)
+	// Storage: Contracts ContractInfoOf (r:1 w:1)
+	// Storage: Contracts CodeStorage (r:2 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// NOTE: this weight is a placeholder until it is benchmarked.
+	fn seal_delegate_call(r: u32, ) -> Weight {
+		(0 as Weight)
+			.saturating_add((17_872_629_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((100 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: System Account (r:1 w:0)
 	// Storage: Contracts ContractInfoOf (r:101 w:101)
 	// Storage: Contracts CodeStorage (r:2 w:0)
 	// Storage: Timestamp Now (r:1 w:0)
@@ -1589,6 +1788,96 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	// Storage: System Account (r:1 w:0)
+	// Storage: Contracts ContractInfoOf (r:1 w:1)
+	// Storage: Contracts CodeStorage (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// NOTE: the weights of `seal_sr25519_verify`, `seal_sr25519_verify_per_kb` and
+	// `seal_ecdsa_to_eth_address` are placeholders until they are benchmarked.
+	fn seal_sr25519_verify(r: u32, ) -> Weight {
+		(203_412_000 as Weight)
+			.saturating_add((13_916_312_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: System Account (r:1 w:0)
+	// Storage: Contracts ContractInfoOf (r:1 w:1)
+	// Storage: Contracts CodeStorage (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	fn seal_sr25519_verify_per_kb(n: u32, ) -> Weight {
+		(915_621_000 as Weight)
+			.saturating_add((108_264_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: System Account (r:1 w:0)
+	// Storage: Contracts ContractInfoOf (r:1 w:1)
+	// Storage: Contracts CodeStorage (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	fn seal_ecdsa_to_eth_address(r: u32, ) -> Weight {
+		(209_536_000 as Weight)
+			.saturating_add((3_783_437_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: System Account (r:1 w:0)
+	// Storage: Contracts ContractInfoOf (r:1 w:1)
+	// Storage: Contracts CodeStorage (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Contracts OwnerInfoOf (r:2 w:2)
+	// NOTE: the weights of `seal_set_code_hash`, `seal_code_hash` and `seal_own_code_hash` are
+	// placeholders until they are benchmarked.
+	fn seal_set_code_hash(r: u32, ) -> Weight {
+		(0 as Weight)
+			.saturating_add((96_207_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((200 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((200 as Weight).saturating_mul(r as Weight)))
+	}
+	// Storage: System Account (r:1 w:0)
+	// Storage: Contracts ContractInfoOf (r:1 w:1)
+	// Storage: Contracts CodeStorage (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// Storage: Contracts ContractInfoOf (r:100 w:0)
+	fn seal_code_hash(r: u32, ) -> Weight {
+		(155_714_000 as Weight)
+			.saturating_add((1_238_415_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((100 as Weight).saturating_mul(r as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: System Account (r:1 w:0)
+	// Storage: Contracts ContractInfoOf (r:1 w:1)
+	// Storage: Contracts CodeStorage (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	fn seal_own_code_hash(r: u32, ) -> Weight {
+		(213_426_000 as Weight)
+			.saturating_add((3_947_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: System Account (r:1 w:0)
+	// Storage: Contracts ContractInfoOf (r:1 w:1)
+	// Storage: Contracts CodeStorage (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	// NOTE: the reentrance count weights are placeholders until they are benchmarked.
+	fn seal_reentrance_count(r: u32, ) -> Weight {
+		(206_587_000 as Weight)
+			.saturating_add((1_643_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: System Account (r:1 w:0)
+	// Storage: Contracts ContractInfoOf (r:1 w:1)
+	// Storage: Contracts CodeStorage (r:1 w:0)
+	// Storage: Timestamp Now (r:1 w:0)
+	fn seal_account_reentrance_count(r: u32, ) -> Weight {
+		(209_818_000 as Weight)
+			.saturating_add((4_127_000 as Weight).saturating_mul(r as Weight))
+			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
 	fn instr_i64const(r: u32, ) -> Weight {
 		(74_569_000 as Weight)
 			// Standard Error: 1_000
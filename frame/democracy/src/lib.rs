@@ -1138,7 +1138,7 @@ pub mod pallet {
 		/// - the referendum has ended such that
 		///   - the vote of the account was in opposition to the result; or
 		///   - there was no conviction to the account's vote; or
-		///   - the account made a split vote
+		///   - the account made a split or split-abstain vote
 		/// ...then the vote is removed cleanly and a following call to `unlock` may result in more
 		/// funds being available.
 		///
@@ -56,6 +56,50 @@ fn split_vote_cancellation_should_work() {
 	});
 }
 
+#[test]
+fn split_abstain_voting_should_work() {
+	new_test_ext().execute_with(|| {
+		let r = begin_referendum();
+		let v = AccountVote::SplitAbstain { aye: 10, nay: 20, abstain: 30 };
+		assert_noop!(Democracy::vote(Origin::signed(5), r, v), Error::<Test>::InsufficientFunds);
+		let v = AccountVote::SplitAbstain { aye: 0, nay: 0, abstain: 50 };
+		assert_ok!(Democracy::vote(Origin::signed(5), r, v));
+		assert_eq!(tally(r), Tally { ayes: 0, nays: 0, turnout: 50 });
+
+		let v = AccountVote::SplitAbstain { aye: 10, nay: 0, abstain: 30 };
+		assert_ok!(Democracy::vote(Origin::signed(5), r, v));
+		assert_eq!(tally(r), Tally { ayes: 1, nays: 0, turnout: 40 });
+	});
+}
+
+#[test]
+fn split_abstain_vote_cancellation_should_work() {
+	new_test_ext().execute_with(|| {
+		let r = begin_referendum();
+		let v = AccountVote::SplitAbstain { aye: 10, nay: 20, abstain: 20 };
+		assert_ok!(Democracy::vote(Origin::signed(5), r, v));
+		assert_eq!(Balances::locks(5)[0].amount, 50);
+		assert_ok!(Democracy::remove_vote(Origin::signed(5), r));
+		assert_eq!(tally(r), Tally { ayes: 0, nays: 0, turnout: 0 });
+		assert_ok!(Democracy::unlock(Origin::signed(5), 5));
+		assert_eq!(Balances::locks(5), vec![]);
+	});
+}
+
+#[test]
+fn split_abstain_vote_is_not_locked_after_referendum() {
+	new_test_ext().execute_with(|| {
+		let r = begin_referendum();
+		let v = AccountVote::SplitAbstain { aye: 10, nay: 0, abstain: 40 };
+		assert_ok!(Democracy::vote(Origin::signed(5), r, v));
+		fast_forward_to(5);
+		assert!(matches!(Democracy::referendum_info(r), Some(ReferendumInfo::Finished { .. })));
+		assert_ok!(Democracy::remove_vote(Origin::signed(5), r));
+		assert_ok!(Democracy::unlock(Origin::signed(5), 5));
+		assert_eq!(Balances::locks(5), vec![]);
+	});
+}
+
 #[test]
 fn single_proposal_should_work() {
 	new_test_ext().execute_with(|| {
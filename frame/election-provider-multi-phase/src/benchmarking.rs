@@ -368,6 +368,98 @@ frame_benchmarking::benchmarks! {
 		assert!(<MultiPhase<T>>::queued_solution().is_some());
 	}
 
+	register_paged {
+		<MultiPhase<T>>::create_snapshot().map_err(<&str>::from)?;
+		MultiPhase::<T>::on_initialize_open_signed();
+
+		// the claimed score is checked against a queued solution.
+		<QueuedSolution<T>>::put(ReadySolution {
+			supports: vec![],
+			score: [1, 0, 0],
+			compute: Default::default(),
+		});
+		let score = [10_000_000u128, 0, 0];
+
+		let caller = frame_benchmarking::whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, T::Currency::minimum_balance() * 10u32.into());
+	}: _(RawOrigin::Signed(caller.clone()), score)
+	verify {
+		assert!(<PagedSignedSubmissions<T>>::contains_key(&caller));
+	}
+
+	// The worst case is the last page of a valid solution of `p` pages, which then ejects a queued
+	// paged solution of as many pages. The weight of the feasibility check of the page is added on
+	// top of this one.
+	submit_page {
+		let p in 1 .. T::Pages::get().max(1);
+
+		let witness = SolutionOrSnapshotSize {
+			voters: T::BenchmarkingConfig::VOTERS[0],
+			targets: T::BenchmarkingConfig::TARGETS[0],
+		};
+		let raw_solution = solution_with_size::<T>(
+			witness,
+			T::BenchmarkingConfig::ACTIVE_VOTERS[0],
+			T::BenchmarkingConfig::DESIRED_TARGETS[0],
+		)?;
+		<CurrentPhase<T>>::put(Phase::Signed);
+
+		// the last page refers to the same voters as the first one.
+		let last_page = T::Pages::get().max(1) - 1;
+		if !last_page.is_zero() {
+			let voters = <Snapshot<T>>::get().ok_or("snapshot missing")?.voters;
+			<PagedVoterSnapshot<T>>::insert(last_page, voters);
+		}
+
+		// all the other pages of the solution back the same targets as the last one.
+		let caller: T::AccountId = frame_benchmarking::whitelisted_caller();
+		let supports = <MultiPhase<T>>::feasibility_check_page(last_page, raw_solution.clone())
+			.map_err(|_| "invalid solution")?;
+		let mut backings = Supports::<T::AccountId>::new();
+		for page in 0..p - 1 {
+			helpers::add_backings(&mut backings, &supports);
+			<PagedSubmissionSupports<T>>::insert(&caller, page, supports.clone());
+		}
+		let mut all_backings = backings.clone();
+		helpers::add_backings(&mut all_backings, &supports);
+		let claimed_score = all_backings.evaluate();
+
+		let ejected: T::AccountId = account("ejected", 0, SEED);
+		let ready = ReadySolution { supports: vec![], score: [1, 0, 0], compute: Default::default() };
+		<QueuedSolution<T>>::put(ready);
+		for page in 0..p {
+			<QueuedSolutionPages<T>>::insert(page, supports.clone());
+		}
+		<QueuedPagedSubmission<T>>::put((
+			ejected,
+			PagedSignedSubmission {
+				deposit: Default::default(),
+				claimed_score: [1, 0, 0],
+				verified_pages: last_page + 1,
+				backings: Default::default(),
+			},
+		));
+
+		T::Currency::make_free_balance_be(
+			&caller,
+			T::Currency::minimum_balance().max(One::one()) * 1_000_000u32.into(),
+		);
+		<PagedSignedSubmissions<T>>::insert(
+			&caller,
+			PagedSignedSubmission {
+				deposit: Default::default(),
+				claimed_score,
+				verified_pages: last_page,
+				backings,
+			},
+		);
+	}: _(RawOrigin::Signed(caller.clone()), last_page, Box::new(raw_solution))
+	verify {
+		assert!(<PagedSignedSubmissions<T>>::get(&caller).is_none());
+		assert_eq!(<QueuedPagedSubmission<T>>::get().map(|(who, _)| who), Some(caller));
+		assert_eq!(<QueuedSolutionPages<T>>::iter().count() as u32, p);
+	}
+
 	// This is checking a valid solution. The worse case is indeed a valid solution.
 	feasibility_check {
 		// number of votes in snapshot.
@@ -18,6 +18,7 @@
 //! Some helper functions/macros for this crate.
 
 use crate::{unsigned::VoterOf, Config, SolutionTargetIndexOf, SolutionVoterIndexOf, VoteWeight};
+use sp_npos_elections::{Support, Supports};
 use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 
 #[macro_export]
@@ -203,3 +204,38 @@ pub fn stake_of_fn<'a, T: Config>(
 		}
 	}
 }
+
+/// Merge the supports of all pages of a paged solution into one.
+///
+/// The backing of a target that is supported in more than one page is added up.
+pub fn merge_supports<'a, AccountId: Ord + Clone + 'a>(
+	pages: impl IntoIterator<Item = &'a Supports<AccountId>>,
+) -> Supports<AccountId> {
+	let mut merged: BTreeMap<AccountId, Support<AccountId>> = BTreeMap::new();
+	for (target, support) in pages.into_iter().flatten() {
+		let entry = merged.entry(target.clone()).or_default();
+		entry.total = entry.total.saturating_add(support.total);
+		entry.voters.extend(support.voters.iter().cloned());
+	}
+	merged.into_iter().collect()
+}
+
+/// Add the backing of each target in `supports` to `backings`.
+///
+/// `backings` is kept sorted by target, and only keeps track of the total backing of each target,
+/// not of its voters.
+pub fn add_backings<AccountId: Ord + Clone>(
+	backings: &mut Supports<AccountId>,
+	supports: &Supports<AccountId>,
+) {
+	for (target, support) in supports {
+		match backings.binary_search_by(|(backed, _)| backed.cmp(target)) {
+			Ok(index) => {
+				let backing = &mut backings[index].1;
+				backing.total = backing.total.saturating_add(support.total);
+			},
+			Err(index) => backings
+				.insert(index, (target.clone(), Support { total: support.total, voters: vec![] })),
+		}
+	}
+}
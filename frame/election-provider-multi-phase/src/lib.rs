@@ -105,6 +105,13 @@
 //! than the best queued one (see [`pallet::Config::SolutionImprovementThreshold`]) and will limit
 //! the weigh of the solution to [`pallet::Config::MinerMaxWeight`].
 //!
+//! The miner first runs [`pallet::Config::Solver`], and then each of the
+//! [`pallet::Config::MinerAlgorithms`], one per execution of the offchain worker (see
+//! [`pallet::Config::OffchainRepeat`]). The best solution so far and the index of the next
+//! algorithm are cached in the offchain storage, so that an interrupted miner resumes where it left
+//! off instead of starting over. The cached solution is only (re-)submitted if it beats the queued
+//! one.
+//!
 //! The unsigned phase can be made passive depending on how the previous signed phase went, by
 //! setting the first inner value of [`Phase`] to `false`. For now, the signed phase is always
 //! active.
@@ -151,6 +158,42 @@
 //!
 //! See the `staking-miner` documentation in the Polkadot repository for more information.
 //!
+//! ## Paged Elections
+//!
+//! If [`Config::Pages`] is more than one, the election takes place over multiple pages, each
+//! containing at most [`Config::VoterSnapshotPerBlock`] voters. This raises the number of voters
+//! that can take part in the election, as no single block needs to handle all of them at once.
+//!
+//! ```ignore
+//!                                                                              elect()
+//!        + <--T::Pages - 1--> +   <--T::SignedPhase-->  +  <--T::UnsignedPhase-->   +
+//!   +-------------------------------------------------------------------------------+
+//!    Off +   Phase::Snapshot  +       Phase::Signed     +      Phase::Unsigned      +
+//! ```
+//!
+//! The snapshot is collected in [`Phase::Snapshot`], one page per block, via
+//! [`ElectionDataProvider::voters_paged`]. The first page of voters and all the targets are stored
+//! in [`Snapshot`], the rest of the pages in [`PagedVoterSnapshot`]. If the paged snapshot cannot
+//! be collected in time, the entire snapshot is collected in a single block instead.
+//!
+//! In the signed phase, a paged solution is first registered via [`Call::register_paged`], with a
+//! claimed score, and then its pages are submitted one by one via [`Call::submit_page`]. Each page
+//! is a [`RawSolution`] that only refers to the voters of the corresponding page of the snapshot.
+//! Pages are verified incrementally upon arrival, and the total backing of each target is added up
+//! along the way. Once the last page is verified, the claimed score is checked against these
+//! backings. A valid paged solution is queued right away if it is better than the queued solution.
+//! Its pages are kept as they are in [`QueuedSolutionPages`], and handed out one by one through
+//! [`ElectionProvider::elect_paged`]. An invalid page results in the entire deposit being slashed.
+//! At most [`Config::SignedMaxSubmissions`] paged solutions can be registered in each round.
+//!
+//! At the end of the signed phase, only the submitter of the paged solution that is still queued
+//! is rewarded. The submitters of paged solutions that were replaced by a better one get their
+//! deposit back, whilst paged solutions that are not completed by then are slashed.
+//!
+//! The unsigned phase, the fallback and the emergency phase are not paged. Their solutions only
+//! ever refer to the first page of the snapshot, and are handed out in the first page of
+//! [`ElectionProvider::elect_paged`].
+//!
 //! ## Feasible Solution (correct solution)
 //!
 //! All submissions must undergo a feasibility check. Signed solutions are checked on by one at the
@@ -231,7 +274,7 @@
 
 use codec::{Decode, Encode};
 use frame_election_provider_support::{
-	ElectionDataProvider, ElectionProvider, InstantElectionProvider,
+	ElectionDataProvider, ElectionProvider, InstantElectionProvider, PageIndex,
 };
 use frame_support::{
 	dispatch::DispatchResultWithPostInfo,
@@ -268,12 +311,14 @@ pub mod helpers;
 
 const LOG_TARGET: &'static str = "runtime::election-provider";
 
+pub mod paged;
 pub mod signed;
 pub mod unsigned;
 pub mod weights;
 use unsigned::VoterOf;
 pub use weights::WeightInfo;
 
+pub use paged::{PagedSignedSubmission, PagedSignedSubmissionOf};
 pub use signed::{
 	BalanceOf, NegativeImbalanceOf, PositiveImbalanceOf, SignedSubmission, SignedSubmissionOf,
 	SignedSubmissions, SubmissionIndicesOf,
@@ -355,6 +400,11 @@ pub enum Phase<Bn> {
 	/// After that, the only way to leave this phase is through a successful
 	/// `T::ElectionProvider::elect`.
 	Emergency,
+	/// A paged snapshot is being collected, one page per block. The inner value is the next page
+	/// to be collected.
+	///
+	/// Once the last page is collected, the signed phase starts.
+	Snapshot(PageIndex),
 }
 
 impl<Bn> Default for Phase<Bn> {
@@ -393,6 +443,11 @@ impl<Bn: PartialEq + Eq> Phase<Bn> {
 	pub fn is_off(&self) -> bool {
 		matches!(self, Phase::Off)
 	}
+
+	/// Whether a paged snapshot is being collected or not.
+	pub fn is_snapshot(&self) -> bool {
+		matches!(self, Phase::Snapshot(_))
+	}
 }
 
 /// The type of `Computation` that provided this election data.
@@ -459,11 +514,14 @@ pub struct ReadySolution<A> {
 /// [`ElectionDataProvider`] and are kept around until the round is finished.
 ///
 /// These are stored together because they are often accessed together.
+///
+/// In a paged election, this only contains the voters of the first page. See
+/// [`PagedVoterSnapshot`] for the rest.
 #[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, Default, TypeInfo)]
 #[codec(mel_bound(T: Config))]
 #[scale_info(skip_type_params(T))]
 pub struct RoundSnapshot<T: Config> {
-	/// All of the voters.
+	/// All of the voters, or those of the first page in a paged election.
 	pub voters: Vec<VoterOf<T>>,
 	/// All of the targets.
 	pub targets: Vec<T::AccountId>,
@@ -644,15 +702,22 @@ pub mod pallet {
 		#[pallet::constant]
 		type SignedDepositWeight: Get<BalanceOf<Self>>;
 
-		/// The maximum number of voters to put in the snapshot. At the moment, snapshots are only
-		/// over a single block, but once multi-block elections are introduced they will take place
-		/// over multiple blocks.
+		/// The maximum number of voters to put in each page of the snapshot.
 		///
 		/// Also, note the data type: If the voters are represented by a `u32` in `type
 		/// CompactSolution`, the same `u32` is used here to ensure bounds are respected.
 		#[pallet::constant]
 		type VoterSnapshotPerBlock: Get<SolutionVoterIndexOf<Self>>;
 
+		/// The number of pages of the election.
+		///
+		/// The snapshot is collected over this many blocks, each page containing at most
+		/// [`Config::VoterSnapshotPerBlock`] voters, and the election result is handed out in this
+		/// many pages via [`ElectionProvider::elect_paged`]. Set to `1` for a single block
+		/// election.
+		#[pallet::constant]
+		type Pages: Get<PageIndex>;
+
 		/// Handler for the slashed deposits.
 		type SlashHandler: OnUnbalanced<NegativeImbalanceOf<Self>>;
 
@@ -703,6 +768,12 @@ pub mod pallet {
 		/// OCW election solution miner algorithm implementation.
 		type Solver: NposSolver<AccountId = Self::AccountId>;
 
+		/// Additional algorithms that the OCW miner runs after [`Config::Solver`], one per
+		/// execution of the offchain worker.
+		///
+		/// The best scoring solution of all algorithms is submitted.
+		type MinerAlgorithms: Get<Vec<unsigned::MinerAlgorithm>>;
+
 		/// Origin that can control this pallet. Note that any action taken by this origin (such)
 		/// as providing an emergency solution is not checked. Thus, it must be a trusted origin.
 		type ForceOrigin: EnsureOrigin<Self::Origin>;
@@ -721,6 +792,8 @@ pub mod pallet {
 
 			let signed_deadline = T::SignedPhase::get() + T::UnsignedPhase::get();
 			let unsigned_deadline = T::UnsignedPhase::get();
+			let pages = T::Pages::get().max(1);
+			let snapshot_deadline = signed_deadline + T::BlockNumber::from(pages - 1);
 
 			let remaining = next_election - now;
 			let current_phase = Self::current_phase();
@@ -733,6 +806,41 @@ pub mod pallet {
 				Self::snapshot_metadata()
 			);
 			match current_phase {
+				Phase::Off if remaining <= snapshot_deadline && remaining > signed_deadline => {
+					// a paged snapshot is collected one page per block, starting with the first.
+					match Self::create_snapshot_page(0) {
+						Ok(_) => {
+							<CurrentPhase<T>>::put(Phase::Snapshot(1));
+							T::WeightInfo::on_initialize_nothing()
+						},
+						Err(why) => {
+							log!(warn, "failed to start collecting the snapshot due to {:?}", why);
+							T::WeightInfo::on_initialize_nothing()
+						},
+					}
+				},
+				Phase::Snapshot(page) => match Self::create_snapshot_page(page) {
+					Ok(_) if page.saturating_add(1) < pages => {
+						<CurrentPhase<T>>::put(Phase::Snapshot(page.saturating_add(1)));
+						T::WeightInfo::on_initialize_nothing()
+					},
+					Ok(_) if remaining > unsigned_deadline => {
+						Self::on_initialize_open_signed();
+						T::WeightInfo::on_initialize_open_signed()
+					},
+					Ok(_) => {
+						Self::on_initialize_open_unsigned(true, now);
+						T::WeightInfo::on_initialize_open_unsigned()
+					},
+					Err(why) => {
+						// start over. If we are already too close to the election, the entire
+						// snapshot is collected at once in the next block.
+						log!(warn, "failed to collect snapshot page {} due to {:?}", page, why);
+						Self::kill_snapshot();
+						<CurrentPhase<T>>::put(Phase::Off);
+						T::WeightInfo::on_initialize_nothing()
+					},
+				},
 				Phase::Off if remaining <= signed_deadline && remaining > unsigned_deadline => {
 					// NOTE: if signed-phase length is zero, second part of the if-condition fails.
 					match Self::create_snapshot() {
@@ -901,7 +1009,7 @@ pub mod pallet {
 			// Store the newly received solution.
 			log!(info, "queued unsigned solution with score {:?}", ready.score);
 			let ejected_a_solution = <QueuedSolution<T>>::exists();
-			<QueuedSolution<T>>::put(ready);
+			Self::queue_solution(ready);
 			Self::deposit_event(Event::SolutionStored {
 				election_compute: ElectionCompute::Unsigned,
 				prev_ejected: ejected_a_solution,
@@ -947,7 +1055,7 @@ pub mod pallet {
 			let solution =
 				ReadySolution { supports, score: [0, 0, 0], compute: ElectionCompute::Emergency };
 
-			<QueuedSolution<T>>::put(solution);
+			Self::queue_solution(solution);
 			Ok(())
 		}
 
@@ -1062,9 +1170,63 @@ pub mod pallet {
 			let solution =
 				ReadySolution { supports, score: [0, 0, 0], compute: ElectionCompute::Fallback };
 
-			<QueuedSolution<T>>::put(solution);
+			Self::queue_solution(solution);
 			Ok(())
 		}
+
+		/// Register a paged solution for the signed phase, with the given claimed score.
+		///
+		/// The dispatch origin of this call must be __signed__.
+		///
+		/// The pages of the solution must then be submitted in order via [`Call::submit_page`].
+		/// Only one paged solution per account, and at most [`Config::SignedMaxSubmissions`] paged
+		/// solutions in total, can be registered in each round.
+		///
+		/// A base deposit is reserved for the solution, and a further deposit is reserved for each
+		/// submitted page. The deposit is slashed if any of its pages or its claimed score turn out
+		/// to be invalid, or if the solution is not complete by the end of the signed phase.
+		/// Otherwise, it is returned at the end of the signed phase, along with a reward if the
+		/// solution is still queued by then.
+		#[pallet::weight(T::WeightInfo::register_paged())]
+		pub fn register_paged(
+			origin: OriginFor<T>,
+			claimed_score: ElectionScore,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::current_phase().is_signed(), Error::<T>::PreDispatchEarlySubmission);
+
+			Self::do_register_paged(who, claimed_score)
+		}
+
+		/// Submit a single page of a paged solution, previously registered via
+		/// [`Call::register_paged`].
+		///
+		/// The dispatch origin of this call must be __signed__.
+		///
+		/// Pages must be submitted in ascending order, starting from page `0`, and each one must
+		/// only refer to the voters of the same page of the snapshot. The page is verified on the
+		/// spot. Once the last page is verified, the solution is queued if it is better than the
+		/// currently queued one.
+		///
+		/// The weight of the feasibility check of the page is charged on top of
+		/// [`WeightInfo::submit_page`], and is refunded down to the actual size of the page. The
+		/// weight of queueing all the pages of the solution is refunded unless this is the last
+		/// page.
+		#[pallet::weight(
+			T::WeightInfo::submit_page(T::Pages::get().max(1))
+				.saturating_add(T::SignedMaxWeight::get())
+		)]
+		pub fn submit_page(
+			origin: OriginFor<T>,
+			page: PageIndex,
+			raw_solution: Box<RawSolution<SolutionOf<T>>>,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			ensure!(Self::current_phase().is_signed(), Error::<T>::PreDispatchEarlySubmission);
+
+			let actual_weight = Self::do_submit_page(who, page, *raw_solution)?;
+			Ok(Some(actual_weight).into())
+		}
 	}
 
 	#[pallet::event]
@@ -1088,6 +1250,8 @@ pub mod pallet {
 		SignedPhaseStarted { round: u32 },
 		/// The unsigned phase of the given round has started.
 		UnsignedPhaseStarted { round: u32 },
+		/// A page of a paged signed submission has been verified.
+		PageVerified { account: <T as frame_system::Config>::AccountId, page: PageIndex },
 	}
 
 	/// Error of the pallet that can be returned in response to dispatches.
@@ -1117,6 +1281,14 @@ pub mod pallet {
 		CallNotAllowed,
 		/// The fallback failed
 		FallbackFailed,
+		/// The origin has already registered a paged solution in this round.
+		PagedAlreadyRegistered,
+		/// The origin has not registered a paged solution in this round.
+		PagedNotRegistered,
+		/// The submitted page is not the next expected page of the paged solution.
+		PagedWrongPage,
+		/// The maximum number of paged solutions has already been registered in this round.
+		PagedQueueFull,
 	}
 
 	#[pallet::validate_unsigned]
@@ -1188,10 +1360,20 @@ pub mod pallet {
 	pub type CurrentPhase<T: Config> = StorageValue<_, Phase<T::BlockNumber>, ValueQuery>;
 
 	/// Current best solution, signed or unsigned, queued to be returned upon `elect`.
+	///
+	/// The supports of a paged solution are not kept here, but in [`QueuedSolutionPages`].
 	#[pallet::storage]
 	#[pallet::getter(fn queued_solution)]
 	pub type QueuedSolution<T: Config> = StorageValue<_, ReadySolution<T::AccountId>>;
 
+	/// The supports of each page of the queued solution, if it was submitted in pages.
+	///
+	/// Only exists along with the [`QueuedSolution`] that holds their score, and is handed out
+	/// upon `elect_paged`. The same target may be backed in more than one page.
+	#[pallet::storage]
+	pub type QueuedSolutionPages<T: Config> =
+		StorageMap<_, Twox64Concat, PageIndex, Supports<T::AccountId>>;
+
 	/// Snapshot data of the round.
 	///
 	/// This is created at the beginning of the signed phase and cleared upon calling `elect`.
@@ -1199,6 +1381,14 @@ pub mod pallet {
 	#[pallet::getter(fn snapshot)]
 	pub type Snapshot<T: Config> = StorageValue<_, RoundSnapshot<T>>;
 
+	/// The voters of all pages of the snapshot but the first, keyed by page index.
+	///
+	/// Only used if [`Config::Pages`] is more than one. The first page of voters is kept in
+	/// [`Snapshot`], and this is cleared along with it.
+	#[pallet::storage]
+	pub type PagedVoterSnapshot<T: Config> =
+		StorageMap<_, Twox64Concat, PageIndex, Vec<VoterOf<T>>>;
+
 	/// Desired number of targets to elect for this round.
 	///
 	/// Only exists when [`Snapshot`] is present.
@@ -1252,6 +1442,33 @@ pub mod pallet {
 
 	// `SignedSubmissions` items end here.
 
+	/// The incomplete paged signed submissions of this round, keyed by their submitter.
+	///
+	/// Bounded by [`Config::SignedMaxSubmissions`].
+	#[pallet::storage]
+	pub(crate) type PagedSignedSubmissions<T: Config> =
+		CountedStorageMap<_, Twox64Concat, T::AccountId, PagedSignedSubmissionOf<T>, OptionQuery>;
+
+	/// The submitter of the queued paged solution, along with their submission.
+	///
+	/// The submitter is only rewarded at the end of the signed phase, if their solution has not
+	/// been replaced by a better one by then.
+	#[pallet::storage]
+	pub(crate) type QueuedPagedSubmission<T: Config> =
+		StorageValue<_, (T::AccountId, PagedSignedSubmissionOf<T>), OptionQuery>;
+
+	/// The supports of each verified page of a paged signed submission.
+	#[pallet::storage]
+	pub(crate) type PagedSubmissionSupports<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		PageIndex,
+		Supports<T::AccountId>,
+		OptionQuery,
+	>;
+
 	/// The minimum score that each 'untrusted' solution must attain in order to be considered
 	/// feasible.
 	///
@@ -1279,13 +1496,13 @@ impl<T: Config> Pallet<T> {
 					// This is executed at the beginning of each round. Any cache is now invalid.
 					// Clear it.
 					unsigned::kill_ocw_solution::<T>();
-					Self::mine_check_save_submit()
+					Self::restore_or_compute_then_maybe_submit()
 				});
 				log!(debug, "initial offchain thread output: {:?}", initial_output);
 			},
 			Phase::Unsigned((true, opened)) if opened < now => {
-				// Try and resubmit the cached solution, and recompute ONLY if it is not
-				// feasible.
+				// Resume mining with the next algorithm, and resubmit the best solution if it
+				// still beats the queued one.
 				let resubmit_output = Self::ensure_offchain_repeat_frequency(now)
 					.and_then(|_| Self::restore_or_compute_then_maybe_submit());
 				log!(debug, "resubmit offchain thread output: {:?}", resubmit_output);
@@ -1347,13 +1564,19 @@ impl<T: Config> Pallet<T> {
 	fn create_snapshot_external(
 	) -> Result<(Vec<T::AccountId>, Vec<VoterOf<T>>, u32), ElectionError<T>> {
 		let target_limit = <SolutionTargetIndexOf<T>>::max_value().saturated_into::<usize>();
-		// for now we have just a single block snapshot.
+		// this is only the first page of the snapshot.
 		let voter_limit = T::VoterSnapshotPerBlock::get().saturated_into::<usize>();
 
 		let targets =
 			T::DataProvider::targets(Some(target_limit)).map_err(ElectionError::DataProvider)?;
-		let voters =
-			T::DataProvider::voters(Some(voter_limit)).map_err(ElectionError::DataProvider)?;
+		// only a snapshot of more than one page needs the data provider to keep track of where
+		// the next page starts.
+		let voters = if T::Pages::get() > 1 {
+			T::DataProvider::voters_paged(Some(voter_limit), 0)
+		} else {
+			T::DataProvider::voters(Some(voter_limit))
+		}
+		.map_err(ElectionError::DataProvider)?;
 		let desired_targets =
 			T::DataProvider::desired_targets().map_err(ElectionError::DataProvider)?;
 
@@ -1366,25 +1589,55 @@ impl<T: Config> Pallet<T> {
 		Ok((targets, voters, desired_targets))
 	}
 
-	/// Creates the snapshot. Writes new data to:
+	/// Creates the snapshot, all of its pages at once. Writes new data to:
 	///
 	/// 1. [`SnapshotMetadata`]
 	/// 2. [`RoundSnapshot`]
 	/// 3. [`DesiredTargets`]
+	/// 4. [`PagedVoterSnapshot`], if [`Config::Pages`] is more than one.
 	///
 	/// Returns `Ok(())` if operation is okay.
 	///
 	/// This is a *self-weighing* function, it will register its own extra weight as
 	/// [`DispatchClass::Mandatory`] with the system pallet.
 	pub fn create_snapshot() -> Result<(), ElectionError<T>> {
-		// this is self-weighing itself..
-		let (targets, voters, desired_targets) = Self::create_snapshot_external()?;
-
-		// ..therefore we only measure the weight of this and add it.
-		let internal_weight =
-			T::WeightInfo::create_snapshot_internal(voters.len() as u32, targets.len() as u32);
-		Self::create_snapshot_internal(targets, voters, desired_targets);
-		Self::register_weight(internal_weight);
+		(0..T::Pages::get().max(1)).try_for_each(Self::create_snapshot_page)
+	}
+
+	/// Creates a single page of the snapshot.
+	///
+	/// The first page also contains all the targets and the desired number of targets, and is
+	/// stored in [`Snapshot`]. Any other page only contains voters, and is stored in
+	/// [`PagedVoterSnapshot`].
+	///
+	/// This is a *self-weighing* function, it will register its own extra weight as
+	/// [`DispatchClass::Mandatory`] with the system pallet.
+	pub fn create_snapshot_page(page: PageIndex) -> Result<(), ElectionError<T>> {
+		if page.is_zero() {
+			// this is self-weighing itself..
+			let (targets, voters, desired_targets) = Self::create_snapshot_external()?;
+
+			// ..therefore we only measure the weight of this and add it.
+			let internal_weight =
+				T::WeightInfo::create_snapshot_internal(voters.len() as u32, targets.len() as u32);
+			Self::create_snapshot_internal(targets, voters, desired_targets);
+			Self::register_weight(internal_weight);
+		} else {
+			let voter_limit = T::VoterSnapshotPerBlock::get().saturated_into::<usize>();
+			let voters = T::DataProvider::voters_paged(Some(voter_limit), page)
+				.map_err(ElectionError::DataProvider)?;
+
+			// Defensive-only.
+			if voters.len() > voter_limit {
+				debug_assert!(false, "Snapshot limit has not been respected.");
+				return Err(ElectionError::DataProvider("Snapshot too big for submission."))
+			}
+
+			let internal_weight = T::WeightInfo::create_snapshot_internal(voters.len() as u32, 0);
+			log!(debug, "collected page {} of the snapshot with {} voters", page, voters.len());
+			<PagedVoterSnapshot<T>>::insert(page, voters);
+			Self::register_weight(internal_weight);
+		}
 		Ok(())
 	}
 
@@ -1403,6 +1656,14 @@ impl<T: Config> Pallet<T> {
 		<Snapshot<T>>::kill();
 		<SnapshotMetadata<T>>::kill();
 		<DesiredTargets<T>>::kill();
+		let _ = <PagedVoterSnapshot<T>>::remove_all(None);
+	}
+
+	/// Queue the given solution, replacing any previously queued one.
+	pub(crate) fn queue_solution(ready_solution: ReadySolution<T::AccountId>) {
+		// the pages of a previously queued paged solution no longer apply.
+		let _ = <QueuedSolutionPages<T>>::remove_all(None);
+		<QueuedSolution<T>>::put(ready_solution);
 	}
 
 	/// Checks the feasibility of a solution.
@@ -1439,10 +1700,28 @@ impl<T: Config> Pallet<T> {
 		let RoundSnapshot { voters: snapshot_voters, targets: snapshot_targets } =
 			Self::snapshot().ok_or(FeasibilityError::SnapshotUnavailable)?;
 
+		let supports = Self::supports_of(solution, &snapshot_voters, &snapshot_targets)?;
+
+		// Finally, check that the claimed score was indeed correct.
+		let known_score = supports.evaluate();
+		ensure!(known_score == score, FeasibilityError::InvalidScore);
+
+		Ok(ReadySolution { supports, compute, score })
+	}
+
+	/// Computes the supports of `solution`, based on the given voters and targets of the snapshot.
+	///
+	/// Ensures that all the indices of the solution are valid, and that each voter only backs
+	/// targets that they have voted for.
+	pub(crate) fn supports_of(
+		solution: SolutionOf<T>,
+		snapshot_voters: &Vec<VoterOf<T>>,
+		snapshot_targets: &Vec<T::AccountId>,
+	) -> Result<Supports<T::AccountId>, FeasibilityError> {
 		// ----- Start building. First, we need some closures.
-		let cache = helpers::generate_voter_cache::<T>(&snapshot_voters);
-		let voter_at = helpers::voter_at_fn::<T>(&snapshot_voters);
-		let target_at = helpers::target_at_fn::<T>(&snapshot_targets);
+		let cache = helpers::generate_voter_cache::<T>(snapshot_voters);
+		let voter_at = helpers::voter_at_fn::<T>(snapshot_voters);
+		let target_at = helpers::target_at_fn::<T>(snapshot_targets);
 		let voter_index = helpers::voter_index_fn_usize::<T>(&cache);
 
 		// Then convert solution -> assignment. This will fail if any of the indices are gibberish,
@@ -1477,18 +1756,12 @@ impl<T: Config> Pallet<T> {
 			.collect::<Result<(), FeasibilityError>>()?;
 
 		// ----- Start building support. First, we need one more closure.
-		let stake_of = helpers::stake_of_fn::<T>(&snapshot_voters, &cache);
+		let stake_of = helpers::stake_of_fn::<T>(snapshot_voters, &cache);
 
 		// This might fail if the normalization fails. Very unlikely. See `integrity_test`.
 		let staked_assignments = assignment_ratio_to_staked_normalized(assignments, stake_of)
 			.map_err::<FeasibilityError, _>(Into::into)?;
-		let supports = sp_npos_elections::to_supports(&staked_assignments);
-
-		// Finally, check that the claimed score was indeed correct.
-		let known_score = supports.evaluate();
-		ensure!(known_score == score, FeasibilityError::InvalidScore);
-
-		Ok(ReadySolution { supports, compute, score })
+		Ok(sp_npos_elections::to_supports(&staked_assignments))
 	}
 
 	/// Perform the tasks to be done after a new `elect` has been triggered:
@@ -1496,6 +1769,7 @@ impl<T: Config> Pallet<T> {
 	/// 1. Increment round.
 	/// 2. Change phase to [`Phase::Off`]
 	/// 3. Clear all snapshot data.
+	/// 4. Clear any pages of the queued solution that have not been handed out.
 	fn rotate_round() {
 		// Inc round.
 		<Round<T>>::mutate(|r| *r += 1);
@@ -1505,6 +1779,8 @@ impl<T: Config> Pallet<T> {
 
 		// Kill snapshots.
 		Self::kill_snapshot();
+
+		let _ = <QueuedSolutionPages<T>>::remove_all(None);
 	}
 
 	fn do_elect() -> Result<Supports<T::AccountId>, ElectionError<T>> {
@@ -1561,6 +1837,11 @@ impl<T: Config> ElectionProvider for Pallet<T> {
 	fn elect() -> Result<Supports<T::AccountId>, Self::Error> {
 		match Self::do_elect() {
 			Ok(supports) => {
+				// a paged solution is handed out all at once, merged across its pages.
+				let pages = <QueuedSolutionPages<T>>::iter_values().collect::<Vec<_>>();
+				let supports =
+					if pages.is_empty() { supports } else { helpers::merge_supports(pages.iter()) };
+
 				// All went okay, record the weight, put sign to be Off, clean snapshot, etc.
 				Self::weigh_supports(&supports);
 				Self::rotate_round();
@@ -1573,6 +1854,41 @@ impl<T: Config> ElectionProvider for Pallet<T> {
 			},
 		}
 	}
+
+	fn pages() -> PageIndex {
+		T::Pages::get().max(1)
+	}
+
+	fn elect_paged(page: PageIndex) -> Result<Supports<T::AccountId>, Self::Error> {
+		let outcome = if page.is_zero() {
+			Self::do_elect().map(|supports| {
+				// a solution that was not submitted in pages is handed out in the first page.
+				if !<QueuedSolutionPages<T>>::contains_key(0) {
+					<QueuedSolutionPages<T>>::insert(0, supports);
+				}
+				// no more solutions are accepted while the remaining pages are handed out.
+				<CurrentPhase<T>>::put(Phase::Off);
+			})
+		} else {
+			Ok(())
+		};
+
+		match outcome {
+			Ok(_) => {
+				let supports = <QueuedSolutionPages<T>>::take(page).unwrap_or_default();
+				Self::weigh_supports(&supports);
+				if page.saturating_add(1) >= Self::pages() {
+					Self::rotate_round();
+				}
+				Ok(supports)
+			},
+			Err(why) => {
+				log!(error, "Entering emergency mode: {:?}", why);
+				<CurrentPhase<T>>::put(Phase::Emergency);
+				Err(why)
+			},
+		}
+	}
 }
 
 /// convert a DispatchError to a custom InvalidTransaction with the inner code being the error
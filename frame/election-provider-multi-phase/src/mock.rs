@@ -27,7 +27,7 @@ use frame_support::{
 	weights::Weight,
 	BoundedVec,
 };
-use multi_phase::unsigned::{IndexAssignmentOf, VoterOf};
+use multi_phase::unsigned::{IndexAssignmentOf, MinerAlgorithm, VoterOf};
 use parking_lot::RwLock;
 use sp_core::{
 	offchain::{
@@ -179,6 +179,58 @@ pub fn raw_solution() -> RawSolution<SolutionOf<Runtime>> {
 	RawSolution { solution, score, round }
 }
 
+/// Spit out a verifiable paged solution, namely the claimed score and the raw solution of each
+/// page.
+///
+/// The entire solution is mined over the voters of all pages, and then split into pages.
+pub fn raw_paged_solution() -> (ElectionScore, Vec<RawSolution<SolutionOf<Runtime>>>) {
+	let RoundSnapshot { voters: first_page, targets } = MultiPhase::snapshot().unwrap();
+	let desired_targets = MultiPhase::desired_targets().unwrap();
+	let pages = (0..Pages::get())
+		.map(|page| {
+			if page == 0 {
+				first_page.clone()
+			} else {
+				PagedVoterSnapshot::<Runtime>::get(page).unwrap()
+			}
+		})
+		.collect::<Vec<_>>();
+	let all_voters = pages.iter().flatten().cloned().collect::<Vec<_>>();
+
+	let ElectionResult::<_, SolutionAccuracyOf<Runtime>> { winners: _, assignments } =
+		seq_phragmen(desired_targets as usize, targets.clone(), all_voters.clone(), None).unwrap();
+
+	let cache = helpers::generate_voter_cache::<Runtime>(&all_voters);
+	let stake_of = helpers::stake_of_fn::<Runtime>(&all_voters, &cache);
+	let score = {
+		let staked = assignment_ratio_to_staked_normalized(assignments.clone(), &stake_of).unwrap();
+		to_supports(&staked).evaluate()
+	};
+
+	let target_index = helpers::target_index_fn_linear::<Runtime>(&targets);
+	let round = MultiPhase::round();
+	let solutions = pages
+		.iter()
+		.map(|voters| {
+			let voter_index = helpers::voter_index_fn_linear::<Runtime>(voters);
+			let page_assignments = assignments
+				.iter()
+				.filter(|a| voters.iter().any(|(v, _, _)| *v == a.who))
+				.cloned()
+				.collect::<Vec<_>>();
+			let solution = <SolutionOf<Runtime>>::from_assignment(
+				&page_assignments,
+				&voter_index,
+				&target_index,
+			)
+			.unwrap();
+			RawSolution { solution, score, round }
+		})
+		.collect::<Vec<_>>();
+
+	(score, solutions)
+}
+
 pub fn witness() -> SolutionOrSnapshotSize {
 	MultiPhase::snapshot()
 		.map(|snap| SolutionOrSnapshotSize {
@@ -264,6 +316,7 @@ parameter_types! {
 	pub static MinerMaxLength: u32 = 256;
 	pub static MockWeightInfo: bool = false;
 	pub static VoterSnapshotPerBlock: VoterIndex = u32::max_value();
+	pub static Pages: PageIndex = 1;
 
 	pub static EpochLength: u64 = 30;
 	pub static OnChianFallback: bool = true;
@@ -368,10 +421,25 @@ impl multi_phase::weights::WeightInfo for DualMockWeightInfo {
 			<() as multi_phase::weights::WeightInfo>::feasibility_check(v, t, a, d)
 		}
 	}
+	fn register_paged() -> Weight {
+		if MockWeightInfo::get() {
+			Zero::zero()
+		} else {
+			<() as multi_phase::weights::WeightInfo>::register_paged()
+		}
+	}
+	fn submit_page(p: u32) -> Weight {
+		if MockWeightInfo::get() {
+			Zero::zero()
+		} else {
+			<() as multi_phase::weights::WeightInfo>::submit_page(p)
+		}
+	}
 }
 
 parameter_types! {
 	pub static Balancing: Option<(usize, ExtendedBalance)> = Some((0, 0));
+	pub static MinerAlgorithms: Vec<MinerAlgorithm> = vec![];
 }
 
 pub struct TestBenchmarkingConfig;
@@ -414,7 +482,9 @@ impl crate::Config for Runtime {
 	type ForceOrigin = frame_system::EnsureRoot<AccountId>;
 	type Solution = TestNposSolution;
 	type VoterSnapshotPerBlock = VoterSnapshotPerBlock;
+	type Pages = Pages;
 	type Solver = SequentialPhragmen<AccountId, SolutionAccuracyOf<Runtime>, Balancing>;
+	type MinerAlgorithms = MinerAlgorithms;
 }
 
 impl<LocalCall> frame_system::offchain::SendTransactionTypes<LocalCall> for Runtime
@@ -458,6 +528,20 @@ impl ElectionDataProvider for StakingMock {
 		Ok(voters)
 	}
 
+	fn voters_paged(
+		maybe_max_len: Option<usize>,
+		page: PageIndex,
+	) -> data_provider::Result<Vec<VoterOf<Runtime>>> {
+		let voters = Voters::get();
+		let page_len = maybe_max_len.unwrap_or(voters.len()).max(1);
+
+		Ok(voters
+			.chunks(page_len)
+			.nth(page as usize)
+			.map(|c| c.to_vec())
+			.unwrap_or_default())
+	}
+
 	fn desired_targets() -> data_provider::Result<u32> {
 		Ok(DesiredTargets::get())
 	}
@@ -560,6 +644,15 @@ impl ExtBuilder {
 		<SignedMaxWeight>::set(weight);
 		self
 	}
+	pub fn miner_algorithms(self, algorithms: Vec<MinerAlgorithm>) -> Self {
+		<MinerAlgorithms>::set(algorithms);
+		self
+	}
+	pub fn pages(self, pages: PageIndex, voters_per_page: VoterIndex) -> Self {
+		<Pages>::set(pages);
+		<VoterSnapshotPerBlock>::set(voters_per_page);
+		self
+	}
 	pub fn build(self) -> sp_io::TestExternalities {
 		sp_tracing::try_init_simple();
 		let mut storage =
@@ -0,0 +1,677 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The paged signed submissions, and the incremental verification of their pages.
+//!
+//! See the "Paged Elections" section of the crate-level documentation for an overview.
+
+use crate::{
+	helpers, BalanceOf, Config, ElectionCompute, Error, Event, FeasibilityError,
+	PagedSignedSubmissions, PagedSubmissionSupports, PagedVoterSnapshot, Pallet,
+	QueuedPagedSubmission, QueuedSolutionPages, RawSolution, ReadySolution, RoundSnapshot,
+	SolutionOf, SolutionOrSnapshotSize, WeightInfo,
+};
+use codec::{Decode, Encode};
+use frame_election_provider_support::PageIndex;
+use frame_support::{
+	dispatch::{DispatchError, DispatchResult},
+	ensure,
+	traits::{defensive_prelude::*, Currency, Get, OnUnbalanced, ReservableCurrency},
+	weights::Weight,
+};
+use sp_npos_elections::{is_score_better, ElectionScore, EvaluateSupport, NposSolution, Supports};
+use sp_runtime::{
+	traits::{Saturating, Zero},
+	Perbill, RuntimeDebug,
+};
+
+/// A signed solution that is submitted in pages, and verified page by page.
+#[derive(PartialEq, Eq, Clone, Encode, Decode, RuntimeDebug, scale_info::TypeInfo)]
+pub struct PagedSignedSubmission<AccountId, Balance> {
+	/// The deposit reserved for this submission so far.
+	pub deposit: Balance,
+	/// The score that the entire solution is claimed to have.
+	pub claimed_score: ElectionScore,
+	/// The number of pages that have been verified so far. This is also the next expected page.
+	pub verified_pages: PageIndex,
+	/// The total backing of each target across all the verified pages, sorted by target.
+	///
+	/// The voters are not kept track of. This never holds more than the desired number of
+	/// targets, and is all that is needed to evaluate the score of the entire solution.
+	pub backings: Supports<AccountId>,
+}
+
+pub type PagedSignedSubmissionOf<T> =
+	PagedSignedSubmission<<T as frame_system::Config>::AccountId, BalanceOf<T>>;
+
+impl<T: Config> Pallet<T> {
+	/// The size of the given page of the snapshot, if it exists.
+	///
+	/// The `voters` are those of the given page only, whilst the `targets` are all of them.
+	pub fn snapshot_page_size(page: PageIndex) -> Option<SolutionOrSnapshotSize> {
+		let SolutionOrSnapshotSize { voters, targets } = Self::snapshot_metadata()?;
+		if page.is_zero() {
+			Some(SolutionOrSnapshotSize { voters, targets })
+		} else {
+			let voters = <PagedVoterSnapshot<T>>::decode_len(page)? as u32;
+			Some(SolutionOrSnapshotSize { voters, targets })
+		}
+	}
+
+	/// Checks the feasibility of a single page of a paged solution, returning its supports.
+	///
+	/// Unlike [`Self::feasibility_check`], the claimed score and the number of winners can only be
+	/// checked once the supports of all pages are known. The score of `raw_solution` is ignored.
+	pub fn feasibility_check_page(
+		page: PageIndex,
+		raw_solution: RawSolution<SolutionOf<T>>,
+	) -> Result<Supports<T::AccountId>, FeasibilityError> {
+		let RawSolution { solution, round, .. } = raw_solution;
+		ensure!(Self::round() == round, FeasibilityError::InvalidRound);
+
+		// a single page can never back more winners than the entire solution.
+		let desired_targets =
+			Self::desired_targets().ok_or(FeasibilityError::SnapshotUnavailable)?;
+		ensure!(
+			solution.unique_targets().len() as u32 <= desired_targets,
+			FeasibilityError::WrongWinnerCount
+		);
+
+		let RoundSnapshot { voters: first_page_voters, targets } =
+			Self::snapshot().ok_or(FeasibilityError::SnapshotUnavailable)?;
+		let voters = if page.is_zero() {
+			first_page_voters
+		} else {
+			<PagedVoterSnapshot<T>>::get(page).ok_or(FeasibilityError::SnapshotUnavailable)?
+		};
+
+		Self::supports_of(solution, &voters, &targets)
+	}
+
+	/// Whether a solution with the given `score` can be queued, namely whether it passes the
+	/// [`crate::MinimumUntrustedScore`] and is sufficiently better than the queued solution.
+	fn is_score_queueable(score: ElectionScore) -> bool {
+		Self::minimum_untrusted_score()
+			.map_or(true, |min_score| is_score_better(score, min_score, Perbill::zero())) &&
+			Self::queued_solution().map_or(true, |queued| {
+				is_score_better(score, queued.score, T::SolutionImprovementThreshold::get())
+			})
+	}
+
+	/// Register a new paged submission of `who`, claiming the given score.
+	pub(crate) fn do_register_paged(
+		who: T::AccountId,
+		claimed_score: ElectionScore,
+	) -> DispatchResult {
+		ensure!(
+			!<PagedSignedSubmissions<T>>::contains_key(&who),
+			Error::<T>::PagedAlreadyRegistered
+		);
+		ensure!(
+			<PagedSignedSubmissions<T>>::count() < T::SignedMaxSubmissions::get(),
+			Error::<T>::PagedQueueFull
+		);
+		ensure!(Self::is_score_queueable(claimed_score), Error::<T>::PreDispatchWeakSubmission);
+
+		let deposit = T::SignedDepositBase::get();
+		T::Currency::reserve(&who, deposit).map_err(|_| Error::<T>::SignedCannotPayDeposit)?;
+
+		<PagedSignedSubmissions<T>>::insert(
+			&who,
+			PagedSignedSubmission {
+				deposit,
+				claimed_score,
+				verified_pages: 0,
+				backings: Default::default(),
+			},
+		);
+		Ok(())
+	}
+
+	/// Verify and store the given page of the paged submission of `who`.
+	///
+	/// An invalid page slashes the entire deposit of the submission. So does a page that backs
+	/// targets such that the solution can no longer have the desired number of winners. Once the
+	/// last page is verified, the submission is finalized.
+	///
+	/// Returns the actual weight of [`Call::submit_page`], including the feasibility check of the
+	/// page.
+	pub(crate) fn do_submit_page(
+		who: T::AccountId,
+		page: PageIndex,
+		raw_solution: RawSolution<SolutionOf<T>>,
+	) -> Result<Weight, DispatchError> {
+		let mut submission =
+			<PagedSignedSubmissions<T>>::get(&who).ok_or(Error::<T>::PagedNotRegistered)?;
+		ensure!(
+			page == submission.verified_pages && page < T::Pages::get().max(1),
+			Error::<T>::PagedWrongPage
+		);
+
+		let size = Self::snapshot_page_size(page).ok_or(Error::<T>::MissingSnapshotMetadata)?;
+		let feasibility_weight = Self::feasibility_weight_of(&raw_solution, size);
+		ensure!(feasibility_weight < T::SignedMaxWeight::get(), Error::<T>::SignedTooMuchWeight);
+
+		let page_deposit = Self::variable_deposit_for(&raw_solution, size);
+		T::Currency::reserve(&who, page_deposit).map_err(|_| Error::<T>::SignedCannotPayDeposit)?;
+		submission.deposit = submission.deposit.saturating_add(page_deposit);
+
+		let mut finalized_pages = 0;
+		let outcome = Self::feasibility_check_page(page, raw_solution).and_then(|supports| {
+			helpers::add_backings(&mut submission.backings, &supports);
+			let desired_targets =
+				Self::desired_targets().ok_or(FeasibilityError::SnapshotUnavailable)?;
+			ensure!(
+				submission.backings.len() as u32 <= desired_targets,
+				FeasibilityError::WrongWinnerCount
+			);
+			Ok(supports)
+		});
+
+		match outcome {
+			Ok(supports) => {
+				<PagedSubmissionSupports<T>>::insert(&who, page, supports);
+				submission.verified_pages.saturating_inc();
+				Self::deposit_event(Event::PageVerified { account: who.clone(), page });
+
+				if submission.verified_pages >= T::Pages::get().max(1) {
+					finalized_pages = submission.verified_pages;
+					Self::finalize_paged_submission(&who, submission);
+				} else {
+					<PagedSignedSubmissions<T>>::insert(&who, submission);
+				}
+			},
+			Err(why) => {
+				log!(debug, "page {} of paged submission is invalid: {:?}", page, why);
+				Self::remove_paged_submission(&who);
+				Self::finalize_signed_phase_reject_solution(&who, submission.deposit);
+			},
+		}
+
+		Ok(T::WeightInfo::submit_page(finalized_pages).saturating_add(feasibility_weight))
+	}
+
+	/// Finalize a paged submission of which all pages have been verified.
+	///
+	/// The backings accumulated over all pages are checked against the claimed score and the
+	/// desired number of targets. If they fail to match, the deposit is slashed. Otherwise, the
+	/// solution is queued if it is still good enough, or discarded with the deposit returned if a
+	/// better solution has been queued in the meantime.
+	///
+	/// The supports of a queued solution are never merged: its verified pages are moved as they
+	/// are to [`QueuedSolutionPages`], and only its score is kept in the [`ReadySolution`].
+	///
+	/// The deposit of a queued solution stays reserved, and its submitter is only rewarded by
+	/// [`Self::finalize_paged_submissions`] if the solution is still queued by then.
+	fn finalize_paged_submission(who: &T::AccountId, submission: PagedSignedSubmissionOf<T>) {
+		let score = submission.backings.evaluate();
+		// defensive only: a page could not have been verified without a snapshot.
+		let desired_targets = Self::desired_targets().defensive_unwrap_or_default();
+
+		if submission.backings.len() as u32 != desired_targets || score != submission.claimed_score
+		{
+			log!(debug, "paged submission has a wrong winner count or score");
+			Self::remove_paged_submission(who);
+			Self::finalize_signed_phase_reject_solution(who, submission.deposit);
+			return
+		}
+
+		if !Self::is_score_queueable(score) {
+			log!(debug, "paged submission is not better than the queued solution");
+			Self::remove_paged_submission(who);
+			let _remaining = T::Currency::unreserve(who, submission.deposit);
+			debug_assert!(_remaining.is_zero());
+			return
+		}
+
+		let ejected_a_solution = Self::queued_solution().is_some();
+		// the submitter of an ejected paged solution is no longer in the race for the reward.
+		if let Some((ejected, ejected_submission)) = <QueuedPagedSubmission<T>>::take() {
+			let _remaining = T::Currency::unreserve(&ejected, ejected_submission.deposit);
+			debug_assert!(_remaining.is_zero());
+		}
+
+		Self::queue_solution(ReadySolution {
+			supports: Default::default(),
+			score,
+			compute: ElectionCompute::Signed,
+		});
+		for (page, supports) in <PagedSubmissionSupports<T>>::drain_prefix(who) {
+			<QueuedSolutionPages<T>>::insert(page, supports);
+		}
+		<PagedSignedSubmissions<T>>::remove(who);
+		<QueuedPagedSubmission<T>>::put((who.clone(), submission));
+
+		log!(info, "queued paged signed solution with score {:?}", score);
+		Self::deposit_event(Event::SolutionStored {
+			election_compute: ElectionCompute::Signed,
+			prev_ejected: ejected_a_solution,
+		});
+	}
+
+	/// Remove all the storage items of the paged submission of `who`.
+	fn remove_paged_submission(who: &T::AccountId) {
+		<PagedSignedSubmissions<T>>::remove(who);
+		let _ = <PagedSubmissionSupports<T>>::remove_prefix(who, None);
+	}
+
+	/// Finish the paged submissions at the end of the signed phase.
+	///
+	/// The submitter of the queued paged solution is rewarded, unless a better solution has been
+	/// queued in the meantime, in which case only their deposit is returned. The deposits of all
+	/// the incomplete paged submissions are slashed.
+	///
+	/// This is a *self-weighing* function, it automatically registers its weight internally when
+	/// being called.
+	pub(crate) fn finalize_paged_submissions() {
+		let mut weight = T::DbWeight::get().reads(2);
+		if let Some((who, submission)) = <QueuedPagedSubmission<T>>::take() {
+			weight = weight.saturating_add(T::DbWeight::get().reads_writes(1, 1));
+			let still_queued = Self::queued_solution()
+				.map_or(false, |queued| queued.score == submission.claimed_score);
+
+			if still_queued {
+				let reward = T::SignedRewardBase::get();
+				Self::deposit_event(Event::Rewarded { account: who.clone(), value: reward });
+
+				let _remaining = T::Currency::unreserve(&who, submission.deposit);
+				debug_assert!(_remaining.is_zero());
+				let positive_imbalance = T::Currency::deposit_creating(&who, reward);
+				T::RewardHandler::on_unbalanced(positive_imbalance);
+
+				weight =
+					weight.saturating_add(T::WeightInfo::finalize_signed_phase_accept_solution());
+			} else {
+				let _remaining = T::Currency::unreserve(&who, submission.deposit);
+				debug_assert!(_remaining.is_zero());
+				weight = weight.saturating_add(T::DbWeight::get().writes(1));
+			}
+		}
+
+		for (who, submission) in <PagedSignedSubmissions<T>>::drain() {
+			let _ = <PagedSubmissionSupports<T>>::remove_prefix(&who, None);
+			Self::finalize_signed_phase_reject_solution(&who, submission.deposit);
+			weight = weight
+				.saturating_add(T::WeightInfo::finalize_signed_phase_reject_solution())
+				.saturating_add(
+					T::DbWeight::get()
+						.reads_writes(1, submission.verified_pages.saturating_add(2).into()),
+				);
+		}
+		Self::register_weight(weight);
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::{
+		mock::{
+			balances, multi_phase_events, raw_paged_solution, raw_solution, roll_to, ExtBuilder,
+			MultiPhase, Origin, Runtime,
+		},
+		Phase, QueuedPagedSubmission, QueuedSolution,
+	};
+	use frame_election_provider_support::ElectionProvider;
+	use frame_support::{assert_noop, assert_ok};
+
+	fn submit_all_pages(who: u64, pages: Vec<RawSolution<SolutionOf<Runtime>>>) {
+		for (page, raw_solution) in pages.into_iter().enumerate() {
+			assert_ok!(MultiPhase::submit_page(
+				Origin::signed(who),
+				page as PageIndex,
+				Box::new(raw_solution)
+			));
+		}
+	}
+
+	#[test]
+	fn paged_snapshot_is_collected_over_multiple_blocks() {
+		ExtBuilder::default().pages(3, 3).build_and_execute(|| {
+			roll_to(12);
+			assert_eq!(MultiPhase::current_phase(), Phase::Off);
+			assert!(MultiPhase::snapshot().is_none());
+
+			// the first page is collected `Pages - 1` blocks before the signed phase.
+			roll_to(13);
+			assert_eq!(MultiPhase::current_phase(), Phase::Snapshot(1));
+			assert_eq!(MultiPhase::snapshot().unwrap().voters.len(), 3);
+			assert_eq!(MultiPhase::snapshot().unwrap().targets.len(), 4);
+			assert_eq!(MultiPhase::snapshot_page_size(0).unwrap().voters, 3);
+
+			roll_to(14);
+			assert_eq!(MultiPhase::current_phase(), Phase::Snapshot(2));
+			assert_eq!(PagedVoterSnapshot::<Runtime>::get(1).unwrap().len(), 3);
+
+			// the signed phase starts once the last page is collected.
+			roll_to(15);
+			assert!(MultiPhase::current_phase().is_signed());
+			assert_eq!(PagedVoterSnapshot::<Runtime>::get(2).unwrap().len(), 2);
+			assert_eq!(MultiPhase::snapshot_page_size(2).unwrap().voters, 2);
+
+			// everything is cleared after the election.
+			assert_ok!(MultiPhase::elect());
+			assert!(MultiPhase::snapshot().is_none());
+			assert_eq!(PagedVoterSnapshot::<Runtime>::iter().count(), 0);
+		})
+	}
+
+	#[test]
+	fn paged_solution_is_verified_and_handed_out_in_pages() {
+		ExtBuilder::default().pages(3, 3).build_and_execute(|| {
+			roll_to(15);
+			assert!(MultiPhase::current_phase().is_signed());
+
+			let (score, pages) = raw_paged_solution();
+			assert_ok!(MultiPhase::register_paged(Origin::signed(99), score));
+			assert_eq!(balances(&99), (95, 5));
+
+			submit_all_pages(99, pages);
+
+			// the solution is queued right away, but the submitter is not rewarded yet. Its
+			// supports are only kept in pages.
+			assert_eq!(balances(&99), (95, 5));
+			let queued = MultiPhase::queued_solution().unwrap();
+			assert_eq!(queued.score, score);
+			assert_eq!(queued.compute, ElectionCompute::Signed);
+			assert!(queued.supports.is_empty());
+			assert_eq!(QueuedSolutionPages::<Runtime>::iter().count(), 3);
+			assert!(PagedSignedSubmissions::<Runtime>::get(&99).is_none());
+			assert_eq!(PagedSubmissionSupports::<Runtime>::iter_prefix(&99).count(), 0);
+			assert_eq!(
+				multi_phase_events(),
+				vec![
+					Event::SignedPhaseStarted { round: 1 },
+					Event::PageVerified { account: 99, page: 0 },
+					Event::PageVerified { account: 99, page: 1 },
+					Event::PageVerified { account: 99, page: 2 },
+					Event::SolutionStored {
+						election_compute: ElectionCompute::Signed,
+						prev_ejected: false
+					},
+				]
+			);
+
+			// it is still queued once the signed phase is over, and the submitter is rewarded.
+			roll_to(25);
+			assert_eq!(MultiPhase::queued_solution().unwrap().score, score);
+			assert_eq!(balances(&99), (100 + 7, 0));
+			assert!(QueuedPagedSubmission::<Runtime>::get().is_none());
+			assert!(multi_phase_events().contains(&Event::Rewarded { account: 99, value: 7 }));
+
+			// the pages are handed out as they were verified, and add up to the queued score.
+			let queued_pages = (0..MultiPhase::pages())
+				.map(|page| QueuedSolutionPages::<Runtime>::get(page).unwrap())
+				.collect::<Vec<_>>();
+			let elected = (0..MultiPhase::pages())
+				.map(|page| MultiPhase::elect_paged(page).unwrap())
+				.collect::<Vec<_>>();
+			assert_eq!(elected, queued_pages);
+			assert_eq!(helpers::merge_supports(elected.iter()).evaluate(), score);
+			assert_eq!(MultiPhase::round(), 2);
+			assert_eq!(MultiPhase::current_phase(), Phase::Off);
+			assert_eq!(QueuedSolutionPages::<Runtime>::iter().count(), 0);
+		})
+	}
+
+	#[test]
+	fn paged_solution_can_be_elected_at_once() {
+		ExtBuilder::default().pages(3, 3).build_and_execute(|| {
+			roll_to(15);
+			let (score, pages) = raw_paged_solution();
+			assert_ok!(MultiPhase::register_paged(Origin::signed(99), score));
+			submit_all_pages(99, pages);
+			let merged = helpers::merge_supports(
+				QueuedSolutionPages::<Runtime>::iter_values().collect::<Vec<_>>().iter(),
+			);
+
+			assert_eq!(MultiPhase::elect().unwrap(), merged);
+			assert_eq!(merged.evaluate(), score);
+			assert_eq!(MultiPhase::round(), 2);
+			assert_eq!(QueuedSolutionPages::<Runtime>::iter().count(), 0);
+		})
+	}
+
+	#[test]
+	fn single_page_solution_is_handed_out_in_first_page() {
+		ExtBuilder::default().pages(3, 3).build_and_execute(|| {
+			roll_to(15);
+			let solution = raw_solution();
+			assert_ok!(MultiPhase::submit(Origin::signed(99), Box::new(solution), 0));
+			roll_to(25);
+			let queued = MultiPhase::queued_solution().unwrap();
+
+			assert_eq!(MultiPhase::elect_paged(0).unwrap(), queued.supports);
+			assert_eq!(MultiPhase::current_phase(), Phase::Off);
+			assert_eq!(MultiPhase::round(), 1);
+			assert!(MultiPhase::elect_paged(1).unwrap().is_empty());
+			assert!(MultiPhase::elect_paged(2).unwrap().is_empty());
+			assert_eq!(MultiPhase::round(), 2);
+		})
+	}
+
+	#[test]
+	fn paged_submission_errors() {
+		ExtBuilder::default().pages(3, 3).build_and_execute(|| {
+			roll_to(14);
+			let score = [1, 1, 1];
+			assert_noop!(
+				MultiPhase::register_paged(Origin::signed(99), score),
+				Error::<Runtime>::PreDispatchEarlySubmission,
+			);
+
+			roll_to(15);
+			let (score, pages) = raw_paged_solution();
+			assert_noop!(
+				MultiPhase::submit_page(Origin::signed(99), 0, Box::new(pages[0].clone())),
+				Error::<Runtime>::PagedNotRegistered,
+			);
+
+			assert_ok!(MultiPhase::register_paged(Origin::signed(99), score));
+			assert_noop!(
+				MultiPhase::register_paged(Origin::signed(99), score),
+				Error::<Runtime>::PagedAlreadyRegistered,
+			);
+
+			// pages must come in order.
+			assert_noop!(
+				MultiPhase::submit_page(Origin::signed(99), 1, Box::new(pages[1].clone())),
+				Error::<Runtime>::PagedWrongPage,
+			);
+			assert_ok!(MultiPhase::submit_page(Origin::signed(99), 0, Box::new(pages[0].clone())));
+			assert_noop!(
+				MultiPhase::submit_page(Origin::signed(99), 0, Box::new(pages[0].clone())),
+				Error::<Runtime>::PagedWrongPage,
+			);
+
+			// a weaker solution cannot be registered once a solution is queued.
+			assert_ok!(MultiPhase::register_paged(Origin::signed(999), score));
+			submit_all_pages(999, pages);
+			assert_noop!(
+				MultiPhase::register_paged(Origin::signed(9999), score),
+				Error::<Runtime>::PreDispatchWeakSubmission,
+			);
+		})
+	}
+
+	#[test]
+	fn invalid_page_is_slashed() {
+		ExtBuilder::default().pages(3, 3).build_and_execute(|| {
+			roll_to(15);
+			let (score, mut pages) = raw_paged_solution();
+			assert_ok!(MultiPhase::register_paged(Origin::signed(99), score));
+			assert_ok!(MultiPhase::submit_page(Origin::signed(99), 0, Box::new(pages[0].clone())));
+
+			// page 1 is for the wrong round.
+			pages[1].round += 1;
+			assert_ok!(MultiPhase::submit_page(Origin::signed(99), 1, Box::new(pages[1].clone())));
+
+			assert_eq!(balances(&99), (95, 0));
+			assert!(PagedSignedSubmissions::<Runtime>::get(&99).is_none());
+			assert_eq!(PagedSubmissionSupports::<Runtime>::iter_prefix(&99).count(), 0);
+			assert!(MultiPhase::queued_solution().is_none());
+			assert_eq!(
+				multi_phase_events().last().unwrap(),
+				&Event::Slashed { account: 99, value: 5 }
+			);
+		})
+	}
+
+	#[test]
+	fn wrong_claimed_score_is_slashed() {
+		ExtBuilder::default().pages(3, 3).build_and_execute(|| {
+			roll_to(15);
+			let (mut score, pages) = raw_paged_solution();
+			score[0] += 1;
+			assert_ok!(MultiPhase::register_paged(Origin::signed(99), score));
+
+			// all pages are fine on their own..
+			submit_all_pages(99, pages);
+
+			// ..but the claimed score is wrong.
+			assert_eq!(balances(&99), (95, 0));
+			assert!(MultiPhase::queued_solution().is_none());
+			assert_eq!(
+				multi_phase_events().last().unwrap(),
+				&Event::Slashed { account: 99, value: 5 }
+			);
+		})
+	}
+
+	#[test]
+	fn paged_submissions_are_bounded() {
+		ExtBuilder::default()
+			.pages(3, 3)
+			.signed_max_submission(2)
+			.build_and_execute(|| {
+				roll_to(15);
+				let (score, _) = raw_paged_solution();
+				assert_ok!(MultiPhase::register_paged(Origin::signed(99), score));
+				assert_ok!(MultiPhase::register_paged(Origin::signed(999), score));
+
+				assert_noop!(
+					MultiPhase::register_paged(Origin::signed(9999), score),
+					Error::<Runtime>::PagedQueueFull,
+				);
+			})
+	}
+
+	#[test]
+	fn only_the_final_paged_winner_is_rewarded() {
+		ExtBuilder::default().pages(3, 3).build_and_execute(|| {
+			roll_to(15);
+			let (score, pages) = raw_paged_solution();
+
+			// a weaker paged solution, which leaves the last page empty.
+			let mut weak_pages = pages.clone();
+			weak_pages[2].solution = Default::default();
+			let weak_supports = weak_pages
+				.iter()
+				.enumerate()
+				.map(|(page, raw_solution)| {
+					MultiPhase::feasibility_check_page(page as PageIndex, raw_solution.clone())
+						.unwrap()
+				})
+				.collect::<Vec<_>>();
+			let weak_score = helpers::merge_supports(weak_supports.iter()).evaluate();
+
+			assert_ok!(MultiPhase::register_paged(Origin::signed(999), weak_score));
+			submit_all_pages(999, weak_pages);
+			assert_eq!(MultiPhase::queued_solution().unwrap().score, weak_score);
+			assert_eq!(balances(&999), (95, 5));
+
+			// when the weaker solution is ejected, its deposit is returned without a reward.
+			assert_ok!(MultiPhase::register_paged(Origin::signed(99), score));
+			submit_all_pages(99, pages);
+			assert_eq!(MultiPhase::queued_solution().unwrap().score, score);
+			assert_eq!(balances(&999), (100, 0));
+			assert_eq!(balances(&99), (95, 5));
+
+			// only the final winner is rewarded at the end of the signed phase.
+			roll_to(25);
+			assert_eq!(balances(&99), (100 + 7, 0));
+			assert_eq!(balances(&999), (100, 0));
+			assert_eq!(
+				multi_phase_events()
+					.into_iter()
+					.filter(|e| matches!(e, Event::Rewarded { .. }))
+					.collect::<Vec<_>>(),
+				vec![Event::Rewarded { account: 99, value: 7 }]
+			);
+		})
+	}
+
+	#[test]
+	fn incomplete_paged_submissions_are_slashed() {
+		ExtBuilder::default().pages(3, 3).build_and_execute(|| {
+			roll_to(15);
+			let (score, pages) = raw_paged_solution();
+			assert_ok!(MultiPhase::register_paged(Origin::signed(99), score));
+			assert_ok!(MultiPhase::submit_page(Origin::signed(99), 0, Box::new(pages[0].clone())));
+			assert_eq!(balances(&99), (95, 5));
+
+			// the signed phase ends.
+			roll_to(25);
+			assert!(MultiPhase::current_phase().is_unsigned());
+
+			assert_eq!(balances(&99), (95, 0));
+			assert!(multi_phase_events().contains(&Event::Slashed { account: 99, value: 5 }));
+			assert_eq!(PagedSignedSubmissions::<Runtime>::iter().count(), 0);
+			assert_eq!(PagedSubmissionSupports::<Runtime>::iter().count(), 0);
+		})
+	}
+
+	#[test]
+	fn queued_paged_solution_is_not_replaced_by_weaker_signed_solution() {
+		ExtBuilder::default().pages(3, 3).build_and_execute(|| {
+			roll_to(15);
+
+			// a single page solution only covers the first page of voters.
+			let single_page = raw_solution();
+			assert_ok!(MultiPhase::submit(Origin::signed(999), Box::new(single_page), 0));
+			assert_eq!(balances(&999), (95, 5));
+
+			let (score, pages) = raw_paged_solution();
+			assert_ok!(MultiPhase::register_paged(Origin::signed(99), score));
+			submit_all_pages(99, pages);
+
+			roll_to(25);
+
+			// the paged solution is kept and rewarded, and the single page one is discarded.
+			assert_eq!(MultiPhase::queued_solution().unwrap().score, score);
+			assert_eq!(QueuedSolutionPages::<Runtime>::iter().count(), 3);
+			assert_eq!(balances(&99), (100 + 7, 0));
+			assert_eq!(balances(&999), (100, 0));
+		})
+	}
+
+	#[test]
+	fn queueing_a_single_page_solution_clears_queued_pages() {
+		ExtBuilder::default().pages(3, 3).build_and_execute(|| {
+			roll_to(15);
+			let (score, pages) = raw_paged_solution();
+			assert_ok!(MultiPhase::register_paged(Origin::signed(99), score));
+			submit_all_pages(99, pages);
+			assert_eq!(QueuedSolutionPages::<Runtime>::iter().count(), 3);
+
+			MultiPhase::queue_solution(Default::default());
+
+			assert_eq!(QueuedSolutionPages::<Runtime>::iter().count(), 0);
+			assert!(QueuedSolution::<Runtime>::exists());
+		})
+	}
+}
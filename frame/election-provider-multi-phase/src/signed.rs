@@ -18,9 +18,9 @@
 //! The signed phase implementation.
 
 use crate::{
-	Config, ElectionCompute, Pallet, QueuedSolution, RawSolution, ReadySolution,
-	SignedSubmissionIndices, SignedSubmissionNextIndex, SignedSubmissionsMap, SolutionOf,
-	SolutionOrSnapshotSize, Weight, WeightInfo,
+	Config, ElectionCompute, Pallet, RawSolution, ReadySolution, SignedSubmissionIndices,
+	SignedSubmissionNextIndex, SignedSubmissionsMap, SolutionOf, SolutionOrSnapshotSize, Weight,
+	WeightInfo,
 };
 use codec::{Decode, Encode, HasCompact};
 use frame_support::{
@@ -341,13 +341,14 @@ impl<T: Config> Pallet<T> {
 	/// Returns true if we have a good solution in the signed phase.
 	///
 	/// This drains the [`SignedSubmissions`], potentially storing the best valid one in
-	/// [`QueuedSolution`].
+	/// [`crate::QueuedSolution`].
 	///
 	/// This is a *self-weighing* function, it automatically registers its weight internally when
 	/// being called.
 	pub fn finalize_signed_phase() -> bool {
 		let (weight, found_solution) = Self::finalize_signed_phase_internal();
 		Self::register_weight(weight);
+		Self::finalize_paged_submissions();
 		found_solution
 	}
 
@@ -362,6 +363,21 @@ impl<T: Config> Pallet<T> {
 
 		while let Some(best) = all_submissions.pop_last() {
 			let SignedSubmission { raw_solution, who, deposit, reward } = best;
+
+			// a paged solution may have been queued during the signed phase. If it is at least as
+			// good as the best submission, the rest of the submissions are pointless to check.
+			weight = weight.saturating_add(T::DbWeight::get().reads(1));
+			if Self::queued_solution().map_or(false, |queued| {
+				!is_score_better(
+					raw_solution.score,
+					queued.score,
+					T::SolutionImprovementThreshold::get(),
+				)
+			}) {
+				let _remaining = T::Currency::unreserve(&who, deposit);
+				debug_assert!(_remaining.is_zero());
+				break
+			}
 			let active_voters = raw_solution.solution.voter_count() as u32;
 			let feasibility_weight = {
 				// defensive only: at the end of signed phase, snapshot will exits.
@@ -426,7 +442,7 @@ impl<T: Config> Pallet<T> {
 		reward: BalanceOf<T>,
 	) {
 		// write this ready solution.
-		<QueuedSolution<T>>::put(ready_solution);
+		Self::queue_solution(ready_solution);
 
 		// emit reward event
 		Self::deposit_event(crate::Event::Rewarded { account: who.clone(), value: reward });
@@ -475,6 +491,15 @@ impl<T: Config> Pallet<T> {
 	pub fn deposit_for(
 		raw_solution: &RawSolution<SolutionOf<T>>,
 		size: SolutionOrSnapshotSize,
+	) -> BalanceOf<T> {
+		T::SignedDepositBase::get().saturating_add(Self::variable_deposit_for(raw_solution, size))
+	}
+
+	/// The part of [`Self::deposit_for`] that depends on the solution itself, namely the per-byte
+	/// and per-weight deposit.
+	pub fn variable_deposit_for(
+		raw_solution: &RawSolution<SolutionOf<T>>,
+		size: SolutionOrSnapshotSize,
 	) -> BalanceOf<T> {
 		let encoded_len: u32 = raw_solution.encoded_size().saturated_into();
 		let encoded_len: BalanceOf<T> = encoded_len.into();
@@ -484,9 +509,7 @@ impl<T: Config> Pallet<T> {
 		let weight_deposit =
 			T::SignedDepositWeight::get().saturating_mul(feasibility_weight.saturated_into());
 
-		T::SignedDepositBase::get()
-			.saturating_add(len_deposit)
-			.saturating_add(weight_deposit)
+		len_deposit.saturating_add(weight_deposit)
 	}
 }
 
@@ -22,10 +22,11 @@ use crate::{
 	ReadySolution, RoundSnapshot, SolutionAccuracyOf, SolutionOf, SolutionOrSnapshotSize, Weight,
 	WeightInfo,
 };
-use codec::Encode;
+use codec::{Decode, Encode};
 use frame_election_provider_support::{NposSolver, PerThing128};
 use frame_support::{dispatch::DispatchResult, ensure, traits::Get};
 use frame_system::offchain::SubmitTransaction;
+use scale_info::TypeInfo;
 use sp_arithmetic::Perbill;
 use sp_npos_elections::{
 	assignment_ratio_to_staked_normalized, assignment_staked_to_ratio_normalized, is_score_better,
@@ -33,7 +34,7 @@ use sp_npos_elections::{
 };
 use sp_runtime::{
 	offchain::storage::{MutateStorageError, StorageValueRef},
-	DispatchError, SaturatedConversion,
+	DispatchError, RuntimeDebug, SaturatedConversion,
 };
 use sp_std::{cmp::Ordering, prelude::*};
 
@@ -45,6 +46,9 @@ pub(crate) const OFFCHAIN_LOCK: &[u8] = b"parity/multi-phase-unsigned-election/l
 /// Storage key used to cache the solution `call`.
 pub(crate) const OFFCHAIN_CACHED_CALL: &[u8] = b"parity/multi-phase-unsigned-election/call";
 
+/// Storage key used to store the index of the next algorithm that the miner runs.
+pub(crate) const OFFCHAIN_MINER_PROGRESS: &[u8] = b"parity/multi-phase-unsigned-election/progress";
+
 /// A voter's fundamental data: their ID, their stake, and the list of candidates for whom they
 /// voted.
 pub type VoterOf<T> = frame_election_provider_support::VoterOf<<T as Config>::DataProvider>;
@@ -57,6 +61,15 @@ pub type Assignment<T> =
 /// runtime `T`.
 pub type IndexAssignmentOf<T> = sp_npos_elections::IndexAssignmentOf<SolutionOf<T>>;
 
+/// An NPoS algorithm that the OCW miner can run, in addition to [`crate::Config::Solver`].
+#[derive(Encode, Decode, Clone, Copy, PartialEq, Eq, RuntimeDebug, TypeInfo)]
+pub enum MinerAlgorithm {
+	/// [`sp_npos_elections::seq_phragmen`], followed by `balancing` iterations of balancing.
+	SeqPhragmen { balancing: u32 },
+	/// [`sp_npos_elections::phragmms`], followed by `balancing` iterations of balancing.
+	PhragMMS { balancing: u32 },
+}
+
 /// Error type of the pallet's [`crate::Config::Solver`].
 pub type SolverErrorOf<T> = <<T as Config>::Solver as NposSolver>::Error;
 /// Error type for operations related to the OCW npos solution miner.
@@ -125,11 +138,30 @@ fn restore_solution<T: Config>() -> Result<Call<T>, MinerError<T>> {
 		.ok_or(MinerError::NoStoredSolution)
 }
 
-/// Clear a saved solution from OCW storage.
+/// Clear a saved solution, and the progress of the miner that led to it, from OCW storage.
 pub(super) fn kill_ocw_solution<T: Config>() {
 	log!(debug, "clearing offchain call cache storage.");
 	let mut storage = StorageValueRef::persistent(&OFFCHAIN_CACHED_CALL);
 	storage.clear();
+	let mut progress = StorageValueRef::persistent(&OFFCHAIN_MINER_PROGRESS);
+	progress.clear();
+}
+
+/// Save the index of the next algorithm that the miner should run into OCW storage.
+fn save_progress(next_algorithm: u32) {
+	StorageValueRef::persistent(&OFFCHAIN_MINER_PROGRESS).set(&next_algorithm);
+}
+
+/// Get the index of the next algorithm that the miner should run from OCW storage.
+///
+/// Only meaningful along with a saved solution, as it denotes the algorithms that have already
+/// been tried to improve it.
+fn restore_progress() -> u32 {
+	StorageValueRef::persistent(&OFFCHAIN_MINER_PROGRESS)
+		.get()
+		.ok()
+		.flatten()
+		.unwrap_or(0)
 }
 
 /// Clear the offchain repeat storage.
@@ -148,50 +180,89 @@ fn ocw_solution_exists<T: Config>() -> bool {
 }
 
 impl<T: Config> Pallet<T> {
-	/// Attempt to restore a solution from cache. Otherwise, compute it fresh. Either way, submit
-	/// if our call's score is greater than that of the cached solution.
+	/// Attempt to restore the best solution so far from cache, and try to improve it with the next
+	/// algorithm of the miner. Either way, submit the best solution if it beats the queued one.
+	///
+	/// At most one algorithm is run per call. If there is no cached solution, mining starts over
+	/// with [`Config::Solver`].
 	pub fn restore_or_compute_then_maybe_submit() -> Result<(), MinerError<T>> {
 		log!(debug, "miner attempting to restore or compute an unsigned solution.");
 
-		let call = restore_solution::<T>()
-			.and_then(|call| {
-				// ensure the cached call is still current before submitting
-				if let Call::submit_unsigned { raw_solution, .. } = &call {
-					// prevent errors arising from state changes in a forkful chain
-					Self::basic_checks(raw_solution, "restored")?;
-					Ok(call)
-				} else {
-					Err(MinerError::SolutionCallInvalid)
+		let cached = match restore_solution::<T>() {
+			Ok(Call::submit_unsigned { raw_solution, witness }) => {
+				// prevent errors arising from state changes in a forkful chain
+				if let Err(error) =
+					Self::feasibility_check(*raw_solution.clone(), ElectionCompute::Unsigned)
+				{
+					log!(trace, "wiping infeasible solution.");
+					// kill the infeasible solution, hopefully in the next runs (whenever they may
+					// be) we mine a new one. Note that failing `Feasibility` can only mean that the
+					// solution was computed over a snapshot that has changed due to a fork.
+					kill_ocw_solution::<T>();
+					clear_offchain_repeat_frequency();
+					return Err(error.into())
 				}
-			})
-			.or_else::<MinerError<T>, _>(|error| {
-				log!(debug, "restoring solution failed due to {:?}", error);
-				match error {
-					MinerError::NoStoredSolution => {
-						log!(trace, "mining a new solution.");
-						// if not present or cache invalidated due to feasibility, regenerate.
-						// note that failing `Feasibility` can only mean that the solution was
-						// computed over a snapshot that has changed due to a fork.
-						let call = Self::mine_checked_call()?;
-						save_solution(&call)?;
-						Ok(call)
+				Some((*raw_solution, witness))
+			},
+			Ok(_) => return Err(MinerError::SolutionCallInvalid),
+			Err(MinerError::NoStoredSolution) => None,
+			Err(error) => return Err(error),
+		};
+
+		let next_algorithm = if cached.is_some() { restore_progress() } else { 0 };
+		let (raw_solution, witness) = match Self::mine_solution_with_algorithm(next_algorithm) {
+			Some(mined) => {
+				save_progress(next_algorithm.saturating_add(1));
+				let mined = mined.and_then(|(raw_solution, witness)| {
+					Self::feasibility_check(raw_solution.clone(), ElectionCompute::Unsigned)?;
+					Ok((raw_solution, witness))
+				});
+
+				match (mined, cached) {
+					(Ok(mined), Some(cached))
+						if !is_score_better(mined.0.score, cached.0.score, Perbill::zero()) =>
+					{
+						log!(
+							debug,
+							"algorithm {} did not improve the cached solution.",
+							next_algorithm
+						);
+						cached
 					},
-					MinerError::Feasibility(_) => {
-						log!(trace, "wiping infeasible solution.");
-						// kill the infeasible solution, hopefully in the next runs (whenever they
-						// may be) we mine a new one.
-						kill_ocw_solution::<T>();
-						clear_offchain_repeat_frequency();
-						Err(error)
+					(Ok((raw_solution, witness)), _) => {
+						log!(
+							debug,
+							"algorithm {} mined a solution with score {:?}.",
+							next_algorithm,
+							raw_solution.score
+						);
+						save_solution(&Call::submit_unsigned {
+							raw_solution: Box::new(raw_solution.clone()),
+							witness,
+						})?;
+						(raw_solution, witness)
 					},
-					_ => {
-						// nothing to do. Return the error as-is.
-						Err(error)
+					(Err(error), Some(cached)) => {
+						log!(debug, "algorithm {} failed due to {:?}.", next_algorithm, error);
+						cached
 					},
+					(Err(error), None) => return Err(error),
 				}
-			})?;
+			},
+			None => cached.ok_or(MinerError::NoStoredSolution)?,
+		};
 
-		Self::submit_call(call)
+		// only (re-)submit if we beat the queued solution.
+		if Self::queued_solution().map_or(false, |q| {
+			!is_score_better(raw_solution.score, q.score, T::SolutionImprovementThreshold::get())
+		}) {
+			log!(debug, "queued solution is at least as good as the mined one, not submitting.");
+			return Ok(())
+		}
+		Self::unsigned_pre_dispatch_checks(&raw_solution)
+			.map_err(MinerError::PreDispatchChecksFailed)?;
+
+		Self::submit_call(Call::submit_unsigned { raw_solution: Box::new(raw_solution), witness })
 	}
 
 	/// Mine a new solution, cache it, and submit it back to the chain as an unsigned transaction.
@@ -200,6 +271,8 @@ impl<T: Config> Pallet<T> {
 
 		let call = Self::mine_checked_call()?;
 		save_solution(&call)?;
+		// the solution of `T::Solver` is now cached, resume with the next algorithm.
+		save_progress(1);
 		Self::submit_call(call)
 	}
 
@@ -282,6 +355,48 @@ impl<T: Config> Pallet<T> {
 			.and_then(|e| Self::prepare_election_result::<S::Accuracy>(e))
 	}
 
+	/// Mine a new npos solution with the `index`-th algorithm of the miner, where the first one is
+	/// [`Config::Solver`], followed by [`Config::MinerAlgorithms`].
+	///
+	/// Returns `None` if there is no such algorithm.
+	pub fn mine_solution_with_algorithm(
+		index: u32,
+	) -> Option<Result<(RawSolution<SolutionOf<T>>, SolutionOrSnapshotSize), MinerError<T>>> {
+		match index.checked_sub(1) {
+			None => Some(Self::mine_solution::<T::Solver>()),
+			Some(index) => T::MinerAlgorithms::get()
+				.get(index as usize)
+				.map(|algorithm| Self::mine_solution_by(*algorithm)),
+		}
+	}
+
+	/// Mine a new npos solution with `algorithm`.
+	fn mine_solution_by(
+		algorithm: MinerAlgorithm,
+	) -> Result<(RawSolution<SolutionOf<T>>, SolutionOrSnapshotSize), MinerError<T>> {
+		let RoundSnapshot { voters, targets } =
+			Self::snapshot().ok_or(MinerError::SnapshotUnAvailable)?;
+		let desired_targets =
+			Self::desired_targets().ok_or(MinerError::SnapshotUnAvailable)? as usize;
+
+		let election_result = match algorithm {
+			MinerAlgorithm::SeqPhragmen { balancing } => sp_npos_elections::seq_phragmen(
+				desired_targets,
+				targets,
+				voters,
+				Some((balancing as usize, 0)),
+			),
+			MinerAlgorithm::PhragMMS { balancing } => sp_npos_elections::phragmms(
+				desired_targets,
+				targets,
+				voters,
+				Some((balancing as usize, 0)),
+			),
+		}?;
+
+		Self::prepare_election_result::<SolutionAccuracyOf<T>>(election_result)
+	}
+
 	/// Convert a raw solution from [`sp_npos_elections::ElectionResult`] to [`RawSolution`], which
 	/// is ready to be submitted to the chain.
 	///
@@ -674,6 +789,12 @@ mod max_weight {
 		fn feasibility_check(v: u32, _t: u32, a: u32, d: u32) -> Weight {
 			unreachable!()
 		}
+		fn register_paged() -> Weight {
+			unreachable!()
+		}
+		fn submit_page(_p: u32) -> Weight {
+			unreachable!()
+		}
 	}
 
 	#[test]
@@ -1431,6 +1552,108 @@ mod tests {
 		})
 	}
 
+	#[test]
+	fn miner_can_mine_with_each_algorithm() {
+		ExtBuilder::default()
+			.miner_algorithms(vec![
+				MinerAlgorithm::SeqPhragmen { balancing: 2 },
+				MinerAlgorithm::PhragMMS { balancing: 2 },
+			])
+			.build_and_execute(|| {
+				roll_to(25);
+				assert!(MultiPhase::current_phase().is_unsigned());
+
+				// the solver and both algorithms produce a feasible solution.
+				for index in 0..3 {
+					let (raw, _) =
+						MultiPhase::mine_solution_with_algorithm(index).unwrap().unwrap();
+					assert_ok!(MultiPhase::feasibility_check(raw, ElectionCompute::Unsigned));
+				}
+
+				// there is no fourth algorithm.
+				assert!(MultiPhase::mine_solution_with_algorithm(3).is_none());
+			})
+	}
+
+	#[test]
+	fn ocw_resumes_with_next_algorithm() {
+		let (mut ext, pool) = ExtBuilder::default()
+			.miner_algorithms(vec![MinerAlgorithm::PhragMMS { balancing: 2 }])
+			.build_offchainify(0);
+		ext.execute_with(|| {
+			const BLOCK: u64 = 25;
+			let offchain_repeat = <Runtime as Config>::OffchainRepeat::get();
+			let progress = StorageValueRef::persistent(&OFFCHAIN_MINER_PROGRESS);
+
+			roll_to(BLOCK);
+			assert_eq!(MultiPhase::current_phase(), Phase::Unsigned((true, BLOCK)));
+
+			// the first execution only runs `Solver`.
+			MultiPhase::offchain_worker(BLOCK);
+			assert_eq!(pool.read().transactions.len(), 1);
+			assert_eq!(progress.get::<u32>().unwrap(), Some(1));
+			pool.try_write().unwrap().transactions.clear();
+
+			// the next one runs phragmms, and submits the best solution again.
+			MultiPhase::offchain_worker(BLOCK + offchain_repeat + 1);
+			assert_eq!(pool.read().transactions.len(), 1);
+			assert_eq!(progress.get::<u32>().unwrap(), Some(2));
+			assert!(ocw_solution_exists::<Runtime>());
+			pool.try_write().unwrap().transactions.clear();
+
+			// all algorithms have been run, the cached solution is still resubmitted.
+			MultiPhase::offchain_worker(BLOCK + 2 * offchain_repeat + 2);
+			assert_eq!(pool.read().transactions.len(), 1);
+			assert_eq!(progress.get::<u32>().unwrap(), Some(2));
+		})
+	}
+
+	#[test]
+	fn ocw_keeps_cached_solution_if_not_improved() {
+		let (mut ext, pool) = ExtBuilder::default()
+			.miner_algorithms(vec![MinerAlgorithm::SeqPhragmen { balancing: 0 }])
+			.build_offchainify(0);
+		ext.execute_with(|| {
+			const BLOCK: u64 = 25;
+			let offchain_repeat = <Runtime as Config>::OffchainRepeat::get();
+
+			roll_to(BLOCK);
+			MultiPhase::offchain_worker(BLOCK);
+			let tx_cache = pool.read().transactions[0].clone();
+			pool.try_write().unwrap().transactions.clear();
+
+			// the same algorithm as `Solver` cannot do better, so the same solution is resubmitted.
+			MultiPhase::offchain_worker(BLOCK + offchain_repeat + 1);
+			assert_eq!(pool.read().transactions.len(), 1);
+			assert_eq!(pool.read().transactions[0], tx_cache);
+		})
+	}
+
+	#[test]
+	fn ocw_only_resubmits_if_queued_solution_is_beaten() {
+		let (mut ext, pool) = ExtBuilder::default().build_offchainify(0);
+		ext.execute_with(|| {
+			const BLOCK: u64 = 25;
+			let offchain_repeat = <Runtime as Config>::OffchainRepeat::get();
+
+			roll_to(BLOCK);
+			MultiPhase::offchain_worker(BLOCK);
+			assert_eq!(pool.read().transactions.len(), 1);
+
+			// the submitted solution is included in a block.
+			let encoded = pool.try_write().unwrap().transactions.remove(0);
+			let extrinsic = Extrinsic::decode(&mut &*encoded).unwrap();
+			assert_ok!(extrinsic.call.dispatch(Origin::none()));
+			assert!(MultiPhase::queued_solution().is_some());
+
+			// the cached solution does not beat the queued one, nothing is resubmitted.
+			assert_ok!(MultiPhase::restore_or_compute_then_maybe_submit());
+			MultiPhase::offchain_worker(BLOCK + offchain_repeat + 1);
+			assert!(pool.read().transactions.is_empty());
+			assert!(ocw_solution_exists::<Runtime>());
+		})
+	}
+
 	#[test]
 	fn trim_assignments_length_does_not_modify_when_short_enough() {
 		ExtBuilder::default().build_and_execute(|| {
@@ -56,6 +56,8 @@ pub trait WeightInfo {
 	fn submit(c: u32, ) -> Weight;
 	fn submit_unsigned(v: u32, t: u32, a: u32, d: u32, ) -> Weight;
 	fn feasibility_check(v: u32, t: u32, a: u32, d: u32, ) -> Weight;
+	fn register_paged() -> Weight;
+	fn submit_page(p: u32, ) -> Weight;
 }
 
 /// Weights for pallet_election_provider_multi_phase using the Substrate node and recommended hardware.
@@ -178,6 +180,40 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add((1_420_000 as Weight).saturating_mul(d as Weight))
 			.saturating_add(T::DbWeight::get().reads(4 as Weight))
 	}
+	// NOTE: the weights of `register_paged` and `submit_page` are placeholders, based on the
+	// storage accesses and the execution time of `submit`, until they are benchmarked. The `p`
+	// terms of `submit_page` cover moving each page of the solution to `QueuedSolutionPages`.
+	// Storage: ElectionProviderMultiPhase CurrentPhase (r:1 w:0)
+	// Storage: ElectionProviderMultiPhase PagedSignedSubmissions (r:1 w:1)
+	// Storage: ElectionProviderMultiPhase CounterForPagedSignedSubmissions (r:1 w:1)
+	// Storage: ElectionProviderMultiPhase MinimumUntrustedScore (r:1 w:0)
+	// Storage: ElectionProviderMultiPhase QueuedSolution (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	fn register_paged() -> Weight {
+		(39_488_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: ElectionProviderMultiPhase CurrentPhase (r:1 w:0)
+	// Storage: ElectionProviderMultiPhase PagedSignedSubmissions (r:1 w:1)
+	// Storage: ElectionProviderMultiPhase CounterForPagedSignedSubmissions (r:1 w:1)
+	// Storage: ElectionProviderMultiPhase SnapshotMetadata (r:1 w:0)
+	// Storage: ElectionProviderMultiPhase PagedVoterSnapshot (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: ElectionProviderMultiPhase PagedSubmissionSupports (r:1 w:2)
+	// Storage: ElectionProviderMultiPhase DesiredTargets (r:1 w:0)
+	// Storage: ElectionProviderMultiPhase MinimumUntrustedScore (r:1 w:0)
+	// Storage: ElectionProviderMultiPhase QueuedSolution (r:1 w:1)
+	// Storage: ElectionProviderMultiPhase QueuedPagedSubmission (r:1 w:1)
+	// Storage: ElectionProviderMultiPhase QueuedSolutionPages (r:0 w:2)
+	fn submit_page(p: u32, ) -> Weight {
+		(39_488_000 as Weight)
+			.saturating_add((4_205_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(p as Weight)))
+	}
 }
 
 // For backwards compatibility and tests
@@ -299,4 +335,38 @@ impl WeightInfo for () {
 			.saturating_add((1_420_000 as Weight).saturating_mul(d as Weight))
 			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
 	}
+	// NOTE: the weights of `register_paged` and `submit_page` are placeholders, based on the
+	// storage accesses and the execution time of `submit`, until they are benchmarked. The `p`
+	// terms of `submit_page` cover moving each page of the solution to `QueuedSolutionPages`.
+	// Storage: ElectionProviderMultiPhase CurrentPhase (r:1 w:0)
+	// Storage: ElectionProviderMultiPhase PagedSignedSubmissions (r:1 w:1)
+	// Storage: ElectionProviderMultiPhase CounterForPagedSignedSubmissions (r:1 w:1)
+	// Storage: ElectionProviderMultiPhase MinimumUntrustedScore (r:1 w:0)
+	// Storage: ElectionProviderMultiPhase QueuedSolution (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	fn register_paged() -> Weight {
+		(39_488_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: ElectionProviderMultiPhase CurrentPhase (r:1 w:0)
+	// Storage: ElectionProviderMultiPhase PagedSignedSubmissions (r:1 w:1)
+	// Storage: ElectionProviderMultiPhase CounterForPagedSignedSubmissions (r:1 w:1)
+	// Storage: ElectionProviderMultiPhase SnapshotMetadata (r:1 w:0)
+	// Storage: ElectionProviderMultiPhase PagedVoterSnapshot (r:1 w:0)
+	// Storage: System Account (r:2 w:2)
+	// Storage: ElectionProviderMultiPhase PagedSubmissionSupports (r:1 w:2)
+	// Storage: ElectionProviderMultiPhase DesiredTargets (r:1 w:0)
+	// Storage: ElectionProviderMultiPhase MinimumUntrustedScore (r:1 w:0)
+	// Storage: ElectionProviderMultiPhase QueuedSolution (r:1 w:1)
+	// Storage: ElectionProviderMultiPhase QueuedPagedSubmission (r:1 w:1)
+	// Storage: ElectionProviderMultiPhase QueuedSolutionPages (r:0 w:2)
+	fn submit_page(p: u32, ) -> Weight {
+		(39_488_000 as Weight)
+			.saturating_add((4_205_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(p as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(p as Weight)))
+	}
 }
@@ -168,6 +168,7 @@
 
 pub mod onchain;
 use frame_support::{traits::Get, BoundedVec};
+use sp_arithmetic::traits::Zero;
 use sp_std::{fmt::Debug, prelude::*};
 
 /// Re-export some type as they are used in the interface.
@@ -177,6 +178,11 @@ pub use sp_npos_elections::{
 	VoteWeight,
 };
 
+/// The index of a page in a paged election.
+///
+/// Page `0` is always the first page. Single-page elections only ever use page `0`.
+pub type PageIndex = u32;
+
 /// Types that are used by the data provider trait.
 pub mod data_provider {
 	/// Alias for the result type of the election data provider.
@@ -214,6 +220,29 @@ pub trait ElectionDataProvider {
 	/// appropriate weight at the end of execution with the system pallet directly.
 	fn voters(maybe_max_len: Option<usize>) -> data_provider::Result<Vec<VoterOf<Self>>>;
 
+	/// A single page of all possible voters for the election.
+	///
+	/// Pages are requested in ascending order, starting from page `0`. A request for page `0`
+	/// always starts from the beginning of the voter set, and each subsequent page continues from
+	/// where the previous one ended. Once all voters have been returned, any further pages are
+	/// empty.
+	///
+	/// If `maybe_max_len` is `Some(v)` then the resulting vector MUST NOT be longer than `v` items
+	/// long.
+	///
+	/// By default, page `0` returns all of [`Self::voters`] and every other page is empty, which
+	/// is the right behaviour for data providers that do not support pagination.
+	fn voters_paged(
+		maybe_max_len: Option<usize>,
+		page: PageIndex,
+	) -> data_provider::Result<Vec<VoterOf<Self>>> {
+		if page.is_zero() {
+			Self::voters(maybe_max_len)
+		} else {
+			Ok(Default::default())
+		}
+	}
+
 	/// The number of targets to elect.
 	///
 	/// This should be implemented as a self-weighing function. The implementor should register its
@@ -317,6 +346,30 @@ pub trait ElectionProvider {
 	/// This should be implemented as a self-weighing function. The implementor should register its
 	/// appropriate weight at the end of execution with the system pallet directly.
 	fn elect() -> Result<Supports<Self::AccountId>, Self::Error>;
+
+	/// The number of pages that this election provider returns its result in.
+	///
+	/// Defaults to `1`, i.e. a single page containing the entire result of [`Self::elect`].
+	fn pages() -> PageIndex {
+		1
+	}
+
+	/// Elect a single page of the result.
+	///
+	/// Pages are indexed from `0` to `Self::pages() - 1`, and should be requested in ascending
+	/// order. The same target may be backed in more than one page, in which case the caller is
+	/// expected to merge its backing across all pages. Requesting the last page concludes the
+	/// election.
+	///
+	/// By default, page `0` returns the entire result of [`Self::elect`], and every other page is
+	/// empty.
+	fn elect_paged(page: PageIndex) -> Result<Supports<Self::AccountId>, Self::Error> {
+		if page.is_zero() {
+			Self::elect()
+		} else {
+			Ok(Default::default())
+		}
+	}
 }
 
 /// A sub-trait of the [`ElectionProvider`] for cases where we need to be sure an election needs to
@@ -369,6 +422,12 @@ pub trait SortedListProvider<AccountId> {
 	/// An iterator over the list, which can have `take` called on it.
 	fn iter() -> Box<dyn Iterator<Item = AccountId>>;
 
+	/// Same as [`Self::iter`], but starting right after `start`, skipping it and everything before
+	/// it.
+	///
+	/// Returns an error if `start` is not a member of the list.
+	fn iter_from(start: &AccountId) -> Result<Box<dyn Iterator<Item = AccountId>>, Self::Error>;
+
 	/// The current count of ids in the list.
 	fn count() -> u32;
 
@@ -384,6 +443,20 @@ pub trait SortedListProvider<AccountId> {
 	/// Hook for removing am id from the list.
 	fn on_remove(id: &AccountId);
 
+	/// Lock the order of the list until [`Self::unlock`] is called.
+	///
+	/// While locked, ids can still be inserted and removed, but the existing ids keep their
+	/// position, even if their weight is updated. This allows the list to be iterated over
+	/// multiple blocks with [`Self::iter_from`].
+	///
+	/// Does nothing by default.
+	fn lock() {}
+
+	/// Unlock the order of the list, see [`Self::lock`].
+	///
+	/// Does nothing by default.
+	fn unlock() {}
+
 	/// Regenerate this list from scratch. Returns the count of items inserted.
 	///
 	/// This should typically only be used at a runtime upgrade.
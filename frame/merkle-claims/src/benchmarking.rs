@@ -0,0 +1,113 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Merkle claims pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{account, benchmarks, whitelisted_caller};
+use frame_support::{traits::EnsureOrigin, BoundedVec};
+use frame_system::RawOrigin as SystemOrigin;
+use sp_runtime::traits::{Bounded, Hash};
+
+use crate::Pallet as MerkleClaims;
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
+	let events = frame_system::Pallet::<T>::events();
+	let system_event: <T as frame_system::Config>::Event = generic_event.into();
+	// compare to the last event record
+	let frame_system::EventRecord { event, .. } = &events[events.len() - 1];
+	assert_eq!(event, &system_event);
+}
+
+fn vesting_terms<T: Config>() -> Option<VestingTerms<T::BlockNumber>> {
+	Some(VestingTerms { starting_block: 10u32.into(), length: 100u32.into() })
+}
+
+/// Creates a campaign with the given root, funded by a new account.
+fn create_campaign<T: Config>(merkle_root: T::Hash, total: BalanceOf<T>) -> CampaignId {
+	let funder: T::AccountId = account("funder", 0, 0);
+	T::Currency::make_free_balance_be(&funder, BalanceOf::<T>::max_value());
+	MerkleClaims::<T>::do_create_campaign(
+		funder,
+		merkle_root,
+		total,
+		100u32.into(),
+		vesting_terms::<T>(),
+	)
+	.expect("The funder is endowed; qed")
+}
+
+benchmarks! {
+	create_campaign {
+		let origin = T::CreateOrigin::successful_origin();
+		let funder = T::CreateOrigin::ensure_origin(origin.clone())
+			.map_err(|_| "The successful origin must resolve to an account")?;
+		T::Currency::make_free_balance_be(&funder, BalanceOf::<T>::max_value());
+		let merkle_root = T::Hashing::hash_of(&0u32);
+		let total = T::Currency::minimum_balance() * 1000u32.into();
+	}: _<T::Origin>(origin, merkle_root, total, 100u32.into(), vesting_terms::<T>())
+	verify {
+		assert_last_event::<T>(
+			Event::CampaignCreated {
+				campaign: 0,
+				funder,
+				merkle_root,
+				total,
+				expiry: 100u32.into(),
+			}.into()
+		);
+	}
+
+	claim {
+		let p in 0 .. T::MaxProofLength::get();
+
+		let campaign = NextCampaignId::<T>::get();
+		let amount = T::Currency::minimum_balance() * 100u32.into();
+		let (public, signature) = T::Helper::sign(|public| {
+			MerkleClaims::<T>::claim_message(campaign, &public.into_account(), amount)
+		});
+		let who = public.into_account();
+
+		let proof: Vec<T::Hash> = (0..p).map(|i| T::Hashing::hash_of(&i)).collect();
+		let leaf = merkle::leaf_hash::<T::Hashing, _, _>(&who, &amount);
+		let merkle_root = merkle::root_from_proof::<T::Hashing>(leaf, &proof);
+		create_campaign::<T>(merkle_root, amount * 2u32.into());
+		let proof = BoundedVec::try_from(proof).expect("The proof is within bounds; qed");
+	}: _(SystemOrigin::None, campaign, who.clone(), amount, proof, signature)
+	verify {
+		assert_last_event::<T>(Event::Claimed { campaign, who, amount }.into());
+	}
+
+	sweep_campaign {
+		let c in 0 .. T::RemoveClaimsLimit::get();
+
+		let total = T::Currency::minimum_balance() * 1000u32.into();
+		let campaign = create_campaign::<T>(T::Hashing::hash_of(&0u32), total);
+		for i in 0..c {
+			Claims::<T>::insert(campaign, account::<T::AccountId>("beneficiary", i, 0), ());
+		}
+		frame_system::Pallet::<T>::set_block_number(100u32.into());
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(SystemOrigin::Signed(caller), campaign)
+	verify {
+		assert_last_event::<T>(Event::CampaignRemoved { campaign }.into());
+	}
+
+	impl_benchmark_test_suite!(MerkleClaims, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,503 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Merkle Claims Module
+//!
+//! Distributes token allocations to a large number of accounts while only storing the root of a
+//! Merkle tree over them.
+//!
+//! ## Overview
+//!
+//! A campaign commits to its allocations with the root of a binary Merkle tree, see [`merkle`].
+//! Each leaf is the hash of an `(account, amount)` pair, computed with the `Hashing` of the
+//! runtime.
+//!
+//! - [`Pallet::create_campaign`] reserves the total of a campaign from the account
+//!   [`Config::CreateOrigin`] resolves to. Campaigns can also be created in genesis, which keeps
+//!   the allocations out of the genesis of `pallet-balances`.
+//! - [`Pallet::claim`] pays an allocation out of the reserve of the funder. It is an unsigned
+//!   extrinsic, validated with the Merkle proof of the allocation and a signature of
+//!   [`Pallet::claim_message`] by the beneficiary, so the beneficiary doesn't need funds to claim.
+//!   If the campaign has [`VestingTerms`], the claimed amount is vested with
+//!   [`Config::VestingSchedule`].
+//! - [`Pallet::sweep_campaign`] can be called by anyone once a campaign expired. It hands the
+//!   unclaimed funds to [`Config::OnUnclaimed`] and removes the campaign from storage.
+//!
+//! ## Related Modules
+//!
+//! * [`System`](../frame_system/index.html)
+//! * [`Support`](../frame_support/index.html)
+
+// Ensure we're `no_std` when compiling for Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+pub mod mock;
+#[cfg(test)]
+mod tests;
+
+pub mod merkle;
+mod types;
+pub mod weights;
+
+use codec::Encode;
+use frame_support::{
+	traits::{Currency, ExistenceRequirement, OnUnbalanced, ReservableCurrency, VestingSchedule},
+	transactional,
+};
+use sp_runtime::{
+	traits::{Convert, IdentifyAccount, One, Saturating, Verify, Zero},
+	ArithmeticError,
+};
+use sp_std::prelude::*;
+
+pub use pallet::*;
+pub use types::*;
+pub use weights::WeightInfo;
+
+/// The context prepended to the message signed by the beneficiary of a claim.
+pub const CLAIM_CONTEXT: &[u8] = b"merkle-claims:claim";
+
+/// A claim for a campaign which doesn't exist.
+const UNKNOWN_CAMPAIGN: u8 = 1;
+/// A claim for more than the unclaimed funds of its campaign.
+const INSUFFICIENT_FUNDS: u8 = 2;
+/// A claim whose amount can't be vested for its beneficiary.
+const CANNOT_VEST: u8 = 3;
+
+/// Creates signed claims for the benchmarks of this pallet.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<Public, Signature> {
+	/// Creates a new key and signs the message returned by `message` for it.
+	fn sign(message: impl Fn(Public) -> Vec<u8>) -> (Public, Signature);
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<Public, Signature> BenchmarkHelper<Public, Signature> for ()
+where
+	Public: From<sp_core::sr25519::Public>,
+	Signature: From<sp_core::sr25519::Signature>,
+{
+	fn sign(message: impl Fn(Public) -> Vec<u8>) -> (Public, Signature) {
+		let key_type = sp_core::crypto::key_types::DUMMY;
+		let public = sp_io::crypto::sr25519_generate(key_type, None);
+		let message = message(public.into());
+		let signature = sp_io::crypto::sr25519_sign(key_type, &public, &message)
+			.expect("The key was generated above; qed");
+		(public.into(), signature.into())
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	/// The module configuration trait.
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The currency the allocations are paid in.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The origin which may create campaigns. The account it resolves to funds the campaign.
+		type CreateOrigin: EnsureOrigin<Self::Origin, Success = Self::AccountId>;
+
+		/// The vesting applied to allocations claimed from campaigns with vesting terms.
+		type VestingSchedule: VestingSchedule<
+			Self::AccountId,
+			Moment = Self::BlockNumber,
+			Currency = Self::Currency,
+		>;
+
+		/// Converts the length of vesting terms into a balance, to compute the amount unlocked
+		/// per block.
+		type BlockNumberToBalance: Convert<Self::BlockNumber, BalanceOf<Self>>;
+
+		/// Handler for the unclaimed funds of expired campaigns, e.g. the treasury.
+		type OnUnclaimed: OnUnbalanced<NegativeImbalanceOf<Self>>;
+
+		/// The signature of a beneficiary for the message of a claim.
+		type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+
+		/// The public key which identifies the beneficiary of a claim.
+		type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
+
+		/// The maximum number of nodes of a Merkle proof, i.e. the maximum depth of the tree of a
+		/// campaign.
+		#[pallet::constant]
+		type MaxProofLength: Get<u32>;
+
+		/// The maximum number of claim records removed by a single call to `sweep_campaign`.
+		#[pallet::constant]
+		type RemoveClaimsLimit: Get<u32>;
+
+		/// The priority of unsigned claim transactions.
+		#[pallet::constant]
+		type UnsignedPriority: Get<TransactionPriority>;
+
+		/// A helper to sign claims in benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type Helper: BenchmarkHelper<Self::OffchainPublic, Self::OffchainSignature>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::storage]
+	/// The identifier of the next campaign.
+	pub type NextCampaignId<T> = StorageValue<_, CampaignId, ValueQuery>;
+
+	#[pallet::storage]
+	/// Details of the campaigns.
+	pub type Campaigns<T: Config> = StorageMap<_, Twox64Concat, CampaignId, CampaignOf<T>>;
+
+	#[pallet::storage]
+	/// The beneficiaries which claimed their allocation, by campaign.
+	pub type Claims<T: Config> =
+		StorageDoubleMap<_, Twox64Concat, CampaignId, Blake2_128Concat, T::AccountId, ()>;
+
+	#[pallet::genesis_config]
+	pub struct GenesisConfig<T: Config> {
+		/// The campaigns to create, as `(funder, merkle_root, total, expiry, vesting)`.
+		pub campaigns: Vec<(
+			T::AccountId,
+			T::Hash,
+			BalanceOf<T>,
+			T::BlockNumber,
+			Option<VestingTerms<T::BlockNumber>>,
+		)>,
+	}
+
+	#[cfg(feature = "std")]
+	impl<T: Config> Default for GenesisConfig<T> {
+		fn default() -> Self {
+			Self { campaigns: Default::default() }
+		}
+	}
+
+	#[pallet::genesis_build]
+	impl<T: Config> GenesisBuild<T> for GenesisConfig<T> {
+		fn build(&self) {
+			for (funder, merkle_root, total, expiry, vesting) in self.campaigns.iter() {
+				Pallet::<T>::do_create_campaign(
+					funder.clone(),
+					*merkle_root,
+					*total,
+					*expiry,
+					*vesting,
+				)
+				.expect("Funders must be endowed with the total of their campaigns at genesis");
+			}
+		}
+	}
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A campaign paying out up to `total` from `funder` was created.
+		CampaignCreated {
+			campaign: CampaignId,
+			funder: T::AccountId,
+			merkle_root: T::Hash,
+			total: BalanceOf<T>,
+			expiry: T::BlockNumber,
+		},
+		/// `who` claimed their allocation of `amount` from a campaign.
+		Claimed { campaign: CampaignId, who: T::AccountId, amount: BalanceOf<T> },
+		/// The `unclaimed` funds of an expired campaign were handed to `OnUnclaimed`.
+		CampaignSwept { campaign: CampaignId, unclaimed: BalanceOf<T> },
+		/// An expired campaign and all its claim records were removed.
+		CampaignRemoved { campaign: CampaignId },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The campaign doesn't exist.
+		UnknownCampaign,
+		/// The expiry of a new campaign must be in the future.
+		InvalidExpiry,
+		/// The campaign expired and can't be claimed anymore.
+		CampaignExpired,
+		/// The campaign hasn't expired yet.
+		CampaignNotExpired,
+		/// The beneficiary already claimed their allocation of the campaign.
+		AlreadyClaimed,
+		/// The Merkle proof doesn't prove the allocation to be part of the campaign.
+		InvalidProof,
+		/// The claim wasn't signed by its beneficiary.
+		InvalidSignature,
+		/// The unclaimed funds of the campaign don't cover the allocation.
+		InsufficientFunds,
+		/// The allocation can't be vested for the beneficiary.
+		CannotVest,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Create a campaign paying out the allocations committed to by `merkle_root`.
+		///
+		/// Origin must be `T::CreateOrigin`. `total` is reserved from the account it resolves to
+		/// and paid out of the reserve as allocations are claimed.
+		///
+		/// - `merkle_root`: The root of the Merkle tree over the allocations.
+		/// - `total`: The maximum amount paid out by the campaign.
+		/// - `expiry`: The block from which the campaign can no longer be claimed.
+		/// - `vesting`: The vesting applied to claimed allocations, if any.
+		///
+		/// Emits `CampaignCreated` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::create_campaign())]
+		pub fn create_campaign(
+			origin: OriginFor<T>,
+			merkle_root: T::Hash,
+			total: BalanceOf<T>,
+			expiry: T::BlockNumber,
+			vesting: Option<VestingTerms<T::BlockNumber>>,
+		) -> DispatchResult {
+			let funder = T::CreateOrigin::ensure_origin(origin)?;
+			Self::do_create_campaign(funder, merkle_root, total, expiry, vesting).map(|_| ())
+		}
+
+		/// Claim the allocation of `amount` to `who` from a campaign.
+		///
+		/// Origin must be None. The transaction is only valid if `proof` proves the allocation to
+		/// be part of the campaign and `signature` is the signature of [`Pallet::claim_message`]
+		/// by `who`.
+		///
+		/// - `campaign`: The campaign to claim from.
+		/// - `who`: The beneficiary of the allocation.
+		/// - `amount`: The amount of the allocation.
+		/// - `proof`: The Merkle proof of the allocation, from the bottom of the tree up.
+		/// - `signature`: The signature of the claim by `who`.
+		///
+		/// Emits `Claimed` event when successful.
+		///
+		/// Weight: `O(p)` where `p = proof.len()`
+		#[pallet::weight(T::WeightInfo::claim(proof.len() as u32))]
+		#[transactional]
+		pub fn claim(
+			origin: OriginFor<T>,
+			campaign: CampaignId,
+			who: T::AccountId,
+			amount: BalanceOf<T>,
+			proof: BoundedVec<T::Hash, T::MaxProofLength>,
+			signature: T::OffchainSignature,
+		) -> DispatchResult {
+			ensure_none(origin)?;
+			let mut details = Self::check_claim(campaign, &who, amount, &proof, &signature)?;
+
+			T::Currency::unreserve(&details.funder, amount);
+			T::Currency::transfer(&details.funder, &who, amount, ExistenceRequirement::AllowDeath)?;
+			if let Some(terms) = details.vesting {
+				let per_block = Self::vested_per_block(amount, terms.length);
+				T::VestingSchedule::add_vesting_schedule(
+					&who,
+					amount,
+					per_block,
+					terms.starting_block,
+				)?;
+			}
+
+			details.remaining = details.remaining.saturating_sub(amount);
+			Campaigns::<T>::insert(campaign, details);
+			Claims::<T>::insert(campaign, &who, ());
+
+			Self::deposit_event(Event::Claimed { campaign, who, amount });
+			Ok(())
+		}
+
+		/// Hand the unclaimed funds of an expired campaign to `T::OnUnclaimed` and remove it.
+		///
+		/// Origin must be Signed and may be any account. At most `T::RemoveClaimsLimit` claim
+		/// records are removed per call. The campaign is kept until all of them are removed, so
+		/// this may need to be called repeatedly.
+		///
+		/// - `campaign`: The expired campaign.
+		///
+		/// Emits `CampaignSwept` event when the unclaimed funds are swept and `CampaignRemoved`
+		/// event when the campaign is removed.
+		///
+		/// Weight: `O(c)` where `c = T::RemoveClaimsLimit`
+		#[pallet::weight(T::WeightInfo::sweep_campaign(T::RemoveClaimsLimit::get()))]
+		pub fn sweep_campaign(
+			origin: OriginFor<T>,
+			campaign: CampaignId,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let mut details = Campaigns::<T>::get(campaign).ok_or(Error::<T>::UnknownCampaign)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() >= details.expiry,
+				Error::<T>::CampaignNotExpired
+			);
+
+			if !details.remaining.is_zero() {
+				let (imbalance, not_slashed) =
+					T::Currency::slash_reserved(&details.funder, details.remaining);
+				T::OnUnclaimed::on_unbalanced(imbalance);
+				let unclaimed = details.remaining.saturating_sub(not_slashed);
+				details.remaining = Zero::zero();
+				Self::deposit_event(Event::CampaignSwept { campaign, unclaimed });
+			}
+
+			let removed =
+				match Claims::<T>::remove_prefix(campaign, Some(T::RemoveClaimsLimit::get())) {
+					sp_io::KillStorageResult::AllRemoved(removed) => {
+						Campaigns::<T>::remove(campaign);
+						Self::deposit_event(Event::CampaignRemoved { campaign });
+						removed
+					},
+					sp_io::KillStorageResult::SomeRemaining(removed) => {
+						Campaigns::<T>::insert(campaign, details);
+						removed
+					},
+				};
+
+			Ok(Some(T::WeightInfo::sweep_campaign(removed)).into())
+		}
+	}
+
+	#[pallet::validate_unsigned]
+	impl<T: Config> ValidateUnsigned for Pallet<T> {
+		type Call = Call<T>;
+
+		fn validate_unsigned(_source: TransactionSource, call: &Self::Call) -> TransactionValidity {
+			if let Call::claim { campaign, who, amount, proof, signature } = call {
+				Self::check_claim(*campaign, who, *amount, proof, signature).map_err(|error| {
+					match error {
+						Error::<T>::CampaignExpired | Error::<T>::AlreadyClaimed =>
+							InvalidTransaction::Stale,
+						Error::<T>::InvalidProof | Error::<T>::InvalidSignature =>
+							InvalidTransaction::BadProof,
+						Error::<T>::UnknownCampaign => InvalidTransaction::Custom(UNKNOWN_CAMPAIGN),
+						Error::<T>::InsufficientFunds =>
+							InvalidTransaction::Custom(INSUFFICIENT_FUNDS),
+						// `CannotVest` is the only other error returned by `check_claim`.
+						_ => InvalidTransaction::Custom(CANNOT_VEST),
+					}
+				})?;
+
+				ValidTransaction::with_tag_prefix("MerkleClaims")
+					.priority(T::UnsignedPriority::get())
+					.and_provides((campaign, who))
+					.propagate(true)
+					.build()
+			} else {
+				InvalidTransaction::Call.into()
+			}
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The message the beneficiary `who` has to sign to claim `amount` from `campaign`.
+		pub fn claim_message(
+			campaign: CampaignId,
+			who: &T::AccountId,
+			amount: BalanceOf<T>,
+		) -> Vec<u8> {
+			(CLAIM_CONTEXT, campaign, who, amount).encode()
+		}
+
+		/// Create a campaign funded by `funder` and return its identifier.
+		pub(crate) fn do_create_campaign(
+			funder: T::AccountId,
+			merkle_root: T::Hash,
+			total: BalanceOf<T>,
+			expiry: T::BlockNumber,
+			vesting: Option<VestingTerms<T::BlockNumber>>,
+		) -> Result<CampaignId, DispatchError> {
+			ensure!(expiry > frame_system::Pallet::<T>::block_number(), Error::<T>::InvalidExpiry);
+
+			let campaign = NextCampaignId::<T>::get();
+			let next_campaign = campaign.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+			T::Currency::reserve(&funder, total)?;
+
+			NextCampaignId::<T>::put(next_campaign);
+			Campaigns::<T>::insert(
+				campaign,
+				Campaign { funder: funder.clone(), merkle_root, remaining: total, expiry, vesting },
+			);
+
+			Self::deposit_event(Event::CampaignCreated {
+				campaign,
+				funder,
+				merkle_root,
+				total,
+				expiry,
+			});
+			Ok(campaign)
+		}
+
+		/// Check that `who` may claim `amount` from `campaign` and return the campaign.
+		///
+		/// The signature is checked last, as it is the most expensive check.
+		fn check_claim(
+			campaign: CampaignId,
+			who: &T::AccountId,
+			amount: BalanceOf<T>,
+			proof: &[T::Hash],
+			signature: &T::OffchainSignature,
+		) -> Result<CampaignOf<T>, Error<T>> {
+			let details = Campaigns::<T>::get(campaign).ok_or(Error::<T>::UnknownCampaign)?;
+			ensure!(
+				frame_system::Pallet::<T>::block_number() < details.expiry,
+				Error::<T>::CampaignExpired
+			);
+			ensure!(!Claims::<T>::contains_key(campaign, who), Error::<T>::AlreadyClaimed);
+			ensure!(amount <= details.remaining, Error::<T>::InsufficientFunds);
+
+			let leaf = merkle::leaf_hash::<T::Hashing, _, _>(who, &amount);
+			ensure!(
+				merkle::verify::<T::Hashing>(&details.merkle_root, leaf, proof),
+				Error::<T>::InvalidProof
+			);
+
+			if let Some(terms) = details.vesting {
+				let per_block = Self::vested_per_block(amount, terms.length);
+				T::VestingSchedule::can_add_vesting_schedule(
+					who,
+					amount,
+					per_block,
+					terms.starting_block,
+				)
+				.map_err(|_| Error::<T>::CannotVest)?;
+			}
+
+			let message = Self::claim_message(campaign, who, amount);
+			ensure!(signature.verify(&*message, who), Error::<T>::InvalidSignature);
+
+			Ok(details)
+		}
+
+		/// The amount of a vested allocation of `amount` unlocked per block over `length` blocks.
+		fn vested_per_block(amount: BalanceOf<T>, length: T::BlockNumber) -> BalanceOf<T> {
+			let length = T::BlockNumberToBalance::convert(length).max(One::one());
+			(amount / length).max(One::one())
+		}
+	}
+}
@@ -0,0 +1,90 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The binary Merkle tree used to commit to the allocations of a campaign.
+//!
+//! Inner nodes hash the concatenation of their children in ascending order, so proofs don't need
+//! to state on which side a node is. A node without a sibling is promoted to the next level
+//! unchanged.
+
+use codec::Encode;
+use sp_runtime::traits::Hash;
+use sp_std::prelude::*;
+
+/// The leaf of the allocation of `amount` to `who`.
+pub fn leaf_hash<H: Hash, AccountId: Encode, Balance: Encode>(
+	who: &AccountId,
+	amount: &Balance,
+) -> H::Output {
+	H::hash_of(&(who, amount))
+}
+
+fn node_hash<H: Hash>(a: &H::Output, b: &H::Output) -> H::Output {
+	if a.as_ref() <= b.as_ref() {
+		H::hash_of(&(a, b))
+	} else {
+		H::hash_of(&(b, a))
+	}
+}
+
+fn next_level<H: Hash>(level: &[H::Output]) -> Vec<H::Output> {
+	level
+		.chunks(2)
+		.map(|pair| match pair {
+			[a, b] => node_hash::<H>(a, b),
+			[a] => *a,
+			_ => unreachable!("chunks are of length 1 or 2; qed"),
+		})
+		.collect()
+}
+
+/// The root of the tree over `leaves`. The root of an empty tree is the default hash.
+pub fn root<H: Hash>(leaves: &[H::Output]) -> H::Output {
+	let mut level = leaves.to_vec();
+	while level.len() > 1 {
+		level = next_level::<H>(&level);
+	}
+	level.pop().unwrap_or_default()
+}
+
+/// The proof of the leaf at `index` of `leaves`, from the bottom of the tree up. Empty if `index`
+/// is out of bounds.
+pub fn proof<H: Hash>(leaves: &[H::Output], mut index: usize) -> Vec<H::Output> {
+	let mut proof = Vec::new();
+	if index >= leaves.len() {
+		return proof
+	}
+	let mut level = leaves.to_vec();
+	while level.len() > 1 {
+		if let Some(sibling) = level.get(index ^ 1) {
+			proof.push(*sibling);
+		}
+		index /= 2;
+		level = next_level::<H>(&level);
+	}
+	proof
+}
+
+/// The root of the tree which `proof` proves `leaf` to be part of.
+pub fn root_from_proof<H: Hash>(leaf: H::Output, proof: &[H::Output]) -> H::Output {
+	proof.iter().fold(leaf, |node, sibling| node_hash::<H>(&node, sibling))
+}
+
+/// Whether `proof` proves that `leaf` is part of the tree with the given `root`.
+pub fn verify<H: Hash>(root: &H::Output, leaf: H::Output, proof: &[H::Output]) -> bool {
+	&root_from_proof::<H>(leaf, proof) == root
+}
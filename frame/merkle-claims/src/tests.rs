@@ -0,0 +1,342 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for Merkle claims pallet.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok, pallet_prelude::*, traits::GenesisBuild};
+use pallet_balances::Error as BalancesError;
+use sp_core::H256;
+use sp_runtime::{
+	testing::TestSignature,
+	traits::{BlakeTwo256, Hash},
+};
+
+const FUNDER: u64 = 1;
+
+fn last_event() -> mock::Event {
+	System::events().pop().expect("Event expected").event
+}
+
+fn allocations() -> Vec<(u64, u64)> {
+	vec![(10, 100), (11, 200), (12, 300)]
+}
+
+fn leaves() -> Vec<H256> {
+	allocations()
+		.iter()
+		.map(|(who, amount)| merkle::leaf_hash::<BlakeTwo256, _, _>(who, amount))
+		.collect()
+}
+
+fn merkle_root() -> H256 {
+	merkle::root::<BlakeTwo256>(&leaves())
+}
+
+fn claim_args(
+	campaign: CampaignId,
+	index: usize,
+) -> (u64, u64, BoundedVec<H256, ConstU32<8>>, TestSignature) {
+	let (who, amount) = allocations()[index];
+	let proof = merkle::proof::<BlakeTwo256>(&leaves(), index).try_into().unwrap();
+	let signature = TestSignature(who, MerkleClaims::claim_message(campaign, &who, amount));
+	(who, amount, proof, signature)
+}
+
+fn claim_call(campaign: CampaignId, index: usize) -> crate::Call<Test> {
+	let (who, amount, proof, signature) = claim_args(campaign, index);
+	crate::Call::claim { campaign, who, amount, proof, signature }
+}
+
+fn claim(campaign: CampaignId, index: usize) -> DispatchResult {
+	let (who, amount, proof, signature) = claim_args(campaign, index);
+	MerkleClaims::claim(Origin::none(), campaign, who, amount, proof, signature)
+}
+
+fn validate(call: &crate::Call<Test>) -> TransactionValidity {
+	<MerkleClaims as ValidateUnsigned>::validate_unsigned(TransactionSource::External, call)
+}
+
+#[test]
+fn merkle_proofs_work() {
+	for size in 1..=7usize {
+		let leaves: Vec<H256> = (0..size as u64).map(|i| BlakeTwo256::hash_of(&i)).collect();
+		let root = merkle::root::<BlakeTwo256>(&leaves);
+		let unknown_leaf = BlakeTwo256::hash_of(&(size as u64));
+		for (index, leaf) in leaves.iter().enumerate() {
+			let proof = merkle::proof::<BlakeTwo256>(&leaves, index);
+			assert!(merkle::verify::<BlakeTwo256>(&root, *leaf, &proof));
+			assert!(!merkle::verify::<BlakeTwo256>(&root, unknown_leaf, &proof));
+		}
+		assert!(merkle::proof::<BlakeTwo256>(&leaves, size).is_empty());
+	}
+	assert_eq!(merkle::root::<BlakeTwo256>(&[]), H256::default());
+}
+
+#[test]
+fn create_campaign_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			MerkleClaims::create_campaign(Origin::signed(FUNDER), merkle_root(), 600, 1, None),
+			Error::<Test>::InvalidExpiry
+		);
+		assert_noop!(
+			MerkleClaims::create_campaign(Origin::signed(2), merkle_root(), 600, 10, None),
+			BalancesError::<Test>::InsufficientBalance
+		);
+
+		assert_ok!(MerkleClaims::create_campaign(
+			Origin::signed(FUNDER),
+			merkle_root(),
+			600,
+			10,
+			None
+		));
+		assert_eq!(Balances::reserved_balance(FUNDER), 600);
+		assert_eq!(
+			Campaigns::<Test>::get(0),
+			Some(Campaign {
+				funder: FUNDER,
+				merkle_root: merkle_root(),
+				remaining: 600,
+				expiry: 10,
+				vesting: None
+			})
+		);
+		assert_eq!(NextCampaignId::<Test>::get(), 1);
+		assert_eq!(
+			last_event(),
+			mock::Event::MerkleClaims(crate::Event::CampaignCreated {
+				campaign: 0,
+				funder: FUNDER,
+				merkle_root: merkle_root(),
+				total: 600,
+				expiry: 10,
+			})
+		);
+	});
+}
+
+#[test]
+fn claim_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(claim(0, 1), Error::<Test>::UnknownCampaign);
+		assert_ok!(MerkleClaims::create_campaign(
+			Origin::signed(FUNDER),
+			merkle_root(),
+			600,
+			10,
+			None
+		));
+
+		assert_ok!(claim(0, 1));
+		assert_eq!(Balances::free_balance(11), 200);
+		assert_eq!(Balances::free_balance(FUNDER), 400);
+		assert_eq!(Balances::reserved_balance(FUNDER), 400);
+		assert_eq!(Campaigns::<Test>::get(0).unwrap().remaining, 400);
+		assert!(Claims::<Test>::contains_key(0, 11));
+		assert_eq!(
+			last_event(),
+			mock::Event::MerkleClaims(crate::Event::Claimed { campaign: 0, who: 11, amount: 200 })
+		);
+
+		assert_noop!(claim(0, 1), Error::<Test>::AlreadyClaimed);
+
+		// The amount is part of the leaf.
+		let proof: BoundedVec<_, _> =
+			merkle::proof::<BlakeTwo256>(&leaves(), 0).try_into().unwrap();
+		let signature = TestSignature(10, MerkleClaims::claim_message(0, &10, 101));
+		assert_noop!(
+			MerkleClaims::claim(Origin::none(), 0, 10, 101, proof.clone(), signature),
+			Error::<Test>::InvalidProof
+		);
+		// The claim must be signed by the beneficiary.
+		let signature = TestSignature(11, MerkleClaims::claim_message(0, &10, 100));
+		assert_noop!(
+			MerkleClaims::claim(Origin::none(), 0, 10, 100, proof.clone(), signature),
+			Error::<Test>::InvalidSignature
+		);
+		let signature = TestSignature(10, MerkleClaims::claim_message(0, &10, 100));
+		assert_noop!(
+			MerkleClaims::claim(Origin::signed(10), 0, 10, 100, proof, signature),
+			DispatchError::BadOrigin
+		);
+	});
+}
+
+#[test]
+fn claim_should_not_exceed_campaign_total() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MerkleClaims::create_campaign(
+			Origin::signed(FUNDER),
+			merkle_root(),
+			450,
+			10,
+			None
+		));
+
+		assert_ok!(claim(0, 1));
+		assert_noop!(claim(0, 2), Error::<Test>::InsufficientFunds);
+		assert_ok!(claim(0, 0));
+		assert_eq!(Balances::reserved_balance(FUNDER), 150);
+	});
+}
+
+#[test]
+fn claim_with_vesting_should_work() {
+	new_test_ext().execute_with(|| {
+		let vesting = VestingTerms { starting_block: 5, length: 10 };
+		assert_ok!(MerkleClaims::create_campaign(
+			Origin::signed(FUNDER),
+			merkle_root(),
+			300,
+			20,
+			Some(vesting)
+		));
+
+		assert_ok!(claim(0, 2));
+		assert_eq!(Balances::free_balance(12), 300);
+		assert_eq!(Vesting::vesting_balance(&12), Some(300));
+
+		System::set_block_number(10);
+		assert_eq!(Vesting::vesting_balance(&12), Some(150));
+
+		// The beneficiary can take on at most two vesting schedules.
+		assert_ok!(MerkleClaims::create_campaign(
+			Origin::signed(FUNDER),
+			merkle_root(),
+			300,
+			20,
+			Some(vesting)
+		));
+		assert_ok!(claim(1, 2));
+		assert_ok!(MerkleClaims::create_campaign(
+			Origin::signed(FUNDER),
+			merkle_root(),
+			300,
+			20,
+			Some(vesting)
+		));
+		assert_noop!(claim(2, 2), Error::<Test>::CannotVest);
+	});
+}
+
+#[test]
+fn validate_unsigned_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_eq!(validate(&claim_call(0, 0)), InvalidTransaction::Custom(1).into());
+		assert_ok!(MerkleClaims::create_campaign(
+			Origin::signed(FUNDER),
+			merkle_root(),
+			600,
+			10,
+			None
+		));
+
+		assert_eq!(
+			validate(&claim_call(0, 0)),
+			Ok(ValidTransaction {
+				priority: 100,
+				requires: vec![],
+				provides: vec![("MerkleClaims", 0u32, 10u64).encode()],
+				longevity: TransactionLongevity::max_value(),
+				propagate: true,
+			})
+		);
+
+		let mut call = claim_call(0, 0);
+		if let crate::Call::claim { ref mut signature, .. } = call {
+			signature.0 = 11;
+		}
+		assert_eq!(validate(&call), InvalidTransaction::BadProof.into());
+		let mut call = claim_call(0, 0);
+		if let crate::Call::claim { ref mut amount, .. } = call {
+			*amount = 101;
+		}
+		assert_eq!(validate(&call), InvalidTransaction::BadProof.into());
+
+		assert_ok!(claim(0, 0));
+		assert_eq!(validate(&claim_call(0, 0)), InvalidTransaction::Stale.into());
+
+		System::set_block_number(10);
+		assert_eq!(validate(&claim_call(0, 1)), InvalidTransaction::Stale.into());
+		assert_noop!(claim(0, 1), Error::<Test>::CampaignExpired);
+	});
+}
+
+#[test]
+fn sweep_campaign_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(MerkleClaims::create_campaign(
+			Origin::signed(FUNDER),
+			merkle_root(),
+			600,
+			10,
+			None
+		));
+		assert_ok!(claim(0, 1));
+
+		assert_noop!(
+			MerkleClaims::sweep_campaign(Origin::signed(3), 0),
+			Error::<Test>::CampaignNotExpired
+		);
+
+		System::set_block_number(10);
+		assert_ok!(MerkleClaims::sweep_campaign(Origin::signed(3), 0));
+		assert_eq!(Balances::free_balance(TREASURY), 400);
+		assert_eq!(Balances::reserved_balance(FUNDER), 0);
+		assert_eq!(Balances::free_balance(FUNDER), 400);
+		assert!(Campaigns::<Test>::get(0).is_none());
+		assert!(!Claims::<Test>::contains_key(0, 11));
+		System::assert_has_event(
+			crate::Event::CampaignSwept { campaign: 0, unclaimed: 400 }.into(),
+		);
+		System::assert_last_event(crate::Event::CampaignRemoved { campaign: 0 }.into());
+
+		assert_noop!(
+			MerkleClaims::sweep_campaign(Origin::signed(3), 0),
+			Error::<Test>::UnknownCampaign
+		);
+		assert_noop!(claim(0, 2), Error::<Test>::UnknownCampaign);
+	});
+}
+
+#[test]
+fn genesis_campaigns_should_work() {
+	let mut t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+	pallet_balances::GenesisConfig::<Test> { balances: vec![(FUNDER, 1000)] }
+		.assimilate_storage(&mut t)
+		.unwrap();
+	let vesting = VestingTerms { starting_block: 5, length: 10 };
+	crate::GenesisConfig::<Test> {
+		campaigns: vec![(FUNDER, merkle_root(), 600, 10, Some(vesting))],
+	}
+	.assimilate_storage(&mut t)
+	.unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| {
+		System::set_block_number(1);
+		assert_eq!(Balances::reserved_balance(FUNDER), 600);
+		assert_eq!(Campaigns::<Test>::get(0).unwrap().vesting, Some(vesting));
+
+		assert_ok!(claim(0, 0));
+		assert_eq!(Balances::free_balance(10), 100);
+		assert_eq!(Vesting::vesting_balance(&10), Some(100));
+	});
+}
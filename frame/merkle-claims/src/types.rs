@@ -0,0 +1,63 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Various basic types for use in the Merkle claims pallet.
+
+use super::*;
+use frame_support::pallet_prelude::*;
+#[cfg(feature = "std")]
+use serde::{Deserialize, Serialize};
+
+/// Identifier of a campaign.
+pub type CampaignId = u32;
+
+pub(super) type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+pub(super) type NegativeImbalanceOf<T> = <<T as Config>::Currency as Currency<
+	<T as frame_system::Config>::AccountId,
+>>::NegativeImbalance;
+pub(super) type CampaignOf<T> = Campaign<
+	<T as frame_system::Config>::AccountId,
+	BalanceOf<T>,
+	<T as frame_system::Config>::BlockNumber,
+	<T as frame_system::Config>::Hash,
+>;
+
+/// The vesting applied to the allocations of a campaign when they are claimed.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+#[cfg_attr(feature = "std", derive(Serialize, Deserialize))]
+pub struct VestingTerms<BlockNumber> {
+	/// The block from which a claimed allocation starts to unlock.
+	pub starting_block: BlockNumber,
+	/// The number of blocks over which a claimed allocation unlocks linearly.
+	pub length: BlockNumber,
+}
+
+/// Information about a campaign.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Campaign<AccountId, Balance, BlockNumber, Hash> {
+	/// The account from which the allocations are paid.
+	pub funder: AccountId,
+	/// The root of the Merkle tree over the allocations.
+	pub merkle_root: Hash,
+	/// The amount reserved from the funder which has not been claimed yet.
+	pub remaining: Balance,
+	/// The block from which the campaign can no longer be claimed and may be swept.
+	pub expiry: BlockNumber,
+	/// The vesting applied to claimed allocations, if any.
+	pub vesting: Option<VestingTerms<BlockNumber>>,
+}
@@ -0,0 +1,103 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Placeholder weights for pallet_merkle_claims
+//!
+//! These weights are NOT the output of the benchmark CLI. They are estimates based on the storage
+//! accesses of each call and must be replaced by running the benchmarks of the pallet with the
+//! benchmark CLI before the pallet is used in production.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_merkle_claims.
+pub trait WeightInfo {
+	fn create_campaign() -> Weight;
+	fn claim(p: u32) -> Weight;
+	fn sweep_campaign(c: u32) -> Weight;
+}
+
+/// Weights for pallet_merkle_claims using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: MerkleClaims NextCampaignId (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: MerkleClaims Campaigns (r:0 w:1)
+	fn create_campaign() -> Weight {
+		(38_204_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: MerkleClaims Campaigns (r:1 w:1)
+	// Storage: MerkleClaims Claimed (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	fn claim(p: u32) -> Weight {
+		(97_581_000 as Weight)
+			.saturating_add((482_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(6 as Weight))
+			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+	}
+	// Storage: MerkleClaims Campaigns (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: MerkleClaims Claimed (r:0 w:1)
+	fn sweep_campaign(c: u32) -> Weight {
+		(33_910_000 as Weight)
+			.saturating_add((1_127_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: MerkleClaims NextCampaignId (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: MerkleClaims Campaigns (r:0 w:1)
+	fn create_campaign() -> Weight {
+		(38_204_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: MerkleClaims Campaigns (r:1 w:1)
+	// Storage: MerkleClaims Claimed (r:1 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Vesting Vesting (r:1 w:1)
+	// Storage: Balances Locks (r:1 w:1)
+	fn claim(p: u32) -> Weight {
+		(97_581_000 as Weight)
+			.saturating_add((482_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(6 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+	}
+	// Storage: MerkleClaims Campaigns (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: MerkleClaims Claimed (r:0 w:1)
+	fn sweep_campaign(c: u32) -> Weight {
+		(33_910_000 as Weight)
+			.saturating_add((1_127_000 as Weight).saturating_mul(c as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(c as Weight)))
+	}
+}
@@ -0,0 +1,122 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Message queue pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{benchmarks, whitelisted_caller};
+use frame_support::traits::EnqueueMessage;
+use frame_system::RawOrigin;
+use sp_std::prelude::*;
+
+use crate::Pallet as MessageQueue;
+
+/// A message of the maximum length.
+fn max_message<T: Config>() -> Vec<u8> {
+	// Bounded slices must be shorter than their bound.
+	vec![1u8; MaxMessageLen::<T>::get().saturating_sub(1) as usize]
+}
+
+/// Enqueue a message of the maximum length for `origin`.
+fn enqueue_max_message<T: Config>(origin: MessageOriginOf<T>) {
+	let message = max_message::<T>();
+	let message = BoundedSlice::try_from(&message[..]).expect("message is within the bound");
+	MessageQueue::<T>::enqueue_message(message, origin);
+}
+
+benchmarks! {
+	where_clause {
+		where MessageOriginOf<T>: From<u32>
+	}
+
+	// A ready ring of two queues, so that the head moves on to the other queue.
+	bump_service_head {
+		enqueue_max_message::<T>(0.into());
+		enqueue_max_message::<T>(1.into());
+		let mut weight = WeightCounter::from_limit(Weight::max_value());
+	}: {
+		MessageQueue::<T>::bump_service_head(&mut weight);
+	}
+	verify {
+		assert_eq!(ServiceHead::<T>::get(), Some(1.into()));
+	}
+
+	// The weight only allows reading and writing the state of the queue.
+	service_queue_base {
+		enqueue_max_message::<T>(0.into());
+		let mut weight = WeightCounter::from_limit(T::WeightInfo::service_queue_base());
+	}: {
+		MessageQueue::<T>::service_queue(0.into(), &mut weight, Weight::max_value());
+	}
+
+	// The weight only allows reading and writing a full page.
+	service_page_base {
+		let origin: MessageOriginOf<T> = 0.into();
+		enqueue_max_message::<T>(origin.clone());
+		let mut book_state = BookStateFor::<T>::get(&origin);
+		let mut weight = WeightCounter::from_limit(T::WeightInfo::service_page_base());
+	}: {
+		MessageQueue::<T>::service_page(&origin, &mut book_state, &mut weight, Weight::max_value());
+	}
+
+	// Process a message of the maximum length.
+	service_page_item {
+		let origin: MessageOriginOf<T> = 0.into();
+		enqueue_max_message::<T>(origin.clone());
+		let mut book_state = BookStateFor::<T>::get(&origin);
+		let mut page = Pages::<T>::get(&origin, 0).expect("the message was enqueued");
+		let mut weight = WeightCounter::from_limit(Weight::max_value());
+	}: {
+		MessageQueue::<T>::service_page_item(
+			&origin,
+			0,
+			&mut book_state,
+			&mut page,
+			&mut weight,
+			Weight::max_value(),
+		);
+	}
+	verify {
+		assert!(page.is_complete());
+	}
+
+	// Execute a parked message of the maximum length.
+	execute_overweight {
+		let origin: MessageOriginOf<T> = 0.into();
+		let message = max_message::<T>();
+		let mut page = Page::<T::HeapSize>::from_message(&message);
+		page.skip_first(false);
+		Pages::<T>::insert(&origin, 0, page);
+		BookStateFor::<T>::insert(&origin, BookState {
+			begin: 1,
+			end: 1,
+			count: 1,
+			ready_neighbours: None,
+			message_count: 1,
+			size: message.len() as u64,
+		});
+		let caller: T::AccountId = whitelisted_caller();
+	}: _(RawOrigin::Signed(caller), origin.clone(), 0, 0, Weight::max_value())
+	verify {
+		assert!(!Pages::<T>::contains_key(&origin, 0));
+		assert_eq!(BookStateFor::<T>::get(&origin).message_count, 0);
+	}
+
+	impl_benchmark_test_suite!(MessageQueue, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,713 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Message Queue Module
+//!
+//! Stores messages in paged queues, one per origin, and processes them with a
+//! [`Config::MessageProcessor`] in the hooks of the pallet, within bounded weight.
+//!
+//! ## Overview
+//!
+//! Other pallets enqueue messages through the [`EnqueueMessage`] implementation of this pallet.
+//! The messages of an origin are stored on pages of [`Config::HeapSize`] bytes. Queues with
+//! messages which were not serviced yet are ready, and form a ring which is serviced round-robin,
+//! starting with a different queue on each service.
+//!
+//! The queues are serviced:
+//! - in `on_initialize`, with up to [`Config::ServiceWeight`], which is reserved for the queues in
+//!   each block;
+//! - in `on_idle`, with the weight which is left in the block.
+//!
+//! A message which needs more weight than is left in a service stays in its queue, which
+//! continues to be serviced with the next message of another queue. A message which needs more
+//! weight than any service of [`Config::ServiceWeight`] could give it is overweight. It is parked
+//! on its page and can be executed with [`Pallet::execute_overweight`] by any signed account,
+//! which pays for the weight.
+//!
+//! ## Related Modules
+//!
+//! * [`System`](../frame_system/index.html)
+//! * [`Support`](../frame_support/index.html)
+
+// Ensure we're `no_std` when compiling for Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+pub mod mock;
+#[cfg(any(test, feature = "runtime-benchmarks"))]
+pub mod mock_helpers;
+#[cfg(test)]
+mod tests;
+
+mod types;
+pub mod weights;
+
+use codec::MaxEncodedLen;
+use frame_support::{
+	ensure,
+	traits::{EnqueueMessage, Footprint, Get, ProcessMessage, ProcessMessageError},
+	weights::Weight,
+	BoundedSlice,
+};
+use sp_runtime::traits::{Hash, Saturating};
+use sp_std::marker::PhantomData;
+
+pub use pallet::*;
+pub use types::*;
+pub use weights::WeightInfo;
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	/// The module configuration trait.
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// Processes the messages of the queues.
+		type MessageProcessor: ProcessMessage;
+
+		/// The size of the heap of a page in bytes.
+		///
+		/// A message and its header need to fit on a single page.
+		#[pallet::constant]
+		type HeapSize: Get<u32>;
+
+		/// The weight reserved for servicing the queues in `on_initialize` of each block.
+		///
+		/// Messages which need more than this to be processed are overweight. Without it, no
+		/// weight is reserved and the queues are only serviced in `on_idle`. Messages are then
+		/// overweight if they need more than the maximum weight of a block.
+		#[pallet::constant]
+		type ServiceWeight: Get<Option<Weight>>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::storage]
+	/// The state of the queue of each origin.
+	pub type BookStateFor<T: Config> =
+		StorageMap<_, Twox64Concat, MessageOriginOf<T>, BookState<MessageOriginOf<T>>, ValueQuery>;
+
+	#[pallet::storage]
+	/// The ready queue which is serviced first on the next service.
+	pub type ServiceHead<T: Config> = StorageValue<_, MessageOriginOf<T>, OptionQuery>;
+
+	#[pallet::storage]
+	/// The pages of the queues, by origin and index.
+	pub type Pages<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		MessageOriginOf<T>,
+		Twox64Concat,
+		PageIndex,
+		Page<T::HeapSize>,
+		OptionQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The message with hash `hash` couldn't be processed and was dropped.
+		ProcessingFailed { hash: T::Hash, origin: MessageOriginOf<T>, error: ProcessMessageError },
+		/// The message with hash `hash` was processed, successfully or not.
+		Processed { hash: T::Hash, origin: MessageOriginOf<T>, weight_used: Weight, success: bool },
+		/// The message with hash `hash` is overweight and was parked.
+		OverweightEnqueued {
+			hash: T::Hash,
+			origin: MessageOriginOf<T>,
+			page_index: PageIndex,
+			message_index: u32,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The page doesn't exist.
+		NoPage,
+		/// The message doesn't exist.
+		NoMessage,
+		/// The message was already processed.
+		AlreadyProcessed,
+		/// The message is still queued and wasn't found to be overweight.
+		Queued,
+		/// The weight limit is not enough to process the message.
+		InsufficientWeight,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Service the queues with the reserved `T::ServiceWeight`.
+		fn on_initialize(_n: T::BlockNumber) -> Weight {
+			match T::ServiceWeight::get() {
+				Some(weight_limit) => Self::service_queues(weight_limit),
+				None => 0,
+			}
+		}
+
+		/// Service the queues with the weight which is left in the block.
+		fn on_idle(_n: T::BlockNumber, remaining_weight: Weight) -> Weight {
+			Self::service_queues(remaining_weight)
+		}
+
+		#[cfg(feature = "try-runtime")]
+		fn post_upgrade() -> Result<(), &'static str> {
+			Self::do_try_state()
+		}
+
+		fn integrity_test() {
+			assert!(
+				MaxMessageLen::<T>::get() > 0,
+				"the heap of a page must be larger than the header of a message"
+			);
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Execute an overweight message.
+		///
+		/// Origin must be Signed. The sender pays for up to `weight_limit` of weight for
+		/// processing the message.
+		///
+		/// - `message_origin`: The origin of the message.
+		/// - `page`: The index of the page of the message.
+		/// - `index`: The index of the message on the page.
+		/// - `weight_limit`: The maximum weight processing the message may use.
+		///
+		/// Emits `Processed` or `ProcessingFailed` event when successful.
+		///
+		/// Weight: `O(1)` plus the weight used for processing the message.
+		#[pallet::weight(T::WeightInfo::execute_overweight().saturating_add(*weight_limit))]
+		pub fn execute_overweight(
+			origin: OriginFor<T>,
+			message_origin: MessageOriginOf<T>,
+			page: PageIndex,
+			index: u32,
+			#[pallet::compact] weight_limit: Weight,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+
+			let weight_used =
+				Self::do_execute_overweight(message_origin, page, index, weight_limit)?;
+
+			Ok(Some(T::WeightInfo::execute_overweight().saturating_add(weight_used)).into())
+		}
+	}
+}
+
+/// The maximum length of a message, which is the size of the heap of a page less the header of
+/// the message.
+pub struct MaxMessageLen<T>(PhantomData<T>);
+impl<T: Config> Get<u32> for MaxMessageLen<T> {
+	fn get() -> u32 {
+		T::HeapSize::get().saturating_sub(ItemHeader::max_encoded_len() as u32)
+	}
+}
+
+impl<T: Config> Pallet<T> {
+	/// Append `message` to the queue of `origin`, and add the queue to the ready ring unless it is
+	/// already ready.
+	fn do_enqueue_message(origin: &MessageOriginOf<T>, message: &[u8]) {
+		let mut book_state = BookStateFor::<T>::get(origin);
+		book_state.message_count.saturating_inc();
+		book_state.size.saturating_accrue(message.len() as u64);
+
+		if book_state.ready_neighbours.is_none() {
+			match Self::ready_ring_knit(origin) {
+				Ok(neighbours) => book_state.ready_neighbours = Some(neighbours),
+				Err(()) => debug_assert!(false, "the ready ring must be consistent"),
+			}
+		}
+
+		if book_state.end > book_state.begin {
+			let last = book_state.end.saturating_sub(1);
+			if let Some(mut page) = Pages::<T>::get(origin, last) {
+				if page.try_append_message(message).is_ok() {
+					Pages::<T>::insert(origin, last, page);
+					BookStateFor::<T>::insert(origin, book_state);
+					return
+				}
+			}
+		}
+
+		Pages::<T>::insert(origin, book_state.end, Page::from_message(message));
+		book_state.end.saturating_inc();
+		book_state.count.saturating_inc();
+		BookStateFor::<T>::insert(origin, book_state);
+	}
+
+	/// Insert `origin` into the ready ring, before the current head, and return its neighbours.
+	///
+	/// `origin` must not be in the ring yet.
+	fn ready_ring_knit(origin: &MessageOriginOf<T>) -> Result<Neighbours<MessageOriginOf<T>>, ()> {
+		let head = match ServiceHead::<T>::get() {
+			Some(head) => head,
+			None => {
+				ServiceHead::<T>::put(origin);
+				return Ok(Neighbours { prev: origin.clone(), next: origin.clone() })
+			},
+		};
+
+		let mut head_book_state = BookStateFor::<T>::get(&head);
+		let mut head_neighbours = head_book_state.ready_neighbours.take().ok_or(())?;
+		let tail = head_neighbours.prev;
+		head_neighbours.prev = origin.clone();
+		head_book_state.ready_neighbours = Some(head_neighbours);
+		BookStateFor::<T>::insert(&head, head_book_state);
+
+		// The tail is read after the head was written, as they are the same for a single queue.
+		let mut tail_book_state = BookStateFor::<T>::get(&tail);
+		let mut tail_neighbours = tail_book_state.ready_neighbours.take().ok_or(())?;
+		tail_neighbours.next = origin.clone();
+		tail_book_state.ready_neighbours = Some(tail_neighbours);
+		BookStateFor::<T>::insert(&tail, tail_book_state);
+
+		Ok(Neighbours { prev: tail, next: head })
+	}
+
+	/// Remove `origin` with `neighbours` from the ready ring.
+	fn ready_ring_unknit(origin: &MessageOriginOf<T>, neighbours: Neighbours<MessageOriginOf<T>>) {
+		if origin == &neighbours.next {
+			debug_assert!(origin == &neighbours.prev, "a single queue is its own neighbour");
+			ServiceHead::<T>::kill();
+			return
+		}
+
+		BookStateFor::<T>::mutate(&neighbours.next, |book_state| {
+			if let Some(ref mut next_neighbours) = book_state.ready_neighbours {
+				next_neighbours.prev = neighbours.prev.clone();
+			}
+		});
+		BookStateFor::<T>::mutate(&neighbours.prev, |book_state| {
+			if let Some(ref mut prev_neighbours) = book_state.ready_neighbours {
+				prev_neighbours.next = neighbours.next.clone();
+			}
+		});
+		if ServiceHead::<T>::get().as_ref() == Some(origin) {
+			ServiceHead::<T>::put(neighbours.next);
+		}
+	}
+
+	/// Move the service head to the next ready queue and return the previous head.
+	pub(crate) fn bump_service_head(weight: &mut WeightCounter) -> Option<MessageOriginOf<T>> {
+		if !weight.check_accrue(T::WeightInfo::bump_service_head()) {
+			return None
+		}
+
+		let head = ServiceHead::<T>::get()?;
+		let neighbours = BookStateFor::<T>::get(&head).ready_neighbours?;
+		ServiceHead::<T>::put(neighbours.next);
+		Some(head)
+	}
+
+	/// The weight needed to service a single message, besides processing it.
+	fn single_message_overhead() -> Weight {
+		T::WeightInfo::bump_service_head()
+			.saturating_add(T::WeightInfo::service_queue_base())
+			.saturating_add(T::WeightInfo::service_page_base())
+			.saturating_add(T::WeightInfo::service_page_item())
+	}
+
+	/// The maximum weight a message may need to be processed, before it is overweight.
+	pub fn overweight_limit() -> Weight {
+		T::ServiceWeight::get()
+			.unwrap_or_else(|| T::BlockWeights::get().max_block)
+			.saturating_sub(Self::single_message_overhead())
+	}
+
+	/// Service the ready queues round-robin, using no more than `weight_limit`, and return the
+	/// weight used.
+	pub(crate) fn service_queues(weight_limit: Weight) -> Weight {
+		let mut weight = WeightCounter::from_limit(weight_limit);
+		let overweight_limit = Self::overweight_limit();
+
+		let mut next = match Self::bump_service_head(&mut weight) {
+			Some(head) => head,
+			None => return weight.used,
+		};
+		// The first queue of a sequence of queues without progress. Servicing stops once it is
+		// reached again without any progress in between.
+		let mut last_no_progress = None;
+		loop {
+			let (progressed, maybe_next) =
+				Self::service_queue(next.clone(), &mut weight, overweight_limit);
+			next = match maybe_next {
+				Some(maybe_next) if progressed => {
+					last_no_progress = None;
+					maybe_next
+				},
+				Some(maybe_next) => {
+					if last_no_progress.as_ref() == Some(&maybe_next) {
+						break
+					}
+					if last_no_progress.is_none() {
+						last_no_progress = Some(next.clone());
+					}
+					maybe_next
+				},
+				None => break,
+			}
+		}
+		weight.used
+	}
+
+	/// Service the queue of `origin` until it is empty or the weight runs out.
+	///
+	/// Returns whether any message was processed and the next ready queue.
+	pub(crate) fn service_queue(
+		origin: MessageOriginOf<T>,
+		weight: &mut WeightCounter,
+		overweight_limit: Weight,
+	) -> (bool, Option<MessageOriginOf<T>>) {
+		if !weight.check_accrue(T::WeightInfo::service_queue_base()) {
+			return (false, None)
+		}
+
+		let mut book_state = BookStateFor::<T>::get(&origin);
+		let mut total_processed = 0u32;
+		while book_state.end > book_state.begin {
+			let (processed, status) =
+				Self::service_page(&origin, &mut book_state, weight, overweight_limit);
+			total_processed.saturating_accrue(processed);
+			if status == PageExecutionStatus::Bailed {
+				break
+			}
+		}
+
+		let next_ready = book_state.ready_neighbours.as_ref().map(|n| n.next.clone());
+		if book_state.begin >= book_state.end {
+			// The queue has no messages left to service, so it is no longer ready.
+			if let Some(neighbours) = book_state.ready_neighbours.take() {
+				Self::ready_ring_unknit(&origin, neighbours);
+			}
+		}
+		BookStateFor::<T>::insert(&origin, &book_state);
+		(total_processed > 0, next_ready)
+	}
+
+	/// Service the first page of the queue of `origin` until all its messages are serviced or the
+	/// weight runs out.
+	///
+	/// Returns the number of processed messages.
+	pub(crate) fn service_page(
+		origin: &MessageOriginOf<T>,
+		book_state: &mut BookState<MessageOriginOf<T>>,
+		weight: &mut WeightCounter,
+		overweight_limit: Weight,
+	) -> (u32, PageExecutionStatus) {
+		if !weight.check_accrue(T::WeightInfo::service_page_base()) {
+			return (0, PageExecutionStatus::Bailed)
+		}
+
+		let page_index = book_state.begin;
+		let mut page = match Pages::<T>::get(origin, page_index) {
+			Some(page) => page,
+			None => {
+				debug_assert!(false, "the pages from `begin` to `end` must exist");
+				book_state.begin.saturating_inc();
+				return (0, PageExecutionStatus::NoMore)
+			},
+		};
+
+		let mut total_processed = 0u32;
+		let status = loop {
+			match Self::service_page_item(
+				origin,
+				page_index,
+				book_state,
+				&mut page,
+				weight,
+				overweight_limit,
+			) {
+				ItemExecutionStatus::Bailed => break PageExecutionStatus::Bailed,
+				ItemExecutionStatus::NoItem => break PageExecutionStatus::NoMore,
+				ItemExecutionStatus::Executed(true) => total_processed.saturating_inc(),
+				ItemExecutionStatus::Executed(false) => (),
+			}
+		};
+
+		if page.is_complete() {
+			book_state.begin.saturating_inc();
+		}
+		if page.remaining == 0 {
+			Pages::<T>::remove(origin, page_index);
+			book_state.count.saturating_dec();
+		} else {
+			// Complete pages are kept for their overweight messages.
+			Pages::<T>::insert(origin, page_index, page);
+		}
+		(total_processed, status)
+	}
+
+	/// Service the first message of `page` which was not serviced yet.
+	pub(crate) fn service_page_item(
+		origin: &MessageOriginOf<T>,
+		page_index: PageIndex,
+		book_state: &mut BookState<MessageOriginOf<T>>,
+		page: &mut Page<T::HeapSize>,
+		weight: &mut WeightCounter,
+		overweight_limit: Weight,
+	) -> ItemExecutionStatus {
+		let (header, message) = match page.peek_first() {
+			Some(item) => item,
+			None => return ItemExecutionStatus::NoItem,
+		};
+		if !weight.check_accrue(T::WeightInfo::service_page_item()) {
+			return ItemExecutionStatus::Bailed
+		}
+
+		let is_processed = match Self::process_message_payload(
+			origin.clone(),
+			page_index,
+			page.first_index,
+			message,
+			weight,
+			overweight_limit,
+		) {
+			MessageExecutionStatus::InsufficientWeight => return ItemExecutionStatus::Bailed,
+			MessageExecutionStatus::Overweight => false,
+			MessageExecutionStatus::Processed | MessageExecutionStatus::Unprocessable => true,
+		};
+		if is_processed {
+			book_state.message_count.saturating_dec();
+			book_state.size.saturating_reduce(header.payload_len as u64);
+		}
+		page.skip_first(is_processed);
+		ItemExecutionStatus::Executed(is_processed)
+	}
+
+	/// Process `message` with the weight left in `weight`.
+	///
+	/// A message which needs more than `overweight_limit` is parked as overweight.
+	fn process_message_payload(
+		origin: MessageOriginOf<T>,
+		page_index: PageIndex,
+		message_index: u32,
+		message: &[u8],
+		weight: &mut WeightCounter,
+		overweight_limit: Weight,
+	) -> MessageExecutionStatus {
+		let hash = T::Hashing::hash(message);
+		match T::MessageProcessor::process_message(message, origin.clone(), weight.remaining()) {
+			Err(ProcessMessageError::Overweight(required)) if required > overweight_limit => {
+				Self::deposit_event(Event::OverweightEnqueued {
+					hash,
+					origin,
+					page_index,
+					message_index,
+				});
+				MessageExecutionStatus::Overweight
+			},
+			Err(ProcessMessageError::Overweight(_)) => MessageExecutionStatus::InsufficientWeight,
+			Err(error) => {
+				Self::deposit_event(Event::ProcessingFailed { hash, origin, error });
+				MessageExecutionStatus::Unprocessable
+			},
+			Ok((success, weight_used)) => {
+				weight.saturating_accrue(weight_used);
+				Self::deposit_event(Event::Processed { hash, origin, weight_used, success });
+				MessageExecutionStatus::Processed
+			},
+		}
+	}
+
+	/// Process the overweight message `index` on the page `page_index` of the queue of `origin`,
+	/// using no more than `weight_limit`, and return the weight used.
+	pub(crate) fn do_execute_overweight(
+		origin: MessageOriginOf<T>,
+		page_index: PageIndex,
+		index: u32,
+		weight_limit: Weight,
+	) -> Result<Weight, Error<T>> {
+		let mut book_state = BookStateFor::<T>::get(&origin);
+		let mut page = Pages::<T>::get(&origin, page_index).ok_or(Error::<T>::NoPage)?;
+		let (pos, header, message) = page.peek_index(index).ok_or(Error::<T>::NoMessage)?;
+		ensure!(
+			page_index < book_state.begin ||
+				(page_index == book_state.begin && index < page.first_index),
+			Error::<T>::Queued
+		);
+		ensure!(!header.is_processed, Error::<T>::AlreadyProcessed);
+
+		let mut weight = WeightCounter::from_limit(weight_limit);
+		// The message is already parked, so it is not parked again if it needs too much weight.
+		match Self::process_message_payload(
+			origin.clone(),
+			page_index,
+			index,
+			message,
+			&mut weight,
+			Weight::max_value(),
+		) {
+			MessageExecutionStatus::InsufficientWeight | MessageExecutionStatus::Overweight =>
+				return Err(Error::<T>::InsufficientWeight),
+			MessageExecutionStatus::Processed | MessageExecutionStatus::Unprocessable => (),
+		}
+
+		page.note_processed_at_pos(pos);
+		book_state.message_count.saturating_dec();
+		book_state.size.saturating_reduce(header.payload_len as u64);
+		if page.remaining == 0 {
+			Pages::<T>::remove(&origin, page_index);
+			book_state.count.saturating_dec();
+		} else {
+			Pages::<T>::insert(&origin, page_index, page);
+		}
+		BookStateFor::<T>::insert(&origin, book_state);
+		Ok(weight.used)
+	}
+
+	/// Ensure the consistency of the queues, their pages and the ready ring.
+	#[cfg(any(test, feature = "try-runtime"))]
+	pub fn do_try_state() -> Result<(), &'static str> {
+		let mut ready_queues = 0u32;
+		for (origin, book_state) in BookStateFor::<T>::iter() {
+			ensure!(book_state.begin <= book_state.end, "the first page must not be after the end");
+
+			let mut page_count = 0;
+			let mut message_count = 0u64;
+			let mut size = 0u64;
+			for (page_index, page) in Pages::<T>::iter_prefix(&origin) {
+				ensure!(page_index < book_state.end, "pages must be before the end");
+				ensure!(page.remaining > 0, "pages without remaining messages must be removed");
+				ensure!(
+					page_index >= book_state.begin || page.is_complete(),
+					"pages before the first page must be complete"
+				);
+				Self::try_state_page(&page)?;
+				page_count += 1;
+				message_count.saturating_accrue(page.remaining as u64);
+				size.saturating_accrue(page.remaining_size as u64);
+			}
+			ensure!(page_count == book_state.count, "the page count must match the pages");
+			ensure!(message_count == book_state.message_count, "the message count must match");
+			ensure!(size == book_state.size, "the size must match the messages");
+			for page_index in book_state.begin..book_state.end {
+				ensure!(
+					Pages::<T>::contains_key(&origin, page_index),
+					"the pages from the first page to the end must exist"
+				);
+			}
+
+			if let Some(neighbours) = book_state.ready_neighbours {
+				ensure!(book_state.begin < book_state.end, "ready queues must have pages");
+				let next = BookStateFor::<T>::get(&neighbours.next).ready_neighbours;
+				ensure!(
+					next.map(|n| n.prev) == Some(origin.clone()),
+					"the next ready queue must link back"
+				);
+				let prev = BookStateFor::<T>::get(&neighbours.prev).ready_neighbours;
+				ensure!(
+					prev.map(|n| n.next) == Some(origin),
+					"the previous ready queue must link back"
+				);
+				ready_queues += 1;
+			}
+		}
+
+		match ServiceHead::<T>::get() {
+			Some(head) => {
+				// Walk the ring once, which must visit all ready queues.
+				let mut ring_len = 0u32;
+				let mut current = head.clone();
+				loop {
+					ring_len += 1;
+					ensure!(ring_len <= ready_queues, "the ready ring must be closed");
+					current = BookStateFor::<T>::get(&current)
+						.ready_neighbours
+						.ok_or("queues in the ready ring must be ready")?
+						.next;
+					if current == head {
+						break
+					}
+				}
+				ensure!(ring_len == ready_queues, "the ready ring must contain all ready queues");
+			},
+			None => ensure!(ready_queues == 0, "the ready ring must have a head"),
+		}
+		Ok(())
+	}
+
+	/// Ensure the consistency of the bookkeeping of `page` with its heap.
+	#[cfg(any(test, feature = "try-runtime"))]
+	fn try_state_page(page: &Page<T::HeapSize>) -> Result<(), &'static str> {
+		let mut pos = 0;
+		let mut count = 0u32;
+		let mut remaining = 0u32;
+		let mut remaining_size = 0u32;
+		while pos < page.heap.len() {
+			let (header, _) = page.item_at(pos).ok_or("the heap must only contain messages")?;
+			if count == page.first_index {
+				ensure!(pos == page.first as usize, "the first position must match its index");
+			}
+			if !header.is_processed {
+				remaining += 1;
+				remaining_size.saturating_accrue(header.payload_len);
+			}
+			pos.saturating_accrue(header.item_len());
+			count += 1;
+		}
+		ensure!(pos == page.heap.len(), "the last message must end with the heap");
+		ensure!(count == page.count, "the message count of a page must match its heap");
+		ensure!(page.first_index <= page.count, "the first index must not be after the end");
+		ensure!(remaining == page.remaining, "the remaining messages of a page must match");
+		ensure!(remaining_size == page.remaining_size, "the remaining size of a page must match");
+		Ok(())
+	}
+}
+
+impl<T: Config> EnqueueMessage<MessageOriginOf<T>> for Pallet<T> {
+	type MaxMessageLen = MaxMessageLen<T>;
+
+	fn enqueue_message(message: BoundedSlice<u8, Self::MaxMessageLen>, origin: MessageOriginOf<T>) {
+		Self::do_enqueue_message(&origin, message.into());
+	}
+
+	fn enqueue_messages<'a>(
+		messages: impl Iterator<Item = BoundedSlice<'a, u8, Self::MaxMessageLen>>,
+		origin: MessageOriginOf<T>,
+	) {
+		for message in messages {
+			Self::do_enqueue_message(&origin, message.into());
+		}
+	}
+
+	fn sweep_queue(origin: MessageOriginOf<T>) {
+		let mut book_state = BookStateFor::<T>::get(&origin);
+		if let Some(neighbours) = book_state.ready_neighbours.take() {
+			Self::ready_ring_unknit(&origin, neighbours);
+			BookStateFor::<T>::insert(&origin, book_state);
+		}
+	}
+
+	fn footprint(origin: MessageOriginOf<T>) -> Footprint {
+		let book_state = BookStateFor::<T>::get(&origin);
+		Footprint { count: book_state.message_count, size: book_state.size }
+	}
+}
@@ -0,0 +1,164 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Test environment for message queue pallet.
+
+use super::*;
+use crate as pallet_message_queue;
+
+use codec::{Decode, Encode};
+use frame_support::{
+	construct_runtime, parameter_types,
+	traits::{ConstU32, ConstU64},
+};
+use scale_info::TypeInfo;
+use sp_core::H256;
+use sp_runtime::{
+	testing::Header,
+	traits::{BlakeTwo256, IdentityLookup},
+};
+
+type UncheckedExtrinsic = frame_system::mocking::MockUncheckedExtrinsic<Test>;
+type Block = frame_system::mocking::MockBlock<Test>;
+
+construct_runtime!(
+	pub enum Test where
+		Block = Block,
+		NodeBlock = Block,
+		UncheckedExtrinsic = UncheckedExtrinsic,
+	{
+		System: frame_system::{Pallet, Call, Config, Storage, Event<T>},
+		MessageQueue: pallet_message_queue::{Pallet, Call, Storage, Event<T>},
+	}
+);
+
+impl frame_system::Config for Test {
+	type BaseCallFilter = frame_support::traits::Everything;
+	type BlockWeights = ();
+	type BlockLength = ();
+	type Origin = Origin;
+	type Call = Call;
+	type Index = u64;
+	type BlockNumber = u64;
+	type Hash = H256;
+	type Hashing = BlakeTwo256;
+	type AccountId = u64;
+	type Lookup = IdentityLookup<Self::AccountId>;
+	type Header = Header;
+	type Event = Event;
+	type BlockHashCount = ConstU64<250>;
+	type DbWeight = ();
+	type Version = ();
+	type PalletInfo = PalletInfo;
+	type AccountData = ();
+	type OnNewAccount = ();
+	type OnKilledAccount = ();
+	type SystemWeightInfo = ();
+	type SS58Prefix = ();
+	type OnSetCode = ();
+	type MaxConsumers = ConstU32<16>;
+}
+
+parameter_types! {
+	pub static ServiceWeight: Option<Weight> = Some(10);
+	pub static MessagesProcessed: Vec<(Vec<u8>, MessageOrigin)> = vec![];
+}
+
+impl Config for Test {
+	type Event = Event;
+	type MessageProcessor = RecordingMessageProcessor;
+	type HeapSize = ConstU32<40>;
+	type ServiceWeight = ServiceWeight;
+	type WeightInfo = MockedWeightInfo;
+}
+
+/// Weights of zero, so that only processing the messages uses weight.
+pub struct MockedWeightInfo;
+impl WeightInfo for MockedWeightInfo {
+	fn bump_service_head() -> Weight {
+		0
+	}
+	fn service_queue_base() -> Weight {
+		0
+	}
+	fn service_page_base() -> Weight {
+		0
+	}
+	fn service_page_item() -> Weight {
+		0
+	}
+	fn execute_overweight() -> Weight {
+		0
+	}
+}
+
+/// The origins of the messages in the tests.
+#[derive(Copy, Clone, Encode, Decode, MaxEncodedLen, TypeInfo, Eq, PartialEq, Debug)]
+pub enum MessageOrigin {
+	Here,
+	There,
+	Everywhere(u32),
+}
+
+impl From<u32> for MessageOrigin {
+	fn from(i: u32) -> Self {
+		Self::Everywhere(i)
+	}
+}
+
+/// Records the processed messages in [`MessagesProcessed`].
+///
+/// A message `weight=N` needs a weight of `N` to be processed, any other message a weight of 1.
+/// Messages starting with `bad` have a bad format, and messages starting with `fail` are
+/// processed without success.
+pub struct RecordingMessageProcessor;
+impl ProcessMessage for RecordingMessageProcessor {
+	type Origin = MessageOrigin;
+
+	fn process_message(
+		message: &[u8],
+		origin: Self::Origin,
+		weight_limit: Weight,
+	) -> Result<(bool, Weight), ProcessMessageError> {
+		if message.starts_with(b"bad") {
+			return Err(ProcessMessageError::BadFormat)
+		}
+		let required = sp_std::str::from_utf8(message)
+			.ok()
+			.and_then(|m| m.strip_prefix("weight="))
+			.and_then(|w| w.parse().ok())
+			.unwrap_or(1);
+		if required > weight_limit {
+			return Err(ProcessMessageError::Overweight(required))
+		}
+		let success = !message.starts_with(b"fail");
+		if success {
+			let mut processed = MessagesProcessed::get();
+			processed.push((message.to_vec(), origin));
+			MessagesProcessed::set(processed);
+		}
+		Ok((success, required))
+	}
+}
+
+pub(crate) fn new_test_ext() -> sp_io::TestExternalities {
+	let t = frame_system::GenesisConfig::default().build_storage::<Test>().unwrap();
+
+	let mut ext = sp_io::TestExternalities::new(t);
+	ext.execute_with(|| System::set_block_number(1));
+	ext
+}
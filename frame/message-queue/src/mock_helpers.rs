@@ -0,0 +1,51 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Helpers for tests and benchmarks using the message queue pallet.
+
+use codec::{FullCodec, MaxEncodedLen};
+use frame_support::{
+	traits::{ProcessMessage, ProcessMessageError},
+	weights::Weight,
+};
+use scale_info::TypeInfo;
+use sp_std::{fmt::Debug, marker::PhantomData};
+
+/// A message processor which processes any message successfully with a weight of 1.
+///
+/// Useful for benchmarks, which measure the overhead of servicing the queues apart from
+/// processing the messages.
+pub struct NoopMessageProcessor<Origin>(PhantomData<Origin>);
+
+impl<Origin> ProcessMessage for NoopMessageProcessor<Origin>
+where
+	Origin: FullCodec + MaxEncodedLen + Clone + Eq + PartialEq + TypeInfo + Debug,
+{
+	type Origin = Origin;
+
+	fn process_message(
+		_message: &[u8],
+		_origin: Self::Origin,
+		weight_limit: Weight,
+	) -> Result<(bool, Weight), ProcessMessageError> {
+		let required = 1;
+		if required > weight_limit {
+			return Err(ProcessMessageError::Overweight(required))
+		}
+		Ok((true, required))
+	}
+}
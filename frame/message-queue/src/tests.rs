@@ -0,0 +1,331 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for message queue pallet.
+
+use super::*;
+use crate::mock::{MessageOrigin::*, *};
+use frame_support::{assert_noop, assert_ok, traits::Hooks};
+use sp_runtime::traits::BadOrigin;
+
+fn msg(x: &'static str) -> BoundedSlice<'static, u8, MaxMessageLen<Test>> {
+	BoundedSlice::try_from(x.as_bytes()).unwrap()
+}
+
+fn hash(x: &str) -> <Test as frame_system::Config>::Hash {
+	<Test as frame_system::Config>::Hashing::hash(x.as_bytes())
+}
+
+fn assert_processed(expected: Vec<(&str, MessageOrigin)>) {
+	let expected: Vec<_> = expected.into_iter().map(|(m, o)| (m.as_bytes().to_vec(), o)).collect();
+	assert_eq!(MessagesProcessed::get(), expected);
+}
+
+#[test]
+fn enqueue_message_should_work() {
+	new_test_ext().execute_with(|| {
+		MessageQueue::enqueue_message(msg("a"), Here);
+		MessageQueue::enqueue_message(msg("bc"), Here);
+
+		let book_state = BookStateFor::<Test>::get(Here);
+		assert_eq!((book_state.begin, book_state.end, book_state.count), (0, 1, 1));
+		assert_eq!(book_state.ready_neighbours, Some(Neighbours { prev: Here, next: Here }));
+		assert_eq!(MessageQueue::footprint(Here), Footprint { count: 2, size: 3 });
+		assert_eq!(Pages::<Test>::get(Here, 0).unwrap().count, 2);
+		assert_eq!(ServiceHead::<Test>::get(), Some(Here));
+		assert_ok!(MessageQueue::do_try_state());
+
+		// A new page is started once the last one is full.
+		MessageQueue::enqueue_messages(
+			vec![msg("weight=10"), msg("weight=10"), msg("weight=10")].into_iter(),
+			Here,
+		);
+		let book_state = BookStateFor::<Test>::get(Here);
+		assert_eq!((book_state.begin, book_state.end, book_state.count), (0, 2, 2));
+		assert_eq!(Pages::<Test>::get(Here, 0).unwrap().count, 3);
+		assert_eq!(Pages::<Test>::get(Here, 1).unwrap().count, 2);
+		assert_eq!(MessageQueue::footprint(Here), Footprint { count: 5, size: 30 });
+
+		// The queues of other origins are added to the ready ring.
+		MessageQueue::enqueue_message(msg("a"), There);
+		assert_eq!(
+			BookStateFor::<Test>::get(Here).ready_neighbours,
+			Some(Neighbours { prev: There, next: There })
+		);
+		assert_eq!(
+			BookStateFor::<Test>::get(There).ready_neighbours,
+			Some(Neighbours { prev: Here, next: Here })
+		);
+		assert_eq!(ServiceHead::<Test>::get(), Some(Here));
+		assert_eq!(MessageQueue::footprint(Everywhere(0)), Footprint::default());
+		assert_ok!(MessageQueue::do_try_state());
+	});
+}
+
+#[test]
+fn service_queues_should_respect_the_weight() {
+	new_test_ext().execute_with(|| {
+		MessageQueue::enqueue_messages(
+			vec![msg("weight=4"), msg("weight=4"), msg("weight=4")].into_iter(),
+			Here,
+		);
+
+		// `ServiceWeight` is only enough for two of the messages.
+		assert_eq!(MessageQueue::on_initialize(1), 8);
+		assert_processed(vec![("weight=4", Here), ("weight=4", Here)]);
+		assert_eq!(MessageQueue::footprint(Here), Footprint { count: 1, size: 8 });
+		System::assert_last_event(
+			crate::Event::Processed {
+				hash: hash("weight=4"),
+				origin: Here,
+				weight_used: 4,
+				success: true,
+			}
+			.into(),
+		);
+		assert_ok!(MessageQueue::do_try_state());
+
+		// The remaining message waits for enough weight.
+		assert_eq!(MessageQueue::on_idle(1, 3), 0);
+		assert_eq!(MessageQueue::footprint(Here), Footprint { count: 1, size: 8 });
+		assert_eq!(MessageQueue::on_idle(1, 100), 4);
+		assert_eq!(MessagesProcessed::get().len(), 3);
+
+		// Empty queues and their pages are removed.
+		assert_eq!(MessageQueue::footprint(Here), Footprint::default());
+		let book_state = BookStateFor::<Test>::get(Here);
+		assert_eq!((book_state.begin, book_state.end, book_state.count), (1, 1, 0));
+		assert_eq!(book_state.ready_neighbours, None);
+		assert_eq!(Pages::<Test>::iter().count(), 0);
+		assert_eq!(ServiceHead::<Test>::get(), None);
+		assert_eq!(MessageQueue::on_idle(1, 100), 0);
+		assert_ok!(MessageQueue::do_try_state());
+	});
+}
+
+#[test]
+fn service_queues_should_be_round_robin() {
+	new_test_ext().execute_with(|| {
+		MessageQueue::enqueue_messages(vec![msg("a"), msg("b")].into_iter(), Here);
+		MessageQueue::enqueue_messages(vec![msg("c"), msg("d")].into_iter(), There);
+		MessageQueue::enqueue_messages(vec![msg("e"), msg("f")].into_iter(), Everywhere(1));
+
+		assert_eq!(MessageQueue::service_queues(1), 1);
+		assert_eq!(ServiceHead::<Test>::get(), Some(There));
+		assert_eq!(MessageQueue::service_queues(1), 1);
+		assert_eq!(ServiceHead::<Test>::get(), Some(Everywhere(1)));
+		assert_eq!(MessageQueue::service_queues(1), 1);
+		assert_eq!(ServiceHead::<Test>::get(), Some(Here));
+		assert_processed(vec![("a", Here), ("c", There), ("e", Everywhere(1))]);
+		assert_ok!(MessageQueue::do_try_state());
+
+		// With enough weight, all queues are serviced in the same order.
+		assert_eq!(MessageQueue::service_queues(100), 3);
+		assert_processed(vec![
+			("a", Here),
+			("c", There),
+			("e", Everywhere(1)),
+			("b", Here),
+			("d", There),
+			("f", Everywhere(1)),
+		]);
+		assert_eq!(ServiceHead::<Test>::get(), None);
+		assert_ok!(MessageQueue::do_try_state());
+	});
+}
+
+#[test]
+fn failing_messages_should_be_dropped() {
+	new_test_ext().execute_with(|| {
+		MessageQueue::enqueue_messages(vec![msg("bad"), msg("fail"), msg("a")].into_iter(), Here);
+
+		assert_eq!(MessageQueue::on_initialize(1), 2);
+		System::assert_has_event(
+			crate::Event::ProcessingFailed {
+				hash: hash("bad"),
+				origin: Here,
+				error: ProcessMessageError::BadFormat,
+			}
+			.into(),
+		);
+		System::assert_has_event(
+			crate::Event::Processed {
+				hash: hash("fail"),
+				origin: Here,
+				weight_used: 1,
+				success: false,
+			}
+			.into(),
+		);
+		assert_processed(vec![("a", Here)]);
+		assert_eq!(MessageQueue::footprint(Here), Footprint::default());
+		assert_eq!(Pages::<Test>::iter().count(), 0);
+		assert_ok!(MessageQueue::do_try_state());
+	});
+}
+
+#[test]
+fn overweight_messages_should_be_parked() {
+	new_test_ext().execute_with(|| {
+		MessageQueue::enqueue_messages(vec![msg("weight=20"), msg("a")].into_iter(), Here);
+
+		// The message needs more than `ServiceWeight`, so it is skipped.
+		assert_eq!(MessageQueue::on_initialize(1), 1);
+		System::assert_has_event(
+			crate::Event::OverweightEnqueued {
+				hash: hash("weight=20"),
+				origin: Here,
+				page_index: 0,
+				message_index: 0,
+			}
+			.into(),
+		);
+		assert_processed(vec![("a", Here)]);
+		assert_eq!(MessageQueue::footprint(Here), Footprint { count: 1, size: 9 });
+		assert!(Pages::<Test>::contains_key(Here, 0));
+		assert_eq!(ServiceHead::<Test>::get(), None);
+		assert_ok!(MessageQueue::do_try_state());
+
+		// Parked messages are not serviced again.
+		assert_eq!(MessageQueue::on_idle(1, 1000), 0);
+		assert_eq!(MessageQueue::footprint(Here), Footprint { count: 1, size: 9 });
+	});
+}
+
+#[test]
+fn execute_overweight_should_work() {
+	new_test_ext().execute_with(|| {
+		MessageQueue::enqueue_messages(vec![msg("weight=20"), msg("a")].into_iter(), Here);
+		MessageQueue::on_initialize(1);
+
+		assert_noop!(MessageQueue::execute_overweight(Origin::root(), Here, 0, 0, 20), BadOrigin);
+		assert_noop!(
+			MessageQueue::execute_overweight(Origin::signed(1), Here, 0, 0, 19),
+			Error::<Test>::InsufficientWeight
+		);
+		assert_noop!(
+			MessageQueue::execute_overweight(Origin::signed(1), Here, 0, 1, 20),
+			Error::<Test>::AlreadyProcessed
+		);
+		assert_noop!(
+			MessageQueue::execute_overweight(Origin::signed(1), Here, 0, 2, 20),
+			Error::<Test>::NoMessage
+		);
+		assert_noop!(
+			MessageQueue::execute_overweight(Origin::signed(1), Here, 1, 0, 20),
+			Error::<Test>::NoPage
+		);
+
+		let post_info =
+			MessageQueue::execute_overweight(Origin::signed(1), Here, 0, 0, 30).unwrap();
+		assert_eq!(post_info.actual_weight, Some(20));
+		System::assert_last_event(
+			crate::Event::Processed {
+				hash: hash("weight=20"),
+				origin: Here,
+				weight_used: 20,
+				success: true,
+			}
+			.into(),
+		);
+		assert_processed(vec![("a", Here), ("weight=20", Here)]);
+		assert_eq!(MessageQueue::footprint(Here), Footprint::default());
+		assert_eq!(Pages::<Test>::iter().count(), 0);
+		assert_ok!(MessageQueue::do_try_state());
+
+		// A message is only executed once.
+		assert_noop!(
+			MessageQueue::execute_overweight(Origin::signed(1), Here, 0, 0, 20),
+			Error::<Test>::NoPage
+		);
+	});
+}
+
+#[test]
+fn execute_overweight_should_fail_for_queued_messages() {
+	new_test_ext().execute_with(|| {
+		MessageQueue::enqueue_messages(vec![msg("a"), msg("b")].into_iter(), Here);
+		assert_noop!(
+			MessageQueue::execute_overweight(Origin::signed(1), Here, 0, 0, 20),
+			Error::<Test>::Queued
+		);
+
+		assert_eq!(MessageQueue::service_queues(1), 1);
+		assert_noop!(
+			MessageQueue::execute_overweight(Origin::signed(1), Here, 0, 0, 20),
+			Error::<Test>::AlreadyProcessed
+		);
+		assert_noop!(
+			MessageQueue::execute_overweight(Origin::signed(1), Here, 0, 1, 20),
+			Error::<Test>::Queued
+		);
+	});
+}
+
+#[test]
+fn messages_should_not_be_overweight_without_service_weight() {
+	new_test_ext().execute_with(|| {
+		ServiceWeight::set(None);
+		MessageQueue::enqueue_message(msg("weight=20"), Here);
+
+		// Without `ServiceWeight`, the queues are only serviced in `on_idle`.
+		assert_eq!(MessageQueue::on_initialize(1), 0);
+		assert_eq!(MessageQueue::on_idle(1, 10), 0);
+		assert_eq!(MessageQueue::footprint(Here), Footprint { count: 1, size: 9 });
+		assert_eq!(ServiceHead::<Test>::get(), Some(Here));
+
+		assert_eq!(MessageQueue::on_idle(1, 20), 20);
+		assert_processed(vec![("weight=20", Here)]);
+		assert_ok!(MessageQueue::do_try_state());
+	});
+}
+
+#[test]
+fn sweep_queue_should_stop_servicing_until_the_next_message() {
+	new_test_ext().execute_with(|| {
+		MessageQueue::enqueue_message(msg("a"), Here);
+		MessageQueue::enqueue_message(msg("b"), There);
+
+		MessageQueue::sweep_queue(Here);
+		assert_eq!(ServiceHead::<Test>::get(), Some(There));
+		assert_eq!(BookStateFor::<Test>::get(Here).ready_neighbours, None);
+		assert_ok!(MessageQueue::do_try_state());
+
+		// The messages of the swept queue are kept, but not serviced.
+		assert_eq!(MessageQueue::on_idle(1, 100), 1);
+		assert_processed(vec![("b", There)]);
+		assert_eq!(MessageQueue::footprint(Here), Footprint { count: 1, size: 1 });
+
+		// Enqueueing a message makes the queue ready again.
+		MessageQueue::enqueue_message(msg("c"), Here);
+		assert_eq!(ServiceHead::<Test>::get(), Some(Here));
+		assert_eq!(MessageQueue::on_idle(1, 100), 2);
+		assert_processed(vec![("b", There), ("a", Here), ("c", Here)]);
+		assert_ok!(MessageQueue::do_try_state());
+	});
+}
+
+#[test]
+fn try_state_should_detect_inconsistencies() {
+	new_test_ext().execute_with(|| {
+		MessageQueue::enqueue_message(msg("a"), Here);
+		assert_ok!(MessageQueue::do_try_state());
+
+		BookStateFor::<Test>::mutate(Here, |book_state| book_state.message_count += 1);
+		assert!(MessageQueue::do_try_state().is_err());
+	});
+}
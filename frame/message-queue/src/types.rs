@@ -0,0 +1,270 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Various basic types for use in the message queue pallet.
+
+use super::*;
+use frame_support::pallet_prelude::*;
+
+/// The index of a page of a queue.
+pub type PageIndex = u32;
+
+/// The origin of the messages processed by the [`Config::MessageProcessor`].
+pub type MessageOriginOf<T> = <<T as Config>::MessageProcessor as ProcessMessage>::Origin;
+
+/// The header of each message on the heap of a [`Page`], followed by the message itself.
+#[derive(Copy, Clone, Encode, Decode, MaxEncodedLen, PartialEq, RuntimeDebug)]
+pub struct ItemHeader {
+	/// The length of the message in bytes.
+	pub payload_len: u32,
+	/// Whether the message was processed.
+	pub is_processed: bool,
+}
+
+impl ItemHeader {
+	/// The length of a message with this header on the heap of a page, including the header.
+	pub fn item_len(&self) -> usize {
+		Self::max_encoded_len().saturating_add(self.payload_len as usize)
+	}
+}
+
+/// A page of the messages of an origin.
+///
+/// The messages are stored one after another on the heap, each prefixed by an [`ItemHeader`].
+#[derive(
+	CloneNoBound, Encode, Decode, MaxEncodedLen, PartialEqNoBound, RuntimeDebugNoBound, TypeInfo,
+)]
+#[codec(mel_bound(HeapSize: Get<u32>))]
+#[scale_info(skip_type_params(HeapSize))]
+pub struct Page<HeapSize: Get<u32>> {
+	/// The number of messages which were not processed yet.
+	pub remaining: u32,
+	/// The total length of the messages which were not processed yet.
+	pub remaining_size: u32,
+	/// The number of messages on the heap.
+	pub count: u32,
+	/// The index of the first message which was not serviced yet.
+	///
+	/// All messages before it were either processed or are overweight.
+	pub first_index: u32,
+	/// The position of the first message which was not serviced yet on the heap.
+	pub first: u32,
+	/// The encoded messages.
+	pub heap: BoundedVec<u8, HeapSize>,
+}
+
+impl<HeapSize: Get<u32>> Page<HeapSize> {
+	/// Create a page which only contains `message`.
+	pub fn from_message(message: &[u8]) -> Self {
+		let mut page = Self {
+			remaining: 0,
+			remaining_size: 0,
+			count: 0,
+			first_index: 0,
+			first: 0,
+			heap: BoundedVec::default(),
+		};
+		let appended = page.try_append_message(message);
+		debug_assert!(appended.is_ok(), "messages must fit into an empty page");
+		page
+	}
+
+	/// Append `message` to the heap, or fail if it doesn't fit.
+	pub fn try_append_message(&mut self, message: &[u8]) -> Result<(), ()> {
+		let header = ItemHeader { payload_len: message.len() as u32, is_processed: false };
+		if self.heap.len().saturating_add(header.item_len()) > HeapSize::get() as usize {
+			return Err(())
+		}
+		self.heap = sp_std::mem::take(&mut self.heap)
+			.try_mutate(|heap| {
+				header.encode_to(heap);
+				heap.extend_from_slice(message);
+			})
+			.ok_or(())?;
+
+		self.remaining.saturating_inc();
+		self.remaining_size.saturating_accrue(header.payload_len);
+		self.count.saturating_inc();
+		Ok(())
+	}
+
+	/// The header and the message at the position `pos` of the heap.
+	pub fn item_at(&self, pos: usize) -> Option<(ItemHeader, &[u8])> {
+		let mut input = self.heap.get(pos..)?;
+		let header = ItemHeader::decode(&mut input).ok()?;
+		let payload = input.get(..header.payload_len as usize)?;
+		Some((header, payload))
+	}
+
+	/// The header and the message of the first message which was not serviced yet.
+	pub fn peek_first(&self) -> Option<(ItemHeader, &[u8])> {
+		if self.is_complete() {
+			return None
+		}
+		self.item_at(self.first as usize)
+	}
+
+	/// Move on from the first message which was not serviced yet, noting it as processed if
+	/// `is_processed`.
+	pub fn skip_first(&mut self, is_processed: bool) {
+		let pos = self.first as usize;
+		let header = match self.peek_first() {
+			Some((header, _)) => header,
+			None => return,
+		};
+		if is_processed {
+			self.note_processed_at_pos(pos);
+		}
+		self.first.saturating_accrue(header.item_len() as u32);
+		self.first_index.saturating_inc();
+	}
+
+	/// The position on the heap, the header and the message of the message with index `index`.
+	pub fn peek_index(&self, index: u32) -> Option<(usize, ItemHeader, &[u8])> {
+		if index >= self.count {
+			return None
+		}
+		let mut pos = 0;
+		for _ in 0..index {
+			let (header, _) = self.item_at(pos)?;
+			pos.saturating_accrue(header.item_len());
+		}
+		let (header, payload) = self.item_at(pos)?;
+		Some((pos, header, payload))
+	}
+
+	/// Note the message at the position `pos` of the heap as processed.
+	pub fn note_processed_at_pos(&mut self, pos: usize) {
+		let header = match self.item_at(pos) {
+			Some((header, _)) if !header.is_processed => header,
+			_ => return,
+		};
+		let encoded = ItemHeader { is_processed: true, ..header }.encode();
+		self.heap[pos..pos.saturating_add(encoded.len())].copy_from_slice(&encoded);
+		self.remaining.saturating_dec();
+		self.remaining_size.saturating_reduce(header.payload_len);
+	}
+
+	/// Whether all messages of the page were serviced.
+	pub fn is_complete(&self) -> bool {
+		self.first_index >= self.count
+	}
+}
+
+/// The neighbours of a queue in the ring of ready queues.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct Neighbours<MessageOrigin> {
+	/// The previous queue.
+	pub prev: MessageOrigin,
+	/// The next queue.
+	pub next: MessageOrigin,
+}
+
+/// The state of the queue of an origin.
+///
+/// The pages from `begin` to `end` contain messages which were not serviced yet. Pages before
+/// `begin` are only kept while they contain overweight messages.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, MaxEncodedLen, RuntimeDebug, TypeInfo)]
+pub struct BookState<MessageOrigin> {
+	/// The index of the first page with messages which were not serviced yet.
+	pub begin: PageIndex,
+	/// One more than the index of the last page.
+	pub end: PageIndex,
+	/// The number of pages in storage.
+	pub count: PageIndex,
+	/// The neighbours of the queue in the ring of ready queues, if it is ready.
+	pub ready_neighbours: Option<Neighbours<MessageOrigin>>,
+	/// The number of messages which were not processed yet, including overweight messages.
+	pub message_count: u64,
+	/// The total length of the messages which were not processed yet.
+	pub size: u64,
+}
+
+impl<MessageOrigin> Default for BookState<MessageOrigin> {
+	fn default() -> Self {
+		Self { begin: 0, end: 0, count: 0, ready_neighbours: None, message_count: 0, size: 0 }
+	}
+}
+
+/// Tracks the weight used while servicing the queues.
+#[derive(Copy, Clone, PartialEq, RuntimeDebug)]
+pub struct WeightCounter {
+	/// The weight used so far.
+	pub used: Weight,
+	/// The weight which may be used at most.
+	pub limit: Weight,
+}
+
+impl WeightCounter {
+	/// A counter which may use up to `limit`.
+	pub fn from_limit(limit: Weight) -> Self {
+		Self { used: 0, limit }
+	}
+
+	/// Use `weight` if it doesn't exceed the limit, and return whether it was used.
+	pub fn check_accrue(&mut self, weight: Weight) -> bool {
+		let used = self.used.saturating_add(weight);
+		if used > self.limit {
+			return false
+		}
+		self.used = used;
+		true
+	}
+
+	/// Use `weight`, even if it exceeds the limit.
+	pub fn saturating_accrue(&mut self, weight: Weight) {
+		self.used.saturating_accrue(weight);
+	}
+
+	/// The weight which may still be used.
+	pub fn remaining(&self) -> Weight {
+		self.limit.saturating_sub(self.used)
+	}
+}
+
+/// The outcome of processing a message.
+#[derive(Copy, Clone, Eq, PartialEq, RuntimeDebug)]
+pub(crate) enum MessageExecutionStatus {
+	/// There isn't enough weight left to process the message now.
+	InsufficientWeight,
+	/// The message needs more weight than any service gives it and was parked.
+	Overweight,
+	/// The message was processed.
+	Processed,
+	/// The message can't be processed and was dropped.
+	Unprocessable,
+}
+
+/// The outcome of servicing the first message of a page.
+#[derive(Copy, Clone, Eq, PartialEq, RuntimeDebug)]
+pub(crate) enum ItemExecutionStatus {
+	/// There isn't enough weight left to service the message.
+	Bailed,
+	/// The page has no message left to service.
+	NoItem,
+	/// The message was serviced. Contains whether it was processed, or was parked otherwise.
+	Executed(bool),
+}
+
+/// The outcome of servicing a page.
+#[derive(Copy, Clone, Eq, PartialEq, RuntimeDebug)]
+pub(crate) enum PageExecutionStatus {
+	/// There isn't enough weight left to service the rest of the page.
+	Bailed,
+	/// All messages of the page were serviced.
+	NoMore,
+}
@@ -0,0 +1,105 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Placeholder weights for pallet_message_queue
+//!
+//! These weights are NOT the output of the benchmark CLI. They are estimates based on the storage
+//! accesses of each call and must be replaced by running the benchmarks of the pallet with the
+//! benchmark CLI before the pallet is used in production.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_message_queue.
+pub trait WeightInfo {
+	fn bump_service_head() -> Weight;
+	fn service_queue_base() -> Weight;
+	fn service_page_base() -> Weight;
+	fn service_page_item() -> Weight;
+	fn execute_overweight() -> Weight;
+}
+
+/// Weights for pallet_message_queue using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: MessageQueue ServiceHead (r:1 w:1)
+	// Storage: MessageQueue BookStateFor (r:1 w:0)
+	fn bump_service_head() -> Weight {
+		(6_211_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: MessageQueue BookStateFor (r:1 w:1)
+	fn service_queue_base() -> Weight {
+		(5_064_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: MessageQueue Pages (r:1 w:1)
+	fn service_page_base() -> Weight {
+		(8_925_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	fn service_page_item() -> Weight {
+		(72_403_000 as Weight)
+	}
+	// Storage: MessageQueue BookStateFor (r:1 w:1)
+	// Storage: MessageQueue Pages (r:1 w:1)
+	fn execute_overweight() -> Weight {
+		(58_318_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: MessageQueue ServiceHead (r:1 w:1)
+	// Storage: MessageQueue BookStateFor (r:1 w:0)
+	fn bump_service_head() -> Weight {
+		(6_211_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: MessageQueue BookStateFor (r:1 w:1)
+	fn service_queue_base() -> Weight {
+		(5_064_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: MessageQueue Pages (r:1 w:1)
+	fn service_page_base() -> Weight {
+		(8_925_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	fn service_page_item() -> Weight {
+		(72_403_000 as Weight)
+	}
+	// Storage: MessageQueue BookStateFor (r:1 w:1)
+	// Storage: MessageQueue Pages (r:1 w:1)
+	fn execute_overweight() -> Weight {
+		(58_318_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+}
@@ -48,6 +48,16 @@ fn setup_multi<T: Config>(
 	return Ok((signatories, call_data))
 }
 
+fn setup_managed<T: Config>(s: u32) -> Result<(T::AccountId, Vec<T::AccountId>), &'static str> {
+	let (signatories, _) = setup_multi::<T>(s, 0)?;
+	let creator = signatories[0].clone();
+	let weighted = signatories.iter().cloned().map(|signatory| (signatory, 1)).collect();
+	Multisig::<T>::create_managed(RawOrigin::Signed(creator.clone()).into(), weighted, s)?;
+	let multisig = Multisig::<T>::managed_account_id(&creator, &Multisig::<T>::timepoint());
+	T::Currency::make_free_balance_be(&multisig, BalanceOf::<T>::max_value());
+	Ok((multisig, signatories))
+}
+
 benchmarks! {
 	as_multi_threshold_1 {
 		// Transaction Length
@@ -300,5 +310,108 @@ benchmarks! {
 		assert!(!Calls::<T>::contains_key(call_hash));
 	}
 
+	create_managed {
+		let s in 1 .. T::MaxSignatories::get() as u32;
+		let (signatories, _) = setup_multi::<T>(s, 0)?;
+		let caller = signatories[0].clone();
+		let weighted = signatories.into_iter().map(|signatory| (signatory, 1)).collect();
+		let multisig = Multisig::<T>::managed_account_id(&caller, &Multisig::<T>::timepoint());
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		frame_benchmarking::benchmarking::add_to_whitelist(caller_key.into());
+	}: _(RawOrigin::Signed(caller), weighted, s)
+	verify {
+		assert!(ManagedMultisigs::<T>::contains_key(multisig));
+	}
+
+	update_managed {
+		let s in 1 .. T::MaxSignatories::get() as u32;
+		let (multisig, _) = setup_managed::<T>(1)?;
+		let signatories: Vec<T::AccountId> = (0 .. s).map(|i| account("new", i, SEED)).collect();
+		let mut weighted: Vec<_> = signatories.into_iter().map(|signatory| (signatory, 1)).collect();
+		weighted.sort();
+	}: _(RawOrigin::Signed(multisig.clone()), weighted, s)
+	verify {
+		let managed = ManagedMultisigs::<T>::get(multisig).ok_or("managed multisig not found")?;
+		assert_eq!(managed.threshold, s);
+	}
+
+	dissolve_managed {
+		let s in 1 .. T::MaxSignatories::get() as u32;
+		let (multisig, _) = setup_managed::<T>(s)?;
+	}: _(RawOrigin::Signed(multisig.clone()))
+	verify {
+		assert!(!ManagedMultisigs::<T>::contains_key(multisig));
+	}
+
+	propose_managed {
+		let s in 1 .. T::MaxSignatories::get() as u32;
+		let (multisig, signatories) = setup_managed::<T>(s)?;
+		let caller = signatories[0].clone();
+		let call_hash = [1u8; 32];
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		frame_benchmarking::benchmarking::add_to_whitelist(caller_key.into());
+	}: _(RawOrigin::Signed(caller), multisig.clone(), call_hash)
+	verify {
+		assert!(ManagedProposals::<T>::contains_key(multisig, call_hash));
+	}
+
+	approve_managed {
+		let s in 2 .. T::MaxSignatories::get() as u32;
+		let (multisig, signatories) = setup_managed::<T>(s)?;
+		let call_hash = [1u8; 32];
+		let timepoint = Multisig::<T>::timepoint();
+		let o = RawOrigin::Signed(signatories[0].clone()).into();
+		Multisig::<T>::propose_managed(o, multisig.clone(), call_hash)?;
+		let caller = signatories[1].clone();
+	}: _(RawOrigin::Signed(caller), multisig.clone(), timepoint, call_hash)
+	verify {
+		let proposal = ManagedProposals::<T>::get(multisig, call_hash).ok_or("proposal not found")?;
+		assert_eq!(proposal.approvals.len(), 2);
+	}
+
+	execute_managed {
+		let s in 1 .. T::MaxSignatories::get() as u32;
+		// Transaction Length
+		let z in 0 .. 10_000;
+		let (multisig, signatories) = setup_managed::<T>(s)?;
+		let (_, call) = setup_multi::<T>(0, z)?;
+		let call_hash = blake2_256(&call.encoded());
+		let timepoint = Multisig::<T>::timepoint();
+		let o = RawOrigin::Signed(signatories[0].clone()).into();
+		Multisig::<T>::propose_managed(o, multisig.clone(), call_hash)?;
+		for signatory in signatories.iter().skip(1) {
+			let o = RawOrigin::Signed(signatory.clone()).into();
+			Multisig::<T>::approve_managed(o, multisig.clone(), timepoint, call_hash)?;
+		}
+		let caller = signatories[0].clone();
+		// Whitelist caller account from further DB operations.
+		let caller_key = frame_system::Account::<T>::hashed_key_for(&caller);
+		frame_benchmarking::benchmarking::add_to_whitelist(caller_key.into());
+	}: _(RawOrigin::Signed(caller), multisig.clone(), timepoint, call, Weight::max_value())
+	verify {
+		assert!(!ManagedProposals::<T>::contains_key(multisig, call_hash));
+	}
+
+	expire_managed {
+		let s in 1 .. T::MaxSignatories::get() as u32;
+		let (multisig, signatories) = setup_managed::<T>(s)?;
+		let call_hash = [1u8; 32];
+		let timepoint = Multisig::<T>::timepoint();
+		let o = RawOrigin::Signed(signatories[0].clone()).into();
+		Multisig::<T>::propose_managed(o, multisig.clone(), call_hash)?;
+		for signatory in signatories.iter().skip(1) {
+			let o = RawOrigin::Signed(signatory.clone()).into();
+			Multisig::<T>::approve_managed(o, multisig.clone(), timepoint, call_hash)?;
+		}
+		let expiry = frame_system::Pallet::<T>::block_number() + T::ManagedProposalLifetime::get();
+		frame_system::Pallet::<T>::set_block_number(expiry);
+		let caller = signatories[0].clone();
+	}: _(RawOrigin::Signed(caller), multisig.clone(), timepoint, call_hash)
+	verify {
+		assert!(!ManagedProposals::<T>::contains_key(multisig, call_hash));
+	}
+
 	impl_benchmark_test_suite!(Multisig, crate::tests::new_test_ext(), crate::tests::Test);
 }
@@ -31,6 +31,11 @@
 //! operation. This is useful for multisig wallets where cryptographic threshold signatures are
 //! not available or desired.
 //!
+//! Managed multisig accounts instead keep their signatories, the voting weight of each signatory
+//! and the threshold on chain. These can be changed by the account itself, so its address stays
+//! the same when the set of signatories changes. Calls are dispatched from it through proposals
+//! which expire after `ManagedProposalLifetime` blocks.
+//!
 //! ## Interface
 //!
 //! ### Dispatchable Functions
@@ -39,6 +44,13 @@
 //!   number of signed origins.
 //! * `approve_as_multi` - Approve a call from a composite origin.
 //! * `cancel_as_multi` - Cancel a call from a composite origin.
+//! * `create_managed` - Create a managed multisig account.
+//! * `update_managed` - Change the signatories and threshold of a managed multisig account.
+//! * `dissolve_managed` - Remove a managed multisig account.
+//! * `propose_managed` - Open a proposal of a managed multisig account.
+//! * `approve_managed` - Approve a proposal of a managed multisig account.
+//! * `execute_managed` - Dispatch an approved proposal from a managed multisig account.
+//! * `expire_managed` - Remove an expired proposal of a managed multisig account.
 //!
 //! [`Call`]: ./enum.Call.html
 //! [`Config`]: ./trait.Config.html
@@ -57,6 +69,7 @@ use frame_support::{
 	},
 	ensure,
 	traits::{Currency, Get, ReservableCurrency, WrapperKeepOpaque},
+	transactional,
 	weights::{GetDispatchInfo, Weight},
 	RuntimeDebug,
 };
@@ -64,7 +77,7 @@ use frame_system::{self as system, RawOrigin};
 use scale_info::TypeInfo;
 use sp_io::hashing::blake2_256;
 use sp_runtime::{
-	traits::{Dispatchable, TrailingZeroInput, Zero},
+	traits::{Dispatchable, Saturating, TrailingZeroInput, Zero},
 	DispatchError,
 };
 use sp_std::prelude::*;
@@ -99,6 +112,34 @@ pub struct Multisig<BlockNumber, Balance, AccountId> {
 	approvals: Vec<AccountId>,
 }
 
+/// The configuration of a managed multisig account.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ManagedMultisig<Balance, AccountId> {
+	/// The signatories together with their voting weight. Always sorted by account.
+	signatories: Vec<(AccountId, u16)>,
+	/// The total voting weight of the approvals needed to execute a proposal.
+	threshold: u32,
+	/// The amount held in reserve of the `depositor` for storing the configuration.
+	deposit: Balance,
+	/// The account which holds the deposit.
+	depositor: AccountId,
+}
+
+/// An open proposal of a managed multisig account.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ManagedProposal<BlockNumber, Balance, AccountId> {
+	/// The extrinsic when the proposal was opened.
+	when: Timepoint<BlockNumber>,
+	/// The block from which on the proposal can no longer be approved or executed.
+	expiry: BlockNumber,
+	/// The amount held in reserve of the `depositor`, to be returned once the proposal ends.
+	deposit: Balance,
+	/// The signatory who opened it (i.e. the first to approve it).
+	depositor: AccountId,
+	/// The signatories who approved it so far, including the depositor. Always sorted.
+	approvals: Vec<AccountId>,
+}
+
 type OpaqueCall<T> = WrapperKeepOpaque<<T as Config>::Call>;
 
 type CallHash = [u8; 32];
@@ -147,6 +188,10 @@ pub mod pallet {
 		#[pallet::constant]
 		type MaxSignatories: Get<u16>;
 
+		/// The number of blocks after which an open proposal of a managed multisig expires.
+		#[pallet::constant]
+		type ManagedProposalLifetime: Get<Self::BlockNumber>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -171,6 +216,22 @@ pub mod pallet {
 	pub type Calls<T: Config> =
 		StorageMap<_, Identity, [u8; 32], (OpaqueCall<T>, T::AccountId, BalanceOf<T>)>;
 
+	/// The configuration of the managed multisig accounts.
+	#[pallet::storage]
+	pub type ManagedMultisigs<T: Config> =
+		StorageMap<_, Twox64Concat, T::AccountId, ManagedMultisig<BalanceOf<T>, T::AccountId>>;
+
+	/// The open proposals of the managed multisig accounts.
+	#[pallet::storage]
+	pub type ManagedProposals<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		CallHash,
+		ManagedProposal<T::BlockNumber, BalanceOf<T>, T::AccountId>,
+	>;
+
 	#[pallet::error]
 	pub enum Error<T> {
 		/// Threshold must be 2 or greater.
@@ -201,6 +262,24 @@ pub mod pallet {
 		MaxWeightTooLow,
 		/// The data to be stored is already stored.
 		AlreadyStored,
+		/// The account is not a managed multisig.
+		NotManaged,
+		/// The sender is not a signatory of the managed multisig.
+		NotSignatory,
+		/// A signatory was given a voting weight of zero.
+		ZeroWeight,
+		/// The threshold is zero or exceeds the total voting weight of the signatories.
+		InvalidThreshold,
+		/// The managed multisig or proposal already exists.
+		AlreadyExists,
+		/// The approvals of the proposal don't reach the threshold.
+		InsufficientApprovals,
+		/// The proposal has expired.
+		Expired,
+		/// The proposal has not expired yet.
+		NotExpired,
+		/// The call could not be decoded.
+		UndecodableCall,
 	}
 
 	#[pallet::event]
@@ -230,6 +309,40 @@ pub mod pallet {
 			multisig: T::AccountId,
 			call_hash: CallHash,
 		},
+		/// A managed multisig account has been created.
+		ManagedMultisigCreated { creator: T::AccountId, multisig: T::AccountId, threshold: u32 },
+		/// The signatories or the threshold of a managed multisig account have been changed.
+		ManagedMultisigUpdated { multisig: T::AccountId, threshold: u32 },
+		/// A managed multisig account has been dissolved.
+		ManagedMultisigDissolved { multisig: T::AccountId },
+		/// A new proposal of a managed multisig account has been opened.
+		NewManagedProposal {
+			proposing: T::AccountId,
+			timepoint: Timepoint<T::BlockNumber>,
+			multisig: T::AccountId,
+			call_hash: CallHash,
+		},
+		/// A proposal of a managed multisig account has been approved by a signatory.
+		ManagedProposalApproval {
+			approving: T::AccountId,
+			timepoint: Timepoint<T::BlockNumber>,
+			multisig: T::AccountId,
+			call_hash: CallHash,
+		},
+		/// A proposal of a managed multisig account has been executed.
+		ManagedProposalExecuted {
+			executing: T::AccountId,
+			timepoint: Timepoint<T::BlockNumber>,
+			multisig: T::AccountId,
+			call_hash: CallHash,
+			result: DispatchResult,
+		},
+		/// An expired proposal of a managed multisig account has been removed.
+		ManagedProposalExpired {
+			timepoint: Timepoint<T::BlockNumber>,
+			multisig: T::AccountId,
+			call_hash: CallHash,
+		},
 	}
 
 	#[pallet::hooks]
@@ -499,6 +612,292 @@ pub mod pallet {
 			});
 			Ok(())
 		}
+
+		/// Create a managed multisig account.
+		///
+		/// Unlike the accounts derived by `multi_account_id`, the signatories and the threshold of
+		/// a managed multisig are stored on chain and can be changed by the account itself with
+		/// `update_managed` without changing its address.
+		///
+		/// Payment: `DepositBase` plus `DepositFactor` per signatory will be reserved from the
+		/// sender until the configuration is changed or the account is dissolved.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `signatories`: The accounts who can propose and approve calls together with their
+		/// voting weight. Must be sorted by account and may not be empty.
+		/// - `threshold`: The total voting weight of the approvals needed to execute a proposal.
+		///
+		/// The address of the account is `managed_account_id` of the sender and the current
+		/// timepoint and is emitted in the `ManagedMultisigCreated` event.
+		#[pallet::weight(T::WeightInfo::create_managed(signatories.len() as u32))]
+		pub fn create_managed(
+			origin: OriginFor<T>,
+			signatories: Vec<(T::AccountId, u16)>,
+			threshold: u32,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			Self::ensure_valid_managed(&signatories, threshold)?;
+
+			let id = Self::managed_account_id(&who, &Self::timepoint());
+			ensure!(!ManagedMultisigs::<T>::contains_key(&id), Error::<T>::AlreadyExists);
+
+			let deposit = Self::managed_deposit(signatories.len());
+			T::Currency::reserve(&who, deposit)?;
+			ManagedMultisigs::<T>::insert(
+				&id,
+				ManagedMultisig { signatories, threshold, deposit, depositor: who.clone() },
+			);
+
+			Self::deposit_event(Event::ManagedMultisigCreated {
+				creator: who,
+				multisig: id,
+				threshold,
+			});
+			Ok(())
+		}
+
+		/// Change the signatories and the threshold of a managed multisig account.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the managed multisig account
+		/// itself, i.e. the change must be approved by its current signatories.
+		///
+		/// The deposit for the configuration is returned to its previous depositor and reserved
+		/// from the managed multisig account instead.
+		///
+		/// - `signatories`: The new signatories together with their voting weight. Must be sorted
+		/// by account and may not be empty.
+		/// - `threshold`: The new total voting weight of the approvals needed to execute a
+		/// proposal.
+		///
+		/// Open proposals are kept, their approvals are counted with the new voting weights.
+		#[pallet::weight(T::WeightInfo::update_managed(signatories.len() as u32))]
+		#[transactional]
+		pub fn update_managed(
+			origin: OriginFor<T>,
+			signatories: Vec<(T::AccountId, u16)>,
+			threshold: u32,
+		) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+			Self::ensure_valid_managed(&signatories, threshold)?;
+
+			let mut managed = ManagedMultisigs::<T>::get(&id).ok_or(Error::<T>::NotManaged)?;
+			T::Currency::unreserve(&managed.depositor, managed.deposit);
+			let deposit = Self::managed_deposit(signatories.len());
+			T::Currency::reserve(&id, deposit)?;
+
+			managed.signatories = signatories;
+			managed.threshold = threshold;
+			managed.deposit = deposit;
+			managed.depositor = id.clone();
+			ManagedMultisigs::<T>::insert(&id, managed);
+
+			Self::deposit_event(Event::ManagedMultisigUpdated { multisig: id, threshold });
+			Ok(())
+		}
+
+		/// Dissolve a managed multisig account, returning the deposit for its configuration.
+		///
+		/// The dispatch origin for this call must be _Signed_ by the managed multisig account
+		/// itself.
+		///
+		/// Open proposals can no longer be approved or executed, but can still be removed with
+		/// `expire_managed` once they expired.
+		#[pallet::weight(T::WeightInfo::dissolve_managed(T::MaxSignatories::get().into()))]
+		pub fn dissolve_managed(origin: OriginFor<T>) -> DispatchResult {
+			let id = ensure_signed(origin)?;
+
+			let managed = ManagedMultisigs::<T>::take(&id).ok_or(Error::<T>::NotManaged)?;
+			T::Currency::unreserve(&managed.depositor, managed.deposit);
+
+			Self::deposit_event(Event::ManagedMultisigDissolved { multisig: id });
+			Ok(())
+		}
+
+		/// Open a proposal to dispatch a call from a managed multisig account, approving it.
+		///
+		/// Payment: `DepositBase` plus `DepositFactor` per signatory will be reserved from the
+		/// sender. It is returned once the proposal is executed or removed after it expired.
+		///
+		/// The dispatch origin for this call must be _Signed_ by a signatory of `multisig`.
+		///
+		/// - `multisig`: The managed multisig account to dispatch the call from.
+		/// - `call_hash`: The hash of the call to be executed.
+		///
+		/// The proposal expires after `ManagedProposalLifetime` blocks.
+		#[pallet::weight(T::WeightInfo::propose_managed(T::MaxSignatories::get().into()))]
+		pub fn propose_managed(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			call_hash: CallHash,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let managed = ManagedMultisigs::<T>::get(&multisig).ok_or(Error::<T>::NotManaged)?;
+			Self::signatory_weight(&managed, &who)?;
+			ensure!(
+				!ManagedProposals::<T>::contains_key(&multisig, &call_hash),
+				Error::<T>::AlreadyExists
+			);
+
+			let deposit = Self::managed_deposit(managed.signatories.len());
+			T::Currency::reserve(&who, deposit)?;
+
+			let timepoint = Self::timepoint();
+			let expiry = timepoint.height.saturating_add(T::ManagedProposalLifetime::get());
+			ManagedProposals::<T>::insert(
+				&multisig,
+				&call_hash,
+				ManagedProposal {
+					when: timepoint,
+					expiry,
+					deposit,
+					depositor: who.clone(),
+					approvals: vec![who.clone()],
+				},
+			);
+
+			Self::deposit_event(Event::NewManagedProposal {
+				proposing: who,
+				timepoint,
+				multisig,
+				call_hash,
+			});
+			Ok(())
+		}
+
+		/// Approve an open proposal of a managed multisig account.
+		///
+		/// The dispatch origin for this call must be _Signed_ by a signatory of `multisig`.
+		///
+		/// - `multisig`: The managed multisig account the proposal belongs to.
+		/// - `timepoint`: The timepoint (block number and transaction index) of the transaction
+		/// which opened the proposal.
+		/// - `call_hash`: The hash of the call to be executed.
+		#[pallet::weight(T::WeightInfo::approve_managed(T::MaxSignatories::get().into()))]
+		pub fn approve_managed(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			timepoint: Timepoint<T::BlockNumber>,
+			call_hash: CallHash,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let managed = ManagedMultisigs::<T>::get(&multisig).ok_or(Error::<T>::NotManaged)?;
+			Self::signatory_weight(&managed, &who)?;
+
+			let mut proposal = Self::open_proposal(&multisig, &call_hash, &timepoint)?;
+			let pos = proposal
+				.approvals
+				.binary_search(&who)
+				.err()
+				.ok_or(Error::<T>::AlreadyApproved)?;
+			proposal.approvals.insert(pos, who.clone());
+			ManagedProposals::<T>::insert(&multisig, &call_hash, proposal);
+
+			Self::deposit_event(Event::ManagedProposalApproval {
+				approving: who,
+				timepoint,
+				multisig,
+				call_hash,
+			});
+			Ok(())
+		}
+
+		/// Execute an open proposal of a managed multisig account once its approvals reach the
+		/// threshold.
+		///
+		/// The voting weight of the approvals is counted with the current configuration of the
+		/// managed multisig. Any deposit reserved for the proposal is unreserved on success.
+		///
+		/// The dispatch origin for this call must be _Signed_ by a signatory of `multisig`.
+		///
+		/// - `multisig`: The managed multisig account the proposal belongs to.
+		/// - `timepoint`: The timepoint (block number and transaction index) of the transaction
+		/// which opened the proposal.
+		/// - `call`: The call to be executed.
+		/// - `max_weight`: The maximum weight of the `call`.
+		///
+		/// On success, the result of the call may be found in the deposited
+		/// `ManagedProposalExecuted` event.
+		#[pallet::weight({
+			let s = T::MaxSignatories::get().into();
+			let z = call.encoded_len() as u32;
+
+			T::WeightInfo::execute_managed(s, z).saturating_add(*max_weight)
+		})]
+		pub fn execute_managed(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			timepoint: Timepoint<T::BlockNumber>,
+			call: OpaqueCall<T>,
+			max_weight: Weight,
+		) -> DispatchResultWithPostInfo {
+			let who = ensure_signed(origin)?;
+			let managed = ManagedMultisigs::<T>::get(&multisig).ok_or(Error::<T>::NotManaged)?;
+			Self::signatory_weight(&managed, &who)?;
+
+			let call_hash = blake2_256(call.encoded());
+			let call_len = call.encoded_len();
+			let proposal = Self::open_proposal(&multisig, &call_hash, &timepoint)?;
+			let approved = proposal
+				.approvals
+				.iter()
+				.filter_map(|approval| Self::signatory_weight(&managed, approval).ok())
+				.fold(0u32, |total, weight| total.saturating_add(weight.into()));
+			ensure!(approved >= managed.threshold, Error::<T>::InsufficientApprovals);
+
+			let call = call.try_decode().ok_or(Error::<T>::UndecodableCall)?;
+			ensure!(call.get_dispatch_info().weight <= max_weight, Error::<T>::MaxWeightTooLow);
+
+			// Clean up storage before executing call to avoid an possibility of reentrancy
+			// attack.
+			ManagedProposals::<T>::remove(&multisig, &call_hash);
+			T::Currency::unreserve(&proposal.depositor, proposal.deposit);
+
+			let result = call.dispatch(RawOrigin::Signed(multisig.clone()).into());
+			Self::deposit_event(Event::ManagedProposalExecuted {
+				executing: who,
+				timepoint,
+				multisig,
+				call_hash,
+				result: result.map(|_| ()).map_err(|e| e.error),
+			});
+			Ok(get_result_weight(result)
+				.map(|actual_weight| {
+					T::WeightInfo::execute_managed(T::MaxSignatories::get().into(), call_len as u32)
+						.saturating_add(actual_weight)
+				})
+				.into())
+		}
+
+		/// Remove an expired proposal of a managed multisig account, returning its deposit to the
+		/// signatory who opened it.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// - `multisig`: The managed multisig account the proposal belongs to.
+		/// - `timepoint`: The timepoint (block number and transaction index) of the transaction
+		/// which opened the proposal.
+		/// - `call_hash`: The hash of the call of the proposal.
+		#[pallet::weight(T::WeightInfo::expire_managed(T::MaxSignatories::get().into()))]
+		pub fn expire_managed(
+			origin: OriginFor<T>,
+			multisig: T::AccountId,
+			timepoint: Timepoint<T::BlockNumber>,
+			call_hash: CallHash,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+
+			let proposal =
+				ManagedProposals::<T>::get(&multisig, &call_hash).ok_or(Error::<T>::NotFound)?;
+			ensure!(proposal.when == timepoint, Error::<T>::WrongTimepoint);
+			ensure!(<system::Pallet<T>>::block_number() >= proposal.expiry, Error::<T>::NotExpired);
+
+			ManagedProposals::<T>::remove(&multisig, &call_hash);
+			T::Currency::unreserve(&proposal.depositor, proposal.deposit);
+
+			Self::deposit_event(Event::ManagedProposalExpired { timepoint, multisig, call_hash });
+			Ok(())
+		}
 	}
 }
 
@@ -742,6 +1141,64 @@ impl<T: Config> Pallet<T> {
 		signatories.insert(index, who);
 		Ok(signatories)
 	}
+
+	/// Derive the account ID of a managed multisig created by `who` at `when`.
+	pub fn managed_account_id(
+		who: &T::AccountId,
+		when: &Timepoint<T::BlockNumber>,
+	) -> T::AccountId {
+		let entropy = (b"modlpy/managedms", who, when).using_encoded(blake2_256);
+		Decode::decode(&mut TrailingZeroInput::new(entropy.as_ref()))
+			.expect("infinite length input; no invalid inputs for type; qed")
+	}
+
+	/// The deposit for storing a managed multisig or a proposal with `signatories` signatories.
+	fn managed_deposit(signatories: usize) -> BalanceOf<T> {
+		T::DepositBase::get() + T::DepositFactor::get() * (signatories as u32).into()
+	}
+
+	/// Check that the signatories and the threshold of a managed multisig are valid.
+	fn ensure_valid_managed(signatories: &[(T::AccountId, u16)], threshold: u32) -> DispatchResult {
+		ensure!(!signatories.is_empty(), Error::<T>::TooFewSignatories);
+		ensure!(
+			signatories.len() <= T::MaxSignatories::get() as usize,
+			Error::<T>::TooManySignatories
+		);
+		ensure!(
+			signatories.windows(2).all(|pair| pair[0].0 < pair[1].0),
+			Error::<T>::SignatoriesOutOfOrder
+		);
+		ensure!(signatories.iter().all(|(_, weight)| !weight.is_zero()), Error::<T>::ZeroWeight);
+		let total = signatories.iter().map(|(_, weight)| *weight as u32).sum::<u32>();
+		ensure!(threshold > 0 && threshold <= total, Error::<T>::InvalidThreshold);
+		Ok(())
+	}
+
+	/// The voting weight of `who` in a managed multisig, failing if it is not a signatory.
+	fn signatory_weight(
+		managed: &ManagedMultisig<BalanceOf<T>, T::AccountId>,
+		who: &T::AccountId,
+	) -> Result<u16, DispatchError> {
+		let index = managed
+			.signatories
+			.binary_search_by(|(signatory, _)| signatory.cmp(who))
+			.map_err(|_| Error::<T>::NotSignatory)?;
+		Ok(managed.signatories[index].1)
+	}
+
+	/// Get a proposal of a managed multisig which has been opened at `timepoint` and has not
+	/// expired yet.
+	fn open_proposal(
+		multisig: &T::AccountId,
+		call_hash: &CallHash,
+		timepoint: &Timepoint<T::BlockNumber>,
+	) -> Result<ManagedProposal<T::BlockNumber, BalanceOf<T>, T::AccountId>, DispatchError> {
+		let proposal =
+			ManagedProposals::<T>::get(multisig, call_hash).ok_or(Error::<T>::NotFound)?;
+		ensure!(&proposal.when == timepoint, Error::<T>::WrongTimepoint);
+		ensure!(<system::Pallet<T>>::block_number() < proposal.expiry, Error::<T>::Expired);
+		Ok(proposal)
+	}
 }
 
 /// Return the weight of a dispatch call result as an `Option`.
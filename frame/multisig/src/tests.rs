@@ -109,6 +109,7 @@ impl Config for Test {
 	type DepositBase = ConstU64<1>;
 	type DepositFactor = ConstU64<1>;
 	type MaxSignatories = ConstU16<3>;
+	type ManagedProposalLifetime = ConstU64<10>;
 	type WeightInfo = ();
 }
 
@@ -944,3 +945,245 @@ fn multisig_handles_no_preimage_after_all_approve() {
 		assert_eq!(Balances::free_balance(6), 15);
 	});
 }
+
+#[test]
+fn create_managed_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Multisig::create_managed(Origin::signed(1), vec![], 1),
+			Error::<Test>::TooFewSignatories
+		);
+		assert_noop!(
+			Multisig::create_managed(Origin::signed(1), vec![(1, 1), (2, 1), (3, 1), (4, 1)], 2),
+			Error::<Test>::TooManySignatories,
+		);
+		assert_noop!(
+			Multisig::create_managed(Origin::signed(1), vec![(2, 1), (1, 1)], 2),
+			Error::<Test>::SignatoriesOutOfOrder,
+		);
+		assert_noop!(
+			Multisig::create_managed(Origin::signed(1), vec![(1, 1), (2, 0)], 1),
+			Error::<Test>::ZeroWeight,
+		);
+		assert_noop!(
+			Multisig::create_managed(Origin::signed(1), vec![(1, 1), (2, 1)], 0),
+			Error::<Test>::InvalidThreshold,
+		);
+		assert_noop!(
+			Multisig::create_managed(Origin::signed(1), vec![(1, 1), (2, 1)], 3),
+			Error::<Test>::InvalidThreshold,
+		);
+
+		assert_ok!(Multisig::create_managed(Origin::signed(1), vec![(1, 1), (2, 1), (3, 2)], 3));
+		let multi = Multisig::managed_account_id(&1, &now());
+		assert_eq!(Balances::reserved_balance(1), 4);
+		System::assert_last_event(
+			pallet_multisig::Event::ManagedMultisigCreated {
+				creator: 1,
+				multisig: multi,
+				threshold: 3,
+			}
+			.into(),
+		);
+
+		// The address is derived from the creator and the timepoint.
+		assert_noop!(
+			Multisig::create_managed(Origin::signed(1), vec![(1, 1)], 1),
+			Error::<Test>::AlreadyExists,
+		);
+		assert_ok!(Multisig::create_managed(Origin::signed(2), vec![(1, 1)], 1));
+		assert_ne!(Multisig::managed_account_id(&2, &now()), multi);
+	});
+}
+
+#[test]
+fn managed_proposal_lifecycle_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Multisig::create_managed(Origin::signed(1), vec![(1, 1), (2, 1), (3, 2)], 3));
+		let multi = Multisig::managed_account_id(&1, &now());
+		assert_ok!(Balances::transfer(Origin::signed(4), multi, 5));
+
+		let call = call_transfer(6, 3);
+		let call_weight = call.get_dispatch_info().weight;
+		let data = call.encode();
+		let hash = blake2_256(&data);
+
+		assert_noop!(
+			Multisig::propose_managed(Origin::signed(4), multi, hash),
+			Error::<Test>::NotSignatory,
+		);
+		assert_ok!(Multisig::propose_managed(Origin::signed(1), multi, hash));
+		assert_eq!(Balances::reserved_balance(1), 8);
+		let timepoint = now();
+		assert_noop!(
+			Multisig::propose_managed(Origin::signed(2), multi, hash),
+			Error::<Test>::AlreadyExists,
+		);
+
+		assert_noop!(
+			Multisig::approve_managed(Origin::signed(1), multi, timepoint, hash),
+			Error::<Test>::AlreadyApproved,
+		);
+		assert_noop!(
+			Multisig::approve_managed(Origin::signed(4), multi, timepoint, hash),
+			Error::<Test>::NotSignatory,
+		);
+		assert_ok!(Multisig::approve_managed(Origin::signed(2), multi, timepoint, hash));
+		assert_noop!(
+			Multisig::execute_managed(
+				Origin::signed(2),
+				multi,
+				timepoint,
+				OpaqueCall::from_encoded(data.clone()),
+				call_weight
+			),
+			Error::<Test>::InsufficientApprovals,
+		);
+
+		// The voting weight of 3 is enough on its own to reach the threshold with 1.
+		assert_ok!(Multisig::approve_managed(Origin::signed(3), multi, timepoint, hash));
+		assert_noop!(
+			Multisig::execute_managed(
+				Origin::signed(2),
+				multi,
+				Timepoint { height: 2, index: 0 },
+				OpaqueCall::from_encoded(data.clone()),
+				call_weight
+			),
+			Error::<Test>::WrongTimepoint,
+		);
+		assert_noop!(
+			Multisig::execute_managed(
+				Origin::signed(2),
+				multi,
+				timepoint,
+				OpaqueCall::from_encoded(data.clone()),
+				0
+			),
+			Error::<Test>::MaxWeightTooLow,
+		);
+		assert_ok!(Multisig::execute_managed(
+			Origin::signed(2),
+			multi,
+			timepoint,
+			OpaqueCall::from_encoded(data),
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 3);
+		assert_eq!(Balances::reserved_balance(1), 4);
+		assert!(!ManagedProposals::<Test>::contains_key(multi, hash));
+		System::assert_last_event(
+			pallet_multisig::Event::ManagedProposalExecuted {
+				executing: 2,
+				timepoint,
+				multisig: multi,
+				call_hash: hash,
+				result: Ok(()),
+			}
+			.into(),
+		);
+	});
+}
+
+#[test]
+fn managed_proposal_expires() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Multisig::create_managed(Origin::signed(1), vec![(1, 1), (2, 1)], 2));
+		let multi = Multisig::managed_account_id(&1, &now());
+		let hash = blake2_256(&call_transfer(6, 3).encode());
+
+		assert_ok!(Multisig::propose_managed(Origin::signed(2), multi, hash));
+		let timepoint = now();
+		assert_eq!(Balances::reserved_balance(2), 3);
+
+		System::set_block_number(10);
+		assert_noop!(
+			Multisig::expire_managed(Origin::signed(5), multi, timepoint, hash),
+			Error::<Test>::NotExpired,
+		);
+
+		System::set_block_number(11);
+		assert_noop!(
+			Multisig::approve_managed(Origin::signed(1), multi, timepoint, hash),
+			Error::<Test>::Expired,
+		);
+		assert_ok!(Multisig::expire_managed(Origin::signed(5), multi, timepoint, hash));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert!(!ManagedProposals::<Test>::contains_key(multi, hash));
+		System::assert_last_event(
+			pallet_multisig::Event::ManagedProposalExpired {
+				timepoint,
+				multisig: multi,
+				call_hash: hash,
+			}
+			.into(),
+		);
+
+		// A new proposal for the same call can be opened now.
+		assert_ok!(Multisig::propose_managed(Origin::signed(1), multi, hash));
+	});
+}
+
+#[test]
+fn managed_multisig_can_be_updated_and_dissolved() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Multisig::create_managed(Origin::signed(1), vec![(1, 1), (2, 1)], 1));
+		let multi = Multisig::managed_account_id(&1, &now());
+		assert_ok!(Balances::transfer(Origin::signed(4), multi, 8));
+
+		let call = call_transfer(6, 3);
+		let call_weight = call.get_dispatch_info().weight;
+		let data = call.encode();
+		let hash = blake2_256(&data);
+		assert_ok!(Multisig::propose_managed(Origin::signed(1), multi, hash));
+		let timepoint = now();
+
+		assert_noop!(
+			Multisig::update_managed(Origin::signed(1), vec![(2, 1), (4, 1)], 2),
+			Error::<Test>::NotManaged,
+		);
+		assert_noop!(
+			Multisig::update_managed(Origin::signed(multi), vec![(2, 1), (4, 1)], 3),
+			Error::<Test>::InvalidThreshold,
+		);
+		// The change is dispatched by the managed account itself, e.g. from a proposal.
+		assert_ok!(Multisig::update_managed(Origin::signed(multi), vec![(2, 1), (4, 1)], 2));
+		assert_eq!(Balances::reserved_balance(1), 3);
+		assert_eq!(Balances::reserved_balance(multi), 3);
+		System::assert_last_event(
+			pallet_multisig::Event::ManagedMultisigUpdated { multisig: multi, threshold: 2 }.into(),
+		);
+
+		// The approval of the removed signatory 1 no longer counts.
+		assert_ok!(Multisig::approve_managed(Origin::signed(2), multi, timepoint, hash));
+		assert_noop!(
+			Multisig::execute_managed(
+				Origin::signed(2),
+				multi,
+				timepoint,
+				OpaqueCall::from_encoded(data.clone()),
+				call_weight
+			),
+			Error::<Test>::InsufficientApprovals,
+		);
+		assert_ok!(Multisig::approve_managed(Origin::signed(4), multi, timepoint, hash));
+		assert_ok!(Multisig::execute_managed(
+			Origin::signed(4),
+			multi,
+			timepoint,
+			OpaqueCall::from_encoded(data),
+			call_weight
+		));
+		assert_eq!(Balances::free_balance(6), 3);
+		assert_eq!(Balances::reserved_balance(1), 0);
+
+		assert_ok!(Multisig::dissolve_managed(Origin::signed(multi)));
+		assert_eq!(Balances::reserved_balance(multi), 0);
+		assert!(!ManagedMultisigs::<Test>::contains_key(multi));
+		assert_noop!(
+			Multisig::propose_managed(Origin::signed(2), multi, hash),
+			Error::<Test>::NotManaged,
+		);
+		assert_noop!(Multisig::dissolve_managed(Origin::signed(multi)), Error::<Test>::NotManaged);
+	});
+}
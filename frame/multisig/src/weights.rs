@@ -56,6 +56,13 @@ pub trait WeightInfo {
 	fn approve_as_multi_approve(s: u32, ) -> Weight;
 	fn approve_as_multi_complete(s: u32, ) -> Weight;
 	fn cancel_as_multi(s: u32, ) -> Weight;
+	fn create_managed(s: u32, ) -> Weight;
+	fn update_managed(s: u32, ) -> Weight;
+	fn dissolve_managed(s: u32, ) -> Weight;
+	fn propose_managed(s: u32, ) -> Weight;
+	fn approve_managed(s: u32, ) -> Weight;
+	fn execute_managed(s: u32, z: u32, ) -> Weight;
+	fn expire_managed(s: u32, ) -> Weight;
 }
 
 /// Weights for pallet_multisig using the Substrate node and recommended hardware.
@@ -159,6 +166,66 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
+	// NOTE: the weights of the `*_managed` calls are placeholders until they are benchmarked.
+	// Storage: unknown [0x3a65787472696e7369635f696e646578] (r:1 w:0)
+	// Storage: Multisig ManagedMultisigs (r:1 w:1)
+	fn create_managed(s: u32, ) -> Weight {
+		(27_301_000 as Weight)
+			.saturating_add((97_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Multisig ManagedMultisigs (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn update_managed(s: u32, ) -> Weight {
+		(33_624_000 as Weight)
+			.saturating_add((118_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Multisig ManagedMultisigs (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn dissolve_managed(s: u32, ) -> Weight {
+		(28_716_000 as Weight)
+			.saturating_add((64_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Multisig ManagedMultisigs (r:1 w:0)
+	// Storage: Multisig ManagedProposals (r:1 w:1)
+	// Storage: unknown [0x3a65787472696e7369635f696e646578] (r:1 w:0)
+	fn propose_managed(s: u32, ) -> Weight {
+		(31_052_000 as Weight)
+			.saturating_add((102_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Multisig ManagedMultisigs (r:1 w:0)
+	// Storage: Multisig ManagedProposals (r:1 w:1)
+	fn approve_managed(s: u32, ) -> Weight {
+		(21_987_000 as Weight)
+			.saturating_add((165_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Multisig ManagedMultisigs (r:1 w:0)
+	// Storage: Multisig ManagedProposals (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn execute_managed(s: u32, z: u32, ) -> Weight {
+		(38_440_000 as Weight)
+			.saturating_add((287_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add((2_000 as Weight).saturating_mul(z as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Multisig ManagedProposals (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn expire_managed(s: u32, ) -> Weight {
+		(26_338_000 as Weight)
+			.saturating_add((71_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -261,4 +328,64 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
+	// NOTE: the weights of the `*_managed` calls are placeholders until they are benchmarked.
+	// Storage: unknown [0x3a65787472696e7369635f696e646578] (r:1 w:0)
+	// Storage: Multisig ManagedMultisigs (r:1 w:1)
+	fn create_managed(s: u32, ) -> Weight {
+		(27_301_000 as Weight)
+			.saturating_add((97_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Multisig ManagedMultisigs (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn update_managed(s: u32, ) -> Weight {
+		(33_624_000 as Weight)
+			.saturating_add((118_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Multisig ManagedMultisigs (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn dissolve_managed(s: u32, ) -> Weight {
+		(28_716_000 as Weight)
+			.saturating_add((64_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Multisig ManagedMultisigs (r:1 w:0)
+	// Storage: Multisig ManagedProposals (r:1 w:1)
+	// Storage: unknown [0x3a65787472696e7369635f696e646578] (r:1 w:0)
+	fn propose_managed(s: u32, ) -> Weight {
+		(31_052_000 as Weight)
+			.saturating_add((102_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Multisig ManagedMultisigs (r:1 w:0)
+	// Storage: Multisig ManagedProposals (r:1 w:1)
+	fn approve_managed(s: u32, ) -> Weight {
+		(21_987_000 as Weight)
+			.saturating_add((165_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Multisig ManagedMultisigs (r:1 w:0)
+	// Storage: Multisig ManagedProposals (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn execute_managed(s: u32, z: u32, ) -> Weight {
+		(38_440_000 as Weight)
+			.saturating_add((287_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add((2_000 as Weight).saturating_mul(z as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Multisig ManagedProposals (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn expire_managed(s: u32, ) -> Weight {
+		(26_338_000 as Weight)
+			.saturating_add((71_000 as Weight).saturating_mul(s as Weight))
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
 }
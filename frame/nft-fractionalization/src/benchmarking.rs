@@ -0,0 +1,100 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! NFT fractionalization pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{benchmarks, whitelisted_caller};
+use frame_support::{
+	assert_ok,
+	traits::tokens::nonfungibles::{Create as NftCreate, Mutate as NftMutate},
+};
+use frame_system::RawOrigin as SystemOrigin;
+use sp_runtime::traits::Bounded;
+
+use crate::Pallet as NftFractionalization;
+
+fn mint_nft<T: Config>(
+	nft_instance: T::NftInstanceId,
+) -> (T::AccountId, <T::Lookup as StaticLookup>::Source)
+where
+	T::Nfts: NftCreate<T::AccountId> + NftMutate<T::AccountId>,
+{
+	let caller: T::AccountId = whitelisted_caller();
+	let caller_lookup = T::Lookup::unlookup(caller.clone());
+	T::Currency::make_free_balance_be(&caller, DepositOf::<T>::max_value());
+	let nft_class = T::BenchmarkHelper::class(0);
+	assert_ok!(T::Nfts::create_class(&nft_class, &caller, &caller));
+	assert_ok!(T::Nfts::mint_into(&nft_class, &nft_instance, &caller));
+	(caller, caller_lookup)
+}
+
+fn assert_last_event<T: Config>(generic_event: <T as Config>::Event) {
+	let events = frame_system::Pallet::<T>::events();
+	let system_event: <T as frame_system::Config>::Event = generic_event.into();
+	// compare to the last event record
+	let frame_system::EventRecord { event, .. } = &events[events.len() - 1];
+	assert_eq!(event, &system_event);
+}
+
+benchmarks! {
+	where_clause {
+		where T::Nfts: NftCreate<T::AccountId> + NftMutate<T::AccountId>
+	}
+
+	fractionalize {
+		let asset = T::BenchmarkHelper::asset(0);
+		let nft_class = T::BenchmarkHelper::class(0);
+		let nft_instance = T::BenchmarkHelper::instance(0);
+		let (caller, caller_lookup) = mint_nft::<T>(nft_instance);
+	}: _(SystemOrigin::Signed(caller.clone()), nft_class, nft_instance, asset, caller_lookup, 1000u32.into())
+	verify {
+		assert_last_event::<T>(
+			Event::NftFractionalized {
+				nft_class,
+				nft_instance,
+				fractions: 1000u32.into(),
+				asset,
+				beneficiary: caller,
+			}.into()
+		);
+	}
+
+	unify {
+		let asset = T::BenchmarkHelper::asset(0);
+		let nft_class = T::BenchmarkHelper::class(0);
+		let nft_instance = T::BenchmarkHelper::instance(0);
+		let (caller, caller_lookup) = mint_nft::<T>(nft_instance);
+		NftFractionalization::<T>::fractionalize(
+			SystemOrigin::Signed(caller.clone()).into(),
+			nft_class,
+			nft_instance,
+			asset,
+			caller_lookup.clone(),
+			1000u32.into(),
+		)?;
+	}: _(SystemOrigin::Signed(caller.clone()), nft_class, nft_instance, asset, caller_lookup)
+	verify {
+		assert_last_event::<T>(
+			Event::NftUnified { nft_class, nft_instance, asset, beneficiary: caller }.into()
+		);
+	}
+
+	impl_benchmark_test_suite!(NftFractionalization, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,316 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # NFT Fractionalization Module
+//!
+//! Locks a non-fungible asset and mints a supply of a newly created fungible asset representing
+//! shares of it.
+//!
+//! ## Overview
+//!
+//! The module only relies on the `nonfungibles` and `fungibles` traits and therefore works with
+//! any implementation of them, e.g. `pallet-uniques` and `pallet-assets`.
+//!
+//! - [`Pallet::fractionalize`] transfers an NFT of the caller to the account of this pallet,
+//!   creates a new asset and mints the requested amount of fractions of it to a beneficiary.
+//!   [`Config::Deposit`] is reserved from the caller until the NFT is unified again.
+//! - [`Pallet::unify`] burns the full supply of fractions from the caller, destroys the asset and
+//!   transfers the NFT to a beneficiary.
+//!
+//! The created asset is administered by the account of this pallet, so no further fractions can
+//! be minted for it. It is not sufficient, so the beneficiary of the fractions needs to exist.
+//!
+//! ## Related Modules
+//!
+//! * [`System`](../frame_system/index.html)
+//! * [`Support`](../frame_support/index.html)
+
+// Ensure we're `no_std` when compiling for Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+pub mod mock;
+#[cfg(test)]
+mod tests;
+
+mod types;
+pub mod weights;
+
+use frame_support::{
+	traits::{
+		tokens::{
+			fungibles::{
+				Create as AssetCreate, Destroy as AssetDestroy, Inspect as AssetInspect,
+				Mutate as AssetMutate,
+			},
+			nonfungibles::{Inspect as NftInspect, Transfer as NftTransfer},
+			AssetId, Balance as AssetBalance,
+		},
+		Currency, ReservableCurrency,
+	},
+	transactional, PalletId,
+};
+use sp_runtime::traits::{AccountIdConversion, One, StaticLookup, Zero};
+
+pub use pallet::*;
+pub use types::*;
+pub use weights::WeightInfo;
+
+/// Provides the identifiers used in the benchmarks of this pallet.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<AssetId, ClassId, InstanceId> {
+	/// Returns an asset id from a given integer.
+	fn asset(id: u32) -> AssetId;
+	/// Returns an NFT class id from a given integer.
+	fn class(id: u32) -> ClassId;
+	/// Returns an NFT instance id from a given integer.
+	fn instance(id: u32) -> InstanceId;
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<AssetId, ClassId, InstanceId> BenchmarkHelper<AssetId, ClassId, InstanceId> for ()
+where
+	AssetId: From<u32>,
+	ClassId: From<u32>,
+	InstanceId: From<u32>,
+{
+	fn asset(id: u32) -> AssetId {
+		id.into()
+	}
+	fn class(id: u32) -> ClassId {
+		id.into()
+	}
+	fn instance(id: u32) -> InstanceId {
+		id.into()
+	}
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	/// The module configuration trait.
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The currency mechanism, used for paying for deposits.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The deposit paid by the caller of `fractionalize` for the asset it creates.
+		#[pallet::constant]
+		type Deposit: Get<DepositOf<Self>>;
+
+		/// The pallet's id, used for deriving the account which holds the locked NFTs.
+		#[pallet::constant]
+		type PalletId: Get<PalletId>;
+
+		/// Identifier for the fungible assets representing the fractions.
+		type AssetId: AssetId + Member + Parameter + MaxEncodedLen;
+
+		/// The balance type of the fungible assets representing the fractions.
+		type AssetBalance: AssetBalance + Member + Parameter + MaxEncodedLen;
+
+		/// The fungible assets used to represent the fractions.
+		type Assets: AssetInspect<Self::AccountId, AssetId = Self::AssetId, Balance = Self::AssetBalance>
+			+ AssetCreate<Self::AccountId>
+			+ AssetMutate<Self::AccountId>
+			+ AssetDestroy<Self::AccountId>;
+
+		/// Identifier for the classes of the NFTs which can be fractionalized.
+		type NftClassId: Member + Parameter + MaxEncodedLen + Copy;
+
+		/// Identifier for the instances of the NFTs which can be fractionalized.
+		type NftInstanceId: Member + Parameter + MaxEncodedLen + Copy;
+
+		/// The NFTs which can be fractionalized.
+		type Nfts: NftInspect<
+				Self::AccountId,
+				ClassId = Self::NftClassId,
+				InstanceId = Self::NftInstanceId,
+			> + NftTransfer<Self::AccountId>;
+
+		#[cfg(feature = "runtime-benchmarks")]
+		/// A set of helper functions for benchmarking.
+		type BenchmarkHelper: BenchmarkHelper<Self::AssetId, Self::NftClassId, Self::NftInstanceId>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::storage]
+	/// Details of the fractionalized NFTs, by NFT class and instance.
+	pub type NftToAsset<T: Config> = StorageMap<
+		_,
+		Blake2_128Concat,
+		(T::NftClassId, T::NftInstanceId),
+		DetailsOf<T>,
+		OptionQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// An NFT was locked and `fractions` of `asset` were minted to `beneficiary`.
+		NftFractionalized {
+			nft_class: T::NftClassId,
+			nft_instance: T::NftInstanceId,
+			fractions: T::AssetBalance,
+			asset: T::AssetId,
+			beneficiary: T::AccountId,
+		},
+		/// The fractions of `asset` were burned and the NFT was transferred to `beneficiary`.
+		NftUnified {
+			nft_class: T::NftClassId,
+			nft_instance: T::NftInstanceId,
+			asset: T::AssetId,
+			beneficiary: T::AccountId,
+		},
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The asset id doesn't match the one the NFT was fractionalized into.
+		IncorrectAssetId,
+		/// The signing account has no permission to do the operation.
+		NoPermission,
+		/// The NFT doesn't exist.
+		NftNotFound,
+		/// The NFT has not been fractionalized.
+		NftNotFractionalized,
+		/// An NFT can't be fractionalized into zero fractions.
+		ZeroFractions,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Lock an NFT and mint a new fungible asset representing shares of it.
+		///
+		/// Origin must be Signed and must be the owner of the NFT. `T::Deposit` is reserved from
+		/// the sender.
+		///
+		/// - `nft_class`: The class of the NFT to be fractionalized.
+		/// - `nft_instance`: The instance of the NFT to be fractionalized.
+		/// - `asset`: The id of the asset to be created. It must not exist yet.
+		/// - `beneficiary`: The account receiving the fractions.
+		/// - `fractions`: The total supply of the created asset.
+		///
+		/// Emits `NftFractionalized` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::fractionalize())]
+		#[transactional]
+		pub fn fractionalize(
+			origin: OriginFor<T>,
+			nft_class: T::NftClassId,
+			nft_instance: T::NftInstanceId,
+			asset: T::AssetId,
+			beneficiary: <T::Lookup as StaticLookup>::Source,
+			fractions: T::AssetBalance,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
+
+			let nft_owner =
+				T::Nfts::owner(&nft_class, &nft_instance).ok_or(Error::<T>::NftNotFound)?;
+			ensure!(nft_owner == who, Error::<T>::NoPermission);
+			ensure!(!fractions.is_zero(), Error::<T>::ZeroFractions);
+
+			let deposit = T::Deposit::get();
+			T::Currency::reserve(&who, deposit)?;
+
+			let pallet_account = Self::account_id();
+			T::Nfts::transfer(&nft_class, &nft_instance, &pallet_account)?;
+			T::Assets::create(asset, pallet_account, false, One::one())?;
+			T::Assets::mint_into(asset, &beneficiary, fractions)?;
+
+			NftToAsset::<T>::insert(
+				(nft_class, nft_instance),
+				Details { asset, fractions, deposit, asset_creator: who },
+			);
+
+			Self::deposit_event(Event::NftFractionalized {
+				nft_class,
+				nft_instance,
+				fractions,
+				asset,
+				beneficiary,
+			});
+			Ok(())
+		}
+
+		/// Burn the full supply of fractions of an NFT and release it.
+		///
+		/// Origin must be Signed and must hold all the fractions of the NFT. The deposit is
+		/// returned to the account which fractionalized the NFT.
+		///
+		/// - `nft_class`: The class of the fractionalized NFT.
+		/// - `nft_instance`: The instance of the fractionalized NFT.
+		/// - `asset`: The id of the asset representing the fractions.
+		/// - `beneficiary`: The account receiving the NFT.
+		///
+		/// Emits `NftUnified` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::unify())]
+		#[transactional]
+		pub fn unify(
+			origin: OriginFor<T>,
+			nft_class: T::NftClassId,
+			nft_instance: T::NftInstanceId,
+			asset: T::AssetId,
+			beneficiary: <T::Lookup as StaticLookup>::Source,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let beneficiary = T::Lookup::lookup(beneficiary)?;
+
+			let details = NftToAsset::<T>::take((nft_class, nft_instance))
+				.ok_or(Error::<T>::NftNotFractionalized)?;
+			ensure!(details.asset == asset, Error::<T>::IncorrectAssetId);
+
+			T::Assets::burn_from(asset, &who, details.fractions)?;
+			let witness =
+				T::Assets::get_destroy_witness(&asset).ok_or(Error::<T>::IncorrectAssetId)?;
+			T::Assets::destroy(asset, witness, None)?;
+
+			T::Currency::unreserve(&details.asset_creator, details.deposit);
+			T::Nfts::transfer(&nft_class, &nft_instance, &beneficiary)?;
+
+			Self::deposit_event(Event::NftUnified { nft_class, nft_instance, asset, beneficiary });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// The account ID of the pallet, which holds the fractionalized NFTs.
+		///
+		/// This actually does computation. If you need to keep using it, then make sure you cache
+		/// the value and only call this once.
+		pub fn account_id() -> T::AccountId {
+			T::PalletId::get().into_account()
+		}
+	}
+}
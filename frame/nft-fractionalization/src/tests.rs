@@ -0,0 +1,148 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for NFT fractionalization pallet.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_noop, assert_ok, traits::Currency};
+use pallet_assets::Error as AssetsError;
+
+fn last_event() -> mock::Event {
+	System::events().pop().expect("Event expected").event
+}
+
+fn setup() {
+	Balances::make_free_balance_be(&1, 100);
+	Balances::make_free_balance_be(&2, 100);
+	Balances::make_free_balance_be(&3, 100);
+	assert_ok!(Uniques::force_create(Origin::root(), 0, 1, true));
+	assert_ok!(Uniques::mint(Origin::signed(1), 0, 42, 1));
+}
+
+fn nft_owner(instance: u32) -> Option<u64> {
+	<Uniques as NftInspect<u64>>::owner(&0, &instance)
+}
+
+#[test]
+fn fractionalize_should_work() {
+	new_test_ext().execute_with(|| {
+		setup();
+
+		assert_ok!(NftFractionalization::fractionalize(Origin::signed(1), 0, 42, 7, 2, 1000));
+		assert_eq!(nft_owner(42), Some(NftFractionalization::account_id()));
+		assert_eq!(<Assets as AssetInspect<u64>>::balance(7, &2), 1000);
+		assert_eq!(<Assets as AssetInspect<u64>>::total_issuance(7), 1000);
+		assert_eq!(Balances::reserved_balance(&1), 3);
+		assert_eq!(
+			NftToAsset::<Test>::get((0, 42)),
+			Some(Details { asset: 7, fractions: 1000, deposit: 3, asset_creator: 1 })
+		);
+		assert_eq!(
+			last_event(),
+			mock::Event::NftFractionalization(crate::Event::NftFractionalized {
+				nft_class: 0,
+				nft_instance: 42,
+				fractions: 1000,
+				asset: 7,
+				beneficiary: 2,
+			})
+		);
+
+		// The fractionalized NFT is owned by the pallet now.
+		assert_noop!(
+			NftFractionalization::fractionalize(Origin::signed(1), 0, 42, 8, 2, 1000),
+			Error::<Test>::NoPermission
+		);
+	});
+}
+
+#[test]
+fn fractionalize_should_fail_for_invalid_input() {
+	new_test_ext().execute_with(|| {
+		setup();
+
+		assert_noop!(
+			NftFractionalization::fractionalize(Origin::signed(2), 0, 42, 7, 2, 1000),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			NftFractionalization::fractionalize(Origin::signed(1), 0, 43, 7, 2, 1000),
+			Error::<Test>::NftNotFound
+		);
+		assert_noop!(
+			NftFractionalization::fractionalize(Origin::signed(1), 0, 42, 7, 2, 0),
+			Error::<Test>::ZeroFractions
+		);
+
+		assert_ok!(Assets::force_create(Origin::root(), 7, 3, false, 1));
+		assert_noop!(
+			NftFractionalization::fractionalize(Origin::signed(1), 0, 42, 7, 2, 1000),
+			AssetsError::<Test>::InUse
+		);
+		assert_eq!(nft_owner(42), Some(1));
+		assert_eq!(Balances::reserved_balance(&1), 0);
+	});
+}
+
+#[test]
+fn unify_should_work() {
+	new_test_ext().execute_with(|| {
+		setup();
+		assert_ok!(NftFractionalization::fractionalize(Origin::signed(1), 0, 42, 7, 2, 1000));
+
+		assert_noop!(
+			NftFractionalization::unify(Origin::signed(2), 0, 43, 7, 3),
+			Error::<Test>::NftNotFractionalized
+		);
+		assert_noop!(
+			NftFractionalization::unify(Origin::signed(2), 0, 42, 8, 3),
+			Error::<Test>::IncorrectAssetId
+		);
+		assert_noop!(
+			NftFractionalization::unify(Origin::signed(1), 0, 42, 7, 3),
+			AssetsError::<Test>::NoAccount
+		);
+
+		// All fractions are needed to unify the NFT.
+		assert_ok!(Assets::transfer(Origin::signed(2), 7, 3, 1));
+		assert_noop!(
+			NftFractionalization::unify(Origin::signed(2), 0, 42, 7, 3),
+			AssetsError::<Test>::BalanceLow
+		);
+		assert_ok!(Assets::transfer(Origin::signed(3), 7, 2, 1));
+
+		assert_ok!(NftFractionalization::unify(Origin::signed(2), 0, 42, 7, 3));
+		assert_eq!(nft_owner(42), Some(3));
+		assert_eq!(<Assets as AssetInspect<u64>>::total_issuance(7), 0);
+		assert_eq!(Balances::reserved_balance(&1), 0);
+		assert!(NftToAsset::<Test>::get((0, 42)).is_none());
+		assert_eq!(
+			last_event(),
+			mock::Event::NftFractionalization(crate::Event::NftUnified {
+				nft_class: 0,
+				nft_instance: 42,
+				asset: 7,
+				beneficiary: 3,
+			})
+		);
+
+		// The asset was destroyed, so the NFT can be fractionalized into it again.
+		assert_ok!(NftFractionalization::fractionalize(Origin::signed(3), 0, 42, 7, 3, 10));
+		assert_eq!(Balances::reserved_balance(&3), 3);
+	});
+}
@@ -0,0 +1,43 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Various basic types for use in the NFT fractionalization pallet.
+
+use super::*;
+use frame_support::pallet_prelude::*;
+
+pub(super) type DepositOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+pub(super) type DetailsOf<T> = Details<
+	<T as Config>::AssetId,
+	<T as Config>::AssetBalance,
+	DepositOf<T>,
+	<T as frame_system::Config>::AccountId,
+>;
+
+/// Information about a fractionalized NFT.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub struct Details<AssetId, Fractions, Deposit, AccountId> {
+	/// The asset representing the fractions of the NFT.
+	pub asset: AssetId,
+	/// The number of fractions minted for the NFT.
+	pub fractions: Fractions,
+	/// The deposit reserved for the asset.
+	pub deposit: Deposit,
+	/// The account which fractionalized the NFT and from which the deposit was reserved.
+	pub asset_creator: AccountId,
+}
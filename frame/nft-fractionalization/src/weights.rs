@@ -0,0 +1,102 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Placeholder weights for pallet_nft_fractionalization
+//!
+//! These weights are NOT the output of the benchmark CLI. They are estimates based on the storage
+//! accesses of each call and must be replaced by running the benchmarks of the pallet with the
+//! benchmark CLI before the pallet is used in production.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_nft_fractionalization.
+pub trait WeightInfo {
+	fn fractionalize() -> Weight;
+	fn unify() -> Weight;
+}
+
+/// Weights for pallet_nft_fractionalization using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: NftFractionalization NftToAsset (r:0 w:1)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: Uniques InstancePriceOf (r:0 w:1)
+	// Storage: Uniques PendingSwapOf (r:0 w:1)
+	fn fractionalize() -> Weight {
+		(87_412_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+	}
+	// Storage: NftFractionalization NftToAsset (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: Assets Metadata (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: Uniques InstancePriceOf (r:0 w:1)
+	// Storage: Uniques PendingSwapOf (r:0 w:1)
+	fn unify() -> Weight {
+		(95_305_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: NftFractionalization NftToAsset (r:0 w:1)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: Uniques InstancePriceOf (r:0 w:1)
+	// Storage: Uniques PendingSwapOf (r:0 w:1)
+	fn fractionalize() -> Weight {
+		(87_412_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+	}
+	// Storage: NftFractionalization NftToAsset (r:1 w:1)
+	// Storage: Assets Asset (r:1 w:1)
+	// Storage: Assets Account (r:1 w:1)
+	// Storage: Assets Metadata (r:1 w:0)
+	// Storage: System Account (r:1 w:1)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: Uniques InstancePriceOf (r:0 w:1)
+	// Storage: Uniques PendingSwapOf (r:0 w:1)
+	fn unify() -> Weight {
+		(95_305_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
+	}
+}
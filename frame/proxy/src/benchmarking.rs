@@ -246,5 +246,52 @@ benchmarks! {
 		assert!(!Proxies::<T>::contains_key(&anon));
 	}
 
+	add_restricted_proxy {
+		let p in 1 .. (T::MaxProxies::get() - 1).into() => add_proxies::<T>(p, None)?;
+		let caller: T::AccountId = whitelisted_caller();
+		let allowed_calls = vec![(0, 0); T::MaxAllowedCalls::get() as usize];
+	}: _(
+		RawOrigin::Signed(caller.clone()),
+		account("target", T::MaxProxies::get().into(), SEED),
+		T::ProxyType::default(),
+		T::BlockNumber::zero(),
+		Some(T::BlockNumber::max_value()),
+		Some(allowed_calls)
+	)
+	verify {
+		let (proxies, _) = Proxies::<T>::get(&caller);
+		assert_eq!(proxies.len() as u32, p + 1);
+		assert_eq!(ProxyRestrictions::<T>::iter_prefix(&caller).count(), 1);
+	}
+
+	remove_expired_proxy {
+		let p in 1 .. (T::MaxProxies::get() - 1).into() => add_proxies::<T>(p, None)?;
+		let real: T::AccountId = whitelisted_caller();
+		let delegate: T::AccountId = account("target", T::MaxProxies::get().into(), SEED);
+		let expiry = frame_system::Pallet::<T>::block_number() + 1u32.into();
+		Proxy::<T>::add_restricted_proxy(
+			RawOrigin::Signed(real.clone()).into(),
+			delegate.clone(),
+			T::ProxyType::default(),
+			T::BlockNumber::zero(),
+			Some(expiry),
+			None,
+		)?;
+		frame_system::Pallet::<T>::set_block_number(expiry);
+		let caller: T::AccountId = account("caller", 0, SEED);
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value() / 2u32.into());
+	}: _(
+		RawOrigin::Signed(caller),
+		real.clone(),
+		delegate,
+		T::ProxyType::default(),
+		T::BlockNumber::zero()
+	)
+	verify {
+		let (proxies, _) = Proxies::<T>::get(&real);
+		assert_eq!(proxies.len() as u32, p);
+		assert_eq!(ProxyRestrictions::<T>::iter_prefix(&real).count(), 0);
+	}
+
 	impl_benchmark_test_suite!(Proxy, crate::tests::new_test_ext(), crate::tests::Test);
 }
@@ -23,6 +23,11 @@
 //! wish to execute some duration prior to execution happens. In this case, the target account may
 //! reject the announcement and in doing so, veto the execution.
 //!
+//! Proxies added with `add_restricted_proxy` can additionally be limited to an expiry block and
+//! to a list of allowed calls, independently of their `ProxyType`. Once expired, such a proxy
+//! can be removed by anyone with `remove_expired_proxy`, who receives the deposit held for its
+//! restriction as a reward.
+//!
 //! - [`Config`]
 //! - [`Call`]
 
@@ -37,9 +42,13 @@ use codec::{Decode, Encode, MaxEncodedLen};
 use frame_support::{
 	dispatch::DispatchError,
 	ensure,
-	traits::{Currency, Get, InstanceFilter, IsSubType, IsType, OriginTrait, ReservableCurrency},
+	traits::{
+		BalanceStatus, Currency, Get, InstanceFilter, IsSubType, IsType, OriginTrait,
+		ReservableCurrency,
+	},
+	transactional,
 	weights::GetDispatchInfo,
-	RuntimeDebug,
+	BoundedVec, CloneNoBound, EqNoBound, PartialEqNoBound, RuntimeDebug, RuntimeDebugNoBound,
 };
 use frame_system::{self as system};
 use scale_info::TypeInfo;
@@ -94,6 +103,33 @@ pub struct Announcement<AccountId, Hash, BlockNumber> {
 	height: BlockNumber,
 }
 
+/// The pallet and call index of a call, i.e. the first two bytes of its encoding.
+pub type CallIndex = (u8, u8);
+
+/// Additional restrictions of a proxy, on top of its `ProxyType`.
+#[derive(
+	CloneNoBound,
+	EqNoBound,
+	PartialEqNoBound,
+	Encode,
+	Decode,
+	RuntimeDebugNoBound,
+	MaxEncodedLen,
+	TypeInfo,
+)]
+#[codec(mel_bound(T: Config))]
+#[scale_info(skip_type_params(T))]
+pub struct ProxyRestriction<T: Config> {
+	/// The block from which on the proxy can no longer be used. It can then be removed by anyone
+	/// with `remove_expired_proxy`.
+	pub expiry: Option<T::BlockNumber>,
+	/// The only calls the proxy may make. If `None`, all calls allowed by the proxy type may be
+	/// made.
+	pub allowed_calls: Option<BoundedVec<CallIndex, T::MaxAllowedCalls>>,
+	/// The amount held on deposit for the restriction.
+	pub deposit: BalanceOf<T>,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::{DispatchResult, *};
@@ -175,6 +211,10 @@ pub mod pallet {
 		/// into a pre-existing storage value.
 		#[pallet::constant]
 		type AnnouncementDepositFactor: Get<BalanceOf<Self>>;
+
+		/// The maximum amount of calls on the allowlist of a restricted proxy.
+		#[pallet::constant]
+		type MaxAllowedCalls: Get<u32>;
 	}
 
 	#[pallet::call]
@@ -278,6 +318,7 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 			let (_, old_deposit) = Proxies::<T>::take(&who);
 			T::Currency::unreserve(&who, old_deposit);
+			Self::remove_restrictions(&who);
 
 			Ok(())
 		}
@@ -373,6 +414,7 @@ pub mod pallet {
 
 			let (_, deposit) = Proxies::<T>::take(&who);
 			T::Currency::unreserve(&spawner, deposit);
+			Self::remove_restrictions(&who);
 
 			Ok(())
 		}
@@ -408,7 +450,7 @@ pub mod pallet {
 			Proxies::<T>::get(&real)
 				.0
 				.into_iter()
-				.find(|x| &x.delegate == &who)
+				.find(|x| &x.delegate == &who && !Self::is_expired(&real, x))
 				.ok_or(Error::<T>::NotProxy)?;
 
 			let announcement = Announcement {
@@ -546,6 +588,126 @@ pub mod pallet {
 
 			Ok(())
 		}
+
+		/// Register a proxy account for the sender that expires and/or may only make some calls.
+		///
+		/// The proxy is registered just like with `add_proxy`. In addition, a deposit of
+		/// `ProxyDepositBase + ProxyDepositFactor` is reserved for its restriction.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `delegate`: The account that the `caller` would like to make a proxy.
+		/// - `proxy_type`: The permissions allowed for this proxy account.
+		/// - `delay`: The announcement period required of the initial proxy. Will generally be
+		/// zero.
+		/// - `expiry`: The block from which on the proxy can no longer be used. Must be in the
+		/// future. If `None`, the proxy never expires.
+		/// - `allowed_calls`: The pallet and call indices of the only calls the proxy may make,
+		/// including calls nested in the proxied call. At most `MaxAllowedCalls`. If `None`, all
+		/// calls allowed by `proxy_type` may be made.
+		///
+		/// # <weight>
+		/// Weight is a function of the number of proxies the user has (P).
+		/// # </weight>
+		#[pallet::weight(T::WeightInfo::add_restricted_proxy(T::MaxProxies::get().into()))]
+		#[transactional]
+		pub fn add_restricted_proxy(
+			origin: OriginFor<T>,
+			delegate: T::AccountId,
+			proxy_type: T::ProxyType,
+			delay: T::BlockNumber,
+			expiry: Option<T::BlockNumber>,
+			allowed_calls: Option<Vec<CallIndex>>,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			if let Some(expiry) = expiry {
+				ensure!(expiry > system::Pallet::<T>::block_number(), Error::<T>::InvalidExpiry);
+			}
+			let allowed_calls = allowed_calls
+				.map(|calls| calls.try_into().map_err(|_| Error::<T>::TooMany))
+				.transpose()?;
+
+			Self::add_proxy_delegate(&who, delegate.clone(), proxy_type.clone(), delay)?;
+
+			let deposit = T::ProxyDepositBase::get() + T::ProxyDepositFactor::get();
+			T::Currency::reserve(&who, deposit)?;
+			let proxy_def = ProxyDefinition {
+				delegate: delegate.clone(),
+				proxy_type: proxy_type.clone(),
+				delay,
+			};
+			ProxyRestrictions::<T>::insert(
+				&who,
+				proxy_def,
+				ProxyRestriction { expiry, allowed_calls, deposit },
+			);
+			Self::deposit_event(Event::ProxyRestricted {
+				delegator: who,
+				delegatee: delegate,
+				proxy_type,
+				delay,
+				expiry,
+			});
+
+			Ok(())
+		}
+
+		/// Remove an expired proxy of another account.
+		///
+		/// The deposit held for the restriction of the proxy is paid to the sender, the deposit
+		/// held for the proxy itself is returned to `delegator`.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `delegator`: The account which registered the proxy.
+		/// - `delegate`: The account registered as a proxy.
+		/// - `proxy_type`: The permissions enabled for the proxy.
+		/// - `delay`: The announcement period of the proxy.
+		///
+		/// Fails with `NotExpired` if the proxy has no expiry or it is not reached yet.
+		///
+		/// # <weight>
+		/// Weight is a function of the number of proxies the delegator has (P).
+		/// # </weight>
+		#[pallet::weight(T::WeightInfo::remove_expired_proxy(T::MaxProxies::get().into()))]
+		#[transactional]
+		pub fn remove_expired_proxy(
+			origin: OriginFor<T>,
+			delegator: T::AccountId,
+			delegate: T::AccountId,
+			proxy_type: T::ProxyType,
+			delay: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let proxy_def = ProxyDefinition {
+				delegate: delegate.clone(),
+				proxy_type: proxy_type.clone(),
+				delay,
+			};
+			let restriction =
+				ProxyRestrictions::<T>::take(&delegator, &proxy_def).ok_or(Error::<T>::NotFound)?;
+			let now = system::Pallet::<T>::block_number();
+			ensure!(restriction.expiry.map_or(false, |e| e <= now), Error::<T>::NotExpired);
+
+			Self::remove_proxy_delegate(&delegator, delegate.clone(), proxy_type.clone(), delay)?;
+			let missing = T::Currency::repatriate_reserved(
+				&delegator,
+				&who,
+				restriction.deposit,
+				BalanceStatus::Free,
+			)?;
+			Self::deposit_event(Event::ExpiredProxyRemoved {
+				delegator,
+				delegatee: delegate,
+				proxy_type,
+				remover: who,
+				reward: restriction.deposit.saturating_sub(missing),
+			});
+
+			Ok(())
+		}
 	}
 
 	#[pallet::event]
@@ -570,6 +732,22 @@ pub mod pallet {
 			proxy_type: T::ProxyType,
 			delay: T::BlockNumber,
 		},
+		/// A proxy was restricted to an expiry and/or a list of allowed calls.
+		ProxyRestricted {
+			delegator: T::AccountId,
+			delegatee: T::AccountId,
+			proxy_type: T::ProxyType,
+			delay: T::BlockNumber,
+			expiry: Option<T::BlockNumber>,
+		},
+		/// An expired proxy was removed and the deposit of its restriction paid to `remover`.
+		ExpiredProxyRemoved {
+			delegator: T::AccountId,
+			delegatee: T::AccountId,
+			proxy_type: T::ProxyType,
+			remover: T::AccountId,
+			reward: BalanceOf<T>,
+		},
 	}
 
 	/// Old name generated by `decl_event`.
@@ -594,6 +772,10 @@ pub mod pallet {
 		Unannounced,
 		/// Cannot add self as proxy.
 		NoSelfProxy,
+		/// The expiry of a proxy must be in the future.
+		InvalidExpiry,
+		/// The proxy has no expiry or has not expired yet.
+		NotExpired,
 	}
 
 	/// The set of account proxies. Maps the account which has delegated to the accounts
@@ -624,6 +806,18 @@ pub mod pallet {
 		),
 		ValueQuery,
 	>;
+
+	/// The restrictions of the proxies added with `add_restricted_proxy`, by the account which
+	/// has delegated and the definition of the proxy.
+	#[pallet::storage]
+	pub type ProxyRestrictions<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Blake2_128Concat,
+		ProxyDefinition<T::AccountId, T::ProxyType, T::BlockNumber>,
+		ProxyRestriction<T>,
+	>;
 }
 
 impl<T: Config> Pallet<T> {
@@ -714,7 +908,10 @@ impl<T: Config> Pallet<T> {
 			let (mut proxies, old_deposit) = x.take().ok_or(Error::<T>::NotFound)?;
 			let proxy_def = ProxyDefinition { delegate: delegatee, proxy_type, delay };
 			let i = proxies.binary_search(&proxy_def).ok().ok_or(Error::<T>::NotFound)?;
-			proxies.remove(i);
+			let proxy_def = proxies.remove(i);
+			if let Some(restriction) = ProxyRestrictions::<T>::take(delegator, proxy_def) {
+				T::Currency::unreserve(delegator, restriction.deposit);
+			}
 			let new_deposit = Self::deposit(proxies.len() as u32);
 			if new_deposit > old_deposit {
 				T::Currency::reserve(delegator, new_deposit - old_deposit)?;
@@ -728,6 +925,23 @@ impl<T: Config> Pallet<T> {
 		})
 	}
 
+	/// Remove the restrictions of all proxies of `delegator` and return their deposits.
+	fn remove_restrictions(delegator: &T::AccountId) {
+		for (_, restriction) in ProxyRestrictions::<T>::drain_prefix(delegator) {
+			T::Currency::unreserve(delegator, restriction.deposit);
+		}
+	}
+
+	/// Whether the given proxy of `real` has an expiry which has been reached.
+	fn is_expired(
+		real: &T::AccountId,
+		def: &ProxyDefinition<T::AccountId, T::ProxyType, T::BlockNumber>,
+	) -> bool {
+		ProxyRestrictions::<T>::get(real, def)
+			.and_then(|r| r.expiry)
+			.map_or(false, |expiry| expiry <= system::Pallet::<T>::block_number())
+	}
+
 	pub fn deposit(num_proxies: u32) -> BalanceOf<T> {
 		if num_proxies == 0 {
 			Zero::zero()
@@ -785,7 +999,12 @@ impl<T: Config> Pallet<T> {
 			&x.delegate == delegate &&
 				force_proxy_type.as_ref().map_or(true, |y| &x.proxy_type == y)
 		};
-		Ok(Proxies::<T>::get(real).0.into_iter().find(f).ok_or(Error::<T>::NotProxy)?)
+		Ok(Proxies::<T>::get(real)
+			.0
+			.into_iter()
+			.filter(f)
+			.find(|x| !Self::is_expired(real, x))
+			.ok_or(Error::<T>::NotProxy)?)
 	}
 
 	fn do_proxy(
@@ -793,18 +1012,34 @@ impl<T: Config> Pallet<T> {
 		real: T::AccountId,
 		call: <T as Config>::Call,
 	) {
+		let restriction = ProxyRestrictions::<T>::get(&real, &def);
+		let restricted = restriction.is_some();
+		let allowed_calls = restriction.and_then(|r| r.allowed_calls);
 		// This is a freshly authenticated new account, the origin restrictions doesn't apply.
 		let mut origin: T::Origin = frame_system::RawOrigin::Signed(real).into();
 		origin.add_filter(move |c: &<T as frame_system::Config>::Call| {
 			let c = <T as Config>::Call::from_ref(c);
+			// A proxy restricted to some calls may only make these, also as nested calls.
+			if let Some(ref allowed_calls) = allowed_calls {
+				let allowed =
+					c.using_encoded(|e| e.len() >= 2 && allowed_calls.contains(&(e[0], e[1])));
+				if !allowed {
+					return false
+				}
+			}
 			// We make sure the proxy call does access this pallet to change modify proxies.
 			match c.is_sub_type() {
 				// Proxy call cannot add or remove a proxy with more permissions than it already
 				// has.
 				Some(Call::add_proxy { ref proxy_type, .. }) |
+				Some(Call::add_restricted_proxy { ref proxy_type, .. }) |
 				Some(Call::remove_proxy { ref proxy_type, .. })
 					if !def.proxy_type.is_superset(&proxy_type) =>
 					false,
+				// Restricted proxy cannot add proxies, as these would outlive its restrictions.
+				Some(Call::add_proxy { .. }) | Some(Call::add_restricted_proxy { .. })
+					if restricted =>
+					false,
 				// Proxy call cannot remove all proxies or kill anonymous proxies unless it has full
 				// permissions.
 				Some(Call::remove_proxies { .. }) | Some(Call::kill_anonymous { .. })
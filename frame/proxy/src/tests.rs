@@ -162,6 +162,7 @@ impl Config for Test {
 	type MaxPending = ConstU32<2>;
 	type AnnouncementDepositBase = ConstU64<1>;
 	type AnnouncementDepositFactor = ConstU64<1>;
+	type MaxAllowedCalls = ConstU32<2>;
 }
 
 use super::{Call as ProxyCall, Event as ProxyEvent};
@@ -201,6 +202,11 @@ fn call_transfer(dest: u64, value: u64) -> Call {
 	Call::Balances(BalancesCall::transfer { dest, value })
 }
 
+fn call_index(call: &Call) -> CallIndex {
+	let encoded = call.encode();
+	(encoded[0], encoded[1])
+}
+
 #[test]
 fn announcement_works() {
 	new_test_ext().execute_with(|| {
@@ -559,3 +565,184 @@ fn anonymous_works() {
 		);
 	});
 }
+
+#[test]
+fn restricted_proxy_expires() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Proxy::add_restricted_proxy(Origin::signed(1), 2, ProxyType::Any, 0, Some(1), None),
+			Error::<Test>::InvalidExpiry
+		);
+		assert_ok!(Proxy::add_restricted_proxy(
+			Origin::signed(1),
+			2,
+			ProxyType::Any,
+			0,
+			Some(3),
+			None
+		));
+		System::assert_last_event(
+			ProxyEvent::ProxyRestricted {
+				delegator: 1,
+				delegatee: 2,
+				proxy_type: ProxyType::Any,
+				delay: 0,
+				expiry: Some(3),
+			}
+			.into(),
+		);
+		assert_eq!(Balances::reserved_balance(1), 4);
+
+		let call = Box::new(call_transfer(6, 1));
+		assert_ok!(Proxy::proxy(Origin::signed(2), 1, None, call.clone()));
+		assert_eq!(Balances::free_balance(6), 1);
+		assert_noop!(
+			Proxy::remove_expired_proxy(Origin::signed(4), 1, 2, ProxyType::Any, 0),
+			Error::<Test>::NotExpired
+		);
+
+		System::set_block_number(3);
+		assert_noop!(
+			Proxy::proxy(Origin::signed(2), 1, None, call.clone()),
+			Error::<Test>::NotProxy
+		);
+		assert_noop!(Proxy::announce(Origin::signed(2), 1, H256::zero()), Error::<Test>::NotProxy);
+
+		assert_ok!(Proxy::remove_expired_proxy(Origin::signed(4), 1, 2, ProxyType::Any, 0));
+		System::assert_last_event(
+			ProxyEvent::ExpiredProxyRemoved {
+				delegator: 1,
+				delegatee: 2,
+				proxy_type: ProxyType::Any,
+				remover: 4,
+				reward: 2,
+			}
+			.into(),
+		);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 7);
+		assert_eq!(Balances::free_balance(4), 12);
+		assert!(Proxies::<Test>::get(1).0.is_empty());
+		assert_eq!(ProxyRestrictions::<Test>::iter_prefix(1).count(), 0);
+
+		// Proxies without an expiry never expire.
+		assert_ok!(Proxy::add_restricted_proxy(
+			Origin::signed(1),
+			2,
+			ProxyType::Any,
+			0,
+			None,
+			None
+		));
+		System::set_block_number(100);
+		assert_noop!(
+			Proxy::remove_expired_proxy(Origin::signed(4), 1, 2, ProxyType::Any, 0),
+			Error::<Test>::NotExpired
+		);
+		assert_ok!(Proxy::proxy(Origin::signed(2), 1, None, call));
+	});
+}
+
+#[test]
+fn restricted_proxy_only_makes_allowed_calls() {
+	new_test_ext().execute_with(|| {
+		let transfer = call_transfer(6, 1);
+		let add_proxy =
+			Call::Proxy(ProxyCall::add_proxy { delegate: 4, proxy_type: ProxyType::Any, delay: 0 });
+		assert_noop!(
+			Proxy::add_restricted_proxy(
+				Origin::signed(1),
+				2,
+				ProxyType::Any,
+				0,
+				None,
+				Some(vec![(0, 0), (0, 1), (0, 2)])
+			),
+			Error::<Test>::TooMany
+		);
+		assert_ok!(Proxy::add_restricted_proxy(
+			Origin::signed(1),
+			2,
+			ProxyType::Any,
+			0,
+			None,
+			Some(vec![call_index(&transfer), call_index(&add_proxy)])
+		));
+
+		assert_ok!(Proxy::proxy(Origin::signed(2), 1, None, Box::new(transfer.clone())));
+		System::assert_last_event(ProxyEvent::ProxyExecuted { result: Ok(()) }.into());
+		assert_eq!(Balances::free_balance(6), 1);
+
+		let call =
+			Box::new(Call::Balances(BalancesCall::transfer_keep_alive { dest: 6, value: 1 }));
+		assert_ok!(Proxy::proxy(Origin::signed(2), 1, None, call));
+		System::assert_last_event(
+			ProxyEvent::ProxyExecuted { result: Err(SystemError::CallFiltered.into()) }.into(),
+		);
+
+		// Restricted proxies cannot add proxies, even if allowed to make the call.
+		assert_ok!(Proxy::proxy(Origin::signed(2), 1, None, Box::new(add_proxy)));
+		System::assert_last_event(
+			ProxyEvent::ProxyExecuted { result: Err(SystemError::CallFiltered.into()) }.into(),
+		);
+
+		// Nested calls need to be allowed as well.
+		let batch = Box::new(Call::Utility(UtilityCall::batch { calls: vec![transfer.clone()] }));
+		assert_ok!(Proxy::proxy(Origin::signed(2), 1, None, batch));
+		System::assert_last_event(
+			ProxyEvent::ProxyExecuted { result: Err(SystemError::CallFiltered.into()) }.into(),
+		);
+		assert_ok!(Proxy::remove_proxy(Origin::signed(1), 2, ProxyType::Any, 0));
+		let batch = Call::Utility(UtilityCall::batch { calls: vec![transfer.clone()] });
+		assert_ok!(Proxy::add_restricted_proxy(
+			Origin::signed(1),
+			2,
+			ProxyType::Any,
+			0,
+			None,
+			Some(vec![call_index(&batch)])
+		));
+		assert_ok!(Proxy::proxy(Origin::signed(2), 1, None, Box::new(batch)));
+		expect_events(vec![
+			UtilityEvent::BatchInterrupted { index: 0, error: SystemError::CallFiltered.into() }
+				.into(),
+			ProxyEvent::ProxyExecuted { result: Ok(()) }.into(),
+		]);
+		assert_eq!(Balances::free_balance(6), 1);
+	});
+}
+
+#[test]
+fn removing_restricted_proxies_returns_deposits() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Proxy::add_restricted_proxy(
+			Origin::signed(1),
+			2,
+			ProxyType::Any,
+			0,
+			Some(5),
+			None
+		));
+		assert_ok!(Proxy::add_restricted_proxy(
+			Origin::signed(1),
+			3,
+			ProxyType::JustTransfer,
+			0,
+			None,
+			Some(vec![(0, 0)])
+		));
+		assert_eq!(Balances::reserved_balance(1), 7);
+		assert_noop!(
+			Proxy::add_proxy(Origin::signed(1), 2, ProxyType::Any, 0),
+			Error::<Test>::Duplicate
+		);
+
+		assert_ok!(Proxy::remove_proxy(Origin::signed(1), 2, ProxyType::Any, 0));
+		assert_eq!(Balances::reserved_balance(1), 4);
+		assert_eq!(ProxyRestrictions::<Test>::iter_prefix(1).count(), 1);
+
+		assert_ok!(Proxy::remove_proxies(Origin::signed(1)));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(ProxyRestrictions::<Test>::iter_prefix(1).count(), 0);
+	});
+}
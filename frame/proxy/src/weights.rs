@@ -56,6 +56,8 @@ pub trait WeightInfo {
 	fn remove_proxies(p: u32, ) -> Weight;
 	fn anonymous(p: u32, ) -> Weight;
 	fn kill_anonymous(p: u32, ) -> Weight;
+	fn add_restricted_proxy(p: u32, ) -> Weight;
+	fn remove_expired_proxy(p: u32, ) -> Weight;
 }
 
 /// Weights for pallet_proxy using the Substrate node and recommended hardware.
@@ -155,6 +157,25 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// NOTE: the weights of `add_restricted_proxy` and `remove_expired_proxy` are placeholders until
+	// they are benchmarked.
+	// Storage: Proxy Proxies (r:1 w:1)
+	// Storage: Proxy ProxyRestrictions (r:0 w:1)
+	fn add_restricted_proxy(p: u32, ) -> Weight {
+		(27_412_000 as Weight)
+			.saturating_add((142_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Proxy ProxyRestrictions (r:1 w:1)
+	// Storage: Proxy Proxies (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn remove_expired_proxy(p: u32, ) -> Weight {
+		(34_105_000 as Weight)
+			.saturating_add((149_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
 }
 
 // For backwards compatibility and tests
@@ -253,4 +274,23 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	// NOTE: the weights of `add_restricted_proxy` and `remove_expired_proxy` are placeholders until
+	// they are benchmarked.
+	// Storage: Proxy Proxies (r:1 w:1)
+	// Storage: Proxy ProxyRestrictions (r:0 w:1)
+	fn add_restricted_proxy(p: u32, ) -> Weight {
+		(27_412_000 as Weight)
+			.saturating_add((142_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: Proxy ProxyRestrictions (r:1 w:1)
+	// Storage: Proxy Proxies (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn remove_expired_proxy(p: u32, ) -> Weight {
+		(34_105_000 as Weight)
+			.saturating_add((149_000 as Weight).saturating_mul(p as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
 }
@@ -45,6 +45,24 @@
 //! the number of friends chosen. This deposit is returned in full when the account
 //! owner removes their recovery configuration.
 //!
+//! ### Friend Groups
+//!
+//! In addition to the friends of the recovery configuration, the account owner can add up to
+//! `MaxFriendGroups` further groups of friends, each with its own `threshold` and
+//! `delay_period`, e.g. a group of family members and a group of colleagues. Any friend of any
+//! group can vouch for a recovery attempt, and the account can be recovered as soon as the
+//! vouches of the friends of one group meet the threshold and the delay period of that group.
+//! Each group takes a deposit just like the recovery configuration itself.
+//!
+//! ### Inheritance
+//!
+//! Independently of the recovery configuration, the account owner can designate an heir
+//! together with an `inactivity_period`. If the account does not send any signed transaction
+//! for this period, the heir can claim access to it just like a successful rescuer. Activity is
+//! recorded by the [`TrackActivity`] signed extension, which needs to be part of the runtime's
+//! signed extensions for inheritance to be meaningful. Setting an heir takes an
+//! `InheritanceDeposit`, and the owner can remove the heir at any time.
+//!
 //! ### Recovery Life Cycle
 //!
 //! The intended life cycle of a successful recovery takes the following steps:
@@ -127,6 +145,8 @@
 //!
 //! * `create_recovery` - Create a recovery configuration for your account and make it recoverable.
 //! * `initiate_recovery` - Start the recovery process for a recoverable account.
+//! * `set_heir` - Designate an heir who can claim your account once it has been inactive for a
+//!   given period.
 //!
 //! #### For Friends of a Recoverable Account
 //! * `vouch_recovery` - As a `friend` of a recoverable account, vouch for a recovery attempt on the
@@ -145,6 +165,14 @@
 //!   deposit.
 //! * `remove_recovery` - Remove the recovery configuration from the account, making it
 //!   un-recoverable.
+//! * `add_friend_group` - Add a group of friends which can recover the account on its own.
+//! * `remove_friend_group` - Remove a group of friends from the recovery configuration.
+//! * `remove_heir` - Remove the heir of the account.
+//!
+//! #### For the Heir of an Inactive Account
+//!
+//! * `claim_inheritance` - Claim access to an account which has been inactive for longer than its
+//!   inactivity period.
 //!
 //! #### For Super Users
 //!
@@ -156,13 +184,21 @@
 
 use codec::{Decode, Encode};
 use scale_info::TypeInfo;
-use sp_runtime::traits::{CheckedAdd, CheckedMul, Dispatchable, SaturatedConversion};
-use sp_std::prelude::*;
+use sp_runtime::{
+	traits::{
+		CheckedAdd, CheckedMul, DispatchInfoOf, Dispatchable, SaturatedConversion, SignedExtension,
+		Zero,
+	},
+	transaction_validity::{TransactionValidity, TransactionValidityError, ValidTransaction},
+	ArithmeticError, DispatchError,
+};
+use sp_std::{marker::PhantomData, prelude::*};
 
 use frame_support::{
-	dispatch::PostDispatchInfo,
+	dispatch::{DispatchResult, PostDispatchInfo},
+	ensure,
 	traits::{BalanceStatus, Currency, ReservableCurrency},
-	weights::GetDispatchInfo,
+	weights::{DispatchInfo, GetDispatchInfo, Weight},
 	RuntimeDebug,
 };
 
@@ -203,12 +239,25 @@ pub struct RecoveryConfig<BlockNumber, Balance, AccountId> {
 	threshold: u16,
 }
 
+/// Configuration for inheriting an inactive account.
+#[derive(Clone, Eq, PartialEq, Encode, Decode, Default, RuntimeDebug, TypeInfo)]
+pub struct InheritanceConfig<BlockNumber, Balance, AccountId> {
+	/// The account which can claim the account once it is inactive.
+	heir: AccountId,
+	/// The number of blocks without a signed transaction after which the account is inactive.
+	inactivity_period: BlockNumber,
+	/// The block number of the last signed transaction of the account, or of when the heir was
+	/// set, whichever is later.
+	last_active: BlockNumber,
+	/// The amount held in reserve of the account, to be returned once the heir is removed.
+	deposit: Balance,
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
 	use frame_support::pallet_prelude::*;
 	use frame_system::pallet_prelude::*;
-	use sp_runtime::ArithmeticError;
 
 	#[pallet::pallet]
 	#[pallet::generate_store(pub(super) trait Store)]
@@ -257,6 +306,17 @@ pub mod pallet {
 		/// threshold.
 		#[pallet::constant]
 		type RecoveryDeposit: Get<BalanceOf<Self>>;
+
+		/// The maximum amount of friend groups in addition to the recovery configuration.
+		#[pallet::constant]
+		type MaxFriendGroups: Get<u16>;
+
+		/// The amount of currency needed to reserve for designating an heir.
+		///
+		/// This is held for an additional storage item whose value size is
+		/// `sizeof(AccountId) + 2 * sizeof(BlockNumber) + sizeof(Balance)` bytes.
+		#[pallet::constant]
+		type InheritanceDeposit: Get<BalanceOf<Self>>;
 	}
 
 	/// Events type.
@@ -279,6 +339,16 @@ pub mod pallet {
 		AccountRecovered { lost_account: T::AccountId, rescuer_account: T::AccountId },
 		/// A recovery process has been removed for an account.
 		RecoveryRemoved { lost_account: T::AccountId },
+		/// A group of friends has been added to the recovery configuration of an account.
+		FriendGroupAdded { account: T::AccountId, index: u16 },
+		/// A group of friends has been removed from the recovery configuration of an account.
+		FriendGroupRemoved { account: T::AccountId, index: u16 },
+		/// An heir has been designated for an account.
+		HeirSet { account: T::AccountId, heir: T::AccountId },
+		/// The heir of an account has been removed.
+		HeirRemoved { account: T::AccountId },
+		/// An inactive account has been claimed by its heir.
+		AccountInherited { account: T::AccountId, heir: T::AccountId },
 	}
 
 	#[pallet::error]
@@ -315,6 +385,18 @@ pub mod pallet {
 		AlreadyProxy,
 		/// Some internal state is broken.
 		BadState,
+		/// There are already `MaxFriendGroups` friend groups
+		MaxFriendGroups,
+		/// There is no friend group at this index
+		NoFriendGroup,
+		/// The inactivity period must be greater than zero
+		ZeroInactivityPeriod,
+		/// This account has no heir
+		NoHeir,
+		/// This account is not the heir of the account to be claimed
+		NotHeir,
+		/// The account has not been inactive for its inactivity period
+		NotInactive,
 	}
 
 	/// The set of recoverable accounts and their recovery configuration.
@@ -349,6 +431,27 @@ pub mod pallet {
 	#[pallet::getter(fn proxy)]
 	pub type Proxy<T: Config> = StorageMap<_, Blake2_128Concat, T::AccountId, T::AccountId>;
 
+	/// The additional groups of friends of recoverable accounts.
+	#[pallet::storage]
+	#[pallet::getter(fn friend_groups)]
+	pub type FriendGroups<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Vec<RecoveryConfig<T::BlockNumber, BalanceOf<T>, T::AccountId>>,
+		ValueQuery,
+	>;
+
+	/// The inheritance configuration of accounts which have designated an heir.
+	#[pallet::storage]
+	#[pallet::getter(fn inheritance)]
+	pub type Inheritances<T: Config> = StorageMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		InheritanceConfig<T::BlockNumber, BalanceOf<T>, T::AccountId>,
+	>;
+
 	#[pallet::call]
 	impl<T: Config> Pallet<T> {
 		/// Send a call through a recovered account.
@@ -454,20 +557,8 @@ pub mod pallet {
 			let who = ensure_signed(origin)?;
 			// Check account is not already set up for recovery
 			ensure!(!<Recoverable<T>>::contains_key(&who), Error::<T>::AlreadyRecoverable);
-			// Check user input is valid
-			ensure!(threshold >= 1, Error::<T>::ZeroThreshold);
-			ensure!(!friends.is_empty(), Error::<T>::NotEnoughFriends);
-			ensure!(threshold as usize <= friends.len(), Error::<T>::NotEnoughFriends);
-			let max_friends = T::MaxFriends::get() as usize;
-			ensure!(friends.len() <= max_friends, Error::<T>::MaxFriends);
-			ensure!(Self::is_sorted_and_unique(&friends), Error::<T>::NotSorted);
-			// Total deposit is base fee + number of friends * factor fee
-			let friend_deposit = T::FriendDepositFactor::get()
-				.checked_mul(&friends.len().saturated_into())
-				.ok_or(ArithmeticError::Overflow)?;
-			let total_deposit = T::ConfigDepositBase::get()
-				.checked_add(&friend_deposit)
-				.ok_or(ArithmeticError::Overflow)?;
+			// Check user input is valid and calculate the deposit
+			let total_deposit = Self::friends_deposit(&friends, threshold)?;
 			// Reserve the deposit
 			T::Currency::reserve(&who, total_deposit)?;
 			// Create the recovery configuration
@@ -566,8 +657,12 @@ pub mod pallet {
 			// Get the active recovery process for the rescuer.
 			let mut active_recovery =
 				Self::active_recovery(&lost, &rescuer).ok_or(Error::<T>::NotStarted)?;
-			// Make sure the voter is a friend
-			ensure!(Self::is_friend(&recovery_config.friends, &who), Error::<T>::NotFriend);
+			// Make sure the voter is a friend of any group
+			ensure!(
+				Self::is_friend(&recovery_config.friends, &who) ||
+					Self::friend_groups(&lost).iter().any(|g| Self::is_friend(&g.friends, &who)),
+				Error::<T>::NotFriend
+			);
 			// Either insert the vouch, or return an error that the user already vouched.
 			match active_recovery.friends.binary_search(&who) {
 				Ok(_pos) => Err(Error::<T>::AlreadyVouched)?,
@@ -613,18 +708,19 @@ pub mod pallet {
 			let active_recovery =
 				Self::active_recovery(&account, &who).ok_or(Error::<T>::NotStarted)?;
 			ensure!(!Proxy::<T>::contains_key(&who), Error::<T>::AlreadyProxy);
-			// Make sure the delay period has passed
+			// Make sure the delay period has passed and the threshold is met for any group
 			let current_block_number = <frame_system::Pallet<T>>::block_number();
-			let recoverable_block_number = active_recovery
-				.created
-				.checked_add(&recovery_config.delay_period)
-				.ok_or(ArithmeticError::Overflow)?;
-			ensure!(recoverable_block_number <= current_block_number, Error::<T>::DelayPeriod);
-			// Make sure the threshold is met
-			ensure!(
-				recovery_config.threshold as usize <= active_recovery.friends.len(),
-				Error::<T>::Threshold
-			);
+			Self::check_recovered(&recovery_config, &active_recovery, current_block_number)
+				.or_else(|e| {
+					let recovered_by_group = Self::friend_groups(&account).iter().any(|g| {
+						Self::check_recovered(g, &active_recovery, current_block_number).is_ok()
+					});
+					if recovered_by_group {
+						Ok(())
+					} else {
+						Err(e)
+					}
+				})?;
 			frame_system::Pallet::<T>::inc_consumers(&who).map_err(|_| Error::<T>::BadState)?;
 			// Create the recovery storage item
 			Proxy::<T>::insert(&who, &account);
@@ -709,6 +805,10 @@ pub mod pallet {
 
 			// Unreserve the initial deposit for the recovery configuration.
 			T::Currency::unreserve(&who, recovery_config.deposit);
+			// Unreserve the deposits of the friend groups.
+			for group in <FriendGroups<T>>::take(&who) {
+				T::Currency::unreserve(&who, group.deposit);
+			}
 			Self::deposit_event(Event::<T>::RecoveryRemoved { lost_account: who });
 			Ok(())
 		}
@@ -733,6 +833,211 @@ pub mod pallet {
 			frame_system::Pallet::<T>::dec_consumers(&who);
 			Ok(())
 		}
+
+		/// Add a group of friends to the recovery configuration of your account.
+		///
+		/// The account can be recovered as soon as the friends of any one group, including the
+		/// friends of the recovery configuration itself, have vouched for a recovery attempt.
+		///
+		/// Payment: `ConfigDepositBase` + `FriendDepositFactor` * #_of_friends balance
+		/// will be reserved for storing the group. This deposit is returned in full when the
+		/// group or the recovery configuration is removed.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be a
+		/// recoverable account (i.e. has a recovery configuration).
+		///
+		/// Parameters:
+		/// - `friends`: A list of friends you trust to vouch for recovery attempts. Should be
+		///   ordered and contain no duplicate values.
+		/// - `threshold`: The number of friends of this group that must vouch for a recovery
+		///   attempt before the account can be recovered.
+		/// - `delay_period`: The number of blocks after a recovery attempt is initialized that
+		///   needs to pass before the account can be recovered through this group.
+		///
+		/// # <weight>
+		/// - Key: F (len of friends), G (number of friend groups)
+		/// - One storage read to check that account is recoverable. O(1).
+		/// - A check that the friends list is sorted and unique. O(F)
+		/// - One currency reserve operation. O(X)
+		/// - One storage mutation. O(1). Codec O(G * F).
+		/// - One event.
+		///
+		/// Total Complexity: O(G * F + X)
+		/// # </weight>
+		#[pallet::weight(100_000_000)]
+		pub fn add_friend_group(
+			origin: OriginFor<T>,
+			friends: Vec<T::AccountId>,
+			threshold: u16,
+			delay_period: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(<Recoverable<T>>::contains_key(&who), Error::<T>::NotRecoverable);
+			let deposit = Self::friends_deposit(&friends, threshold)?;
+			let index =
+				<FriendGroups<T>>::try_mutate(&who, |groups| -> Result<_, DispatchError> {
+					ensure!(
+						groups.len() < T::MaxFriendGroups::get() as usize,
+						Error::<T>::MaxFriendGroups
+					);
+					T::Currency::reserve(&who, deposit)?;
+					groups.push(RecoveryConfig { delay_period, deposit, friends, threshold });
+					Ok(groups.len() as u16 - 1)
+				})?;
+			Self::deposit_event(Event::<T>::FriendGroupAdded { account: who, index });
+			Ok(())
+		}
+
+		/// Remove a group of friends from the recovery configuration of your account.
+		///
+		/// The groups after the removed one move up by one index.
+		///
+		/// Payment: The deposit of the group is unreserved.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `index`: The index of the group to be removed.
+		///
+		/// # <weight>
+		/// - Key: F (len of friends), G (number of friend groups)
+		/// - One storage mutation. O(1). Codec O(G * F).
+		/// - One balance call to unreserve. O(X)
+		/// - One event.
+		///
+		/// Total Complexity: O(G * F + X)
+		/// # </weight>
+		#[pallet::weight(30_000_000)]
+		pub fn remove_friend_group(origin: OriginFor<T>, index: u16) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let group = <FriendGroups<T>>::try_mutate_exists(
+				&who,
+				|maybe_groups| -> Result<_, DispatchError> {
+					let groups = maybe_groups.as_mut().ok_or(Error::<T>::NoFriendGroup)?;
+					ensure!((index as usize) < groups.len(), Error::<T>::NoFriendGroup);
+					let group = groups.remove(index as usize);
+					if groups.is_empty() {
+						*maybe_groups = None;
+					}
+					Ok(group)
+				},
+			)?;
+			T::Currency::unreserve(&who, group.deposit);
+			Self::deposit_event(Event::<T>::FriendGroupRemoved { account: who, index });
+			Ok(())
+		}
+
+		/// Designate an heir who can claim your account once it has been inactive for
+		/// `inactivity_period` blocks.
+		///
+		/// Replaces any previous heir. The inactivity period starts anew with this call.
+		///
+		/// Payment: `InheritanceDeposit` balance will be reserved, unless an heir is already
+		/// set. This deposit is returned in full when the user calls `remove_heir`.
+		///
+		/// The dispatch origin for this call must be _Signed_.
+		///
+		/// Parameters:
+		/// - `heir`: The account which can claim your account.
+		/// - `inactivity_period`: The number of blocks without a signed transaction of your account
+		///   after which the heir can claim it.
+		///
+		/// # <weight>
+		/// - One storage read/write of the inheritance configuration. O(1)
+		/// - One currency reserve operation. O(X)
+		/// - One event.
+		///
+		/// Total Complexity: O(X)
+		/// # </weight>
+		#[pallet::weight(30_000_000)]
+		pub fn set_heir(
+			origin: OriginFor<T>,
+			heir: T::AccountId,
+			inactivity_period: T::BlockNumber,
+		) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(heir != who, Error::<T>::NotAllowed);
+			ensure!(!inactivity_period.is_zero(), Error::<T>::ZeroInactivityPeriod);
+			let deposit = match Self::inheritance(&who) {
+				Some(config) => config.deposit,
+				None => {
+					let deposit = T::InheritanceDeposit::get();
+					T::Currency::reserve(&who, deposit)?;
+					deposit
+				},
+			};
+			let last_active = <frame_system::Pallet<T>>::block_number();
+			<Inheritances<T>>::insert(
+				&who,
+				InheritanceConfig { heir: heir.clone(), inactivity_period, last_active, deposit },
+			);
+			Self::deposit_event(Event::<T>::HeirSet { account: who, heir });
+			Ok(())
+		}
+
+		/// Remove the heir of your account.
+		///
+		/// Payment: The `InheritanceDeposit` is unreserved.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must have an heir.
+		///
+		/// # <weight>
+		/// - One storage read/remove of the inheritance configuration. O(1)
+		/// - One balance call to unreserve. O(X)
+		/// - One event.
+		///
+		/// Total Complexity: O(X)
+		/// # </weight>
+		#[pallet::weight(30_000_000)]
+		pub fn remove_heir(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let config = <Inheritances<T>>::take(&who).ok_or(Error::<T>::NoHeir)?;
+			T::Currency::unreserve(&who, config.deposit);
+			Self::deposit_event(Event::<T>::HeirRemoved { account: who });
+			Ok(())
+		}
+
+		/// Allow the heir of an inactive account to claim it.
+		///
+		/// On success, the heir can use `as_recovered` to make calls on behalf of the account,
+		/// the heir is removed from the account and its deposit is unreserved.
+		///
+		/// The dispatch origin for this call must be _Signed_ and must be the heir of `account`.
+		///
+		/// Parameters:
+		/// - `account`: The inactive account you want to claim.
+		///
+		/// # <weight>
+		/// - One storage read/remove of the inheritance configuration. O(1)
+		/// - One storage read to get the current block number. O(1)
+		/// - One balance call to unreserve. O(X)
+		/// - One storage write. O(1)
+		/// - One event.
+		///
+		/// Total Complexity: O(X)
+		/// # </weight>
+		#[pallet::weight(100_000_000)]
+		pub fn claim_inheritance(origin: OriginFor<T>, account: T::AccountId) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			let config = Self::inheritance(&account).ok_or(Error::<T>::NoHeir)?;
+			ensure!(config.heir == who, Error::<T>::NotHeir);
+			ensure!(!Proxy::<T>::contains_key(&who), Error::<T>::AlreadyProxy);
+			let inactive_block_number = config
+				.last_active
+				.checked_add(&config.inactivity_period)
+				.ok_or(ArithmeticError::Overflow)?;
+			ensure!(
+				inactive_block_number <= <frame_system::Pallet<T>>::block_number(),
+				Error::<T>::NotInactive
+			);
+			frame_system::Pallet::<T>::inc_consumers(&who).map_err(|_| Error::<T>::BadState)?;
+			<Inheritances<T>>::remove(&account);
+			T::Currency::unreserve(&account, config.deposit);
+			// Create the recovery storage item
+			Proxy::<T>::insert(&who, &account);
+			Self::deposit_event(Event::<T>::AccountInherited { account, heir: who });
+			Ok(())
+		}
 	}
 }
 
@@ -746,4 +1051,129 @@ impl<T: Config> Pallet<T> {
 	fn is_friend(friends: &Vec<T::AccountId>, friend: &T::AccountId) -> bool {
 		friends.binary_search(&friend).is_ok()
 	}
+
+	/// Check that a friends list and threshold are valid and return the deposit for them.
+	fn friends_deposit(
+		friends: &Vec<T::AccountId>,
+		threshold: u16,
+	) -> Result<BalanceOf<T>, DispatchError> {
+		ensure!(threshold >= 1, Error::<T>::ZeroThreshold);
+		ensure!(!friends.is_empty(), Error::<T>::NotEnoughFriends);
+		ensure!(threshold as usize <= friends.len(), Error::<T>::NotEnoughFriends);
+		let max_friends = T::MaxFriends::get() as usize;
+		ensure!(friends.len() <= max_friends, Error::<T>::MaxFriends);
+		ensure!(Self::is_sorted_and_unique(friends), Error::<T>::NotSorted);
+		// Total deposit is base fee + number of friends * factor fee
+		let friend_deposit = T::FriendDepositFactor::get()
+			.checked_mul(&friends.len().saturated_into())
+			.ok_or(ArithmeticError::Overflow)?;
+		let total_deposit = T::ConfigDepositBase::get()
+			.checked_add(&friend_deposit)
+			.ok_or(ArithmeticError::Overflow)?;
+		Ok(total_deposit)
+	}
+
+	/// Check that an active recovery process has passed the delay period of a group of friends
+	/// and has been vouched for by a threshold of them.
+	fn check_recovered(
+		group: &RecoveryConfig<T::BlockNumber, BalanceOf<T>, T::AccountId>,
+		active_recovery: &ActiveRecovery<T::BlockNumber, BalanceOf<T>, T::AccountId>,
+		now: T::BlockNumber,
+	) -> DispatchResult {
+		let recoverable_block_number = active_recovery
+			.created
+			.checked_add(&group.delay_period)
+			.ok_or(ArithmeticError::Overflow)?;
+		ensure!(recoverable_block_number <= now, Error::<T>::DelayPeriod);
+		let vouches = active_recovery
+			.friends
+			.iter()
+			.filter(|friend| Self::is_friend(&group.friends, friend))
+			.count();
+		ensure!(group.threshold as usize <= vouches, Error::<T>::Threshold);
+		Ok(())
+	}
+
+	/// Record a signed transaction of `who` for its inheritance configuration, if any.
+	///
+	/// Returns the weight of the storage accesses.
+	fn note_activity(who: &T::AccountId) -> Weight {
+		if let Some(mut config) = Self::inheritance(who) {
+			let now = <frame_system::Pallet<T>>::block_number();
+			if config.last_active != now {
+				config.last_active = now;
+				<Inheritances<T>>::insert(who, config);
+				return T::DbWeight::get().reads_writes(1, 1)
+			}
+		}
+		T::DbWeight::get().reads(1)
+	}
+}
+
+/// Record the signed transactions of accounts with an heir, which resets their inactivity
+/// period.
+///
+/// Only signed transactions of the account itself are recorded, not calls dispatched on its
+/// behalf, e.g. through a proxy.
+///
+/// The weight of recording the activity is not part of the weight of the call. It is registered
+/// with the system pallet in the class of the call instead.
+#[derive(Encode, Decode, Clone, Eq, PartialEq, TypeInfo)]
+#[scale_info(skip_type_params(T))]
+pub struct TrackActivity<T: Config + Send + Sync>(PhantomData<T>);
+
+impl<T: Config + Send + Sync> sp_std::fmt::Debug for TrackActivity<T> {
+	#[cfg(feature = "std")]
+	fn fmt(&self, f: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		write!(f, "TrackActivity")
+	}
+
+	#[cfg(not(feature = "std"))]
+	fn fmt(&self, _: &mut sp_std::fmt::Formatter) -> sp_std::fmt::Result {
+		Ok(())
+	}
+}
+
+impl<T: Config + Send + Sync> TrackActivity<T> {
+	/// Create new `SignedExtension` to record the activity of accounts.
+	pub fn new() -> Self {
+		Self(PhantomData)
+	}
+}
+
+impl<T: Config + Send + Sync> SignedExtension for TrackActivity<T>
+where
+	<T as frame_system::Config>::Call: Dispatchable<Info = DispatchInfo>,
+{
+	type AccountId = T::AccountId;
+	type Call = <T as frame_system::Config>::Call;
+	type AdditionalSigned = ();
+	type Pre = ();
+	const IDENTIFIER: &'static str = "TrackActivity";
+
+	fn additional_signed(&self) -> sp_std::result::Result<(), TransactionValidityError> {
+		Ok(())
+	}
+
+	fn pre_dispatch(
+		self,
+		who: &Self::AccountId,
+		_call: &Self::Call,
+		info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> Result<Self::Pre, TransactionValidityError> {
+		let weight = Pallet::<T>::note_activity(who);
+		<frame_system::Pallet<T>>::register_extra_weight_unchecked(weight, info.class);
+		Ok(())
+	}
+
+	fn validate(
+		&self,
+		_who: &Self::AccountId,
+		_call: &Self::Call,
+		_info: &DispatchInfoOf<Self::Call>,
+		_len: usize,
+	) -> TransactionValidity {
+		Ok(ValidTransaction::default())
+	}
 }
@@ -54,7 +54,7 @@ impl frame_system::Config for Test {
 	type BaseCallFilter = frame_support::traits::Everything;
 	type BlockWeights = ();
 	type BlockLength = ();
-	type DbWeight = ();
+	type DbWeight = frame_support::weights::constants::RocksDbWeight;
 	type Origin = Origin;
 	type Call = Call;
 	type Index = u64;
@@ -97,6 +97,7 @@ parameter_types! {
 	pub const ConfigDepositBase: u64 = 10;
 	pub const FriendDepositFactor: u64 = 1;
 	pub const RecoveryDeposit: u64 = 10;
+	pub const InheritanceDeposit: u64 = 5;
 }
 
 impl Config for Test {
@@ -107,6 +108,8 @@ impl Config for Test {
 	type FriendDepositFactor = FriendDepositFactor;
 	type MaxFriends = ConstU16<3>;
 	type RecoveryDeposit = RecoveryDeposit;
+	type MaxFriendGroups = ConstU16<2>;
+	type InheritanceDeposit = InheritanceDeposit;
 }
 
 pub type BalancesCall = pallet_balances::Call<Test>;
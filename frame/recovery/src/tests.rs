@@ -20,7 +20,8 @@
 use super::*;
 use frame_support::{assert_noop, assert_ok, traits::Currency};
 use mock::{
-	new_test_ext, run_to_block, Balances, BalancesCall, Call, Origin, Recovery, RecoveryCall, Test,
+	new_test_ext, run_to_block, Balances, BalancesCall, Call, Origin, Recovery, RecoveryCall,
+	System, Test,
 };
 use sp_runtime::traits::BadOrigin;
 
@@ -422,3 +423,134 @@ fn remove_recovery_works() {
 		assert_ok!(Recovery::remove_recovery(Origin::signed(5)));
 	});
 }
+
+#[test]
+fn friend_groups_work() {
+	new_test_ext().execute_with(|| {
+		// Cannot add a friend group to an unrecoverable account
+		assert_noop!(
+			Recovery::add_friend_group(Origin::signed(5), vec![6, 7], 2, 20),
+			Error::<Test>::NotRecoverable
+		);
+		assert_ok!(Recovery::create_recovery(Origin::signed(5), vec![2, 3, 4], 3, 10));
+		// Groups are checked just like the recovery configuration
+		assert_noop!(
+			Recovery::add_friend_group(Origin::signed(5), vec![7, 6], 2, 20),
+			Error::<Test>::NotSorted
+		);
+		assert_noop!(
+			Recovery::add_friend_group(Origin::signed(5), vec![6, 7], 3, 20),
+			Error::<Test>::NotEnoughFriends
+		);
+		assert_ok!(Recovery::add_friend_group(Origin::signed(5), vec![6, 7], 2, 20));
+		assert_ok!(Recovery::add_friend_group(Origin::signed(5), vec![8], 1, 30));
+		assert_noop!(
+			Recovery::add_friend_group(Origin::signed(5), vec![9], 1, 30),
+			Error::<Test>::MaxFriendGroups
+		);
+		// Deposit is taken for the configuration and each group
+		assert_eq!(Balances::reserved_balance(5), 13 + 12 + 11);
+
+		assert_ok!(Recovery::initiate_recovery(Origin::signed(1), 5));
+		// Friends of any group can vouch
+		assert_noop!(Recovery::vouch_recovery(Origin::signed(9), 5, 1), Error::<Test>::NotFriend);
+		assert_ok!(Recovery::vouch_recovery(Origin::signed(2), 5, 1));
+		assert_ok!(Recovery::vouch_recovery(Origin::signed(6), 5, 1));
+		assert_ok!(Recovery::vouch_recovery(Origin::signed(7), 5, 1));
+
+		run_to_block(11);
+		// Vouches only count for the groups of the vouching friends
+		assert_noop!(Recovery::claim_recovery(Origin::signed(1), 5), Error::<Test>::Threshold);
+		run_to_block(21);
+		// The second group has met its threshold and delay period
+		assert_ok!(Recovery::claim_recovery(Origin::signed(1), 5));
+		assert_eq!(<Proxy<Test>>::get(&1), Some(5));
+	});
+}
+
+#[test]
+fn remove_friend_group_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(
+			Recovery::remove_friend_group(Origin::signed(5), 0),
+			Error::<Test>::NoFriendGroup
+		);
+		assert_ok!(Recovery::create_recovery(Origin::signed(5), vec![2, 3, 4], 3, 10));
+		assert_ok!(Recovery::add_friend_group(Origin::signed(5), vec![6, 7], 2, 20));
+		assert_ok!(Recovery::add_friend_group(Origin::signed(5), vec![8], 1, 30));
+		assert_noop!(
+			Recovery::remove_friend_group(Origin::signed(5), 2),
+			Error::<Test>::NoFriendGroup
+		);
+		assert_ok!(Recovery::remove_friend_group(Origin::signed(5), 0));
+		assert_eq!(Balances::reserved_balance(5), 13 + 11);
+		assert_eq!(Recovery::friend_groups(&5).len(), 1);
+		assert_eq!(Recovery::friend_groups(&5)[0].friends, vec![8]);
+		// Removing the recovery configuration removes the remaining groups
+		assert_ok!(Recovery::remove_recovery(Origin::signed(5)));
+		assert_eq!(Balances::reserved_balance(5), 0);
+		assert!(Recovery::friend_groups(&5).is_empty());
+	});
+}
+
+#[test]
+fn inheritance_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Recovery::set_heir(Origin::signed(1), 1, 10), Error::<Test>::NotAllowed);
+		assert_noop!(
+			Recovery::set_heir(Origin::signed(1), 2, 0),
+			Error::<Test>::ZeroInactivityPeriod
+		);
+		assert_noop!(Recovery::claim_inheritance(Origin::signed(2), 1), Error::<Test>::NoHeir);
+		assert_ok!(Recovery::set_heir(Origin::signed(1), 2, 10));
+		assert_eq!(Balances::reserved_balance(1), 5);
+		// Changing the heir keeps the deposit
+		assert_ok!(Recovery::set_heir(Origin::signed(1), 3, 10));
+		assert_ok!(Recovery::set_heir(Origin::signed(1), 2, 10));
+		assert_eq!(Balances::reserved_balance(1), 5);
+
+		assert_noop!(Recovery::claim_inheritance(Origin::signed(3), 1), Error::<Test>::NotHeir);
+		run_to_block(5);
+		assert_noop!(Recovery::claim_inheritance(Origin::signed(2), 1), Error::<Test>::NotInactive);
+		// A signed transaction of the account resets its inactivity period
+		let call = Call::Balances(BalancesCall::transfer { dest: 3, value: 1 });
+		let info = Default::default();
+		let weight_before = System::block_weight().total();
+		assert_ok!(TrackActivity::<Test>::new().pre_dispatch(&1, &call, &info, 0));
+		assert_eq!(
+			System::block_weight().total(),
+			weight_before + <Test as frame_system::Config>::DbWeight::get().reads_writes(1, 1)
+		);
+		run_to_block(14);
+		assert_noop!(Recovery::claim_inheritance(Origin::signed(2), 1), Error::<Test>::NotInactive);
+		run_to_block(15);
+		assert_ok!(Recovery::claim_inheritance(Origin::signed(2), 1));
+		assert_eq!(<Proxy<Test>>::get(&2), Some(1));
+		assert_eq!(Recovery::inheritance(&1), None);
+		assert_eq!(Balances::reserved_balance(1), 0);
+		// The heir can make calls on behalf of the account
+		assert_ok!(Recovery::as_recovered(Origin::signed(2), 1, Box::new(call)));
+		assert_eq!(Balances::free_balance(3), 101);
+	});
+}
+
+#[test]
+fn remove_heir_works() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(Recovery::remove_heir(Origin::signed(1)), Error::<Test>::NoHeir);
+		assert_ok!(Recovery::set_heir(Origin::signed(1), 2, 10));
+		assert_ok!(Recovery::remove_heir(Origin::signed(1)));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		run_to_block(20);
+		assert_noop!(Recovery::claim_inheritance(Origin::signed(2), 1), Error::<Test>::NoHeir);
+		// Accounts without an heir are not tracked
+		let call = Call::Balances(BalancesCall::transfer { dest: 3, value: 1 });
+		let weight_before = System::block_weight().total();
+		assert_ok!(TrackActivity::<Test>::new().pre_dispatch(&1, &call, &Default::default(), 0));
+		assert_eq!(Recovery::inheritance(&1), None);
+		assert_eq!(
+			System::block_weight().total(),
+			weight_before + <Test as frame_system::Config>::DbWeight::get().reads(1)
+		);
+	});
+}
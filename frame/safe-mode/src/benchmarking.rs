@@ -0,0 +1,117 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Safe mode pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::{benchmarks, whitelisted_caller};
+use frame_support::traits::{EnsureOrigin, Hooks};
+use frame_system::RawOrigin;
+use sp_runtime::traits::{Bounded, One};
+
+use crate::Pallet as SafeMode;
+
+/// Enters the safe-mode with the deposit of a funded caller and returns the caller and the last
+/// block of the safe-mode.
+fn enter_with_deposit<T: Config>() -> Result<(T::AccountId, T::BlockNumber), &'static str> {
+	let caller: T::AccountId = whitelisted_caller();
+	T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+	SafeMode::<T>::enter(RawOrigin::Signed(caller.clone()).into())?;
+	let until = EnteredUntil::<T>::get().ok_or("safe-mode not entered")?;
+	Ok((caller, until))
+}
+
+benchmarks! {
+	on_initialize_noop {
+		let block: T::BlockNumber = One::one();
+	}: {
+		SafeMode::<T>::on_initialize(block);
+	}
+
+	on_initialize_exit {
+		let until: T::BlockNumber = One::one();
+		EnteredUntil::<T>::put(until);
+	}: {
+		SafeMode::<T>::on_initialize(until + One::one());
+	}
+	verify {
+		assert!(!SafeMode::<T>::is_entered());
+	}
+
+	enter {
+		let caller: T::AccountId = whitelisted_caller();
+		T::Currency::make_free_balance_be(&caller, BalanceOf::<T>::max_value());
+	}: _(RawOrigin::Signed(caller))
+	verify {
+		assert!(SafeMode::<T>::is_entered());
+	}
+
+	force_enter {
+		let origin = T::ForceEnterOrigin::successful_origin();
+	}: _<T::Origin>(origin)
+	verify {
+		assert!(SafeMode::<T>::is_entered());
+	}
+
+	force_extend {
+		let origin = T::ForceExtendOrigin::successful_origin();
+		let until: T::BlockNumber = One::one();
+		EnteredUntil::<T>::put(until);
+	}: _<T::Origin>(origin)
+	verify {
+		assert_eq!(EnteredUntil::<T>::get(), Some(until + T::ExtendDuration::get()));
+	}
+
+	force_exit {
+		let origin = T::ForceExitOrigin::successful_origin();
+		EnteredUntil::<T>::put(T::BlockNumber::one());
+	}: _<T::Origin>(origin)
+	verify {
+		assert!(!SafeMode::<T>::is_entered());
+	}
+
+	release_deposit {
+		let (caller, until) = enter_with_deposit::<T>()?;
+		EnteredUntil::<T>::kill();
+		frame_system::Pallet::<T>::set_block_number(
+			until + T::ReleaseDelay::get() + One::one()
+		);
+	}: _(RawOrigin::Signed(caller.clone()), caller.clone(), until)
+	verify {
+		assert!(!Deposits::<T>::contains_key(&caller, until));
+	}
+
+	force_release_deposit {
+		let origin = T::ForceDepositOrigin::successful_origin();
+		let (caller, until) = enter_with_deposit::<T>()?;
+	}: _<T::Origin>(origin, caller.clone(), until)
+	verify {
+		assert!(!Deposits::<T>::contains_key(&caller, until));
+	}
+
+	force_slash_deposit {
+		let origin = T::ForceDepositOrigin::successful_origin();
+		let (caller, until) = enter_with_deposit::<T>()?;
+	}: _<T::Origin>(origin, caller.clone(), until)
+	verify {
+		assert!(!Deposits::<T>::contains_key(&caller, until));
+	}
+
+	impl_benchmark_test_suite!(SafeMode, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,398 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Safe Mode Module
+//!
+//! Puts the runtime into an emergency safe-mode, in which only whitelisted calls can be
+//! dispatched.
+//!
+//! ## Overview
+//!
+//! - [`Pallet::enter`] enters the safe-mode for [`Config::EnterDuration`] blocks. Any signed
+//!   account can call it, but [`Config::EnterDeposit`] is reserved from it.
+//! - [`Pallet::force_enter`], [`Pallet::force_extend`] and [`Pallet::force_exit`] enter, extend and
+//!   exit the safe-mode without a deposit. They require privileged origins.
+//! - [`Pallet::release_deposit`] releases a deposit once the safe-mode was exited and
+//!   [`Config::ReleaseDelay`] passed. Until then, [`Config::ForceDepositOrigin`] may release or
+//!   slash it with [`Pallet::force_release_deposit`] and [`Pallet::force_slash_deposit`].
+//!
+//! The safe-mode is exited automatically after the block it was entered or extended until.
+//!
+//! The pallet implements [`Contains`] for the calls of the runtime. While the safe-mode is not
+//! entered, it contains all calls. Otherwise, it only contains the calls of this pallet and the
+//! calls in [`Config::WhitelistedCalls`]. It is meant to be used as, or as part of,
+//! `frame_system::Config::BaseCallFilter`.
+//!
+//! ## Related Modules
+//!
+//! * [`System`](../frame_system/index.html)
+//! * [`Support`](../frame_support/index.html)
+
+// Ensure we're `no_std` when compiling for Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+pub mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use codec::{Decode, Encode, MaxEncodedLen};
+use frame_support::traits::{
+	CallMetadata, Contains, Currency, GetCallMetadata, PalletInfoAccess, ReservableCurrency,
+};
+use scale_info::TypeInfo;
+use sp_runtime::{traits::Saturating, RuntimeDebug};
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+/// The balance type of the currency used for deposits.
+pub type BalanceOf<T> =
+	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
+
+/// The reason why the safe-mode was exited.
+#[derive(Copy, Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo, MaxEncodedLen)]
+pub enum ExitReason {
+	/// The block the safe-mode was entered or extended until has passed.
+	Timeout,
+	/// The safe-mode was exited by [`Config::ForceExitOrigin`].
+	Force,
+}
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	/// The module configuration trait.
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The currency mechanism, used for the deposits of `enter`.
+		type Currency: ReservableCurrency<Self::AccountId>;
+
+		/// The calls which can be dispatched while the safe-mode is entered.
+		///
+		/// The calls of this pallet can always be dispatched.
+		type WhitelistedCalls: Contains<<Self as frame_system::Config>::Call>;
+
+		/// The number of blocks the safe-mode is entered for by `enter` and `force_enter`.
+		#[pallet::constant]
+		type EnterDuration: Get<Self::BlockNumber>;
+
+		/// The number of blocks the safe-mode is extended by with `force_extend`.
+		#[pallet::constant]
+		type ExtendDuration: Get<Self::BlockNumber>;
+
+		/// The deposit reserved from the caller of `enter`.
+		#[pallet::constant]
+		type EnterDeposit: Get<BalanceOf<Self>>;
+
+		/// The number of blocks after the end of a safe-mode entered by `enter` until its
+		/// deposit can be released by anyone.
+		///
+		/// Gives `ForceDepositOrigin` the time to slash the deposits of unjustified entries.
+		#[pallet::constant]
+		type ReleaseDelay: Get<Self::BlockNumber>;
+
+		/// The origin which may enter the safe-mode without a deposit.
+		type ForceEnterOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The origin which may extend the safe-mode.
+		type ForceExtendOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The origin which may exit the safe-mode.
+		type ForceExitOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The origin which may release or slash deposits at any time.
+		type ForceDepositOrigin: EnsureOrigin<Self::Origin>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn entered_until)]
+	/// The last block of the safe-mode, if it is entered.
+	pub type EnteredUntil<T: Config> = StorageValue<_, T::BlockNumber, OptionQuery>;
+
+	#[pallet::storage]
+	/// The deposits of `enter`, by depositor and the block the safe-mode was entered until.
+	pub type Deposits<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		T::AccountId,
+		Twox64Concat,
+		T::BlockNumber,
+		BalanceOf<T>,
+		OptionQuery,
+	>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// The safe-mode was entered until the block `until`.
+		Entered { until: T::BlockNumber },
+		/// The safe-mode was extended until the block `until`.
+		Extended { until: T::BlockNumber },
+		/// The safe-mode was exited.
+		Exited { reason: ExitReason },
+		/// `amount` was reserved from `account` for entering the safe-mode.
+		DepositPlaced { account: T::AccountId, amount: BalanceOf<T> },
+		/// The deposit of `account` was released.
+		DepositReleased { account: T::AccountId, amount: BalanceOf<T> },
+		/// The deposit of `account` was slashed.
+		DepositSlashed { account: T::AccountId, amount: BalanceOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The safe-mode is entered.
+		Entered,
+		/// The safe-mode is not entered.
+		Exited,
+		/// There is no deposit for the account and block.
+		NoDeposit,
+		/// The deposit can't be released yet.
+		CannotReleaseYet,
+	}
+
+	#[pallet::hooks]
+	impl<T: Config> Hooks<BlockNumberFor<T>> for Pallet<T> {
+		/// Exit the safe-mode once the block it was entered until has passed.
+		fn on_initialize(now: T::BlockNumber) -> Weight {
+			match EnteredUntil::<T>::get() {
+				Some(until) if now > until => {
+					EnteredUntil::<T>::kill();
+					Self::deposit_event(Event::Exited { reason: ExitReason::Timeout });
+					T::WeightInfo::on_initialize_exit()
+				},
+				_ => T::WeightInfo::on_initialize_noop(),
+			}
+		}
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Enter the safe-mode for `T::EnterDuration` blocks.
+		///
+		/// Origin must be Signed. `T::EnterDeposit` is reserved from the sender until it is
+		/// released or slashed. If the sender already has a deposit for the same end block, which
+		/// can happen when the safe-mode is force exited and entered again within one block, the
+		/// new deposit is added to it.
+		///
+		/// Emits `DepositPlaced` and `Entered` events when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::enter())]
+		pub fn enter(origin: OriginFor<T>) -> DispatchResult {
+			let who = ensure_signed(origin)?;
+			ensure!(!Self::is_entered(), Error::<T>::Entered);
+
+			let amount = T::EnterDeposit::get();
+			T::Currency::reserve(&who, amount)?;
+			let until = Self::do_enter();
+			Deposits::<T>::mutate(&who, until, |deposit| {
+				*deposit = Some(deposit.unwrap_or_default().saturating_add(amount))
+			});
+
+			Self::deposit_event(Event::DepositPlaced { account: who, amount });
+			Self::deposit_event(Event::Entered { until });
+			Ok(())
+		}
+
+		/// Enter the safe-mode for `T::EnterDuration` blocks without a deposit.
+		///
+		/// Origin must be `T::ForceEnterOrigin`.
+		///
+		/// Emits `Entered` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::force_enter())]
+		pub fn force_enter(origin: OriginFor<T>) -> DispatchResult {
+			T::ForceEnterOrigin::ensure_origin(origin)?;
+			ensure!(!Self::is_entered(), Error::<T>::Entered);
+
+			let until = Self::do_enter();
+
+			Self::deposit_event(Event::Entered { until });
+			Ok(())
+		}
+
+		/// Extend the safe-mode by `T::ExtendDuration` blocks.
+		///
+		/// Origin must be `T::ForceExtendOrigin`.
+		///
+		/// Emits `Extended` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::force_extend())]
+		pub fn force_extend(origin: OriginFor<T>) -> DispatchResult {
+			T::ForceExtendOrigin::ensure_origin(origin)?;
+
+			let until = EnteredUntil::<T>::get()
+				.ok_or(Error::<T>::Exited)?
+				.saturating_add(T::ExtendDuration::get());
+			EnteredUntil::<T>::put(until);
+
+			Self::deposit_event(Event::Extended { until });
+			Ok(())
+		}
+
+		/// Exit the safe-mode.
+		///
+		/// Origin must be `T::ForceExitOrigin`.
+		///
+		/// Emits `Exited` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::force_exit())]
+		pub fn force_exit(origin: OriginFor<T>) -> DispatchResult {
+			T::ForceExitOrigin::ensure_origin(origin)?;
+			ensure!(Self::is_entered(), Error::<T>::Exited);
+
+			EnteredUntil::<T>::kill();
+
+			Self::deposit_event(Event::Exited { reason: ExitReason::Force });
+			Ok(())
+		}
+
+		/// Release the deposit of an entry of the safe-mode.
+		///
+		/// Origin must be Signed. The safe-mode must be exited and `T::ReleaseDelay` blocks must
+		/// have passed since `block`.
+		///
+		/// - `account`: The account which entered the safe-mode.
+		/// - `block`: The block the safe-mode was entered until.
+		///
+		/// Emits `DepositReleased` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::release_deposit())]
+		pub fn release_deposit(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+			block: T::BlockNumber,
+		) -> DispatchResult {
+			ensure_signed(origin)?;
+			ensure!(!Self::is_entered(), Error::<T>::Entered);
+			let now = frame_system::Pallet::<T>::block_number();
+			ensure!(
+				now > block.saturating_add(T::ReleaseDelay::get()),
+				Error::<T>::CannotReleaseYet
+			);
+
+			Self::do_release_deposit(account, block)
+		}
+
+		/// Release the deposit of an entry of the safe-mode at any time.
+		///
+		/// Origin must be `T::ForceDepositOrigin`.
+		///
+		/// - `account`: The account which entered the safe-mode.
+		/// - `block`: The block the safe-mode was entered until.
+		///
+		/// Emits `DepositReleased` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::force_release_deposit())]
+		pub fn force_release_deposit(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+			block: T::BlockNumber,
+		) -> DispatchResult {
+			T::ForceDepositOrigin::ensure_origin(origin)?;
+
+			Self::do_release_deposit(account, block)
+		}
+
+		/// Slash the deposit of an entry of the safe-mode. The slashed funds are burned.
+		///
+		/// Origin must be `T::ForceDepositOrigin`.
+		///
+		/// - `account`: The account which entered the safe-mode.
+		/// - `block`: The block the safe-mode was entered until.
+		///
+		/// Emits `DepositSlashed` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::force_slash_deposit())]
+		pub fn force_slash_deposit(
+			origin: OriginFor<T>,
+			account: T::AccountId,
+			block: T::BlockNumber,
+		) -> DispatchResult {
+			T::ForceDepositOrigin::ensure_origin(origin)?;
+
+			let amount = Deposits::<T>::take(&account, block).ok_or(Error::<T>::NoDeposit)?;
+			// The negative imbalance is dropped, which burns the slashed funds.
+			let _ = T::Currency::slash_reserved(&account, amount);
+
+			Self::deposit_event(Event::DepositSlashed { account, amount });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Whether the safe-mode is entered.
+		pub fn is_entered() -> bool {
+			EnteredUntil::<T>::exists()
+		}
+
+		/// Enter the safe-mode for `T::EnterDuration` blocks and return its last block.
+		fn do_enter() -> T::BlockNumber {
+			let now = frame_system::Pallet::<T>::block_number();
+			let until = now.saturating_add(T::EnterDuration::get());
+			EnteredUntil::<T>::put(until);
+			until
+		}
+
+		/// Unreserve the deposit of `account` for the safe-mode entered until `block`.
+		fn do_release_deposit(account: T::AccountId, block: T::BlockNumber) -> DispatchResult {
+			let amount = Deposits::<T>::take(&account, block).ok_or(Error::<T>::NoDeposit)?;
+			T::Currency::unreserve(&account, amount);
+
+			Self::deposit_event(Event::DepositReleased { account, amount });
+			Ok(())
+		}
+	}
+}
+
+/// Contains all calls while the safe-mode is not entered, and otherwise only the calls of this
+/// pallet and the whitelisted calls.
+impl<T: Config> Contains<<T as frame_system::Config>::Call> for Pallet<T>
+where
+	<T as frame_system::Config>::Call: GetCallMetadata,
+{
+	fn contains(call: &<T as frame_system::Config>::Call) -> bool {
+		let CallMetadata { pallet_name, .. } = call.get_call_metadata();
+		if pallet_name == <Self as PalletInfoAccess>::name() {
+			return true
+		}
+		!Self::is_entered() || T::WhitelistedCalls::contains(call)
+	}
+}
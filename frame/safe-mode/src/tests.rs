@@ -0,0 +1,214 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for safe mode pallet.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_err, assert_noop, assert_ok, dispatch::Dispatchable, traits::Hooks};
+use sp_runtime::DispatchError;
+
+fn run_to_block(n: u64) {
+	System::set_block_number(n);
+	SafeMode::on_initialize(n);
+}
+
+fn transfer(dest: u64, value: u64) -> Call {
+	Call::Balances(pallet_balances::Call::transfer { dest, value })
+}
+
+fn remark() -> Call {
+	Call::System(frame_system::Call::remark { remark: vec![] })
+}
+
+fn assert_filtered(call: Call, origin: Origin) {
+	assert_err!(
+		call.dispatch(origin).map_err(|e| e.error),
+		DispatchError::from(frame_system::Error::<Test>::CallFiltered)
+	);
+}
+
+#[test]
+fn enter_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(transfer(2, 10).dispatch(Origin::signed(1)));
+
+		assert_ok!(SafeMode::enter(Origin::signed(1)));
+		assert_eq!(SafeMode::entered_until(), Some(11));
+		assert_eq!(Balances::reserved_balance(1), 50);
+		assert_eq!(Deposits::<Test>::get(1, 11), Some(50));
+		System::assert_has_event(Event::SafeMode(crate::Event::DepositPlaced {
+			account: 1,
+			amount: 50,
+		}));
+		System::assert_last_event(Event::SafeMode(crate::Event::Entered { until: 11 }));
+
+		// Only whitelisted calls and the calls of the pallet can be dispatched.
+		assert_filtered(transfer(2, 10), Origin::signed(1));
+		assert_ok!(remark().dispatch(Origin::signed(1)));
+		assert_noop!(
+			Call::SafeMode(crate::Call::enter {})
+				.dispatch(Origin::signed(2))
+				.map_err(|e| e.error),
+			Error::<Test>::Entered
+		);
+		assert_noop!(SafeMode::force_enter(Origin::root()), Error::<Test>::Entered);
+	});
+}
+
+#[test]
+fn enter_fails_without_funds() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(transfer(2, 60).dispatch(Origin::signed(1)));
+		assert_noop!(
+			SafeMode::enter(Origin::signed(1)),
+			pallet_balances::Error::<Test>::InsufficientBalance
+		);
+		assert!(!SafeMode::is_entered());
+	});
+}
+
+#[test]
+fn safe_mode_exits_after_timeout() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(SafeMode::force_enter(Origin::root()));
+		System::assert_last_event(Event::SafeMode(crate::Event::Entered { until: 11 }));
+
+		run_to_block(11);
+		assert!(SafeMode::is_entered());
+		assert_filtered(transfer(2, 10), Origin::signed(1));
+
+		run_to_block(12);
+		assert!(!SafeMode::is_entered());
+		System::assert_last_event(Event::SafeMode(crate::Event::Exited {
+			reason: ExitReason::Timeout,
+		}));
+		assert_ok!(transfer(2, 10).dispatch(Origin::signed(1)));
+	});
+}
+
+#[test]
+fn force_extend_and_force_exit_work() {
+	new_test_ext().execute_with(|| {
+		assert_noop!(SafeMode::force_extend(Origin::root()), Error::<Test>::Exited);
+		assert_noop!(SafeMode::force_exit(Origin::root()), Error::<Test>::Exited);
+		assert_noop!(SafeMode::force_enter(Origin::signed(1)), DispatchError::BadOrigin);
+
+		assert_ok!(SafeMode::force_enter(Origin::root()));
+		assert_noop!(SafeMode::force_extend(Origin::signed(1)), DispatchError::BadOrigin);
+		assert_ok!(SafeMode::force_extend(Origin::root()));
+		assert_eq!(SafeMode::entered_until(), Some(16));
+		System::assert_last_event(Event::SafeMode(crate::Event::Extended { until: 16 }));
+
+		run_to_block(16);
+		assert!(SafeMode::is_entered());
+
+		assert_noop!(SafeMode::force_exit(Origin::signed(1)), DispatchError::BadOrigin);
+		assert_ok!(SafeMode::force_exit(Origin::root()));
+		assert!(!SafeMode::is_entered());
+		System::assert_last_event(Event::SafeMode(crate::Event::Exited {
+			reason: ExitReason::Force,
+		}));
+		assert_ok!(transfer(2, 10).dispatch(Origin::signed(1)));
+	});
+}
+
+#[test]
+fn entering_twice_until_the_same_block_adds_up_the_deposits() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(SafeMode::enter(Origin::signed(1)));
+		assert_ok!(SafeMode::force_exit(Origin::root()));
+		assert_ok!(SafeMode::enter(Origin::signed(1)));
+		assert_eq!(SafeMode::entered_until(), Some(11));
+		assert_eq!(Balances::reserved_balance(1), 100);
+		assert_eq!(Deposits::<Test>::get(1, 11), Some(100));
+
+		assert_ok!(SafeMode::force_exit(Origin::root()));
+		run_to_block(32);
+		assert_ok!(SafeMode::release_deposit(Origin::signed(2), 1, 11));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 100);
+	});
+}
+
+#[test]
+fn release_deposit_works() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(SafeMode::enter(Origin::signed(1)));
+		assert_noop!(SafeMode::release_deposit(Origin::signed(2), 1, 11), Error::<Test>::Entered);
+
+		assert_ok!(SafeMode::force_exit(Origin::root()));
+		assert_noop!(
+			SafeMode::release_deposit(Origin::signed(2), 1, 11),
+			Error::<Test>::CannotReleaseYet
+		);
+		run_to_block(31);
+		assert_noop!(
+			SafeMode::release_deposit(Origin::signed(2), 1, 11),
+			Error::<Test>::CannotReleaseYet
+		);
+
+		run_to_block(32);
+		assert_noop!(SafeMode::release_deposit(Origin::signed(2), 1, 12), Error::<Test>::NoDeposit);
+		assert_ok!(SafeMode::release_deposit(Origin::signed(2), 1, 11));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		assert_eq!(Balances::free_balance(1), 100);
+		System::assert_last_event(Event::SafeMode(crate::Event::DepositReleased {
+			account: 1,
+			amount: 50,
+		}));
+		assert_noop!(SafeMode::release_deposit(Origin::signed(2), 1, 11), Error::<Test>::NoDeposit);
+	});
+}
+
+#[test]
+fn force_release_and_force_slash_deposit_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(SafeMode::enter(Origin::signed(1)));
+		assert_noop!(
+			SafeMode::force_release_deposit(Origin::signed(1), 1, 11),
+			DispatchError::BadOrigin
+		);
+		// Deposits can be released while the safe-mode is entered.
+		assert_ok!(SafeMode::force_release_deposit(Origin::root(), 1, 11));
+		assert_eq!(Balances::reserved_balance(1), 0);
+		System::assert_last_event(Event::SafeMode(crate::Event::DepositReleased {
+			account: 1,
+			amount: 50,
+		}));
+		assert_ok!(SafeMode::force_exit(Origin::root()));
+
+		run_to_block(2);
+		assert_ok!(SafeMode::enter(Origin::signed(2)));
+		assert_noop!(
+			SafeMode::force_slash_deposit(Origin::signed(1), 2, 12),
+			DispatchError::BadOrigin
+		);
+		assert_noop!(
+			SafeMode::force_slash_deposit(Origin::root(), 2, 11),
+			Error::<Test>::NoDeposit
+		);
+		assert_ok!(SafeMode::force_slash_deposit(Origin::root(), 2, 12));
+		assert_eq!(Balances::reserved_balance(2), 0);
+		assert_eq!(Balances::free_balance(2), 50);
+		assert_eq!(Balances::total_issuance(), 250);
+		System::assert_last_event(Event::SafeMode(crate::Event::DepositSlashed {
+			account: 2,
+			amount: 50,
+		}));
+	});
+}
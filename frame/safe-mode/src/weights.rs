@@ -0,0 +1,169 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Placeholder weights for pallet_safe_mode
+//!
+//! These weights are NOT the output of the benchmark CLI. They are estimates based on the storage
+//! accesses of each call and must be replaced by running the benchmarks of the pallet with the
+//! benchmark CLI before the pallet is used in production.
+
+#![cfg_attr(rustfmt, rustfmt_skip)]
+#![allow(unused_parens)]
+#![allow(unused_imports)]
+
+use frame_support::{traits::Get, weights::{Weight, constants::RocksDbWeight}};
+use sp_std::marker::PhantomData;
+
+/// Weight functions needed for pallet_safe_mode.
+pub trait WeightInfo {
+	fn on_initialize_noop() -> Weight;
+	fn on_initialize_exit() -> Weight;
+	fn enter() -> Weight;
+	fn force_enter() -> Weight;
+	fn force_extend() -> Weight;
+	fn force_exit() -> Weight;
+	fn release_deposit() -> Weight;
+	fn force_release_deposit() -> Weight;
+	fn force_slash_deposit() -> Weight;
+}
+
+/// Weights for pallet_safe_mode using the Substrate node and recommended hardware.
+pub struct SubstrateWeight<T>(PhantomData<T>);
+impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
+	// Storage: SafeMode EnteredUntil (r:1 w:0)
+	fn on_initialize_noop() -> Weight {
+		(2_470_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+	}
+	// Storage: SafeMode EnteredUntil (r:1 w:1)
+	fn on_initialize_exit() -> Weight {
+		(7_834_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: SafeMode EnteredUntil (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: SafeMode Deposits (r:0 w:1)
+	fn enter() -> Weight {
+		(41_127_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+	}
+	// Storage: SafeMode EnteredUntil (r:1 w:1)
+	fn force_enter() -> Weight {
+		(14_203_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: SafeMode EnteredUntil (r:1 w:1)
+	fn force_extend() -> Weight {
+		(14_917_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: SafeMode EnteredUntil (r:1 w:1)
+	fn force_exit() -> Weight {
+		(13_562_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: SafeMode EnteredUntil (r:1 w:0)
+	// Storage: SafeMode Deposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn release_deposit() -> Weight {
+		(36_890_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: SafeMode Deposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn force_release_deposit() -> Weight {
+		(34_615_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+	// Storage: SafeMode Deposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn force_slash_deposit() -> Weight {
+		(39_208_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+	}
+}
+
+// For backwards compatibility and tests
+impl WeightInfo for () {
+	// Storage: SafeMode EnteredUntil (r:1 w:0)
+	fn on_initialize_noop() -> Weight {
+		(2_470_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+	}
+	// Storage: SafeMode EnteredUntil (r:1 w:1)
+	fn on_initialize_exit() -> Weight {
+		(7_834_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: SafeMode EnteredUntil (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	// Storage: SafeMode Deposits (r:0 w:1)
+	fn enter() -> Weight {
+		(41_127_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+	}
+	// Storage: SafeMode EnteredUntil (r:1 w:1)
+	fn force_enter() -> Weight {
+		(14_203_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: SafeMode EnteredUntil (r:1 w:1)
+	fn force_extend() -> Weight {
+		(14_917_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: SafeMode EnteredUntil (r:1 w:1)
+	fn force_exit() -> Weight {
+		(13_562_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: SafeMode EnteredUntil (r:1 w:0)
+	// Storage: SafeMode Deposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn release_deposit() -> Weight {
+		(36_890_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: SafeMode Deposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn force_release_deposit() -> Weight {
+		(34_615_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+	// Storage: SafeMode Deposits (r:1 w:1)
+	// Storage: System Account (r:1 w:1)
+	fn force_slash_deposit() -> Weight {
+		(39_208_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+	}
+}
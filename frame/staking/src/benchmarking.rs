@@ -65,6 +65,12 @@ fn add_slashing_spans<T: Config>(who: &T::AccountId, spans: u32) {
 	SlashingSpans::<T>::insert(who, slashing_spans);
 }
 
+// Make `who` the next nominator to be taken by the paged voter snapshot. This is the worst case of
+// removing a nominator, as the cursor of the snapshot then has to be moved past it.
+fn make_next_in_voter_snapshot<T: Config>(who: &T::AccountId) {
+	VoterSnapshotStatus::<T>::put(SnapshotStatus::Nominators(who.clone()));
+}
+
 // This function clears all existing validators and nominators from the set, and generates one new
 // validator being nominated by n nominators, and returns the validator stash account and the
 // nominators' stash and controller. It also starts an era and creates pending payouts.
@@ -317,6 +323,7 @@ benchmarks! {
 		let controller = scenario.origin_controller1.clone();
 		let stash = scenario.origin_stash1.clone();
 		assert!(T::SortedListProvider::contains(&stash));
+		make_next_in_voter_snapshot::<T>(&stash);
 
 		let ed = T::Currency::minimum_balance();
 		let mut ledger = Ledger::<T>::get(&controller).unwrap();
@@ -343,6 +350,7 @@ benchmarks! {
 		let controller = scenario.origin_controller1.clone();
 		let stash = scenario.origin_stash1.clone();
 		assert!(T::SortedListProvider::contains(&stash));
+		make_next_in_voter_snapshot::<T>(&stash);
 
 		let prefs = ValidatorPrefs::default();
 		whitelist_account!(controller);
@@ -456,6 +464,7 @@ benchmarks! {
 		let controller = scenario.origin_controller1.clone();
 		let stash = scenario.origin_stash1.clone();
 		assert!(T::SortedListProvider::contains(&stash));
+		make_next_in_voter_snapshot::<T>(&stash);
 
 		whitelist_account!(controller);
 	}: _(RawOrigin::Signed(controller))
@@ -524,6 +533,7 @@ benchmarks! {
 		let controller = scenario.origin_controller1.clone();
 		let stash = scenario.origin_stash1.clone();
 		assert!(T::SortedListProvider::contains(&stash));
+		make_next_in_voter_snapshot::<T>(&stash);
 		add_slashing_spans::<T>(&stash, s);
 
 	}: _(RawOrigin::Root, stash.clone(), s)
@@ -549,10 +559,10 @@ benchmarks! {
 	}
 
 	payout_stakers_dead_controller {
-		let n in 1 .. T::MaxNominatorRewardedPerValidator::get() as u32;
+		let n in 1 .. T::MaxExposurePageSize::get() as u32;
 		let (validator, nominators) = create_validator_with_nominators::<T>(
 			n,
-			T::MaxNominatorRewardedPerValidator::get() as u32,
+			T::MaxExposurePageSize::get() as u32,
 			true,
 			RewardDestination::Controller,
 		)?;
@@ -582,10 +592,10 @@ benchmarks! {
 	}
 
 	payout_stakers_alive_staked {
-		let n in 1 .. T::MaxNominatorRewardedPerValidator::get() as u32;
+		let n in 1 .. T::MaxExposurePageSize::get() as u32;
 		let (validator, nominators) = create_validator_with_nominators::<T>(
 			n,
-			T::MaxNominatorRewardedPerValidator::get() as u32,
+			T::MaxExposurePageSize::get() as u32,
 			false,
 			RewardDestination::Staked,
 		)?;
@@ -673,6 +683,9 @@ benchmarks! {
 		for i in 0 .. e {
 			<ErasStakers<T>>::insert(i, dummy(), Exposure::<T::AccountId, BalanceOf<T>>::default());
 			<ErasStakersClipped<T>>::insert(i, dummy(), Exposure::<T::AccountId, BalanceOf<T>>::default());
+			<ErasStakersOverview<T>>::insert(i, dummy(), PagedExposureMetadata::<BalanceOf<T>>::default());
+			<ErasStakersPaged<T>>::insert((i, dummy(), 0), ExposurePage::<T::AccountId, BalanceOf<T>>::default());
+			<ClaimedRewards<T>>::insert(i, dummy(), vec![0]);
 			<ErasValidatorPrefs<T>>::insert(i, dummy(), ValidatorPrefs::default());
 			<ErasValidatorReward<T>>::insert(i, BalanceOf::<T>::one());
 			<ErasRewardPoints<T>>::insert(i, EraRewardPoints::<T::AccountId>::default());
@@ -709,6 +722,7 @@ benchmarks! {
 
 		assert!(Bonded::<T>::contains_key(&stash));
 		assert!(T::SortedListProvider::contains(&stash));
+		make_next_in_voter_snapshot::<T>(&stash);
 
 		whitelist_account!(controller);
 	}: _(RawOrigin::Signed(controller), stash.clone(), s)
@@ -883,6 +897,7 @@ benchmarks! {
 		let controller = scenario.origin_controller1.clone();
 		let stash = scenario.origin_stash1.clone();
 		assert!(T::SortedListProvider::contains(&stash));
+		make_next_in_voter_snapshot::<T>(&stash);
 
 		Staking::<T>::set_staking_configs(
 			RawOrigin::Root.into(),
@@ -947,7 +962,7 @@ mod tests {
 
 			let (validator_stash, nominators) = create_validator_with_nominators::<Test>(
 				n,
-				<Test as Config>::MaxNominatorRewardedPerValidator::get(),
+				<Test as Config>::MaxExposurePageSize::get(),
 				false,
 				RewardDestination::Staked,
 			)
@@ -972,7 +987,7 @@ mod tests {
 
 			let (validator_stash, _nominators) = create_validator_with_nominators::<Test>(
 				n,
-				<Test as Config>::MaxNominatorRewardedPerValidator::get(),
+				<Test as Config>::MaxExposurePageSize::get(),
 				false,
 				RewardDestination::Staked,
 			)
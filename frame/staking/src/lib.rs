@@ -114,9 +114,10 @@
 //!
 //! Rewards must be claimed for each era before it gets too old by `$HISTORY_DEPTH` using the
 //! `payout_stakers` call. Any account can call `payout_stakers`, which pays the reward to the
-//! validator as well as its nominators. Only the [`Config::MaxNominatorRewardedPerValidator`]
-//! biggest stakers can claim their reward. This is to limit the i/o cost to mutate storage for each
-//! nominator's account.
+//! validator as well as its nominators. The exposure of each validator is split into pages of at
+//! most [`Config::MaxExposurePageSize`] nominators, and each call to `payout_stakers` pays out a
+//! single page. This is to limit the i/o cost to mutate storage for each nominator's account,
+//! while still rewarding every nominator backing the validator.
 //!
 //! Slashing can occur at any point in time, once misbehavior is reported. Once slashing is
 //! determined, a value is deducted from the balance of the validator and all the nominators who
@@ -336,6 +337,9 @@ macro_rules! log {
 /// Counter for the number of "reward" points earned by a given validator.
 pub type RewardPoint = u32;
 
+/// Index of a page of the exposure of a validator in a given era.
+pub type Page = u32;
+
 /// The balance type of this pallet.
 pub type BalanceOf<T> =
 	<<T as Config>::Currency as Currency<<T as frame_system::Config>::AccountId>>::Balance;
@@ -451,6 +455,9 @@ pub struct StakingLedger<AccountId, Balance: HasCompact> {
 	pub unlocking: Vec<UnlockChunk<Balance>>,
 	/// List of eras for which the stakers behind a validator have claimed rewards. Only updated
 	/// for validators.
+	///
+	/// This is only kept for eras whose exposure was not paged. Rewards claimed for paged
+	/// exposures are tracked in [`ClaimedRewards`].
 	pub claimed_rewards: Vec<EraIndex>,
 }
 
@@ -621,6 +628,87 @@ impl<AccountId, Balance: Default + HasCompact> Default for Exposure<AccountId, B
 	}
 }
 
+impl<AccountId: Clone, Balance: HasCompact + AtLeast32BitUnsigned + Copy>
+	Exposure<AccountId, Balance>
+{
+	/// Split this exposure into an overview and a list of pages, each containing at most
+	/// `page_size` nominators.
+	///
+	/// The overview always reports at least one page, even if there are no nominators, so that
+	/// the validator's own reward can be claimed.
+	pub fn into_pages(
+		self,
+		page_size: Page,
+	) -> (PagedExposureMetadata<Balance>, Vec<ExposurePage<AccountId, Balance>>) {
+		let individual_chunks = self.others.chunks(page_size.max(1) as usize);
+		let mut exposure_pages: Vec<ExposurePage<AccountId, Balance>> =
+			Vec::with_capacity(individual_chunks.len());
+
+		for chunk in individual_chunks {
+			let mut page_total: Balance = Zero::zero();
+			let mut others: Vec<IndividualExposure<AccountId, Balance>> =
+				Vec::with_capacity(chunk.len());
+			for individual in chunk.iter() {
+				page_total = page_total.saturating_add(individual.value);
+				others.push(IndividualExposure {
+					who: individual.who.clone(),
+					value: individual.value,
+				})
+			}
+			exposure_pages.push(ExposurePage { page_total, others });
+		}
+
+		if exposure_pages.is_empty() {
+			exposure_pages.push(ExposurePage { page_total: Zero::zero(), others: vec![] });
+		}
+
+		(
+			PagedExposureMetadata {
+				total: self.total,
+				own: self.own,
+				nominator_count: self.others.len() as u32,
+				page_count: exposure_pages.len() as Page,
+			},
+			exposure_pages,
+		)
+	}
+}
+
+/// Metadata for the paged exposure of a validator in a given era.
+///
+/// The nominators themselves are stored in [`ExposurePage`]s, see [`ErasStakersPaged`].
+#[derive(
+	PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, RuntimeDebug, TypeInfo, Default,
+)]
+pub struct PagedExposureMetadata<Balance: HasCompact> {
+	/// The total balance backing this validator.
+	#[codec(compact)]
+	pub total: Balance,
+	/// The validator's own stash that is exposed.
+	#[codec(compact)]
+	pub own: Balance,
+	/// Number of nominators backing this validator.
+	pub nominator_count: u32,
+	/// Number of pages of nominators.
+	pub page_count: Page,
+}
+
+/// A single page of the nominators backing a validator in a given era.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Clone, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub struct ExposurePage<AccountId, Balance: HasCompact> {
+	/// The sum of all the nominator stakes in this page.
+	#[codec(compact)]
+	pub page_total: Balance,
+	/// The portions of nominators stashes that are exposed.
+	pub others: Vec<IndividualExposure<AccountId, Balance>>,
+}
+
+impl<AccountId, Balance: Default + HasCompact> Default for ExposurePage<AccountId, Balance> {
+	fn default() -> Self {
+		Self { page_total: Default::default(), others: vec![] }
+	}
+}
+
 /// A pending slash record. The value of the slash has been computed but not applied yet,
 /// rather deferred for several eras.
 #[derive(Encode, Decode, RuntimeDebug, TypeInfo)]
@@ -758,6 +846,30 @@ impl Default for Forcing {
 	}
 }
 
+/// The progress of the paged voter snapshot being handed to the election provider.
+///
+/// See [`frame_election_provider_support::ElectionDataProvider::voters_paged`] for more
+/// information.
+#[derive(Clone, PartialEq, Eq, Encode, Decode, RuntimeDebug, TypeInfo)]
+pub enum SnapshotStatus<AccountId> {
+	/// No page has been taken yet; the next page starts from the first validator.
+	Waiting,
+	/// Pages are being taken from the validators, and the given validator was the last one
+	/// included.
+	Validators(AccountId),
+	/// Pages are being taken from the nominators, and the given nominator is the next one to be
+	/// visited.
+	Nominators(AccountId),
+	/// All voters have been included in a page.
+	Consumed,
+}
+
+impl<AccountId> Default for SnapshotStatus<AccountId> {
+	fn default() -> Self {
+		SnapshotStatus::Waiting
+	}
+}
+
 // A value placed in storage that represents the current version of the Staking storage. This value
 // is used by the `on_runtime_upgrade` logic to determine whether we run storage migration logic.
 // This should match directly with the semantic versions of the Rust crate.
@@ -18,7 +18,7 @@
 //! Implementations for the Staking FRAME Pallet.
 
 use frame_election_provider_support::{
-	data_provider, ElectionDataProvider, ElectionProvider, SortedListProvider, Supports,
+	data_provider, ElectionDataProvider, ElectionProvider, PageIndex, SortedListProvider, Supports,
 	VoteWeight, VoteWeightProvider, VoterOf,
 };
 use frame_support::{
@@ -43,8 +43,9 @@ use sp_std::{collections::btree_map::BTreeMap, prelude::*};
 
 use crate::{
 	log, slashing, weights::WeightInfo, ActiveEraInfo, BalanceOf, EraPayout, Exposure, ExposureOf,
-	Forcing, IndividualExposure, Nominations, PositiveImbalanceOf, RewardDestination,
-	SessionInterface, StakingLedger, ValidatorPrefs,
+	ExposurePage, Forcing, IndividualExposure, Nominations, Page, PagedExposureMetadata,
+	PositiveImbalanceOf, RewardDestination, SessionInterface, SnapshotStatus, StakingLedger,
+	ValidatorPrefs,
 };
 
 use super::{pallet::*, STAKING_ID};
@@ -83,9 +84,104 @@ impl<T: Config> Pallet<T> {
 		Self::slashable_balance_of_vote_weight(who, issuance)
 	}
 
+	/// The number of pages of the exposure of `validator` in `era`.
+	///
+	/// Eras planned before exposures were paged, or in which the validator has no exposure,
+	/// always have a single page.
+	pub fn exposure_page_count(era: EraIndex, validator: &T::AccountId) -> Page {
+		<ErasStakersOverview<T>>::get(&era, validator)
+			.map(|overview| overview.page_count.max(1))
+			.unwrap_or(1)
+	}
+
+	/// The full exposure of `validator` in `era`.
+	///
+	/// For eras with paged exposures, the nominators of all pages are collected. If the exposure
+	/// hasn't been set or has been removed then an empty exposure is returned.
+	pub fn eras_stakers(
+		era: EraIndex,
+		validator: &T::AccountId,
+	) -> Exposure<T::AccountId, BalanceOf<T>> {
+		let overview = match <ErasStakersOverview<T>>::get(&era, validator) {
+			Some(overview) => overview,
+			None => return <ErasStakers<T>>::get(&era, validator),
+		};
+
+		let mut others = Vec::with_capacity(overview.nominator_count as usize);
+		for page in 0..overview.page_count {
+			if let Some(exposure_page) = <ErasStakersPaged<T>>::get((era, validator, page)) {
+				others.extend(exposure_page.others);
+			}
+		}
+		Exposure { total: overview.total, own: overview.own, others }
+	}
+
+	/// Get a page of the exposure of `validator` in `era`, along with the overview of the whole
+	/// exposure.
+	///
+	/// The validator's own stake is accounted for in the first page, so that the `page_total`
+	/// of all pages sums up to the total exposure. For eras planned before exposures were paged,
+	/// the clipped exposure is returned as the only page.
+	pub(crate) fn paged_exposure(
+		era: EraIndex,
+		validator: &T::AccountId,
+		page: Page,
+	) -> Option<(PagedExposureMetadata<BalanceOf<T>>, ExposurePage<T::AccountId, BalanceOf<T>>)> {
+		match <ErasStakersOverview<T>>::get(&era, validator) {
+			Some(overview) => {
+				let mut exposure_page =
+					<ErasStakersPaged<T>>::get((era, validator, page)).unwrap_or_default();
+				if page == 0 {
+					exposure_page.page_total =
+						exposure_page.page_total.saturating_add(overview.own);
+				}
+				Some((overview, exposure_page))
+			},
+			None if page == 0 => {
+				let exposure = <ErasStakersClipped<T>>::get(&era, validator);
+				let overview = PagedExposureMetadata {
+					total: exposure.total,
+					own: exposure.own,
+					nominator_count: exposure.others.len() as u32,
+					page_count: 1,
+				};
+				Some((
+					overview,
+					ExposurePage { page_total: exposure.total, others: exposure.others },
+				))
+			},
+			None => None,
+		}
+	}
+
+	/// The lowest page of the exposure of `validator` in `era` for which rewards have not been
+	/// claimed yet, if any.
+	pub fn next_unclaimed_page(era: EraIndex, validator: &T::AccountId) -> Option<Page> {
+		let legacy_claimed = Self::bonded(validator)
+			.and_then(Self::ledger)
+			.map_or(false, |ledger| ledger.claimed_rewards.contains(&era));
+		if legacy_claimed {
+			return None
+		}
+
+		let claimed_pages = <ClaimedRewards<T>>::get(&era, validator);
+		(0..Self::exposure_page_count(era, validator)).find(|page| !claimed_pages.contains(page))
+	}
+
 	pub(super) fn do_payout_stakers(
 		validator_stash: T::AccountId,
 		era: EraIndex,
+	) -> DispatchResultWithPostInfo {
+		let page = Self::next_unclaimed_page(era, &validator_stash).ok_or_else(|| {
+			Error::<T>::AlreadyClaimed.with_weight(T::WeightInfo::payout_stakers_alive_staked(0))
+		})?;
+		Self::do_payout_stakers_by_page(validator_stash, era, page)
+	}
+
+	pub(super) fn do_payout_stakers_by_page(
+		validator_stash: T::AccountId,
+		era: EraIndex,
+		page: Page,
 	) -> DispatchResultWithPostInfo {
 		// Validate input data
 		let current_era = CurrentEra::<T>::get().ok_or_else(|| {
@@ -100,7 +196,7 @@ impl<T: Config> Pallet<T> {
 		);
 
 		// Note: if era has no reward to be claimed, era may be future. better not to update
-		// `ledger.claimed_rewards` in this case.
+		// `ClaimedRewards` in this case.
 		let era_payout = <ErasValidatorReward<T>>::get(&era).ok_or_else(|| {
 			Error::<T>::InvalidEraToReward
 				.with_weight(T::WeightInfo::payout_stakers_alive_staked(0))
@@ -111,19 +207,33 @@ impl<T: Config> Pallet<T> {
 		})?;
 		let mut ledger = <Ledger<T>>::get(&controller).ok_or(Error::<T>::NotController)?;
 
+		ensure!(
+			page < Self::exposure_page_count(era, &ledger.stash),
+			Error::<T>::InvalidPage.with_weight(T::WeightInfo::payout_stakers_alive_staked(0))
+		);
+
+		// Rewards claimed before exposures were paged are still tracked in the ledger.
 		ledger
 			.claimed_rewards
 			.retain(|&x| x >= current_era.saturating_sub(history_depth));
-		match ledger.claimed_rewards.binary_search(&era) {
+		ensure!(
+			ledger.claimed_rewards.binary_search(&era).is_err(),
+			Error::<T>::AlreadyClaimed.with_weight(T::WeightInfo::payout_stakers_alive_staked(0))
+		);
+
+		let mut claimed_pages = <ClaimedRewards<T>>::get(&era, &ledger.stash);
+		match claimed_pages.binary_search(&page) {
 			Ok(_) => Err(Error::<T>::AlreadyClaimed
 				.with_weight(T::WeightInfo::payout_stakers_alive_staked(0)))?,
-			Err(pos) => ledger.claimed_rewards.insert(pos, era),
+			Err(pos) => claimed_pages.insert(pos, page),
 		}
 
-		let exposure = <ErasStakersClipped<T>>::get(&era, &ledger.stash);
+		let (overview, exposure_page) =
+			Self::paged_exposure(era, &ledger.stash, page).unwrap_or_default();
 
 		// Input data seems good, no errors allowed after this point
 
+		<ClaimedRewards<T>>::insert(&era, &ledger.stash, claimed_pages);
 		<Ledger<T>>::insert(&controller, &ledger);
 
 		// Get Era reward points. It has TOTAL and INDIVIDUAL
@@ -160,9 +270,18 @@ impl<T: Config> Pallet<T> {
 		let validator_commission_payout = validator_commission * validator_total_payout;
 
 		let validator_leftover_payout = validator_total_payout - validator_commission_payout;
-		// Now let's calculate how this is split to the validator.
-		let validator_exposure_part = Perbill::from_rational(exposure.own, exposure.total);
-		let validator_staking_payout = validator_exposure_part * validator_leftover_payout;
+
+		// The commission is paid out across pages proportionally to the stake in each page,
+		// while the reward for the validator's own stake is paid out with the first page.
+		let page_stake_part = Perbill::from_rational(exposure_page.page_total, overview.total);
+		let validator_commission_payout = page_stake_part * validator_commission_payout;
+		let validator_staking_payout = if page == 0 {
+			// Now let's calculate how this is split to the validator.
+			let validator_exposure_part = Perbill::from_rational(overview.own, overview.total);
+			validator_exposure_part * validator_leftover_payout
+		} else {
+			Zero::zero()
+		};
 
 		Self::deposit_event(Event::<T>::PayoutStarted(era, ledger.stash.clone()));
 
@@ -179,9 +298,9 @@ impl<T: Config> Pallet<T> {
 		let mut nominator_payout_count: u32 = 0;
 
 		// Lets now calculate how this is split to the nominators.
-		// Reward only the clipped exposures. Note this is not necessarily sorted.
-		for nominator in exposure.others.iter() {
-			let nominator_exposure_part = Perbill::from_rational(nominator.value, exposure.total);
+		// Reward only the nominators in this page. Note this is not necessarily sorted.
+		for nominator in exposure_page.others.iter() {
+			let nominator_exposure_part = Perbill::from_rational(nominator.value, overview.total);
 
 			let nominator_reward: BalanceOf<T> =
 				nominator_exposure_part * validator_leftover_payout;
@@ -194,7 +313,6 @@ impl<T: Config> Pallet<T> {
 			}
 		}
 
-		debug_assert!(nominator_payout_count <= T::MaxNominatorRewardedPerValidator::get());
 		Ok(Some(T::WeightInfo::payout_stakers_alive_staked(nominator_payout_count)).into())
 	}
 
@@ -417,7 +535,7 @@ impl<T: Config> Pallet<T> {
 
 	/// Potentially plan a new era.
 	///
-	/// Get election result from `T::ElectionProvider`.
+	/// Get election result from `T::ElectionProvider`, page by page.
 	/// In case election result has more than [`MinimumValidatorCount`] validator trigger a new era.
 	///
 	/// In case a new era is planned, the new validator set is returned.
@@ -425,20 +543,21 @@ impl<T: Config> Pallet<T> {
 		start_session_index: SessionIndex,
 		is_genesis: bool,
 	) -> Option<Vec<T::AccountId>> {
-		let election_result = if is_genesis {
-			T::GenesisElectionProvider::elect().map_err(|e| {
+		// the voter snapshot is no longer needed, even if not all of its pages have been taken.
+		T::SortedListProvider::unlock();
+		let exposures = if is_genesis {
+			T::GenesisElectionProvider::elect().map(Self::collect_exposures).map_err(|e| {
 				log!(warn, "genesis election provider failed due to {:?}", e);
 				Self::deposit_event(Event::StakingElectionFailed);
 			})
 		} else {
-			T::ElectionProvider::elect().map_err(|e| {
+			Self::elect_paged_exposures().map_err(|e| {
 				log!(warn, "election provider failed due to {:?}", e);
 				Self::deposit_event(Event::StakingElectionFailed);
 			})
 		}
 		.ok()?;
 
-		let exposures = Self::collect_exposures(election_result);
 		if (exposures.len() as u32) < Self::minimum_validator_count().max(1) {
 			// Session will panic if we ever return an empty validator set, thus max(1) ^^.
 			match CurrentEra::<T>::get() {
@@ -482,15 +601,7 @@ impl<T: Config> Pallet<T> {
 		let mut total_stake: BalanceOf<T> = Zero::zero();
 		exposures.into_iter().for_each(|(stash, exposure)| {
 			total_stake = total_stake.saturating_add(exposure.total);
-			<ErasStakers<T>>::insert(new_planned_era, &stash, &exposure);
-
-			let mut exposure_clipped = exposure;
-			let clipped_max_len = T::MaxNominatorRewardedPerValidator::get() as usize;
-			if exposure_clipped.others.len() > clipped_max_len {
-				exposure_clipped.others.sort_by(|a, b| a.value.cmp(&b.value).reverse());
-				exposure_clipped.others.truncate(clipped_max_len);
-			}
-			<ErasStakersClipped<T>>::insert(&new_planned_era, &stash, exposure_clipped);
+			Self::store_paged_exposure(new_planned_era, &stash, exposure);
 		});
 
 		// Insert current era staking information
@@ -550,6 +661,36 @@ impl<T: Config> Pallet<T> {
 			.collect::<Vec<(T::AccountId, Exposure<_, _>)>>()
 	}
 
+	/// Elect the result of `T::ElectionProvider` page by page, and collect it into [`Exposure`]s.
+	///
+	/// A validator that is backed in more than one page has its exposure merged across all of
+	/// them. Validators are kept in the order in which they are first elected.
+	fn elect_paged_exposures() -> Result<
+		Vec<(T::AccountId, Exposure<T::AccountId, BalanceOf<T>>)>,
+		<T::ElectionProvider as ElectionProvider>::Error,
+	> {
+		let mut exposures: Vec<(T::AccountId, Exposure<_, _>)> = Vec::new();
+		let mut index_of: BTreeMap<T::AccountId, usize> = BTreeMap::new();
+		for page in 0..T::ElectionProvider::pages() {
+			let supports = T::ElectionProvider::elect_paged(page)?;
+			for (validator, exposure) in Self::collect_exposures(supports) {
+				match index_of.get(&validator) {
+					Some(&index) => {
+						let merged = &mut exposures[index].1;
+						merged.total = merged.total.saturating_add(exposure.total);
+						merged.own = merged.own.saturating_add(exposure.own);
+						merged.others.extend(exposure.others);
+					},
+					None => {
+						index_of.insert(validator.clone(), exposures.len());
+						exposures.push((validator, exposure));
+					},
+				}
+			}
+		}
+		Ok(exposures)
+	}
+
 	/// Remove all associated data of a stash account from the staking system.
 	///
 	/// Assumes storage is upgraded before calling.
@@ -574,10 +715,27 @@ impl<T: Config> Pallet<T> {
 		Ok(())
 	}
 
+	/// Store the exposure of `validator` in `era` split in pages of at most
+	/// `T::MaxExposurePageSize` nominators.
+	pub(crate) fn store_paged_exposure(
+		era: EraIndex,
+		validator: &T::AccountId,
+		exposure: Exposure<T::AccountId, BalanceOf<T>>,
+	) {
+		let (overview, exposure_pages) = exposure.into_pages(T::MaxExposurePageSize::get());
+		<ErasStakersOverview<T>>::insert(era, validator, overview);
+		exposure_pages.into_iter().enumerate().for_each(|(page, exposure_page)| {
+			<ErasStakersPaged<T>>::insert((era, validator, page as Page), exposure_page);
+		});
+	}
+
 	/// Clear all era information for given era.
 	pub(crate) fn clear_era_information(era_index: EraIndex) {
 		<ErasStakers<T>>::remove_prefix(era_index, None);
 		<ErasStakersClipped<T>>::remove_prefix(era_index, None);
+		<ErasStakersOverview<T>>::remove_prefix(era_index, None);
+		<ErasStakersPaged<T>>::remove_prefix((era_index,), None);
+		<ClaimedRewards<T>>::remove_prefix(era_index, None);
 		<ErasValidatorPrefs<T>>::remove_prefix(era_index, None);
 		<ErasValidatorReward<T>>::remove(era_index);
 		<ErasRewardPoints<T>>::remove(era_index);
@@ -639,7 +797,7 @@ impl<T: Config> Pallet<T> {
 		controller: T::AccountId,
 		exposure: Exposure<T::AccountId, BalanceOf<T>>,
 	) {
-		<ErasStakers<T>>::insert(&current_era, &controller, &exposure);
+		Self::store_paged_exposure(current_era, &controller, exposure);
 	}
 
 	#[cfg(feature = "runtime-benchmarks")]
@@ -708,31 +866,9 @@ impl<T: Config> Pallet<T> {
 				None => break,
 			};
 
-			if let Some(Nominations { submitted_in, mut targets, suppressed: _ }) =
-				<Nominators<T>>::get(&nominator)
-			{
-				log!(
-					trace,
-					"fetched nominator {:?} with weight {:?}",
-					nominator,
-					weight_of(&nominator)
-				);
-				targets.retain(|stash| {
-					slashing_spans
-						.get(stash)
-						.map_or(true, |spans| submitted_in >= spans.last_nonzero_slash())
-				});
-				if !targets.len().is_zero() {
-					all_voters.push((nominator.clone(), weight_of(&nominator), targets));
-					nominators_taken.saturating_inc();
-				}
-			} else {
-				// this can only happen if: 1. there a pretty bad bug in the bags-list (or whatever
-				// is the sorted list) logic and the state of the two pallets is no longer
-				// compatible, or because the nominators is not decodable since they have more
-				// nomination than `T::MaxNominations`. This can rarely happen, and is not really an
-				// emergency or bug if it does.
-				log!(warn, "DEFENSIVE: invalid item in `SortedListProvider`: {:?}, this nominator probably has too many nominations now", nominator)
+			if let Some(voter) = Self::nominator_voter(&nominator, &slashing_spans, &weight_of) {
+				all_voters.push(voter);
+				nominators_taken.saturating_inc();
 			}
 		}
 
@@ -755,6 +891,172 @@ impl<T: Config> Pallet<T> {
 		all_voters
 	}
 
+	/// Get a single page of the voters that are eligible for the npos election.
+	///
+	/// Same as [`Self::get_npos_voters`], except that the voters are handed out over multiple
+	/// calls: each page continues from where the previous one ended, as tracked by
+	/// [`VoterSnapshotStatus`]. Page `0` always starts from scratch. `maybe_max_len` caps the size
+	/// of each individual page.
+	///
+	/// This function is self-weighing as [`DispatchClass::Mandatory`].
+	pub fn get_npos_voters_paged(
+		maybe_max_len: Option<usize>,
+		page: PageIndex,
+	) -> Vec<VoterOf<Self>> {
+		if page.is_zero() {
+			VoterSnapshotStatus::<T>::kill();
+			// keep the order of the nominators fixed until all pages have been generated, such that
+			// no nominator is skipped or visited twice.
+			T::SortedListProvider::lock();
+		}
+		let status = VoterSnapshotStatus::<T>::get();
+
+		let max_allowed_len = {
+			let nominator_count = Nominators::<T>::count() as usize;
+			let validator_count = Validators::<T>::count() as usize;
+			let all_voter_count = validator_count.saturating_add(nominator_count);
+			maybe_max_len.unwrap_or(all_voter_count).min(all_voter_count)
+		};
+		let mut page_voters = Vec::<_>::with_capacity(max_allowed_len);
+
+		// first, continue with the validators, if there are any left.
+		let validators_iter: Box<dyn Iterator<Item = T::AccountId>> = match status {
+			SnapshotStatus::Waiting => Box::new(Validators::<T>::iter().map(|(v, _)| v)),
+			SnapshotStatus::Validators(ref last) => Box::new(
+				Validators::<T>::iter_from(Validators::<T>::hashed_key_for(last)).map(|(v, _)| v),
+			),
+			SnapshotStatus::Nominators(_) | SnapshotStatus::Consumed =>
+				Box::new(sp_std::iter::empty()),
+		};
+
+		let mut validators_taken = 0u32;
+		let mut last_validator = None;
+		for validator in validators_iter.take(max_allowed_len) {
+			let self_vote = (
+				validator.clone(),
+				Self::weight_of(&validator),
+				vec![validator.clone()]
+					.try_into()
+					.expect("`MaxVotesPerVoter` must be greater than or equal to 1"),
+			);
+			page_voters.push(self_vote);
+			validators_taken.saturating_inc();
+			last_validator = Some(validator);
+		}
+
+		// .. then fill the rest of the page with nominators.
+		let nominators_quota = (max_allowed_len as u32).saturating_sub(validators_taken);
+		let slashing_spans = <SlashingSpans<T>>::iter().collect::<BTreeMap<_, _>>();
+		let weight_of = Self::weight_of_fn();
+
+		let mut nominators_iter: Box<dyn Iterator<Item = T::AccountId>> = match status {
+			SnapshotStatus::Waiting | SnapshotStatus::Validators(_) =>
+				T::SortedListProvider::iter(),
+			SnapshotStatus::Nominators(ref next) => match T::SortedListProvider::iter_from(next) {
+				Ok(rest) => Box::new(sp_std::iter::once(next.clone()).chain(rest)),
+				Err(_) => {
+					// `do_remove_nominator` moves the cursor forward, so this should never happen.
+					log!(
+						warn,
+						"next nominator of the voter snapshot {:?} is not in the list",
+						next
+					);
+					Box::new(sp_std::iter::empty())
+				},
+			},
+			SnapshotStatus::Consumed => Box::new(sp_std::iter::empty()),
+		};
+
+		let mut nominators_taken = 0u32;
+		let mut nominators_seen = 0u32;
+		let mut nominators_exhausted = false;
+		while nominators_taken < nominators_quota && nominators_seen < nominators_quota * 2 {
+			let nominator = match nominators_iter.next() {
+				Some(nominator) => {
+					nominators_seen.saturating_inc();
+					nominator
+				},
+				None => {
+					nominators_exhausted = true;
+					break
+				},
+			};
+
+			if let Some(voter) = Self::nominator_voter(&nominator, &slashing_spans, &weight_of) {
+				page_voters.push(voter);
+				nominators_taken.saturating_inc();
+			}
+		}
+
+		let new_status = if nominators_exhausted {
+			SnapshotStatus::Consumed
+		} else if !nominators_seen.is_zero() {
+			// remember the next nominator to visit, rather than the last visited one, such that
+			// the cursor can be moved forward if it is removed before the next page.
+			nominators_iter
+				.next()
+				.map_or(SnapshotStatus::Consumed, SnapshotStatus::Nominators)
+		} else if let Some(validator) = last_validator {
+			SnapshotStatus::Validators(validator)
+		} else {
+			status
+		};
+		if new_status == SnapshotStatus::Consumed {
+			T::SortedListProvider::unlock();
+		}
+		VoterSnapshotStatus::<T>::put(new_status);
+
+		Self::register_weight(T::WeightInfo::get_npos_voters(
+			validators_taken,
+			nominators_taken,
+			slashing_spans.len() as u32,
+		));
+		Self::register_weight(T::DbWeight::get().reads_writes(1, 1));
+
+		log!(
+			info,
+			"generated page {} of npos voters, with {} validators and {} nominators",
+			page,
+			validators_taken,
+			nominators_taken
+		);
+		page_voters
+	}
+
+	/// Convert the nominations of `nominator` into a voter, dropping all the nominations that have
+	/// been submitted before the last non-zero slash of their target.
+	///
+	/// Returns `None` if `nominator` has no nominations left, or they cannot be decoded.
+	fn nominator_voter(
+		nominator: &T::AccountId,
+		slashing_spans: &BTreeMap<T::AccountId, slashing::SlashingSpans>,
+		weight_of: &impl Fn(&T::AccountId) -> VoteWeight,
+	) -> Option<VoterOf<Self>> {
+		if let Some(Nominations { submitted_in, mut targets, suppressed: _ }) =
+			<Nominators<T>>::get(nominator)
+		{
+			log!(trace, "fetched nominator {:?} with weight {:?}", nominator, weight_of(nominator));
+			targets.retain(|stash| {
+				slashing_spans
+					.get(stash)
+					.map_or(true, |spans| submitted_in >= spans.last_nonzero_slash())
+			});
+			if !targets.len().is_zero() {
+				Some((nominator.clone(), weight_of(nominator), targets))
+			} else {
+				None
+			}
+		} else {
+			// this can only happen if: 1. there a pretty bad bug in the bags-list (or whatever
+			// is the sorted list) logic and the state of the two pallets is no longer
+			// compatible, or because the nominators is not decodable since they have more
+			// nomination than `T::MaxNominations`. This can rarely happen, and is not really an
+			// emergency or bug if it does.
+			log!(warn, "DEFENSIVE: invalid item in `SortedListProvider`: {:?}, this nominator probably has too many nominations now", nominator);
+			None
+		}
+	}
+
 	/// Get the targets for an upcoming npos election.
 	///
 	/// This function is self-weighing as [`DispatchClass::Mandatory`].
@@ -802,6 +1104,13 @@ impl<T: Config> Pallet<T> {
 	/// wrong.
 	pub fn do_remove_nominator(who: &T::AccountId) -> bool {
 		if Nominators::<T>::contains_key(who) {
+			// if `who` is the next nominator of the paged voter snapshot, move the cursor forward.
+			if VoterSnapshotStatus::<T>::get() == SnapshotStatus::Nominators(who.clone()) {
+				let next = T::SortedListProvider::iter_from(who).ok().and_then(|mut i| i.next());
+				VoterSnapshotStatus::<T>::put(
+					next.map_or(SnapshotStatus::Consumed, SnapshotStatus::Nominators),
+				);
+			}
 			Nominators::<T>::remove(who);
 			T::SortedListProvider::on_remove(who);
 			debug_assert_eq!(T::SortedListProvider::sanity_check(), Ok(()));
@@ -868,6 +1177,16 @@ impl<T: Config> ElectionDataProvider for Pallet<T> {
 		Ok(voters)
 	}
 
+	fn voters_paged(
+		maybe_max_len: Option<usize>,
+		page: PageIndex,
+	) -> data_provider::Result<Vec<VoterOf<Self>>> {
+		let voters = Self::get_npos_voters_paged(maybe_max_len, page);
+		debug_assert!(maybe_max_len.map_or(true, |max| voters.len() <= max));
+
+		Ok(voters)
+	}
+
 	fn targets(maybe_max_len: Option<usize>) -> data_provider::Result<Vec<T::AccountId>> {
 		let target_count = Validators::<T>::count();
 
@@ -1284,6 +1603,16 @@ impl<T: Config> SortedListProvider<T::AccountId> for UseNominatorsMap<T> {
 	fn iter() -> Box<dyn Iterator<Item = T::AccountId>> {
 		Box::new(Nominators::<T>::iter().map(|(n, _)| n))
 	}
+	fn iter_from(
+		start: &T::AccountId,
+	) -> Result<Box<dyn Iterator<Item = T::AccountId>>, Self::Error> {
+		if Nominators::<T>::contains_key(start) {
+			let start_key = Nominators::<T>::hashed_key_for(start);
+			Ok(Box::new(Nominators::<T>::iter_from(start_key).map(|(n, _)| n)))
+		} else {
+			Err(())
+		}
+	}
 	fn count() -> u32 {
 		Nominators::<T>::count()
 	}
@@ -40,9 +40,9 @@ pub use impls::*;
 
 use crate::{
 	log, slashing, weights::WeightInfo, ActiveEraInfo, BalanceOf, EraPayout, EraRewardPoints,
-	Exposure, Forcing, NegativeImbalanceOf, Nominations, PositiveImbalanceOf, Releases,
-	RewardDestination, SessionInterface, StakingLedger, UnappliedSlash, UnlockChunk,
-	ValidatorPrefs,
+	Exposure, ExposurePage, Forcing, NegativeImbalanceOf, Nominations, Page, PagedExposureMetadata,
+	PositiveImbalanceOf, Releases, RewardDestination, SessionInterface, SnapshotStatus,
+	StakingLedger, UnappliedSlash, UnlockChunk, ValidatorPrefs,
 };
 
 pub const MAX_UNLOCKING_CHUNKS: usize = 32;
@@ -140,12 +140,13 @@ pub mod pallet {
 		/// guess.
 		type NextNewSession: EstimateNextNewSession<Self::BlockNumber>;
 
-		/// The maximum number of nominators rewarded for each validator.
+		/// The maximum number of nominators in a single page of a validator's exposure.
 		///
-		/// For each validator only the `$MaxNominatorRewardedPerValidator` biggest stakers can
-		/// claim their reward. This used to limit the i/o cost for the nominator payout.
+		/// The exposure of each validator is split into pages of at most this many nominators,
+		/// and each page is paid out separately. This is used to limit the i/o cost for the
+		/// nominator payout. All nominators are rewarded, regardless of this value.
 		#[pallet::constant]
-		type MaxNominatorRewardedPerValidator: Get<u32>;
+		type MaxExposurePageSize: Get<u32>;
 
 		/// The fraction of the validator set that is safe to be offending.
 		/// After the threshold is reached a new era will be forced.
@@ -266,6 +267,14 @@ pub mod pallet {
 	#[pallet::storage]
 	pub type MaxNominatorsCount<T> = StorageValue<_, u32, OptionQuery>;
 
+	/// The progress of the paged voter snapshot, if one is being taken.
+	///
+	/// This is reset every time page `0` is requested through
+	/// [`ElectionDataProvider::voters_paged`].
+	#[pallet::storage]
+	pub(crate) type VoterSnapshotStatus<T: Config> =
+		StorageValue<_, SnapshotStatus<T::AccountId>, ValueQuery>;
+
 	/// The current era index.
 	///
 	/// This is the latest planned era, depending on how the Session pallet queues the validator
@@ -292,12 +301,14 @@ pub mod pallet {
 
 	/// Exposure of validator at era.
 	///
+	/// DEPRECATED: this is only read for eras that were planned before exposures were paged, and
+	/// is no longer written to. Use [`Pallet::eras_stakers`] to get the full exposure of any era.
+	///
 	/// This is keyed first by the era index to allow bulk deletion and then the stash account.
 	///
 	/// Is it removed after `HISTORY_DEPTH` eras.
 	/// If stakers hasn't been set or has been removed then empty exposure is returned.
 	#[pallet::storage]
-	#[pallet::getter(fn eras_stakers)]
 	pub type ErasStakers<T: Config> = StorageDoubleMap<
 		_,
 		Twox64Concat,
@@ -308,12 +319,74 @@ pub mod pallet {
 		ValueQuery,
 	>;
 
+	/// Summary of the exposure of a validator at era, for eras with paged exposures.
+	///
+	/// This holds the `total` and `own` stake of the validator, along with the number of
+	/// nominators and the number of pages they have been split into in [`ErasStakersPaged`].
+	///
+	/// This is keyed first by the era index to allow bulk deletion and then the stash account.
+	///
+	/// Is it removed after `HISTORY_DEPTH` eras.
+	#[pallet::storage]
+	#[pallet::getter(fn eras_stakers_overview)]
+	pub type ErasStakersOverview<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		EraIndex,
+		Twox64Concat,
+		T::AccountId,
+		PagedExposureMetadata<BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// Paged exposure of a validator at era.
+	///
+	/// The nominators backing a validator are split into pages of at most
+	/// `T::MaxExposurePageSize` nominators. Each page can be paid out independently with
+	/// [`Call::payout_stakers_by_page`].
+	///
+	/// This is keyed first by the era index to allow bulk deletion, then the stash account and
+	/// finally the page.
+	///
+	/// Is it removed after `HISTORY_DEPTH` eras.
+	#[pallet::storage]
+	#[pallet::getter(fn eras_stakers_paged)]
+	pub type ErasStakersPaged<T: Config> = StorageNMap<
+		_,
+		(
+			NMapKey<Twox64Concat, EraIndex>,
+			NMapKey<Twox64Concat, T::AccountId>,
+			NMapKey<Twox64Concat, Page>,
+		),
+		ExposurePage<T::AccountId, BalanceOf<T>>,
+		OptionQuery,
+	>;
+
+	/// The pages of the exposure of a validator at era for which rewards have been claimed.
+	///
+	/// This is keyed first by the era index to allow bulk deletion and then the stash account.
+	///
+	/// Is it removed after `HISTORY_DEPTH` eras.
+	#[pallet::storage]
+	#[pallet::getter(fn claimed_rewards)]
+	pub type ClaimedRewards<T: Config> = StorageDoubleMap<
+		_,
+		Twox64Concat,
+		EraIndex,
+		Twox64Concat,
+		T::AccountId,
+		Vec<Page>,
+		ValueQuery,
+	>;
+
 	/// Clipped Exposure of validator at era.
 	///
+	/// DEPRECATED: this is only read for eras that were planned before exposures were paged, and
+	/// is no longer written to. See [`ErasStakersOverview`] and [`ErasStakersPaged`].
+	///
 	/// This is similar to [`ErasStakers`] but number of nominators exposed is reduced to the
-	/// `T::MaxNominatorRewardedPerValidator` biggest stakers.
+	/// biggest stakers.
 	/// (Note: the field `total` and `own` of the exposure remains unchanged).
-	/// This is used to limit the i/o cost for the nominator payout.
 	///
 	/// This is keyed fist by the era index to allow bulk deletion and then the stash account.
 	///
@@ -665,6 +738,8 @@ pub mod pallet {
 		TooManyValidators,
 		/// Commission is too low. Must be at least `MinCommission`.
 		CommissionTooLow,
+		/// No page of the validator's exposure exists at the given index for this era.
+		InvalidPage,
 	}
 
 	#[pallet::hooks]
@@ -1308,17 +1383,21 @@ pub mod pallet {
 			Ok(())
 		}
 
-		/// Pay out all the stakers behind a single validator for a single era.
+		/// Pay out the next page of the stakers behind a validator for the given era.
 		///
-		/// - `validator_stash` is the stash account of the validator. Their nominators, up to
-		///   `T::MaxNominatorRewardedPerValidator`, will also receive their rewards.
+		/// - `validator_stash` is the stash account of the validator.
 		/// - `era` may be any era between `[current_era - history_depth; current_era]`.
 		///
 		/// The origin of this call must be _Signed_. Any account can call this function, even if
 		/// it is not one of the stakers.
 		///
+		/// The reward is paid out for the lowest page of the validator's exposure that has not
+		/// been claimed yet. Each page contains at most `T::MaxExposurePageSize` nominators, so
+		/// this call needs to be repeated for each page to pay out all the nominators. See
+		/// [`Call::payout_stakers_by_page`] to pay out a specific page.
+		///
 		/// # <weight>
-		/// - Time complexity: at most O(MaxNominatorRewardedPerValidator).
+		/// - Time complexity: at most O(MaxExposurePageSize).
 		/// - Contains a limited number of reads and writes.
 		/// -----------
 		/// N is the Number of payouts for the validator (including the validator)
@@ -1329,9 +1408,7 @@ pub mod pallet {
 		///   NOTE: weights are assuming that payouts are made to alive stash account (Staked).
 		///   Paying even a dead controller is cheaper weight-wise. We don't do any refunds here.
 		/// # </weight>
-		#[pallet::weight(T::WeightInfo::payout_stakers_alive_staked(
-			T::MaxNominatorRewardedPerValidator::get()
-		))]
+		#[pallet::weight(T::WeightInfo::payout_stakers_alive_staked(T::MaxExposurePageSize::get()))]
 		pub fn payout_stakers(
 			origin: OriginFor<T>,
 			validator_stash: T::AccountId,
@@ -1616,6 +1693,34 @@ pub mod pallet {
 			Self::chill_stash(&stash);
 			Ok(())
 		}
+
+		/// Pay out a page of the stakers behind a validator for the given era and page.
+		///
+		/// - `validator_stash` is the stash account of the validator.
+		/// - `era` may be any era between `[current_era - history_depth; current_era]`.
+		/// - `page` is the page index of nominators to pay out, with value between 0 and
+		///   `num_nominators / T::MaxExposurePageSize`.
+		///
+		/// The origin of this call must be _Signed_. Any account can call this function, even if
+		/// it is not one of the stakers.
+		///
+		/// The validator's own reward is split proportionally across the pages, so that it is
+		/// fully paid out once all pages have been claimed.
+		///
+		/// # <weight>
+		/// - Time complexity: at most O(MaxExposurePageSize).
+		/// - Contains a limited number of reads and writes.
+		/// # </weight>
+		#[pallet::weight(T::WeightInfo::payout_stakers_alive_staked(T::MaxExposurePageSize::get()))]
+		pub fn payout_stakers_by_page(
+			origin: OriginFor<T>,
+			validator_stash: T::AccountId,
+			era: EraIndex,
+			page: Page,
+		) -> DispatchResultWithPostInfo {
+			ensure_signed(origin)?;
+			Self::do_payout_stakers_by_page(validator_stash, era, page)
+		}
 	}
 }
 
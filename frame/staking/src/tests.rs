@@ -145,7 +145,7 @@ fn basic_setup_works() {
 		assert_eq!(Staking::nominators(101).unwrap().targets, vec![11, 21]);
 
 		assert_eq!(
-			Staking::eras_stakers(active_era(), 11),
+			Staking::eras_stakers(active_era(), &11),
 			Exposure {
 				total: 1125,
 				own: 1000,
@@ -153,7 +153,7 @@ fn basic_setup_works() {
 			},
 		);
 		assert_eq!(
-			Staking::eras_stakers(active_era(), 21),
+			Staking::eras_stakers(active_era(), &21),
 			Exposure {
 				total: 1375,
 				own: 1000,
@@ -438,8 +438,8 @@ fn less_than_needed_candidates_works() {
 
 			// But the exposure is updated in a simple way. No external votes exists.
 			// This is purely self-vote.
-			assert!(ErasStakers::<Test>::iter_prefix_values(active_era())
-				.all(|exposure| exposure.others.is_empty()));
+			assert!(ErasStakersOverview::<Test>::iter_prefix_values(active_era())
+				.all(|overview| overview.nominator_count == 0));
 		});
 }
 
@@ -536,9 +536,9 @@ fn nominating_and_rewards_should_work() {
 			assert_eq!(Balances::total_balance(&20), initial_balance_20 + total_payout_0 / 2);
 			initial_balance_20 = Balances::total_balance(&20);
 
-			assert_eq!(ErasStakers::<Test>::iter_prefix_values(active_era()).count(), 2);
+			assert_eq!(ErasStakersOverview::<Test>::iter_prefix_values(active_era()).count(), 2);
 			assert_eq!(
-				Staking::eras_stakers(active_era(), 11),
+				Staking::eras_stakers(active_era(), &11),
 				Exposure {
 					total: 1000 + 800,
 					own: 1000,
@@ -549,7 +549,7 @@ fn nominating_and_rewards_should_work() {
 				},
 			);
 			assert_eq!(
-				Staking::eras_stakers(active_era(), 21),
+				Staking::eras_stakers(active_era(), &21),
 				Exposure {
 					total: 1000 + 1200,
 					own: 1000,
@@ -609,7 +609,7 @@ fn nominators_also_get_slashed_pro_rata() {
 	ExtBuilder::default().build_and_execute(|| {
 		mock::start_active_era(1);
 		let slash_percent = Perbill::from_percent(5);
-		let initial_exposure = Staking::eras_stakers(active_era(), 11);
+		let initial_exposure = Staking::eras_stakers(active_era(), &11);
 		// 101 is a nominator for 11
 		assert_eq!(initial_exposure.others.first().unwrap().who, 101);
 
@@ -864,7 +864,7 @@ fn cannot_transfer_staked_balance() {
 		// Confirm account 11 has some free balance
 		assert_eq!(Balances::free_balance(11), 1000);
 		// Confirm account 11 (via controller 10) is totally staked
-		assert_eq!(Staking::eras_stakers(active_era(), 11).total, 1000);
+		assert_eq!(Staking::eras_stakers(active_era(), &11).total, 1000);
 		// Confirm account 11 cannot transfer as a result
 		assert_noop!(
 			Balances::transfer(Origin::signed(11), 20, 1),
@@ -889,7 +889,7 @@ fn cannot_transfer_staked_balance_2() {
 		// Confirm account 21 has some free balance
 		assert_eq!(Balances::free_balance(21), 2000);
 		// Confirm account 21 (via controller 20) is totally staked
-		assert_eq!(Staking::eras_stakers(active_era(), 21).total, 1000);
+		assert_eq!(Staking::eras_stakers(active_era(), &21).total, 1000);
 		// Confirm account 21 can transfer at most 1000
 		assert_noop!(
 			Balances::transfer(Origin::signed(21), 20, 1001),
@@ -908,7 +908,7 @@ fn cannot_reserve_staked_balance() {
 		// Confirm account 11 has some free balance
 		assert_eq!(Balances::free_balance(11), 1000);
 		// Confirm account 11 (via controller 10) is totally staked
-		assert_eq!(Staking::eras_stakers(active_era(), 11).own, 1000);
+		assert_eq!(Staking::eras_stakers(active_era(), &11).own, 1000);
 		// Confirm account 11 cannot reserve as a result
 		assert_noop!(Balances::reserve(&11, 1), BalancesError::<Test, _>::LiquidityRestrictions);
 
@@ -960,9 +960,10 @@ fn reward_destination_works() {
 				total: 1000 + total_payout_0,
 				active: 1000 + total_payout_0,
 				unlocking: vec![],
-				claimed_rewards: vec![0],
+				claimed_rewards: vec![],
 			})
 		);
+		assert_eq!(ClaimedRewards::<Test>::get(0, &11), vec![0]);
 
 		// Change RewardDestination to Stash
 		<Payee<Test>>::insert(&11, RewardDestination::Stash);
@@ -988,9 +989,10 @@ fn reward_destination_works() {
 				total: 1000 + total_payout_0,
 				active: 1000 + total_payout_0,
 				unlocking: vec![],
-				claimed_rewards: vec![0, 1],
+				claimed_rewards: vec![],
 			})
 		);
+		assert_eq!(ClaimedRewards::<Test>::get(1, &11), vec![0]);
 
 		// Change RewardDestination to Controller
 		<Payee<Test>>::insert(&11, RewardDestination::Controller);
@@ -1017,9 +1019,10 @@ fn reward_destination_works() {
 				total: 1000 + total_payout_0,
 				active: 1000 + total_payout_0,
 				unlocking: vec![],
-				claimed_rewards: vec![0, 1, 2],
+				claimed_rewards: vec![],
 			})
 		);
+		assert_eq!(ClaimedRewards::<Test>::get(2, &11), vec![0]);
 		// Check that amount in staked account is NOT increased.
 		assert_eq!(Balances::free_balance(11), recorded_stash_balance);
 	});
@@ -1049,7 +1052,7 @@ fn validator_payment_prefs_work() {
 
 		// Compute total payout now for whole duration as other parameter won't change
 		let total_payout_1 = current_total_payout_for_duration(reward_time_per_era());
-		let exposure_1 = Staking::eras_stakers(active_era(), 11);
+		let exposure_1 = Staking::eras_stakers(active_era(), &11);
 		Pallet::<Test>::reward_by_ids(vec![(11, 1)]);
 
 		mock::start_active_era(2);
@@ -1155,7 +1158,7 @@ fn bond_extra_and_withdraw_unbonded_works() {
 			})
 		);
 		assert_eq!(
-			Staking::eras_stakers(active_era(), 11),
+			Staking::eras_stakers(active_era(), &11),
 			Exposure { total: 1000, own: 1000, others: vec![] }
 		);
 
@@ -1174,7 +1177,7 @@ fn bond_extra_and_withdraw_unbonded_works() {
 		);
 		// Exposure is a snapshot! only updated after the next era update.
 		assert_ne!(
-			Staking::eras_stakers(active_era(), 11),
+			Staking::eras_stakers(active_era(), &11),
 			Exposure { total: 1000 + 100, own: 1000 + 100, others: vec![] }
 		);
 
@@ -1195,7 +1198,7 @@ fn bond_extra_and_withdraw_unbonded_works() {
 		);
 		// Exposure is now updated.
 		assert_eq!(
-			Staking::eras_stakers(active_era(), 11),
+			Staking::eras_stakers(active_era(), &11),
 			Exposure { total: 1000 + 100, own: 1000 + 100, others: vec![] }
 		);
 
@@ -1589,15 +1592,15 @@ fn reward_to_stake_works() {
 			// Confirm account 10 and 20 are validators
 			assert!(<Validators<Test>>::contains_key(&11) && <Validators<Test>>::contains_key(&21));
 
-			assert_eq!(Staking::eras_stakers(active_era(), 11).total, 1000);
-			assert_eq!(Staking::eras_stakers(active_era(), 21).total, 2000);
+			assert_eq!(Staking::eras_stakers(active_era(), &11).total, 1000);
+			assert_eq!(Staking::eras_stakers(active_era(), &21).total, 2000);
 
 			// Give the man some money.
 			let _ = Balances::make_free_balance_be(&10, 1000);
 			let _ = Balances::make_free_balance_be(&20, 1000);
 
 			// Bypass logic and change current exposure
-			ErasStakers::<Test>::insert(0, 21, Exposure { total: 69, own: 69, others: vec![] });
+			Staking::store_paged_exposure(0, &21, Exposure { total: 69, own: 69, others: vec![] });
 			<Ledger<Test>>::insert(
 				&20,
 				StakingLedger {
@@ -1618,8 +1621,8 @@ fn reward_to_stake_works() {
 			mock::start_active_era(1);
 			mock::make_all_reward_payment(0);
 
-			assert_eq!(Staking::eras_stakers(active_era(), 11).total, 1000);
-			assert_eq!(Staking::eras_stakers(active_era(), 21).total, 69);
+			assert_eq!(Staking::eras_stakers(active_era(), &11).total, 1000);
+			assert_eq!(Staking::eras_stakers(active_era(), &21).total, 69);
 
 			let _11_balance = Balances::free_balance(&11);
 			assert_eq!(_11_balance, 1000 + total_payout_0 / 2);
@@ -1628,8 +1631,8 @@ fn reward_to_stake_works() {
 			mock::start_active_era(2);
 
 			// -- new infos
-			assert_eq!(Staking::eras_stakers(active_era(), 11).total, 1000 + total_payout_0 / 2);
-			assert_eq!(Staking::eras_stakers(active_era(), 21).total, 69 + total_payout_0 / 2);
+			assert_eq!(Staking::eras_stakers(active_era(), &11).total, 1000 + total_payout_0 / 2);
+			assert_eq!(Staking::eras_stakers(active_era(), &21).total, 69 + total_payout_0 / 2);
 		});
 }
 
@@ -1751,8 +1754,8 @@ fn wrong_vote_is_moot() {
 			assert_eq_uvec!(validator_controllers(), vec![20, 10]);
 
 			// our new voter is taken into account
-			assert!(Staking::eras_stakers(active_era(), 11).others.iter().any(|i| i.who == 61));
-			assert!(Staking::eras_stakers(active_era(), 21).others.iter().any(|i| i.who == 61));
+			assert!(Staking::eras_stakers(active_era(), &11).others.iter().any(|i| i.who == 61));
+			assert!(Staking::eras_stakers(active_era(), &21).others.iter().any(|i| i.who == 61));
 		});
 }
 
@@ -1838,7 +1841,7 @@ fn bond_with_little_staked_value_bounded() {
 
 			// 2 is elected.
 			assert_eq_uvec!(validator_controllers(), vec![20, 10, 2]);
-			assert_eq!(Staking::eras_stakers(active_era(), 2).total, 0);
+			assert_eq!(Staking::eras_stakers(active_era(), &2).total, 0);
 
 			// Old ones are rewarded.
 			assert_eq_error_rate!(
@@ -1856,7 +1859,7 @@ fn bond_with_little_staked_value_bounded() {
 			mock::make_all_reward_payment(1);
 
 			assert_eq_uvec!(validator_controllers(), vec![20, 10, 2]);
-			assert_eq!(Staking::eras_stakers(active_era(), 2).total, 0);
+			assert_eq!(Staking::eras_stakers(active_era(), &2).total, 0);
 
 			// 2 is now rewarded.
 			assert_eq_error_rate!(
@@ -1991,8 +1994,8 @@ fn phragmen_should_not_overflow() {
 		assert_eq_uvec!(validator_controllers(), vec![4, 2]);
 
 		// We can safely convert back to values within [u64, u128].
-		assert!(Staking::eras_stakers(active_era(), 3).total > Votes::max_value() as Balance);
-		assert!(Staking::eras_stakers(active_era(), 5).total > Votes::max_value() as Balance);
+		assert!(Staking::eras_stakers(active_era(), &3).total > Votes::max_value() as Balance);
+		assert!(Staking::eras_stakers(active_era(), &5).total > Votes::max_value() as Balance);
 	})
 }
 
@@ -2016,7 +2019,7 @@ fn reward_validator_slashing_validator_does_not_overflow() {
 
 		// Check reward
 		ErasRewardPoints::<Test>::insert(0, reward);
-		ErasStakers::<Test>::insert(0, 11, &exposure);
+		Staking::store_paged_exposure(0, &11, exposure.clone());
 		ErasStakersClipped::<Test>::insert(0, 11, exposure);
 		ErasValidatorReward::<Test>::insert(0, stake);
 		assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, 0));
@@ -2030,9 +2033,9 @@ fn reward_validator_slashing_validator_does_not_overflow() {
 		// it is 0.
 		Staking::bond(Origin::signed(2), 20000, stake - 1, RewardDestination::default()).unwrap();
 		// Override exposure of 11
-		ErasStakers::<Test>::insert(
+		Staking::store_paged_exposure(
 			0,
-			11,
+			&11,
 			Exposure {
 				total: stake,
 				own: 1,
@@ -2043,7 +2046,7 @@ fn reward_validator_slashing_validator_does_not_overflow() {
 		// Check slashing
 		on_offence_now(
 			&[OffenceDetails {
-				offender: (11, Staking::eras_stakers(active_era(), 11)),
+				offender: (11, Staking::eras_stakers(active_era(), &11)),
 				reporters: vec![],
 			}],
 			&[Perbill::from_percent(100)],
@@ -2147,7 +2150,7 @@ fn offence_forces_new_era() {
 	ExtBuilder::default().build_and_execute(|| {
 		on_offence_now(
 			&[OffenceDetails {
-				offender: (11, Staking::eras_stakers(active_era(), 11)),
+				offender: (11, Staking::eras_stakers(active_era(), &11)),
 				reporters: vec![],
 			}],
 			&[Perbill::from_percent(5)],
@@ -2165,7 +2168,7 @@ fn offence_ensures_new_era_without_clobbering() {
 
 		on_offence_now(
 			&[OffenceDetails {
-				offender: (11, Staking::eras_stakers(active_era(), 11)),
+				offender: (11, Staking::eras_stakers(active_era(), &11)),
 				reporters: vec![],
 			}],
 			&[Perbill::from_percent(5)],
@@ -2183,7 +2186,7 @@ fn offence_deselects_validator_even_when_slash_is_zero() {
 
 		on_offence_now(
 			&[OffenceDetails {
-				offender: (11, Staking::eras_stakers(active_era(), 11)),
+				offender: (11, Staking::eras_stakers(active_era(), &11)),
 				reporters: vec![],
 			}],
 			&[Perbill::from_percent(0)],
@@ -2204,7 +2207,7 @@ fn slashing_performed_according_exposure() {
 	// This test checks that slashing is performed according the exposure (or more precisely,
 	// historical exposure), not the current balance.
 	ExtBuilder::default().build_and_execute(|| {
-		assert_eq!(Staking::eras_stakers(active_era(), 11).own, 1000);
+		assert_eq!(Staking::eras_stakers(active_era(), &11).own, 1000);
 
 		// Handle an offence with a historical exposure.
 		on_offence_now(
@@ -2230,7 +2233,7 @@ fn slash_in_old_span_does_not_deselect() {
 
 		on_offence_now(
 			&[OffenceDetails {
-				offender: (11, Staking::eras_stakers(active_era(), 11)),
+				offender: (11, Staking::eras_stakers(active_era(), &11)),
 				reporters: vec![],
 			}],
 			&[Perbill::from_percent(0)],
@@ -2253,7 +2256,7 @@ fn slash_in_old_span_does_not_deselect() {
 
 		on_offence_in_era(
 			&[OffenceDetails {
-				offender: (11, Staking::eras_stakers(active_era(), 11)),
+				offender: (11, Staking::eras_stakers(active_era(), &11)),
 				reporters: vec![],
 			}],
 			&[Perbill::from_percent(0)],
@@ -2269,7 +2272,7 @@ fn slash_in_old_span_does_not_deselect() {
 
 		on_offence_in_era(
 			&[OffenceDetails {
-				offender: (11, Staking::eras_stakers(active_era(), 11)),
+				offender: (11, Staking::eras_stakers(active_era(), &11)),
 				reporters: vec![],
 			}],
 			// NOTE: A 100% slash here would clean up the account, causing de-registration.
@@ -2296,11 +2299,11 @@ fn reporters_receive_their_slice() {
 		// The reporters' reward is calculated from the total exposure.
 		let initial_balance = 1125;
 
-		assert_eq!(Staking::eras_stakers(active_era(), 11).total, initial_balance);
+		assert_eq!(Staking::eras_stakers(active_era(), &11).total, initial_balance);
 
 		on_offence_now(
 			&[OffenceDetails {
-				offender: (11, Staking::eras_stakers(active_era(), 11)),
+				offender: (11, Staking::eras_stakers(active_era(), &11)),
 				reporters: vec![1, 2],
 			}],
 			&[Perbill::from_percent(50)],
@@ -2323,11 +2326,11 @@ fn subsequent_reports_in_same_span_pay_out_less() {
 		// The reporters' reward is calculated from the total exposure.
 		let initial_balance = 1125;
 
-		assert_eq!(Staking::eras_stakers(active_era(), 11).total, initial_balance);
+		assert_eq!(Staking::eras_stakers(active_era(), &11).total, initial_balance);
 
 		on_offence_now(
 			&[OffenceDetails {
-				offender: (11, Staking::eras_stakers(active_era(), 11)),
+				offender: (11, Staking::eras_stakers(active_era(), &11)),
 				reporters: vec![1],
 			}],
 			&[Perbill::from_percent(20)],
@@ -2340,7 +2343,7 @@ fn subsequent_reports_in_same_span_pay_out_less() {
 
 		on_offence_now(
 			&[OffenceDetails {
-				offender: (11, Staking::eras_stakers(active_era(), 11)),
+				offender: (11, Staking::eras_stakers(active_era(), &11)),
 				reporters: vec![1],
 			}],
 			&[Perbill::from_percent(50)],
@@ -2362,7 +2365,7 @@ fn invulnerables_are_not_slashed() {
 		assert_eq!(Balances::free_balance(11), 1000);
 		assert_eq!(Balances::free_balance(21), 2000);
 
-		let exposure = Staking::eras_stakers(active_era(), 21);
+		let exposure = Staking::eras_stakers(active_era(), &21);
 		let initial_balance = Staking::slashable_balance_of(&21);
 
 		let nominator_balances: Vec<_> =
@@ -2371,11 +2374,11 @@ fn invulnerables_are_not_slashed() {
 		on_offence_now(
 			&[
 				OffenceDetails {
-					offender: (11, Staking::eras_stakers(active_era(), 11)),
+					offender: (11, Staking::eras_stakers(active_era(), &11)),
 					reporters: vec![],
 				},
 				OffenceDetails {
-					offender: (21, Staking::eras_stakers(active_era(), 21)),
+					offender: (21, Staking::eras_stakers(active_era(), &21)),
 					reporters: vec![],
 				},
 			],
@@ -2405,7 +2408,7 @@ fn dont_slash_if_fraction_is_zero() {
 
 		on_offence_now(
 			&[OffenceDetails {
-				offender: (11, Staking::eras_stakers(active_era(), 11)),
+				offender: (11, Staking::eras_stakers(active_era(), &11)),
 				reporters: vec![],
 			}],
 			&[Perbill::from_percent(0)],
@@ -2426,7 +2429,7 @@ fn only_slash_for_max_in_era() {
 
 		on_offence_now(
 			&[OffenceDetails {
-				offender: (11, Staking::eras_stakers(active_era(), 11)),
+				offender: (11, Staking::eras_stakers(active_era(), &11)),
 				reporters: vec![],
 			}],
 			&[Perbill::from_percent(50)],
@@ -2438,7 +2441,7 @@ fn only_slash_for_max_in_era() {
 
 		on_offence_now(
 			&[OffenceDetails {
-				offender: (11, Staking::eras_stakers(active_era(), 11)),
+				offender: (11, Staking::eras_stakers(active_era(), &11)),
 				reporters: vec![],
 			}],
 			&[Perbill::from_percent(25)],
@@ -2449,7 +2452,7 @@ fn only_slash_for_max_in_era() {
 
 		on_offence_now(
 			&[OffenceDetails {
-				offender: (11, Staking::eras_stakers(active_era(), 11)),
+				offender: (11, Staking::eras_stakers(active_era(), &11)),
 				reporters: vec![],
 			}],
 			&[Perbill::from_percent(60)],
@@ -2471,7 +2474,7 @@ fn garbage_collection_after_slashing() {
 
 			on_offence_now(
 				&[OffenceDetails {
-					offender: (11, Staking::eras_stakers(active_era(), 11)),
+					offender: (11, Staking::eras_stakers(active_era(), &11)),
 					reporters: vec![],
 				}],
 				&[Perbill::from_percent(10)],
@@ -2483,7 +2486,7 @@ fn garbage_collection_after_slashing() {
 
 			on_offence_now(
 				&[OffenceDetails {
-					offender: (11, Staking::eras_stakers(active_era(), 11)),
+					offender: (11, Staking::eras_stakers(active_era(), &11)),
 					reporters: vec![],
 				}],
 				&[Perbill::from_percent(100)],
@@ -2520,12 +2523,15 @@ fn garbage_collection_on_window_pruning() {
 		assert_eq!(Balances::free_balance(11), 1000);
 		let now = active_era();
 
-		let exposure = Staking::eras_stakers(now, 11);
+		let exposure = Staking::eras_stakers(now, &11);
 		assert_eq!(Balances::free_balance(101), 2000);
 		let nominated_value = exposure.others.iter().find(|o| o.who == 101).unwrap().value;
 
 		on_offence_now(
-			&[OffenceDetails { offender: (11, Staking::eras_stakers(now, 11)), reporters: vec![] }],
+			&[OffenceDetails {
+				offender: (11, Staking::eras_stakers(now, &11)),
+				reporters: vec![],
+			}],
 			&[Perbill::from_percent(10)],
 		);
 
@@ -2560,14 +2566,14 @@ fn slashing_nominators_by_span_max() {
 		assert_eq!(Balances::free_balance(101), 2000);
 		assert_eq!(Staking::slashable_balance_of(&21), 1000);
 
-		let exposure_11 = Staking::eras_stakers(active_era(), 11);
-		let exposure_21 = Staking::eras_stakers(active_era(), 21);
+		let exposure_11 = Staking::eras_stakers(active_era(), &11);
+		let exposure_21 = Staking::eras_stakers(active_era(), &21);
 		let nominated_value_11 = exposure_11.others.iter().find(|o| o.who == 101).unwrap().value;
 		let nominated_value_21 = exposure_21.others.iter().find(|o| o.who == 101).unwrap().value;
 
 		on_offence_in_era(
 			&[OffenceDetails {
-				offender: (11, Staking::eras_stakers(active_era(), 11)),
+				offender: (11, Staking::eras_stakers(active_era(), &11)),
 				reporters: vec![],
 			}],
 			&[Perbill::from_percent(10)],
@@ -2594,7 +2600,7 @@ fn slashing_nominators_by_span_max() {
 		// second slash: higher era, higher value, same span.
 		on_offence_in_era(
 			&[OffenceDetails {
-				offender: (21, Staking::eras_stakers(active_era(), 21)),
+				offender: (21, Staking::eras_stakers(active_era(), &21)),
 				reporters: vec![],
 			}],
 			&[Perbill::from_percent(30)],
@@ -2616,7 +2622,7 @@ fn slashing_nominators_by_span_max() {
 		// in-era value, but lower slash value than slash 2.
 		on_offence_in_era(
 			&[OffenceDetails {
-				offender: (11, Staking::eras_stakers(active_era(), 11)),
+				offender: (11, Staking::eras_stakers(active_era(), &11)),
 				reporters: vec![],
 			}],
 			&[Perbill::from_percent(20)],
@@ -2651,7 +2657,7 @@ fn slashes_are_summed_across_spans() {
 
 		on_offence_now(
 			&[OffenceDetails {
-				offender: (21, Staking::eras_stakers(active_era(), 21)),
+				offender: (21, Staking::eras_stakers(active_era(), &21)),
 				reporters: vec![],
 			}],
 			&[Perbill::from_percent(10)],
@@ -2674,7 +2680,7 @@ fn slashes_are_summed_across_spans() {
 
 		on_offence_now(
 			&[OffenceDetails {
-				offender: (21, Staking::eras_stakers(active_era(), 21)),
+				offender: (21, Staking::eras_stakers(active_era(), &21)),
 				reporters: vec![],
 			}],
 			&[Perbill::from_percent(10)],
@@ -2698,13 +2704,13 @@ fn deferred_slashes_are_deferred() {
 
 		assert_eq!(Balances::free_balance(11), 1000);
 
-		let exposure = Staking::eras_stakers(active_era(), 11);
+		let exposure = Staking::eras_stakers(active_era(), &11);
 		assert_eq!(Balances::free_balance(101), 2000);
 		let nominated_value = exposure.others.iter().find(|o| o.who == 101).unwrap().value;
 
 		on_offence_now(
 			&[OffenceDetails {
-				offender: (11, Staking::eras_stakers(active_era(), 11)),
+				offender: (11, Staking::eras_stakers(active_era(), &11)),
 				reporters: vec![],
 			}],
 			&[Perbill::from_percent(10)],
@@ -2739,7 +2745,7 @@ fn remove_deferred() {
 
 		assert_eq!(Balances::free_balance(11), 1000);
 
-		let exposure = Staking::eras_stakers(active_era(), 11);
+		let exposure = Staking::eras_stakers(active_era(), &11);
 		assert_eq!(Balances::free_balance(101), 2000);
 		let nominated_value = exposure.others.iter().find(|o| o.who == 101).unwrap().value;
 
@@ -2806,7 +2812,7 @@ fn remove_multi_deferred() {
 
 		assert_eq!(Balances::free_balance(11), 1000);
 
-		let exposure = Staking::eras_stakers(active_era(), 11);
+		let exposure = Staking::eras_stakers(active_era(), &11);
 		assert_eq!(Balances::free_balance(101), 2000);
 
 		on_offence_now(
@@ -2816,7 +2822,7 @@ fn remove_multi_deferred() {
 
 		on_offence_now(
 			&[OffenceDetails {
-				offender: (21, Staking::eras_stakers(active_era(), 21)),
+				offender: (21, Staking::eras_stakers(active_era(), &21)),
 				reporters: vec![],
 			}],
 			&[Perbill::from_percent(10)],
@@ -3170,7 +3176,7 @@ fn zero_slash_keeps_nominators() {
 
 		assert_eq!(Balances::free_balance(11), 1000);
 
-		let exposure = Staking::eras_stakers(active_era(), 11);
+		let exposure = Staking::eras_stakers(active_era(), &11);
 		assert_eq!(Balances::free_balance(101), 2000);
 
 		on_offence_now(
@@ -3257,9 +3263,9 @@ fn six_session_delay() {
 }
 
 #[test]
-fn test_max_nominator_rewarded_per_validator_and_cant_steal_someone_else_reward() {
+fn test_nominators_over_max_exposure_page_size_are_rewarded() {
 	ExtBuilder::default().build_and_execute(|| {
-		for i in 0..=<Test as Config>::MaxNominatorRewardedPerValidator::get() {
+		for i in 0..=<Test as Config>::MaxExposurePageSize::get() {
 			let stash = 10_000 + i as AccountId;
 			let controller = 20_000 + i as AccountId;
 			let balance = 10_000 + i as Balance;
@@ -3281,15 +3287,12 @@ fn test_max_nominator_rewarded_per_validator_and_cant_steal_someone_else_reward(
 		mock::start_active_era(2);
 		mock::make_all_reward_payment(1);
 
-		// Assert only nominators from 1 to Max are rewarded
-		for i in 0..=<Test as Config>::MaxNominatorRewardedPerValidator::get() {
+		// Assert all nominators are rewarded, even though they span more than one page.
+		assert!(Staking::exposure_page_count(1, &11) > 1);
+		for i in 0..=<Test as Config>::MaxExposurePageSize::get() {
 			let stash = 10_000 + i as AccountId;
 			let balance = 10_000 + i as Balance;
-			if stash == 10_000 {
-				assert!(Balances::free_balance(&stash) == balance);
-			} else {
-				assert!(Balances::free_balance(&stash) > balance);
-			}
+			assert!(Balances::free_balance(&stash) > balance);
 		}
 	});
 }
@@ -3334,20 +3337,21 @@ fn test_payout_stakers() {
 		let _ = current_total_payout_for_duration(reward_time_per_era());
 
 		mock::start_active_era(2);
+		assert_eq!(Staking::exposure_page_count(1, &11), 2);
 		assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, 1));
 
-		// Top 64 nominators of validator 11 automatically paid out, including the validator
+		// The first page of 64 nominators of validator 11 is paid out, including the validator.
 		// Validator payout goes to controller.
 		assert!(Balances::free_balance(&10) > balance);
-		for i in 36..100 {
-			assert!(Balances::free_balance(&(100 + i)) > balance + i as Balance);
-		}
-		// The bottom 36 do not
-		for i in 0..36 {
-			assert_eq!(Balances::free_balance(&(100 + i)), balance + i as Balance);
-		}
+		let paid_out = |i: AccountId| Balances::free_balance(&(100 + i)) > balance + i as Balance;
+		assert_eq!((0..100).filter(|i| paid_out(*i)).count(), 64);
 
-		// We track rewards in `claimed_rewards` vec
+		// Paying out again pays the remaining 36.
+		assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, 1));
+		assert!((0..100).all(paid_out));
+
+		// We track rewards per page in `ClaimedRewards`, not in the ledger.
+		assert_eq!(ClaimedRewards::<Test>::get(1, &11), vec![0, 1]);
 		assert_eq!(
 			Staking::ledger(&10),
 			Some(StakingLedger {
@@ -3355,9 +3359,14 @@ fn test_payout_stakers() {
 				total: 1000,
 				active: 1000,
 				unlocking: vec![],
-				claimed_rewards: vec![1]
+				claimed_rewards: vec![]
 			})
 		);
+		let err_weight = weights::SubstrateWeight::<Test>::payout_stakers_alive_staked(0);
+		assert_noop!(
+			Staking::payout_stakers(Origin::signed(1337), 11, 1),
+			Error::<Test>::AlreadyClaimed.with_weight(err_weight)
+		);
 
 		for i in 3..16 {
 			Staking::reward_by_ids(vec![(11, 1)]);
@@ -3367,19 +3376,12 @@ fn test_payout_stakers() {
 
 			mock::start_active_era(i);
 			assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, i - 1));
+			assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, i - 1));
 		}
 
-		// We track rewards in `claimed_rewards` vec
-		assert_eq!(
-			Staking::ledger(&10),
-			Some(StakingLedger {
-				stash: 11,
-				total: 1000,
-				active: 1000,
-				unlocking: vec![],
-				claimed_rewards: (1..=14).collect()
-			})
-		);
+		for i in 1..=14 {
+			assert_eq!(ClaimedRewards::<Test>::get(i, &11), vec![0, 1]);
+		}
 
 		for i in 16..100 {
 			Staking::reward_by_ids(vec![(11, 1)]);
@@ -3389,33 +3391,116 @@ fn test_payout_stakers() {
 		}
 
 		// We clean it up as history passes
+		assert!(!ClaimedRewards::<Test>::contains_key(1, &11));
 		assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, 15));
 		assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, 98));
-		assert_eq!(
-			Staking::ledger(&10),
-			Some(StakingLedger {
-				stash: 11,
-				total: 1000,
-				active: 1000,
-				unlocking: vec![],
-				claimed_rewards: vec![15, 98]
-			})
-		);
+		assert_eq!(ClaimedRewards::<Test>::get(15, &11), vec![0]);
+		assert_eq!(ClaimedRewards::<Test>::get(98, &11), vec![0]);
 
 		// Out of order claims works.
+		assert_ok!(Staking::payout_stakers_by_page(Origin::signed(1337), 11, 69, 1));
+		assert_ok!(Staking::payout_stakers_by_page(Origin::signed(1337), 11, 23, 1));
 		assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, 69));
-		assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, 23));
-		assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, 42));
-		assert_eq!(
-			Staking::ledger(&10),
-			Some(StakingLedger {
-				stash: 11,
-				total: 1000,
-				active: 1000,
-				unlocking: vec![],
-				claimed_rewards: vec![15, 23, 42, 69, 98]
-			})
+		assert_eq!(ClaimedRewards::<Test>::get(23, &11), vec![1]);
+		assert_eq!(ClaimedRewards::<Test>::get(69, &11), vec![0, 1]);
+	});
+}
+
+#[test]
+fn payout_stakers_by_page_handles_errors() {
+	ExtBuilder::default().has_stakers(false).build_and_execute(|| {
+		let err_weight = weights::SubstrateWeight::<Test>::payout_stakers_alive_staked(0);
+		let balance = 1000;
+		bond_validator(11, 10, balance);
+		for i in 0..100 {
+			bond_nominator(1000 + i, 100 + i, balance + i as Balance, vec![11]);
+		}
+
+		mock::start_active_era(1);
+		Staking::reward_by_ids(vec![(11, 1)]);
+		let _ = current_total_payout_for_duration(reward_time_per_era());
+		mock::start_active_era(2);
+
+		// Only two pages exist.
+		assert_noop!(
+			Staking::payout_stakers_by_page(Origin::signed(1337), 11, 1, 2),
+			Error::<Test>::InvalidPage.with_weight(err_weight)
 		);
+		assert_ok!(Staking::payout_stakers_by_page(Origin::signed(1337), 11, 1, 1));
+		assert_noop!(
+			Staking::payout_stakers_by_page(Origin::signed(1337), 11, 1, 1),
+			Error::<Test>::AlreadyClaimed.with_weight(err_weight)
+		);
+		assert_ok!(Staking::payout_stakers_by_page(Origin::signed(1337), 11, 1, 0));
+		assert_eq!(Staking::next_unclaimed_page(1, &11), None);
+	});
+}
+
+#[test]
+fn paged_payouts_sum_up_to_unpaged_payout() {
+	// The validator's own reward and commission are split across pages such that paying out all
+	// pages results in the same reward as a single page would.
+	let run = |page_size: u32| {
+		let mut rewards = vec![];
+		ExtBuilder::default().has_stakers(false).build_and_execute(|| {
+			MaxExposurePageSize::set(page_size);
+			let balance = 1000;
+			bond_validator(11, 10, balance);
+			assert_ok!(Staking::validate(
+				Origin::signed(10),
+				ValidatorPrefs { commission: Perbill::from_percent(10), blocked: false }
+			));
+			for i in 0..10 {
+				bond_nominator(1000 + i, 100 + i, balance, vec![11]);
+			}
+
+			mock::start_active_era(1);
+			Staking::reward_by_ids(vec![(11, 1)]);
+			let _ = current_total_payout_for_duration(reward_time_per_era());
+			mock::start_active_era(2);
+
+			while Staking::next_unclaimed_page(1, &11).is_some() {
+				assert_ok!(Staking::payout_stakers(Origin::signed(1337), 11, 1));
+			}
+			rewards.push(Balances::free_balance(&10));
+			for i in 0..10 {
+				rewards.push(Balances::free_balance(&(100 + i)));
+			}
+		});
+		rewards
+	};
+
+	let unpaged = run(64);
+	let paged = run(3);
+	for (a, b) in unpaged.iter().zip(paged.iter()) {
+		// allow for rounding errors of a few units.
+		assert!(a.max(b) - a.min(b) <= 4, "{} != {}", a, b);
+	}
+}
+
+#[test]
+fn eras_stakers_collects_all_pages() {
+	ExtBuilder::default().has_stakers(false).build_and_execute(|| {
+		let balance = 1000;
+		bond_validator(11, 10, balance);
+		for i in 0..100 {
+			bond_nominator(1000 + i, 100 + i, balance, vec![11]);
+		}
+		mock::start_active_era(1);
+
+		// The exposure is only stored in pages.
+		assert_eq!(Staking::exposure_page_count(1, &11), 2);
+		assert!(!ErasStakers::<Test>::contains_key(1, &11));
+		let exposure = Staking::eras_stakers(1, &11);
+		assert_eq!(exposure.own, balance);
+		assert_eq!(exposure.total, balance * 101);
+		assert_eq!(exposure.others.len(), 100);
+
+		// Exposures of eras planned before exposures were paged are still read.
+		let legacy = Exposure { total: 69, own: 69, others: vec![] };
+		ErasStakers::<Test>::insert(0, 21, legacy.clone());
+		assert_eq!(Staking::eras_stakers(0, &21), legacy);
+		assert_eq!(Staking::eras_stakers(0, &31), Exposure::default());
 	});
 }
 
@@ -3490,7 +3575,7 @@ fn payout_stakers_handles_weight_refund() {
 	// Note: this test relies on the assumption that `payout_stakers_alive_staked` is solely used by
 	// `payout_stakers` to calculate the weight of each payout op.
 	ExtBuilder::default().has_stakers(false).build_and_execute(|| {
-		let max_nom_rewarded = <Test as Config>::MaxNominatorRewardedPerValidator::get();
+		let max_nom_rewarded = <Test as Config>::MaxExposurePageSize::get();
 		// Make sure the configured value is meaningful for our use.
 		assert!(max_nom_rewarded >= 4);
 		let half_max_nom_rewarded = max_nom_rewarded / 2;
@@ -3652,7 +3737,7 @@ fn offences_weight_calculated_correctly() {
 		let offenders: Vec<OffenceDetails<<Test as frame_system::Config>::AccountId, pallet_session::historical::IdentificationTuple<Test>>>
 			= (1..10).map(|i|
 				OffenceDetails {
-					offender: (i, Staking::eras_stakers(active_era(), i)),
+					offender: (i, Staking::eras_stakers(active_era(), &i)),
 					reporters: vec![],
 				}
 			).collect();
@@ -3661,7 +3746,7 @@ fn offences_weight_calculated_correctly() {
 		// On Offence with one offenders, Applied
 		let one_offender = [
 			OffenceDetails {
-				offender: (11, Staking::eras_stakers(active_era(), 11)),
+				offender: (11, Staking::eras_stakers(active_era(), &11)),
 				reporters: vec![1],
 			},
 		];
@@ -4080,6 +4165,126 @@ mod election_data_provider {
 			});
 	}
 
+	#[test]
+	fn paged_voters_include_every_voter_once() {
+		ExtBuilder::default()
+			.set_status(41, StakerStatus::Validator)
+			.build_and_execute(|| {
+				// 4 self-votes and 1 nominator.
+				let mut all_voters = Staking::voters(None).unwrap();
+				assert_eq!(all_voters.len(), 5);
+
+				// when taking pages of 2 voters each
+				let page_0 = Staking::voters_paged(Some(2), 0).unwrap();
+				let page_1 = Staking::voters_paged(Some(2), 1).unwrap();
+				let page_2 = Staking::voters_paged(Some(2), 2).unwrap();
+
+				// then validators come first, and the nominator is left for the last page.
+				assert_eq!(page_0.len(), 2);
+				assert_eq!(page_1.len(), 2);
+				assert_eq!(page_2.iter().map(|(who, _, _)| *who).collect::<Vec<_>>(), vec![101]);
+				assert_eq!(VoterSnapshotStatus::<Test>::get(), SnapshotStatus::Consumed);
+
+				// and any further page is empty.
+				assert!(Staking::voters_paged(Some(2), 3).unwrap().is_empty());
+
+				// all pages together are the same as the unpaged voters.
+				let mut paged_voters = page_0
+					.iter()
+					.chain(page_1.iter())
+					.chain(page_2.iter())
+					.cloned()
+					.collect::<Vec<_>>();
+				paged_voters.sort_by_key(|(who, _, _)| *who);
+				all_voters.sort_by_key(|(who, _, _)| *who);
+				assert_eq!(paged_voters, all_voters);
+
+				// requesting page 0 again starts from scratch.
+				assert_eq!(Staking::voters_paged(Some(2), 0).unwrap(), page_0);
+				assert!(matches!(
+					VoterSnapshotStatus::<Test>::get(),
+					SnapshotStatus::Validators(_)
+				));
+			});
+	}
+
+	#[test]
+	fn paged_voters_survive_changes_to_the_list_between_pages() {
+		let who = |page: &Vec<frame_election_provider_support::VoterOf<Staking>>| {
+			page.iter().map(|(who, _, _)| *who).collect::<Vec<_>>()
+		};
+
+		// the next nominator of the snapshot is removed between two pages.
+		ExtBuilder::default()
+			.nominate(true)
+			.add_staker(61, 60, 2_000, StakerStatus::<AccountId>::Nominator(vec![21]))
+			.add_staker(71, 70, 2_000, StakerStatus::<AccountId>::Nominator(vec![21]))
+			.add_staker(81, 80, 2_000, StakerStatus::<AccountId>::Nominator(vec![21]))
+			.build_and_execute(|| {
+				// given
+				assert_eq!(
+					<Test as Config>::SortedListProvider::iter().collect::<Vec<_>>(),
+					vec![61, 71, 81, 101]
+				);
+				let page_0 = Staking::voters_paged(Some(4), 0).unwrap();
+				assert_eq!(who(&page_0), vec![11, 21, 31, 61]);
+				assert_eq!(VoterSnapshotStatus::<Test>::get(), SnapshotStatus::Nominators(71));
+
+				// when
+				assert_ok!(Staking::chill(Origin::signed(70)));
+
+				// then the cursor is moved to the next nominator,
+				assert_eq!(VoterSnapshotStatus::<Test>::get(), SnapshotStatus::Nominators(81));
+				let page_1 = Staking::voters_paged(Some(4), 1).unwrap();
+				assert_eq!(who(&page_1), vec![81, 101]);
+				assert_eq!(VoterSnapshotStatus::<Test>::get(), SnapshotStatus::Consumed);
+
+				// and all pages together are the same as the unpaged voters.
+				let mut paged_voters = page_0.into_iter().chain(page_1).collect::<Vec<_>>();
+				let mut all_voters = Staking::voters(None).unwrap();
+				paged_voters.sort_by_key(|(who, _, _)| *who);
+				all_voters.sort_by_key(|(who, _, _)| *who);
+				assert_eq!(paged_voters, all_voters);
+			});
+
+		// a visited nominator would be moved behind the cursor between two pages.
+		ExtBuilder::default()
+			.nominate(true)
+			.add_staker(61, 60, 2_000, StakerStatus::<AccountId>::Nominator(vec![21]))
+			.add_staker(71, 70, 2_000, StakerStatus::<AccountId>::Nominator(vec![21]))
+			.add_staker(81, 80, 2_000, StakerStatus::<AccountId>::Nominator(vec![21]))
+			.build_and_execute(|| {
+				// given
+				let page_0 = Staking::voters_paged(Some(4), 0).unwrap();
+				assert_eq!(who(&page_0), vec![11, 21, 31, 61]);
+
+				// when 61 drops into the bag of 101
+				assert_ok!(Staking::unbond(Origin::signed(60), 1_500));
+
+				// then 61 keeps its position while the snapshot is taken,
+				assert_eq!(
+					<Test as Config>::SortedListProvider::iter().collect::<Vec<_>>(),
+					vec![61, 71, 81, 101]
+				);
+				assert_noop!(
+					BagsList::rebag(Origin::signed(0), 61),
+					pallet_bags_list::Error::<Test>::Locked
+				);
+
+				// and it is not included twice.
+				let page_1 = Staking::voters_paged(Some(4), 1).unwrap();
+				assert_eq!(who(&page_1), vec![71, 81, 101]);
+				assert_eq!(VoterSnapshotStatus::<Test>::get(), SnapshotStatus::Consumed);
+
+				// once the snapshot is taken, 61 can be moved to the right bag.
+				assert_ok!(BagsList::rebag(Origin::signed(0), 61));
+				assert_eq!(
+					<Test as Config>::SortedListProvider::iter().collect::<Vec<_>>(),
+					vec![71, 81, 101, 61]
+				);
+			});
+	}
+
 	#[test]
 	fn only_iterates_max_2_times_nominators_quota() {
 		ExtBuilder::default()
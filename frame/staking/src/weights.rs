@@ -125,6 +125,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(4 as Weight))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
+	// NOTE: the weights of `withdraw_unbonded_kill`, `validate`, `chill`, `force_unstake`,
+	// `reap_stash` and `chill_other` also account for moving the cursor of the paged voter
+	// snapshot past a removed nominator. This was added by hand, and still needs to be
+	// re-benchmarked.
 	// Storage: Staking Ledger (r:1 w:1)
 	// Storage: Staking CurrentEra (r:1 w:0)
 	// Storage: Staking Bonded (r:1 w:1)
@@ -132,16 +136,17 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Staking Validators (r:1 w:0)
 	// Storage: Staking Nominators (r:1 w:1)
 	// Storage: Staking CounterForNominators (r:1 w:1)
-	// Storage: BagsList ListNodes (r:2 w:2)
-	// Storage: BagsList ListBags (r:1 w:1)
+	// Storage: BagsList ListNodes (r:3 w:2)
+	// Storage: BagsList ListBags (r:2 w:1)
 	// Storage: BagsList CounterForListNodes (r:1 w:1)
+	// Storage: Staking VoterSnapshotStatus (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: Staking Payee (r:0 w:1)
 	fn withdraw_unbonded_kill(_s: u32, ) -> Weight {
 		(58_077_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(13 as Weight))
-			.saturating_add(T::DbWeight::get().writes(11 as Weight))
+			.saturating_add(T::DbWeight::get().reads(16 as Weight))
+			.saturating_add(T::DbWeight::get().writes(12 as Weight))
 	}
 	// Storage: Staking Ledger (r:1 w:0)
 	// Storage: Staking MinValidatorBond (r:1 w:0)
@@ -150,14 +155,15 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Staking MaxValidatorsCount (r:1 w:0)
 	// Storage: Staking Nominators (r:1 w:1)
 	// Storage: Staking CounterForNominators (r:1 w:1)
-	// Storage: BagsList ListNodes (r:2 w:2)
-	// Storage: BagsList ListBags (r:1 w:1)
+	// Storage: BagsList ListNodes (r:3 w:2)
+	// Storage: BagsList ListBags (r:2 w:1)
 	// Storage: BagsList CounterForListNodes (r:1 w:1)
+	// Storage: Staking VoterSnapshotStatus (r:1 w:1)
 	// Storage: Staking CounterForValidators (r:1 w:1)
 	fn validate() -> Weight {
 		(44_603_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(12 as Weight))
-			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+			.saturating_add(T::DbWeight::get().reads(15 as Weight))
+			.saturating_add(T::DbWeight::get().writes(9 as Weight))
 	}
 	// Storage: Staking Ledger (r:1 w:0)
 	// Storage: Staking Nominators (r:1 w:1)
@@ -192,13 +198,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Staking Validators (r:1 w:0)
 	// Storage: Staking Nominators (r:1 w:1)
 	// Storage: Staking CounterForNominators (r:1 w:1)
-	// Storage: BagsList ListNodes (r:2 w:2)
-	// Storage: BagsList ListBags (r:1 w:1)
+	// Storage: BagsList ListNodes (r:3 w:2)
+	// Storage: BagsList ListBags (r:2 w:1)
 	// Storage: BagsList CounterForListNodes (r:1 w:1)
+	// Storage: Staking VoterSnapshotStatus (r:1 w:1)
 	fn chill() -> Weight {
 		(43_815_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(8 as Weight))
-			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
 	}
 	// Storage: Staking Ledger (r:1 w:0)
 	// Storage: Staking Payee (r:0 w:1)
@@ -246,9 +253,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Staking Validators (r:1 w:0)
 	// Storage: Staking Nominators (r:1 w:1)
 	// Storage: Staking CounterForNominators (r:1 w:1)
-	// Storage: BagsList ListNodes (r:2 w:2)
-	// Storage: BagsList ListBags (r:1 w:1)
+	// Storage: BagsList ListNodes (r:3 w:2)
+	// Storage: BagsList ListBags (r:2 w:1)
 	// Storage: BagsList CounterForListNodes (r:1 w:1)
+	// Storage: Staking VoterSnapshotStatus (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: Staking Ledger (r:0 w:1)
@@ -258,8 +266,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		(56_103_000 as Weight)
 			// Standard Error: 1_000
 			.saturating_add((798_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(T::DbWeight::get().reads(11 as Weight))
-			.saturating_add(T::DbWeight::get().writes(12 as Weight))
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().writes(13 as Weight))
 			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
 	}
 	// Storage: Staking UnappliedSlashes (r:1 w:1)
@@ -270,12 +278,16 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// NOTE: the storage accesses were updated for paged exposures, the execution time still needs
+	// to be re-benchmarked.
 	// Storage: Staking CurrentEra (r:1 w:0)
 	// Storage: Staking HistoryDepth (r:1 w:0)
 	// Storage: Staking ErasValidatorReward (r:1 w:0)
 	// Storage: Staking Bonded (r:2 w:0)
 	// Storage: Staking Ledger (r:1 w:1)
-	// Storage: Staking ErasStakersClipped (r:1 w:0)
+	// Storage: Staking ErasStakersOverview (r:1 w:0)
+	// Storage: Staking ClaimedRewards (r:1 w:1)
+	// Storage: Staking ErasStakersPaged (r:1 w:0)
 	// Storage: Staking ErasRewardPoints (r:1 w:0)
 	// Storage: Staking ErasValidatorPrefs (r:1 w:0)
 	// Storage: Staking Payee (r:2 w:0)
@@ -284,17 +296,21 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		(77_948_000 as Weight)
 			// Standard Error: 13_000
 			.saturating_add((23_507_000 as Weight).saturating_mul(n as Weight))
-			.saturating_add(T::DbWeight::get().reads(10 as Weight))
+			.saturating_add(T::DbWeight::get().reads(12 as Weight))
 			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(n as Weight)))
-			.saturating_add(T::DbWeight::get().writes(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
 	}
+	// NOTE: the storage accesses were updated for paged exposures, the execution time still needs
+	// to be re-benchmarked.
 	// Storage: Staking CurrentEra (r:1 w:0)
 	// Storage: Staking HistoryDepth (r:1 w:0)
 	// Storage: Staking ErasValidatorReward (r:1 w:0)
 	// Storage: Staking Bonded (r:2 w:0)
 	// Storage: Staking Ledger (r:2 w:2)
-	// Storage: Staking ErasStakersClipped (r:1 w:0)
+	// Storage: Staking ErasStakersOverview (r:1 w:0)
+	// Storage: Staking ClaimedRewards (r:1 w:1)
+	// Storage: Staking ErasStakersPaged (r:1 w:0)
 	// Storage: Staking ErasRewardPoints (r:1 w:0)
 	// Storage: Staking ErasValidatorPrefs (r:1 w:0)
 	// Storage: Staking Payee (r:2 w:0)
@@ -304,9 +320,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		(94_386_000 as Weight)
 			// Standard Error: 19_000
 			.saturating_add((32_763_000 as Weight).saturating_mul(n as Weight))
-			.saturating_add(T::DbWeight::get().reads(11 as Weight))
+			.saturating_add(T::DbWeight::get().reads(13 as Weight))
 			.saturating_add(T::DbWeight::get().reads((5 as Weight).saturating_mul(n as Weight)))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(4 as Weight))
 			.saturating_add(T::DbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
 	}
 	// Storage: Staking Ledger (r:1 w:1)
@@ -322,6 +338,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(9 as Weight))
 			.saturating_add(T::DbWeight::get().writes(8 as Weight))
 	}
+	// NOTE: the storage accesses were updated for paged exposures, the execution time still needs
+	// to be re-benchmarked.
 	// Storage: Staking CurrentEra (r:1 w:0)
 	// Storage: Staking HistoryDepth (r:1 w:1)
 	// Storage: Staking ErasStakersClipped (r:0 w:2)
@@ -329,6 +347,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Staking ErasValidatorReward (r:0 w:1)
 	// Storage: Staking ErasRewardPoints (r:0 w:1)
 	// Storage: Staking ErasStakers (r:0 w:2)
+	// Storage: Staking ErasStakersOverview (r:0 w:2)
+	// Storage: Staking ErasStakersPaged (r:0 w:2)
+	// Storage: Staking ClaimedRewards (r:0 w:2)
 	// Storage: Staking ErasTotalStake (r:0 w:1)
 	// Storage: Staking ErasStartSessionIndex (r:0 w:1)
 	fn set_history_depth(e: u32, ) -> Weight {
@@ -337,7 +358,7 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add((19_691_000 as Weight).saturating_mul(e as Weight))
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(4 as Weight))
-			.saturating_add(T::DbWeight::get().writes((7 as Weight).saturating_mul(e as Weight)))
+			.saturating_add(T::DbWeight::get().writes((10 as Weight).saturating_mul(e as Weight)))
 	}
 	// Storage: System Account (r:1 w:1)
 	// Storage: Staking Bonded (r:1 w:1)
@@ -346,9 +367,10 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Staking Validators (r:1 w:0)
 	// Storage: Staking Nominators (r:1 w:1)
 	// Storage: Staking CounterForNominators (r:1 w:1)
-	// Storage: BagsList ListNodes (r:2 w:2)
-	// Storage: BagsList ListBags (r:1 w:1)
+	// Storage: BagsList ListNodes (r:3 w:2)
+	// Storage: BagsList ListBags (r:2 w:1)
 	// Storage: BagsList CounterForListNodes (r:1 w:1)
+	// Storage: Staking VoterSnapshotStatus (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: Staking Payee (r:0 w:1)
 	// Storage: Staking SpanSlash (r:0 w:1)
@@ -356,10 +378,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 		(61_871_000 as Weight)
 			// Standard Error: 0
 			.saturating_add((796_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(T::DbWeight::get().reads(12 as Weight))
-			.saturating_add(T::DbWeight::get().writes(12 as Weight))
+			.saturating_add(T::DbWeight::get().reads(15 as Weight))
+			.saturating_add(T::DbWeight::get().writes(13 as Weight))
 			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
 	}
+	// NOTE: the storage accesses were updated for paged exposures, the execution time still needs
+	// to be re-benchmarked.
 	// Storage: Staking CounterForNominators (r:1 w:0)
 	// Storage: Staking CounterForValidators (r:1 w:0)
 	// Storage: Staking Validators (r:2 w:0)
@@ -373,9 +397,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Staking MinimumValidatorCount (r:1 w:0)
 	// Storage: Staking CurrentEra (r:1 w:1)
 	// Storage: Staking HistoryDepth (r:1 w:0)
-	// Storage: Staking ErasStakersClipped (r:0 w:1)
+	// Storage: Staking ErasStakersOverview (r:0 w:1)
 	// Storage: Staking ErasValidatorPrefs (r:0 w:1)
-	// Storage: Staking ErasStakers (r:0 w:1)
+	// Storage: Staking ErasStakersPaged (r:0 w:1)
 	// Storage: Staking ErasTotalStake (r:0 w:1)
 	// Storage: Staking ErasStartSessionIndex (r:0 w:1)
 	fn new_era(v: u32, n: u32, ) -> Weight {
@@ -437,13 +461,14 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Staking CounterForNominators (r:1 w:1)
 	// Storage: Staking MinNominatorBond (r:1 w:0)
 	// Storage: Staking Validators (r:1 w:0)
-	// Storage: BagsList ListNodes (r:2 w:2)
-	// Storage: BagsList ListBags (r:1 w:1)
+	// Storage: BagsList ListNodes (r:3 w:2)
+	// Storage: BagsList ListBags (r:2 w:1)
 	// Storage: BagsList CounterForListNodes (r:1 w:1)
+	// Storage: Staking VoterSnapshotStatus (r:1 w:1)
 	fn chill_other() -> Weight {
 		(54_681_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(11 as Weight))
-			.saturating_add(T::DbWeight::get().writes(6 as Weight))
+			.saturating_add(T::DbWeight::get().reads(14 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
 	}
 }
 
@@ -495,6 +520,10 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(4 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
 	}
+	// NOTE: the weights of `withdraw_unbonded_kill`, `validate`, `chill`, `force_unstake`,
+	// `reap_stash` and `chill_other` also account for moving the cursor of the paged voter
+	// snapshot past a removed nominator. This was added by hand, and still needs to be
+	// re-benchmarked.
 	// Storage: Staking Ledger (r:1 w:1)
 	// Storage: Staking CurrentEra (r:1 w:0)
 	// Storage: Staking Bonded (r:1 w:1)
@@ -502,16 +531,17 @@ impl WeightInfo for () {
 	// Storage: Staking Validators (r:1 w:0)
 	// Storage: Staking Nominators (r:1 w:1)
 	// Storage: Staking CounterForNominators (r:1 w:1)
-	// Storage: BagsList ListNodes (r:2 w:2)
-	// Storage: BagsList ListBags (r:1 w:1)
+	// Storage: BagsList ListNodes (r:3 w:2)
+	// Storage: BagsList ListBags (r:2 w:1)
 	// Storage: BagsList CounterForListNodes (r:1 w:1)
+	// Storage: Staking VoterSnapshotStatus (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: Staking Payee (r:0 w:1)
 	fn withdraw_unbonded_kill(_s: u32, ) -> Weight {
 		(58_077_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(13 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(11 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(16 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(12 as Weight))
 	}
 	// Storage: Staking Ledger (r:1 w:0)
 	// Storage: Staking MinValidatorBond (r:1 w:0)
@@ -520,14 +550,15 @@ impl WeightInfo for () {
 	// Storage: Staking MaxValidatorsCount (r:1 w:0)
 	// Storage: Staking Nominators (r:1 w:1)
 	// Storage: Staking CounterForNominators (r:1 w:1)
-	// Storage: BagsList ListNodes (r:2 w:2)
-	// Storage: BagsList ListBags (r:1 w:1)
+	// Storage: BagsList ListNodes (r:3 w:2)
+	// Storage: BagsList ListBags (r:2 w:1)
 	// Storage: BagsList CounterForListNodes (r:1 w:1)
+	// Storage: Staking VoterSnapshotStatus (r:1 w:1)
 	// Storage: Staking CounterForValidators (r:1 w:1)
 	fn validate() -> Weight {
 		(44_603_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(15 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(9 as Weight))
 	}
 	// Storage: Staking Ledger (r:1 w:0)
 	// Storage: Staking Nominators (r:1 w:1)
@@ -562,13 +593,14 @@ impl WeightInfo for () {
 	// Storage: Staking Validators (r:1 w:0)
 	// Storage: Staking Nominators (r:1 w:1)
 	// Storage: Staking CounterForNominators (r:1 w:1)
-	// Storage: BagsList ListNodes (r:2 w:2)
-	// Storage: BagsList ListBags (r:1 w:1)
+	// Storage: BagsList ListNodes (r:3 w:2)
+	// Storage: BagsList ListBags (r:2 w:1)
 	// Storage: BagsList CounterForListNodes (r:1 w:1)
+	// Storage: Staking VoterSnapshotStatus (r:1 w:1)
 	fn chill() -> Weight {
 		(43_815_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(8 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
 	}
 	// Storage: Staking Ledger (r:1 w:0)
 	// Storage: Staking Payee (r:0 w:1)
@@ -616,9 +648,10 @@ impl WeightInfo for () {
 	// Storage: Staking Validators (r:1 w:0)
 	// Storage: Staking Nominators (r:1 w:1)
 	// Storage: Staking CounterForNominators (r:1 w:1)
-	// Storage: BagsList ListNodes (r:2 w:2)
-	// Storage: BagsList ListBags (r:1 w:1)
+	// Storage: BagsList ListNodes (r:3 w:2)
+	// Storage: BagsList ListBags (r:2 w:1)
 	// Storage: BagsList CounterForListNodes (r:1 w:1)
+	// Storage: Staking VoterSnapshotStatus (r:1 w:1)
 	// Storage: System Account (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: Staking Ledger (r:0 w:1)
@@ -628,8 +661,8 @@ impl WeightInfo for () {
 		(56_103_000 as Weight)
 			// Standard Error: 1_000
 			.saturating_add((798_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(12 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(13 as Weight))
 			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
 	}
 	// Storage: Staking UnappliedSlashes (r:1 w:1)
@@ -640,12 +673,16 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	// NOTE: the storage accesses were updated for paged exposures, the execution time still needs
+	// to be re-benchmarked.
 	// Storage: Staking CurrentEra (r:1 w:0)
 	// Storage: Staking HistoryDepth (r:1 w:0)
 	// Storage: Staking ErasValidatorReward (r:1 w:0)
 	// Storage: Staking Bonded (r:2 w:0)
 	// Storage: Staking Ledger (r:1 w:1)
-	// Storage: Staking ErasStakersClipped (r:1 w:0)
+	// Storage: Staking ErasStakersOverview (r:1 w:0)
+	// Storage: Staking ClaimedRewards (r:1 w:1)
+	// Storage: Staking ErasStakersPaged (r:1 w:0)
 	// Storage: Staking ErasRewardPoints (r:1 w:0)
 	// Storage: Staking ErasValidatorPrefs (r:1 w:0)
 	// Storage: Staking Payee (r:2 w:0)
@@ -654,17 +691,21 @@ impl WeightInfo for () {
 		(77_948_000 as Weight)
 			// Standard Error: 13_000
 			.saturating_add((23_507_000 as Weight).saturating_mul(n as Weight))
-			.saturating_add(RocksDbWeight::get().reads(10 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
 			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(n as Weight)))
-			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
 			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(n as Weight)))
 	}
+	// NOTE: the storage accesses were updated for paged exposures, the execution time still needs
+	// to be re-benchmarked.
 	// Storage: Staking CurrentEra (r:1 w:0)
 	// Storage: Staking HistoryDepth (r:1 w:0)
 	// Storage: Staking ErasValidatorReward (r:1 w:0)
 	// Storage: Staking Bonded (r:2 w:0)
 	// Storage: Staking Ledger (r:2 w:2)
-	// Storage: Staking ErasStakersClipped (r:1 w:0)
+	// Storage: Staking ErasStakersOverview (r:1 w:0)
+	// Storage: Staking ClaimedRewards (r:1 w:1)
+	// Storage: Staking ErasStakersPaged (r:1 w:0)
 	// Storage: Staking ErasRewardPoints (r:1 w:0)
 	// Storage: Staking ErasValidatorPrefs (r:1 w:0)
 	// Storage: Staking Payee (r:2 w:0)
@@ -674,9 +715,9 @@ impl WeightInfo for () {
 		(94_386_000 as Weight)
 			// Standard Error: 19_000
 			.saturating_add((32_763_000 as Weight).saturating_mul(n as Weight))
-			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(13 as Weight))
 			.saturating_add(RocksDbWeight::get().reads((5 as Weight).saturating_mul(n as Weight)))
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
 			.saturating_add(RocksDbWeight::get().writes((3 as Weight).saturating_mul(n as Weight)))
 	}
 	// Storage: Staking Ledger (r:1 w:1)
@@ -692,6 +733,8 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
 	}
+	// NOTE: the storage accesses were updated for paged exposures, the execution time still needs
+	// to be re-benchmarked.
 	// Storage: Staking CurrentEra (r:1 w:0)
 	// Storage: Staking HistoryDepth (r:1 w:1)
 	// Storage: Staking ErasStakersClipped (r:0 w:2)
@@ -699,6 +742,9 @@ impl WeightInfo for () {
 	// Storage: Staking ErasValidatorReward (r:0 w:1)
 	// Storage: Staking ErasRewardPoints (r:0 w:1)
 	// Storage: Staking ErasStakers (r:0 w:2)
+	// Storage: Staking ErasStakersOverview (r:0 w:2)
+	// Storage: Staking ErasStakersPaged (r:0 w:2)
+	// Storage: Staking ClaimedRewards (r:0 w:2)
 	// Storage: Staking ErasTotalStake (r:0 w:1)
 	// Storage: Staking ErasStartSessionIndex (r:0 w:1)
 	fn set_history_depth(e: u32, ) -> Weight {
@@ -707,7 +753,7 @@ impl WeightInfo for () {
 			.saturating_add((19_691_000 as Weight).saturating_mul(e as Weight))
 			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(4 as Weight))
-			.saturating_add(RocksDbWeight::get().writes((7 as Weight).saturating_mul(e as Weight)))
+			.saturating_add(RocksDbWeight::get().writes((10 as Weight).saturating_mul(e as Weight)))
 	}
 	// Storage: System Account (r:1 w:1)
 	// Storage: Staking Bonded (r:1 w:1)
@@ -716,9 +762,10 @@ impl WeightInfo for () {
 	// Storage: Staking Validators (r:1 w:0)
 	// Storage: Staking Nominators (r:1 w:1)
 	// Storage: Staking CounterForNominators (r:1 w:1)
-	// Storage: BagsList ListNodes (r:2 w:2)
-	// Storage: BagsList ListBags (r:1 w:1)
+	// Storage: BagsList ListNodes (r:3 w:2)
+	// Storage: BagsList ListBags (r:2 w:1)
 	// Storage: BagsList CounterForListNodes (r:1 w:1)
+	// Storage: Staking VoterSnapshotStatus (r:1 w:1)
 	// Storage: Balances Locks (r:1 w:1)
 	// Storage: Staking Payee (r:0 w:1)
 	// Storage: Staking SpanSlash (r:0 w:1)
@@ -726,10 +773,12 @@ impl WeightInfo for () {
 		(61_871_000 as Weight)
 			// Standard Error: 0
 			.saturating_add((796_000 as Weight).saturating_mul(s as Weight))
-			.saturating_add(RocksDbWeight::get().reads(12 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(12 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(15 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(13 as Weight))
 			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(s as Weight)))
 	}
+	// NOTE: the storage accesses were updated for paged exposures, the execution time still needs
+	// to be re-benchmarked.
 	// Storage: Staking CounterForNominators (r:1 w:0)
 	// Storage: Staking CounterForValidators (r:1 w:0)
 	// Storage: Staking Validators (r:2 w:0)
@@ -743,9 +792,9 @@ impl WeightInfo for () {
 	// Storage: Staking MinimumValidatorCount (r:1 w:0)
 	// Storage: Staking CurrentEra (r:1 w:1)
 	// Storage: Staking HistoryDepth (r:1 w:0)
-	// Storage: Staking ErasStakersClipped (r:0 w:1)
+	// Storage: Staking ErasStakersOverview (r:0 w:1)
 	// Storage: Staking ErasValidatorPrefs (r:0 w:1)
-	// Storage: Staking ErasStakers (r:0 w:1)
+	// Storage: Staking ErasStakersPaged (r:0 w:1)
 	// Storage: Staking ErasTotalStake (r:0 w:1)
 	// Storage: Staking ErasStartSessionIndex (r:0 w:1)
 	fn new_era(v: u32, n: u32, ) -> Weight {
@@ -807,12 +856,13 @@ impl WeightInfo for () {
 	// Storage: Staking CounterForNominators (r:1 w:1)
 	// Storage: Staking MinNominatorBond (r:1 w:0)
 	// Storage: Staking Validators (r:1 w:0)
-	// Storage: BagsList ListNodes (r:2 w:2)
-	// Storage: BagsList ListBags (r:1 w:1)
+	// Storage: BagsList ListNodes (r:3 w:2)
+	// Storage: BagsList ListBags (r:2 w:1)
 	// Storage: BagsList CounterForListNodes (r:1 w:1)
+	// Storage: Staking VoterSnapshotStatus (r:1 w:1)
 	fn chill_other() -> Weight {
 		(54_681_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(11 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(6 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(14 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
 	}
 }
@@ -35,7 +35,7 @@ mod members;
 pub use members::{AllowAll, DenyAll, Filter};
 pub use members::{
 	AsContains, ChangeMembers, Contains, ContainsLengthBound, Everything, InitializeMembers,
-	IsInVec, Nothing, SortedMembers,
+	InsideBoth, IsInVec, Nothing, SortedMembers,
 };
 
 mod validation;
@@ -91,3 +91,6 @@ pub use dispatch::{EnsureOneOf, EnsureOrigin, OriginTrait, UnfilteredDispatchabl
 
 mod voting;
 pub use voting::{CurrencyToVote, SaturatingCurrencyToVote, U128CurrencyToVote};
+
+mod messages;
+pub use messages::{EnqueueMessage, Footprint, ProcessMessage, ProcessMessageError};
@@ -41,6 +41,14 @@ impl<T> Contains<T> for Nothing {
 	}
 }
 
+/// A [`Contains`] implementation that contains the values contained by both `These` and `Those`.
+pub struct InsideBoth<These, Those>(PhantomData<(These, Those)>);
+impl<T, These: Contains<T>, Those: Contains<T>> Contains<T> for InsideBoth<These, Those> {
+	fn contains(t: &T) -> bool {
+		These::contains(t) && Those::contains(t)
+	}
+}
+
 #[deprecated = "Use `Everything` instead"]
 pub type AllowAll = Everything;
 #[deprecated = "Use `Nothing` instead"]
@@ -94,6 +102,22 @@ mod tests {
 			assert_eq!(OneOrTenToTwenty::contains(&i), i == 1 || i >= 10 && i <= 20);
 		}
 	}
+
+	match_type! {
+		pub type TenToThirty: impl Contains<u8> = { 10..=30 };
+	}
+
+	#[test]
+	fn inside_both_works() {
+		for i in 0..=255 {
+			assert_eq!(
+				InsideBoth::<OneOrTenToTwenty, TenToThirty>::contains(&i),
+				(10..=20).contains(&i)
+			);
+		}
+		assert!(InsideBoth::<Everything, TenToThirty>::contains(&30));
+		assert!(!InsideBoth::<Nothing, TenToThirty>::contains(&30));
+	}
 }
 
 /// A trait for a set which can enumerate its members in order.
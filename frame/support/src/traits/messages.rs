@@ -0,0 +1,88 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2019-2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Traits for dealing with queues of messages.
+
+use crate::{traits::Get, weights::Weight, BoundedSlice};
+use codec::{Decode, Encode, FullCodec, MaxEncodedLen};
+use scale_info::TypeInfo;
+use sp_runtime::RuntimeDebug;
+use sp_std::{fmt::Debug, prelude::*};
+
+/// Errors that can happen when attempting to process a message with [`ProcessMessage`].
+#[derive(Copy, Clone, Eq, PartialEq, Encode, Decode, TypeInfo, RuntimeDebug)]
+pub enum ProcessMessageError {
+	/// The message data format is unknown (e.g. unrecognised header).
+	BadFormat,
+	/// The message data is bad (e.g. decoding returns an error).
+	Corrupt,
+	/// The message format is unknown or its contents are not supported.
+	Unsupported,
+	/// Processing the message needs more than the given weight limit. The contained weight is
+	/// the weight which would be needed.
+	Overweight(Weight),
+}
+
+/// Can process messages from a specific origin.
+pub trait ProcessMessage {
+	/// The transport from where a message originates.
+	type Origin: FullCodec + MaxEncodedLen + Clone + Eq + PartialEq + TypeInfo + Debug;
+
+	/// Process the given message, using no more than `weight_limit` in weight.
+	///
+	/// Returns whether the message was processed successfully and the weight it used. Returns
+	/// [`ProcessMessageError::Overweight`] without any side effects if the message needs more
+	/// than `weight_limit`.
+	fn process_message(
+		message: &[u8],
+		origin: Self::Origin,
+		weight_limit: Weight,
+	) -> Result<(bool, Weight), ProcessMessageError>;
+}
+
+/// The number and the total size of the messages in a queue.
+#[derive(Default, Copy, Clone, Eq, PartialEq, RuntimeDebug)]
+pub struct Footprint {
+	/// The number of messages.
+	pub count: u64,
+	/// The total size of the messages in bytes.
+	pub size: u64,
+}
+
+/// Can enqueue messages for multiple origins.
+pub trait EnqueueMessage<Origin: MaxEncodedLen> {
+	/// The maximal length any enqueued message may have.
+	type MaxMessageLen: Get<u32>;
+
+	/// Enqueue a single `message` from a specific `origin`.
+	fn enqueue_message(message: BoundedSlice<u8, Self::MaxMessageLen>, origin: Origin);
+
+	/// Enqueue multiple `messages` from a specific `origin`.
+	fn enqueue_messages<'a>(
+		messages: impl Iterator<Item = BoundedSlice<'a, u8, Self::MaxMessageLen>>,
+		origin: Origin,
+	);
+
+	/// Stop servicing the queue of `origin`.
+	///
+	/// Its remaining messages are kept and processed again once a new message is enqueued for
+	/// `origin`.
+	fn sweep_queue(origin: Origin);
+
+	/// Return the state footprint of the given queue.
+	fn footprint(origin: Origin) -> Footprint;
+}
@@ -29,7 +29,7 @@
 
 use crate::dispatch::{DispatchError, DispatchResult};
 use codec::{Decode, Encode};
-use sp_runtime::TokenError;
+use sp_runtime::{Perbill, TokenError};
 use sp_std::prelude::*;
 
 /// Trait for providing an interface to many read-only NFT-like sets of asset instances.
@@ -221,3 +221,36 @@ pub trait Transfer<AccountId>: Inspect<AccountId> {
 		destination: &AccountId,
 	) -> DispatchResult;
 }
+
+/// Trait for providing an interface for trading NFT-like assets of multiple classes.
+pub trait Trading<AccountId, Price>: Inspect<AccountId> {
+	/// Returns the price asset `instance` of `class` is for sale at, or `None` if it is not for
+	/// sale.
+	fn instance_price(class: &Self::ClassId, instance: &Self::InstanceId) -> Option<Price>;
+
+	/// Returns the account receiving royalties on sales of instances of `class` and the share of
+	/// the price it receives, or `None` if no royalty is paid.
+	///
+	/// By default this is `None`; no royalties are paid.
+	fn royalty(_class: &Self::ClassId) -> Option<(AccountId, Perbill)> {
+		None
+	}
+
+	/// Set the `price` asset `instance` of `class` is for sale at on behalf of its owner `who`.
+	///
+	/// If `price` is `None`, the asset is no longer for sale.
+	fn set_price(
+		class: &Self::ClassId,
+		instance: &Self::InstanceId,
+		who: &AccountId,
+		price: Option<Price>,
+	) -> DispatchResult;
+
+	/// Buy asset `instance` of `class` for `buyer` paying no more than `bid_price`.
+	fn buy(
+		class: &Self::ClassId,
+		instance: &Self::InstanceId,
+		buyer: &AccountId,
+		bid_price: Price,
+	) -> DispatchResult;
+}
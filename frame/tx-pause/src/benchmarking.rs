@@ -0,0 +1,51 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Transaction pause pallet benchmarking.
+
+#![cfg(feature = "runtime-benchmarks")]
+
+use super::*;
+use frame_benchmarking::benchmarks;
+use frame_support::traits::EnsureOrigin;
+
+fn full_name<T: Config>() -> FullNameOf<T> {
+	let pallet_name: PalletNameOf<T> = b"Dummy".to_vec().try_into().unwrap();
+	let call_name: CallNameOf<T> = b"dummy_call".to_vec().try_into().unwrap();
+	(pallet_name, Some(call_name))
+}
+
+benchmarks! {
+	pause {
+		let origin = T::PauseOrigin::successful_origin();
+		let full_name = full_name::<T>();
+	}: _<T::Origin>(origin, full_name.clone())
+	verify {
+		assert!(PausedCalls::<T>::contains_key(&full_name));
+	}
+
+	unpause {
+		let origin = T::UnpauseOrigin::successful_origin();
+		let full_name = full_name::<T>();
+		PausedCalls::<T>::insert(&full_name, ());
+	}: _<T::Origin>(origin, full_name.clone())
+	verify {
+		assert!(!PausedCalls::<T>::contains_key(&full_name));
+	}
+
+	impl_benchmark_test_suite!(Pallet, crate::mock::new_test_ext(), crate::mock::Test);
+}
@@ -0,0 +1,218 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Transaction Pause Module
+//!
+//! Pauses calls of the runtime without a runtime upgrade.
+//!
+//! ## Overview
+//!
+//! Calls are identified by their [`FullNameOf`]: the name of their pallet and optionally the name
+//! of the call, as found in the metadata of the runtime. A full name without a call name refers
+//! to all calls of the pallet.
+//!
+//! - [`Pallet::pause`] pauses a call or a pallet. Requires [`Config::PauseOrigin`].
+//! - [`Pallet::unpause`] unpauses a call or a pallet again. Requires [`Config::UnpauseOrigin`].
+//!
+//! The pallet implements [`Contains`] for the calls of the runtime. It contains all calls which
+//! are not paused, and is meant to be used as, or as part of,
+//! `frame_system::Config::BaseCallFilter`. The calls of this pallet and the calls in
+//! [`Config::WhitelistedCalls`] can't be paused.
+//!
+//! ## Related Modules
+//!
+//! * [`System`](../frame_system/index.html)
+//! * [`Support`](../frame_support/index.html)
+
+// Ensure we're `no_std` when compiling for Wasm.
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "runtime-benchmarks")]
+mod benchmarking;
+#[cfg(test)]
+pub mod mock;
+#[cfg(test)]
+mod tests;
+pub mod weights;
+
+use frame_support::{
+	traits::{CallMetadata, Contains, GetCallMetadata, PalletInfoAccess},
+	BoundedVec,
+};
+
+pub use pallet::*;
+pub use weights::WeightInfo;
+
+/// The name of a pallet.
+pub type PalletNameOf<T> = BoundedVec<u8, <T as Config>::MaxNameLen>;
+
+/// The name of a call of a pallet.
+pub type CallNameOf<T> = BoundedVec<u8, <T as Config>::MaxNameLen>;
+
+/// The name of a pallet and optionally of one of its calls. Without a call name, it refers to all
+/// calls of the pallet.
+pub type FullNameOf<T> = (PalletNameOf<T>, Option<CallNameOf<T>>);
+
+#[frame_support::pallet]
+pub mod pallet {
+	use super::*;
+	use frame_support::pallet_prelude::*;
+	use frame_system::pallet_prelude::*;
+
+	#[pallet::pallet]
+	#[pallet::generate_store(pub(super) trait Store)]
+	pub struct Pallet<T>(_);
+
+	#[pallet::config]
+	/// The module configuration trait.
+	pub trait Config: frame_system::Config {
+		/// The overarching event type.
+		type Event: From<Event<Self>> + IsType<<Self as frame_system::Config>::Event>;
+
+		/// The origin which may pause calls.
+		type PauseOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The origin which may unpause calls.
+		type UnpauseOrigin: EnsureOrigin<Self::Origin>;
+
+		/// The calls which can't be paused.
+		///
+		/// Queried with the full name of a call and with the name of its pallet alone, so it may
+		/// whitelist single calls as well as whole pallets.
+		type WhitelistedCalls: Contains<FullNameOf<Self>>;
+
+		/// The maximum length of the name of a pallet or a call. Calls with longer names can't be
+		/// paused.
+		#[pallet::constant]
+		type MaxNameLen: Get<u32>;
+
+		/// Weight information for extrinsics in this pallet.
+		type WeightInfo: WeightInfo;
+	}
+
+	#[pallet::storage]
+	#[pallet::getter(fn paused_calls)]
+	/// The paused calls and pallets.
+	pub type PausedCalls<T: Config> =
+		StorageMap<_, Blake2_128Concat, FullNameOf<T>, (), OptionQuery>;
+
+	#[pallet::event]
+	#[pallet::generate_deposit(pub(super) fn deposit_event)]
+	pub enum Event<T: Config> {
+		/// A call or all calls of a pallet were paused.
+		CallPaused { full_name: FullNameOf<T> },
+		/// A call or all calls of a pallet were unpaused.
+		CallUnpaused { full_name: FullNameOf<T> },
+	}
+
+	#[pallet::error]
+	pub enum Error<T> {
+		/// The call or pallet is already paused.
+		IsPaused,
+		/// The call or pallet is not paused.
+		IsUnpaused,
+		/// The call or pallet is whitelisted or part of this pallet, and can't be paused.
+		Unpausable,
+	}
+
+	#[pallet::call]
+	impl<T: Config> Pallet<T> {
+		/// Pause a call, or all calls of a pallet.
+		///
+		/// Origin must be `T::PauseOrigin`.
+		///
+		/// - `full_name`: The name of the pallet and optionally of the call to pause.
+		///
+		/// Emits `CallPaused` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::pause())]
+		pub fn pause(origin: OriginFor<T>, full_name: FullNameOf<T>) -> DispatchResult {
+			T::PauseOrigin::ensure_origin(origin)?;
+
+			Self::ensure_can_pause(&full_name)?;
+			PausedCalls::<T>::insert(&full_name, ());
+
+			Self::deposit_event(Event::CallPaused { full_name });
+			Ok(())
+		}
+
+		/// Unpause a call, or all calls of a pallet.
+		///
+		/// Origin must be `T::UnpauseOrigin`. Unpausing a pallet doesn't unpause calls of it which
+		/// were paused one by one.
+		///
+		/// - `full_name`: The name of the pallet and optionally of the call to unpause.
+		///
+		/// Emits `CallUnpaused` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::unpause())]
+		pub fn unpause(origin: OriginFor<T>, full_name: FullNameOf<T>) -> DispatchResult {
+			T::UnpauseOrigin::ensure_origin(origin)?;
+
+			ensure!(PausedCalls::<T>::contains_key(&full_name), Error::<T>::IsUnpaused);
+			PausedCalls::<T>::remove(&full_name);
+
+			Self::deposit_event(Event::CallUnpaused { full_name });
+			Ok(())
+		}
+	}
+
+	impl<T: Config> Pallet<T> {
+		/// Whether the call `call_name` of the pallet `pallet_name` is paused, either by itself or
+		/// as part of its pallet.
+		pub fn is_paused(pallet_name: &[u8], call_name: &[u8]) -> bool {
+			let pallet_name: PalletNameOf<T> = match pallet_name.to_vec().try_into() {
+				Ok(pallet_name) => pallet_name,
+				Err(_) => return false,
+			};
+			let call_name: CallNameOf<T> = match call_name.to_vec().try_into() {
+				Ok(call_name) => call_name,
+				Err(_) => return false,
+			};
+			let pallet = (pallet_name, None);
+			let call = (pallet.0.clone(), Some(call_name));
+
+			if T::WhitelistedCalls::contains(&pallet) || T::WhitelistedCalls::contains(&call) {
+				return false
+			}
+			PausedCalls::<T>::contains_key(&pallet) || PausedCalls::<T>::contains_key(&call)
+		}
+
+		/// Ensure that `full_name` can be paused.
+		pub fn ensure_can_pause(full_name: &FullNameOf<T>) -> Result<(), Error<T>> {
+			// The calls of this pallet can't be paused, so calls can always be unpaused again.
+			let own_name = <Self as PalletInfoAccess>::name();
+			ensure!(&full_name.0[..] != own_name.as_bytes(), Error::<T>::Unpausable);
+			ensure!(!T::WhitelistedCalls::contains(full_name), Error::<T>::Unpausable);
+			ensure!(!PausedCalls::<T>::contains_key(full_name), Error::<T>::IsPaused);
+			Ok(())
+		}
+	}
+}
+
+/// Contains all calls which are not paused.
+impl<T: Config> Contains<<T as frame_system::Config>::Call> for Pallet<T>
+where
+	<T as frame_system::Config>::Call: GetCallMetadata,
+{
+	fn contains(call: &<T as frame_system::Config>::Call) -> bool {
+		let CallMetadata { pallet_name, function_name } = call.get_call_metadata();
+		!Self::is_paused(pallet_name.as_bytes(), function_name.as_bytes())
+	}
+}
@@ -0,0 +1,140 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Tests for transaction pause pallet.
+
+use super::*;
+use crate::mock::*;
+use frame_support::{assert_err, assert_noop, assert_ok, dispatch::Dispatchable};
+use sp_runtime::DispatchError;
+
+fn full_name(pallet_name: &[u8], call_name: Option<&[u8]>) -> FullNameOf<Test> {
+	(pallet_name.to_vec().try_into().unwrap(), call_name.map(|c| c.to_vec().try_into().unwrap()))
+}
+
+fn transfer(dest: u64, value: u64) -> Call {
+	Call::Balances(pallet_balances::Call::transfer { dest, value })
+}
+
+fn transfer_keep_alive(dest: u64, value: u64) -> Call {
+	Call::Balances(pallet_balances::Call::transfer_keep_alive { dest, value })
+}
+
+fn assert_filtered(call: Call, origin: Origin) {
+	assert_err!(
+		call.dispatch(origin).map_err(|e| e.error),
+		DispatchError::from(frame_system::Error::<Test>::CallFiltered)
+	);
+}
+
+#[test]
+fn pause_and_unpause_call_works() {
+	new_test_ext().execute_with(|| {
+		let name = full_name(b"Balances", Some(b"transfer"));
+		assert_ok!(TxPause::pause(Origin::root(), name.clone()));
+		System::assert_last_event(Event::TxPause(crate::Event::CallPaused {
+			full_name: name.clone(),
+		}));
+		assert!(TxPause::is_paused(b"Balances", b"transfer"));
+		assert_filtered(transfer(2, 10), Origin::signed(1));
+
+		// Other calls of the pallet are not affected.
+		assert!(!TxPause::is_paused(b"Balances", b"transfer_all"));
+		assert_ok!(transfer_keep_alive(2, 10).dispatch(Origin::signed(1)));
+
+		assert_ok!(TxPause::unpause(Origin::root(), name.clone()));
+		System::assert_last_event(Event::TxPause(crate::Event::CallUnpaused { full_name: name }));
+		assert!(!TxPause::is_paused(b"Balances", b"transfer"));
+		assert_ok!(transfer(2, 10).dispatch(Origin::signed(1)));
+		assert_eq!(Balances::free_balance(2), 120);
+	});
+}
+
+#[test]
+fn pause_pallet_works() {
+	new_test_ext().execute_with(|| {
+		let name = full_name(b"Balances", None);
+		assert_ok!(TxPause::pause(Origin::root(), name.clone()));
+		assert!(TxPause::is_paused(b"Balances", b"transfer"));
+		assert!(TxPause::is_paused(b"Balances", b"transfer_all"));
+		assert_filtered(transfer(2, 10), Origin::signed(1));
+
+		// Whitelisted calls of a paused pallet are not paused.
+		assert!(!TxPause::is_paused(b"Balances", b"transfer_keep_alive"));
+		assert_ok!(transfer_keep_alive(2, 10).dispatch(Origin::signed(1)));
+
+		// Unpausing the pallet doesn't unpause calls which were paused on their own.
+		assert_ok!(TxPause::pause(Origin::root(), full_name(b"Balances", Some(b"transfer"))));
+		assert_ok!(TxPause::unpause(Origin::root(), name));
+		assert!(TxPause::is_paused(b"Balances", b"transfer"));
+		assert!(!TxPause::is_paused(b"Balances", b"transfer_all"));
+	});
+}
+
+#[test]
+fn pause_and_unpause_fail_for_invalid_input() {
+	new_test_ext().execute_with(|| {
+		let name = full_name(b"Balances", Some(b"transfer"));
+		assert_noop!(TxPause::pause(Origin::signed(1), name.clone()), DispatchError::BadOrigin);
+		assert_noop!(TxPause::unpause(Origin::root(), name.clone()), Error::<Test>::IsUnpaused);
+
+		assert_ok!(TxPause::pause(Origin::root(), name.clone()));
+		assert_noop!(TxPause::pause(Origin::root(), name.clone()), Error::<Test>::IsPaused);
+		assert_noop!(TxPause::unpause(Origin::signed(1), name), DispatchError::BadOrigin);
+
+		// Neither this pallet nor whitelisted calls can be paused.
+		assert_noop!(
+			TxPause::pause(Origin::root(), full_name(b"TxPause", None)),
+			Error::<Test>::Unpausable
+		);
+		assert_noop!(
+			TxPause::pause(Origin::root(), full_name(b"TxPause", Some(b"unpause"))),
+			Error::<Test>::Unpausable
+		);
+		assert_noop!(
+			TxPause::pause(Origin::root(), full_name(b"Balances", Some(b"transfer_keep_alive"))),
+			Error::<Test>::Unpausable
+		);
+	});
+}
+
+#[test]
+fn paused_calls_are_filtered_within_batches() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(TxPause::pause(Origin::root(), full_name(b"Balances", Some(b"transfer"))));
+
+		assert_ok!(Utility::batch(
+			Origin::signed(1),
+			vec![transfer_keep_alive(2, 10), transfer(2, 10)]
+		));
+		System::assert_last_event(
+			pallet_utility::Event::BatchInterrupted {
+				index: 1,
+				error: frame_system::Error::<Test>::CallFiltered.into(),
+			}
+			.into(),
+		);
+		assert_eq!(Balances::free_balance(2), 110);
+
+		// Pausing the utility pallet pauses the batch itself.
+		assert_ok!(TxPause::pause(Origin::root(), full_name(b"Utility", None)));
+		assert_filtered(
+			Call::Utility(pallet_utility::Call::batch { calls: vec![transfer_keep_alive(2, 10)] }),
+			Origin::signed(1),
+		);
+	});
+}
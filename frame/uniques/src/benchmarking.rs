@@ -379,5 +379,198 @@ benchmarks_instance_pallet! {
 		assert_last_event::<T, I>(Event::ApprovalCancelled { class, instance, owner: caller, delegate }.into());
 	}
 
+	set_price {
+		let (class, caller, _) = create_class::<T, I>();
+		let (instance, ..) = mint_instance::<T, I>(0);
+		let delegate: T::AccountId = account("delegate", 0, SEED);
+		let delegate_lookup = T::Lookup::unlookup(delegate.clone());
+		let price = InstancePrice::<T, I>::from(100u32);
+	}: _(SystemOrigin::Signed(caller.clone()), class, instance, Some(price), Some(delegate_lookup))
+	verify {
+		assert_last_event::<T, I>(Event::InstancePriceSet {
+			class,
+			instance,
+			price,
+			whitelisted_buyer: Some(delegate),
+		}.into());
+	}
+
+	buy_item {
+		let (class, seller, _) = create_class::<T, I>();
+		let (instance, ..) = mint_instance::<T, I>(0);
+		let buyer: T::AccountId = account("buyer", 0, SEED);
+		let buyer_lookup = T::Lookup::unlookup(buyer.clone());
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+		let recipient_lookup = T::Lookup::unlookup(recipient.clone());
+		let price = T::Currency::minimum_balance().saturating_mul(100u32.into());
+		T::Currency::make_free_balance_be(&buyer, price.saturating_mul(2u32.into()));
+		T::Currency::make_free_balance_be(&recipient, T::Currency::minimum_balance());
+		Uniques::<T, I>::set_royalty(
+			SystemOrigin::Signed(seller.clone()).into(),
+			class,
+			Some((recipient_lookup, Perbill::from_percent(10))),
+		)?;
+		Uniques::<T, I>::set_price(
+			SystemOrigin::Signed(seller.clone()).into(),
+			class,
+			instance,
+			Some(price),
+			Some(buyer_lookup),
+		)?;
+	}: _(SystemOrigin::Signed(buyer.clone()), class, instance, price)
+	verify {
+		assert_last_event::<T, I>(Event::InstanceBought {
+			class,
+			instance,
+			price,
+			seller,
+			buyer,
+		}.into());
+	}
+
+	set_royalty {
+		let (class, caller, _) = create_class::<T, I>();
+		let recipient: T::AccountId = account("recipient", 0, SEED);
+		let recipient_lookup = T::Lookup::unlookup(recipient.clone());
+		let rate = Perbill::from_percent(10);
+	}: _(SystemOrigin::Signed(caller), class, Some((recipient_lookup, rate)))
+	verify {
+		assert_last_event::<T, I>(Event::RoyaltySet { class, recipient, rate }.into());
+	}
+
+	create_swap {
+		let (class, caller, _) = create_class::<T, I>();
+		let (instance, ..) = mint_instance::<T, I>(0);
+		let (desired_instance, ..) = mint_instance::<T, I>(1);
+	}: _(SystemOrigin::Signed(caller), class, instance, class, Some(desired_instance))
+	verify {
+		assert_last_event::<T, I>(Event::SwapCreated {
+			class,
+			instance,
+			desired_class: class,
+			desired_instance: Some(desired_instance),
+		}.into());
+	}
+
+	cancel_swap {
+		let (class, caller, _) = create_class::<T, I>();
+		let (instance, ..) = mint_instance::<T, I>(0);
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		Uniques::<T, I>::create_swap(origin, class, instance, class, None)?;
+	}: _(SystemOrigin::Signed(caller), class, instance)
+	verify {
+		assert_last_event::<T, I>(Event::SwapCancelled { class, instance }.into());
+	}
+
+	claim_swap {
+		let (class, caller, _) = create_class::<T, I>();
+		let (instance, ..) = mint_instance::<T, I>(0);
+		let (send_instance, ..) = mint_instance::<T, I>(1);
+		let claimer: T::AccountId = account("claimer", 0, SEED);
+		let claimer_lookup = T::Lookup::unlookup(claimer.clone());
+		Uniques::<T, I>::transfer(
+			SystemOrigin::Signed(caller.clone()).into(),
+			class,
+			send_instance,
+			claimer_lookup,
+		)?;
+		let origin = SystemOrigin::Signed(caller.clone()).into();
+		Uniques::<T, I>::create_swap(origin, class, instance, class, Some(send_instance))?;
+	}: _(SystemOrigin::Signed(claimer.clone()), class, send_instance, class, instance)
+	verify {
+		assert_last_event::<T, I>(Event::SwapClaimed {
+			class,
+			instance,
+			exchanged_class: class,
+			exchanged_instance: send_instance,
+			offerer: caller,
+			claimer,
+		}.into());
+	}
+
+	set_mint_settings {
+		let (class, caller, _) = create_class::<T, I>();
+		let settings = MintSettings {
+			mint_type: MintType::Public,
+			price: Some(T::Currency::minimum_balance()),
+			start_block: Some(1u32.into()),
+			end_block: Some(100u32.into()),
+			max_supply: Some(100),
+		};
+	}: _(SystemOrigin::Signed(caller), class, settings)
+	verify {
+		assert_last_event::<T, I>(Event::MintSettingsSet { class, settings }.into());
+	}
+
+	public_mint {
+		let (class, caller, _) = create_class::<T, I>();
+		let (held_instance, ..) = mint_instance::<T, I>(0);
+		let minter: T::AccountId = account("minter", 0, SEED);
+		let minter_lookup = T::Lookup::unlookup(minter.clone());
+		// leave room for the mint price to be paid to the class owner
+		let balance = DepositBalanceOf::<T, I>::max_value() / 4u32.into();
+		T::Currency::make_free_balance_be(&caller, balance);
+		T::Currency::make_free_balance_be(&minter, balance);
+		Uniques::<T, I>::transfer(
+			SystemOrigin::Signed(caller.clone()).into(),
+			class,
+			held_instance,
+			minter_lookup,
+		)?;
+		// The worst case is a class requiring to hold an instance of another class.
+		let settings = MintSettings {
+			mint_type: MintType::HolderOf(class),
+			price: Some(T::Currency::minimum_balance()),
+			start_block: Some(0u32.into()),
+			end_block: Some(100u32.into()),
+			max_supply: Some(100),
+		};
+		Uniques::<T, I>::set_mint_settings(SystemOrigin::Signed(caller).into(), class, settings)?;
+		let instance = 1u16.into();
+	}: _(SystemOrigin::Signed(minter.clone()), class, instance, Some(held_instance))
+	verify {
+		assert_last_event::<T, I>(Event::Issued { class, instance, owner: minter }.into());
+	}
+
+	mint_pre_signed {
+		let n in 0 .. T::MaxAttributesPerCall::get();
+		let (class, caller, _) = create_class::<T, I>();
+		let minter: T::AccountId = account("minter", 0, SEED);
+		let attributes = (0..n)
+			.map(|i| {
+				let mut key = vec![0u8; T::KeyLimit::get() as usize];
+				key.iter_mut().zip(i.to_le_bytes()).for_each(|(k, b)| *k = b);
+				(key, vec![0u8; T::ValueLimit::get() as usize])
+			})
+			.collect();
+		let mint_data = PreSignedMint {
+			class,
+			instance: 0u16.into(),
+			attributes,
+			metadata: vec![0u8; T::StringLimit::get() as usize],
+			only_account: Some(minter.clone()),
+			deadline: 100u32.into(),
+		};
+		let (public, signature) = T::Helper::sign(&mint_data.signing_payload());
+		T::Currency::make_free_balance_be(&minter, DepositBalanceOf::<T, I>::max_value());
+		let signer = public.into_account();
+		let signer_lookup = T::Lookup::unlookup(signer.clone());
+		Uniques::<T, I>::set_team(
+			SystemOrigin::Signed(caller.clone()).into(),
+			class,
+			signer_lookup.clone(),
+			signer_lookup.clone(),
+			signer_lookup,
+		)?;
+	}: _(SystemOrigin::Signed(minter.clone()), Box::new(mint_data), signature, signer)
+	verify {
+		assert_last_event::<T, I>(Event::MetadataSet {
+			class,
+			instance: 0u16.into(),
+			data: vec![0u8; T::StringLimit::get() as usize].try_into().unwrap(),
+			is_frozen: false,
+		}.into());
+	}
+
 	impl_benchmark_test_suite!(Uniques, crate::mock::new_test_ext(), crate::mock::Test);
 }
@@ -18,7 +18,11 @@
 //! Various pieces of common functionality.
 
 use super::*;
-use frame_support::{ensure, traits::Get};
+use frame_support::{
+	ensure,
+	traits::{ExistenceRequirement, Get},
+	transactional, BoundedVec,
+};
 use sp_runtime::{DispatchError, DispatchResult};
 
 impl<T: Config<I>, I: 'static> Pallet<T, I> {
@@ -43,6 +47,8 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 		let origin = details.owner;
 		details.owner = dest;
 		Asset::<T, I>::insert(&class, &instance, &details);
+		InstancePriceOf::<T, I>::remove(&class, &instance);
+		PendingSwapOf::<T, I>::remove(&class, &instance);
 
 		Self::deposit_event(Event::Transferred {
 			class,
@@ -103,10 +109,17 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 			);
 			ensure!(class_details.attributes == witness.attributes, Error::<T, I>::BadWitness);
 
+			// Listings, swaps and holder mints exist at most once per instance and are therefore
+			// covered by the instances witness.
 			for (instance, details) in Asset::<T, I>::drain_prefix(&class) {
 				Account::<T, I>::remove((&details.owner, &class, &instance));
+				InstancePriceOf::<T, I>::remove(&class, &instance);
+				PendingSwapOf::<T, I>::remove(&class, &instance);
+				Self::release_holder_mint(&class, &instance);
 			}
 			InstanceMetadataOf::<T, I>::remove_prefix(&class, None);
+			ClassRoyaltyOf::<T, I>::remove(&class);
+			ClassMintSettings::<T, I>::remove(&class);
 			ClassMetadataOf::<T, I>::remove(&class);
 			Attribute::<T, I>::remove_prefix((&class,), None);
 			ClassAccount::<T, I>::remove(&class_details.owner, &class);
@@ -137,6 +150,11 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 			let instances =
 				class_details.instances.checked_add(1).ok_or(ArithmeticError::Overflow)?;
+			if let Some(max_supply) =
+				ClassMintSettings::<T, I>::get(&class).and_then(|s| s.max_supply)
+			{
+				ensure!(instances <= max_supply, Error::<T, I>::MaxSupplyReached);
+			}
 			class_details.instances = instances;
 
 			let deposit = match class_details.free_holding {
@@ -180,8 +198,391 @@ impl<T: Config<I>, I: 'static> Pallet<T, I> {
 
 		Asset::<T, I>::remove(&class, &instance);
 		Account::<T, I>::remove((&owner, &class, &instance));
+		InstancePriceOf::<T, I>::remove(&class, &instance);
+		PendingSwapOf::<T, I>::remove(&class, &instance);
+		Self::release_holder_mint(&class, &instance);
 
 		Self::deposit_event(Event::Burned { class, instance, owner });
 		Ok(())
 	}
+
+	pub fn do_set_price(
+		class: T::ClassId,
+		instance: T::InstanceId,
+		sender: T::AccountId,
+		price: Option<InstancePrice<T, I>>,
+		whitelisted_buyer: Option<T::AccountId>,
+	) -> DispatchResult {
+		let details = Asset::<T, I>::get(&class, &instance).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(details.owner == sender, Error::<T, I>::NoPermission);
+
+		if let Some(price) = price {
+			// The royalty is fixed at listing time so that it can not be raised under the seller.
+			let royalty_rate = ClassRoyaltyOf::<T, I>::get(&class)
+				.map_or_else(Perbill::zero, |royalty| royalty.rate);
+			InstancePriceOf::<T, I>::insert(
+				&class,
+				&instance,
+				(price, whitelisted_buyer.clone(), royalty_rate),
+			);
+			Self::deposit_event(Event::InstancePriceSet {
+				class,
+				instance,
+				price,
+				whitelisted_buyer,
+			});
+		} else {
+			InstancePriceOf::<T, I>::remove(&class, &instance);
+			Self::deposit_event(Event::InstancePriceRemoved { class, instance });
+		}
+
+		Ok(())
+	}
+
+	#[transactional]
+	pub fn do_buy_item(
+		class: T::ClassId,
+		instance: T::InstanceId,
+		buyer: T::AccountId,
+		bid_price: InstancePrice<T, I>,
+	) -> DispatchResult {
+		let details = Asset::<T, I>::get(&class, &instance).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(details.owner != buyer, Error::<T, I>::NoPermission);
+
+		let (price, whitelisted_buyer, max_royalty_rate) =
+			InstancePriceOf::<T, I>::get(&class, &instance).ok_or(Error::<T, I>::NotForSale)?;
+		ensure!(bid_price >= price, Error::<T, I>::BidTooLow);
+		if let Some(only_buyer) = whitelisted_buyer {
+			ensure!(only_buyer == buyer, Error::<T, I>::NoPermission);
+		}
+
+		let seller = details.owner;
+		let mut proceeds = price;
+		if let Some(royalty) = ClassRoyaltyOf::<T, I>::get(&class) {
+			let amount = royalty.rate.min(max_royalty_rate) * price;
+			if !amount.is_zero() {
+				T::Currency::transfer(
+					&buyer,
+					&royalty.recipient,
+					amount,
+					ExistenceRequirement::KeepAlive,
+				)?;
+				proceeds.saturating_reduce(amount);
+			}
+		}
+		T::Currency::transfer(&buyer, &seller, proceeds, ExistenceRequirement::KeepAlive)?;
+		Self::do_transfer(class, instance, buyer.clone(), |_, _| Ok(()))?;
+
+		Self::deposit_event(Event::InstanceBought { class, instance, price, seller, buyer });
+		Ok(())
+	}
+
+	pub fn do_create_swap(
+		class: T::ClassId,
+		instance: T::InstanceId,
+		sender: T::AccountId,
+		desired_class: T::ClassId,
+		desired_instance: Option<T::InstanceId>,
+	) -> DispatchResult {
+		let details = Asset::<T, I>::get(&class, &instance).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(details.owner == sender, Error::<T, I>::NoPermission);
+		match desired_instance {
+			Some(desired_instance) => ensure!(
+				Asset::<T, I>::contains_key(&desired_class, &desired_instance),
+				Error::<T, I>::Unknown
+			),
+			None => ensure!(Class::<T, I>::contains_key(&desired_class), Error::<T, I>::Unknown),
+		}
+
+		PendingSwapOf::<T, I>::insert(
+			&class,
+			&instance,
+			PendingSwap { desired_class, desired_instance },
+		);
+		Self::deposit_event(Event::SwapCreated {
+			class,
+			instance,
+			desired_class,
+			desired_instance,
+		});
+		Ok(())
+	}
+
+	pub fn do_cancel_swap(
+		class: T::ClassId,
+		instance: T::InstanceId,
+		sender: T::AccountId,
+	) -> DispatchResult {
+		let details = Asset::<T, I>::get(&class, &instance).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(details.owner == sender, Error::<T, I>::NoPermission);
+		ensure!(PendingSwapOf::<T, I>::contains_key(&class, &instance), Error::<T, I>::UnknownSwap);
+
+		PendingSwapOf::<T, I>::remove(&class, &instance);
+		Self::deposit_event(Event::SwapCancelled { class, instance });
+		Ok(())
+	}
+
+	#[transactional]
+	pub fn do_claim_swap(
+		send_class: T::ClassId,
+		send_instance: T::InstanceId,
+		receive_class: T::ClassId,
+		receive_instance: T::InstanceId,
+		sender: T::AccountId,
+	) -> DispatchResult {
+		let send_details =
+			Asset::<T, I>::get(&send_class, &send_instance).ok_or(Error::<T, I>::Unknown)?;
+		ensure!(send_details.owner == sender, Error::<T, I>::NoPermission);
+		let receive_details =
+			Asset::<T, I>::get(&receive_class, &receive_instance).ok_or(Error::<T, I>::Unknown)?;
+
+		let swap = PendingSwapOf::<T, I>::get(&receive_class, &receive_instance)
+			.ok_or(Error::<T, I>::UnknownSwap)?;
+		ensure!(swap.desired_class == send_class, Error::<T, I>::UnknownSwap);
+		if let Some(desired_instance) = swap.desired_instance {
+			ensure!(desired_instance == send_instance, Error::<T, I>::UnknownSwap);
+		}
+
+		let offerer = receive_details.owner;
+		Self::do_transfer(send_class, send_instance, offerer.clone(), |_, _| Ok(()))?;
+		Self::do_transfer(receive_class, receive_instance, sender.clone(), |_, _| Ok(()))?;
+
+		Self::deposit_event(Event::SwapClaimed {
+			class: receive_class,
+			instance: receive_instance,
+			exchanged_class: send_class,
+			exchanged_instance: send_instance,
+			offerer,
+			claimer: sender,
+		});
+		Ok(())
+	}
+
+	pub fn do_set_attribute(
+		class: T::ClassId,
+		maybe_instance: Option<T::InstanceId>,
+		key: BoundedVec<u8, T::KeyLimit>,
+		value: BoundedVec<u8, T::ValueLimit>,
+		maybe_check_owner: Option<T::AccountId>,
+		maybe_depositor: Option<&T::AccountId>,
+	) -> DispatchResult {
+		let mut class_details = Class::<T, I>::get(&class).ok_or(Error::<T, I>::Unknown)?;
+		if let Some(check_owner) = &maybe_check_owner {
+			ensure!(check_owner == &class_details.owner, Error::<T, I>::NoPermission);
+		}
+		let maybe_is_frozen = match maybe_instance {
+			None => ClassMetadataOf::<T, I>::get(class).map(|v| v.is_frozen),
+			Some(instance) => InstanceMetadataOf::<T, I>::get(class, instance).map(|v| v.is_frozen),
+		};
+		ensure!(!maybe_is_frozen.unwrap_or(false), Error::<T, I>::Frozen);
+
+		let attribute = Attribute::<T, I>::get((class, maybe_instance, &key));
+		if attribute.is_none() {
+			class_details.attributes.saturating_inc();
+		}
+		let old_deposit = attribute.map_or(Zero::zero(), |m| m.1);
+		class_details.total_deposit.saturating_reduce(old_deposit);
+		let mut deposit = Zero::zero();
+		if !class_details.free_holding && maybe_check_owner.is_some() {
+			deposit = T::DepositPerByte::get()
+				.saturating_mul(((key.len() + value.len()) as u32).into())
+				.saturating_add(T::AttributeDepositBase::get());
+		}
+		class_details.total_deposit.saturating_accrue(deposit);
+		if deposit > old_deposit {
+			Self::reserve_deposit(&class_details.owner, maybe_depositor, deposit - old_deposit)?;
+		} else if deposit < old_deposit {
+			T::Currency::unreserve(&class_details.owner, old_deposit - deposit);
+		}
+
+		Attribute::<T, I>::insert((&class, maybe_instance, &key), (&value, deposit));
+		Class::<T, I>::insert(class, &class_details);
+		Self::deposit_event(Event::AttributeSet { class, maybe_instance, key, value });
+		Ok(())
+	}
+
+	pub fn do_set_instance_metadata(
+		class: T::ClassId,
+		instance: T::InstanceId,
+		data: BoundedVec<u8, T::StringLimit>,
+		is_frozen: bool,
+		maybe_check_owner: Option<T::AccountId>,
+		maybe_depositor: Option<&T::AccountId>,
+	) -> DispatchResult {
+		let mut class_details = Class::<T, I>::get(&class).ok_or(Error::<T, I>::Unknown)?;
+
+		if let Some(check_owner) = &maybe_check_owner {
+			ensure!(check_owner == &class_details.owner, Error::<T, I>::NoPermission);
+		}
+
+		InstanceMetadataOf::<T, I>::try_mutate_exists(class, instance, |metadata| {
+			let was_frozen = metadata.as_ref().map_or(false, |m| m.is_frozen);
+			ensure!(maybe_check_owner.is_none() || !was_frozen, Error::<T, I>::Frozen);
+
+			if metadata.is_none() {
+				class_details.instance_metadatas.saturating_inc();
+			}
+			let old_deposit = metadata.take().map_or(Zero::zero(), |m| m.deposit);
+			class_details.total_deposit.saturating_reduce(old_deposit);
+			let mut deposit = Zero::zero();
+			if !class_details.free_holding && maybe_check_owner.is_some() {
+				deposit = T::DepositPerByte::get()
+					.saturating_mul(((data.len()) as u32).into())
+					.saturating_add(T::MetadataDepositBase::get());
+			}
+			if deposit > old_deposit {
+				Self::reserve_deposit(
+					&class_details.owner,
+					maybe_depositor,
+					deposit - old_deposit,
+				)?;
+			} else if deposit < old_deposit {
+				T::Currency::unreserve(&class_details.owner, old_deposit - deposit);
+			}
+			class_details.total_deposit.saturating_accrue(deposit);
+
+			*metadata = Some(InstanceMetadata { deposit, data: data.clone(), is_frozen });
+
+			Class::<T, I>::insert(&class, &class_details);
+			Self::deposit_event(Event::MetadataSet { class, instance, data, is_frozen });
+			Ok(())
+		})
+	}
+
+	/// Reserve `amount` as a deposit of the class owned by `owner`.
+	///
+	/// If a `maybe_depositor` other than `owner` is given, the deposit is paid by it and moved to
+	/// the reserve of `owner`, which holds all deposits of the class.
+	fn reserve_deposit(
+		owner: &T::AccountId,
+		maybe_depositor: Option<&T::AccountId>,
+		amount: DepositBalanceOf<T, I>,
+	) -> DispatchResult {
+		match maybe_depositor {
+			Some(depositor) if depositor != owner => {
+				T::Currency::reserve(depositor, amount)?;
+				T::Currency::repatriate_reserved(depositor, owner, amount, Reserved)?;
+			},
+			_ => T::Currency::reserve(owner, amount)?,
+		}
+		Ok(())
+	}
+
+	/// Allow the held instance that `instance` was minted with, if any, to be used for minting
+	/// again.
+	fn release_holder_mint(class: &T::ClassId, instance: &T::InstanceId) {
+		if let Some((held_class, held_instance)) = HolderMintOf::<T, I>::take(class, instance) {
+			HolderMinted::<T, I>::remove((class, &held_class, &held_instance));
+		}
+	}
+
+	pub fn do_set_mint_settings(
+		class: T::ClassId,
+		settings: MintSettingsFor<T, I>,
+		maybe_check_owner: Option<T::AccountId>,
+	) -> DispatchResult {
+		let details = Class::<T, I>::get(&class).ok_or(Error::<T, I>::Unknown)?;
+		if let Some(check_owner) = &maybe_check_owner {
+			ensure!(check_owner == &details.owner, Error::<T, I>::NoPermission);
+		}
+		if let Some(max_supply) = settings.max_supply {
+			ensure!(max_supply >= details.instances, Error::<T, I>::MaxSupplyTooSmall);
+		}
+
+		ClassMintSettings::<T, I>::insert(&class, &settings);
+		Self::deposit_event(Event::MintSettingsSet { class, settings });
+		Ok(())
+	}
+
+	#[transactional]
+	pub fn do_public_mint(
+		class: T::ClassId,
+		instance: T::InstanceId,
+		who: T::AccountId,
+		holder_instance: Option<T::InstanceId>,
+	) -> DispatchResult {
+		let settings = ClassMintSettings::<T, I>::get(&class).unwrap_or_default();
+		let now = frame_system::Pallet::<T>::block_number();
+		if let Some(start_block) = settings.start_block {
+			ensure!(now >= start_block, Error::<T, I>::MintNotStarted);
+		}
+		if let Some(end_block) = settings.end_block {
+			ensure!(now <= end_block, Error::<T, I>::MintEnded);
+		}
+
+		match settings.mint_type {
+			MintType::Issuer => return Err(Error::<T, I>::MintNotAllowed.into()),
+			MintType::Public => (),
+			MintType::HolderOf(held_class) => {
+				let held_instance = holder_instance.ok_or(Error::<T, I>::BadWitness)?;
+				ensure!(
+					Account::<T, I>::contains_key((&who, &held_class, &held_instance)),
+					Error::<T, I>::MintNotAllowed
+				);
+				let key = (&class, &held_class, &held_instance);
+				ensure!(!HolderMinted::<T, I>::contains_key(key), Error::<T, I>::AlreadyClaimed);
+				HolderMinted::<T, I>::insert(key, ());
+				HolderMintOf::<T, I>::insert(&class, &instance, (held_class, held_instance));
+			},
+		}
+
+		let class_owner = Class::<T, I>::get(&class).ok_or(Error::<T, I>::Unknown)?.owner;
+		if let Some(price) = settings.price {
+			T::Currency::transfer(&who, &class_owner, price, ExistenceRequirement::KeepAlive)?;
+		}
+
+		Self::do_mint(class, instance, who, |_| Ok(()))
+	}
+
+	#[transactional]
+	pub fn do_mint_pre_signed(
+		mint_to: T::AccountId,
+		mint_data: PreSignedMintOf<T, I>,
+		signer: T::AccountId,
+	) -> DispatchResult {
+		let PreSignedMint { class, instance, attributes, metadata, only_account, deadline } =
+			mint_data;
+
+		ensure!(
+			attributes.len() <= T::MaxAttributesPerCall::get() as usize,
+			Error::<T, I>::MaxAttributesLimitReached
+		);
+		if let Some(account) = only_account {
+			ensure!(account == mint_to, Error::<T, I>::NoPermission);
+		}
+		let now = frame_system::Pallet::<T>::block_number();
+		ensure!(deadline >= now, Error::<T, I>::DeadlineExpired);
+
+		let class_owner = Class::<T, I>::get(&class).ok_or(Error::<T, I>::Unknown)?.owner;
+		Self::do_mint(class, instance, mint_to.clone(), |class_details| {
+			ensure!(class_details.issuer == signer, Error::<T, I>::NoPermission);
+			Ok(())
+		})?;
+
+		// The data is set as if by the class owner, but the deposits are paid by the minter.
+		for (key, value) in attributes {
+			let key = BoundedVec::try_from(key).map_err(|_| Error::<T, I>::IncorrectData)?;
+			let value = BoundedVec::try_from(value).map_err(|_| Error::<T, I>::IncorrectData)?;
+			Self::do_set_attribute(
+				class,
+				Some(instance),
+				key,
+				value,
+				Some(class_owner.clone()),
+				Some(&mint_to),
+			)?;
+		}
+		if !metadata.is_empty() {
+			let data = BoundedVec::try_from(metadata).map_err(|_| Error::<T, I>::IncorrectData)?;
+			Self::do_set_instance_metadata(
+				class,
+				instance,
+				data,
+				false,
+				Some(class_owner),
+				Some(&mint_to),
+			)?;
+		}
+		Ok(())
+	}
 }
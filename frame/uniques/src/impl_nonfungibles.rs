@@ -175,3 +175,34 @@ impl<T: Config<I>, I: 'static> InspectEnumerable<T::AccountId> for Pallet<T, I>
 		Box::new(Account::<T, I>::iter_key_prefix((who, class)))
 	}
 }
+
+impl<T: Config<I>, I: 'static> Trading<T::AccountId, InstancePrice<T, I>> for Pallet<T, I> {
+	fn instance_price(
+		class: &Self::ClassId,
+		instance: &Self::InstanceId,
+	) -> Option<InstancePrice<T, I>> {
+		InstancePriceOf::<T, I>::get(class, instance).map(|(price, ..)| price)
+	}
+
+	fn royalty(class: &Self::ClassId) -> Option<(T::AccountId, Perbill)> {
+		ClassRoyaltyOf::<T, I>::get(class).map(|royalty| (royalty.recipient, royalty.rate))
+	}
+
+	fn set_price(
+		class: &Self::ClassId,
+		instance: &Self::InstanceId,
+		who: &T::AccountId,
+		price: Option<InstancePrice<T, I>>,
+	) -> DispatchResult {
+		Self::do_set_price(class.clone(), instance.clone(), who.clone(), price, None)
+	}
+
+	fn buy(
+		class: &Self::ClassId,
+		instance: &Self::InstanceId,
+		buyer: &T::AccountId,
+		bid_price: InstancePrice<T, I>,
+	) -> DispatchResult {
+		Self::do_buy_item(class.clone(), instance.clone(), buyer.clone(), bid_price)
+	}
+}
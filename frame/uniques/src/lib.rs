@@ -45,8 +45,8 @@ use codec::{Decode, Encode, HasCompact};
 use frame_support::traits::{BalanceStatus::Reserved, Currency, ReservableCurrency};
 use frame_system::Config as SystemConfig;
 use sp_runtime::{
-	traits::{Saturating, StaticLookup, Zero},
-	ArithmeticError, RuntimeDebug,
+	traits::{IdentifyAccount, Saturating, StaticLookup, Verify, Zero},
+	ArithmeticError, Perbill, RuntimeDebug,
 };
 use sp_std::prelude::*;
 
@@ -54,6 +54,28 @@ pub use pallet::*;
 pub use types::*;
 pub use weights::WeightInfo;
 
+/// Creates signed mints for the benchmarks of `mint_pre_signed`.
+#[cfg(feature = "runtime-benchmarks")]
+pub trait BenchmarkHelper<Public, Signature> {
+	/// Creates a new key and signs `message` with it.
+	fn sign(message: &[u8]) -> (Public, Signature);
+}
+
+#[cfg(feature = "runtime-benchmarks")]
+impl<Public, Signature> BenchmarkHelper<Public, Signature> for ()
+where
+	Public: From<sp_core::sr25519::Public>,
+	Signature: From<sp_core::sr25519::Signature>,
+{
+	fn sign(message: &[u8]) -> (Public, Signature) {
+		let key_type = sp_core::crypto::key_types::DUMMY;
+		let public = sp_io::crypto::sr25519_generate(key_type, None);
+		let signature = sp_io::crypto::sr25519_sign(key_type, &public, message)
+			.expect("The key was generated above; qed");
+		(public.into(), signature.into())
+	}
+}
+
 #[frame_support::pallet]
 pub mod pallet {
 	use super::*;
@@ -117,6 +139,20 @@ pub mod pallet {
 		#[pallet::constant]
 		type ValueLimit: Get<u32>;
 
+		/// The maximum number of attributes that can be set by a single `mint_pre_signed`.
+		#[pallet::constant]
+		type MaxAttributesPerCall: Get<u32>;
+
+		/// The signature used by issuers to sign mints off-chain.
+		type OffchainSignature: Verify<Signer = Self::OffchainPublic> + Parameter;
+
+		/// The public key which identifies the signer of an off-chain signed mint.
+		type OffchainPublic: IdentifyAccount<AccountId = Self::AccountId>;
+
+		/// A helper to sign pre-signed mints in benchmarks.
+		#[cfg(feature = "runtime-benchmarks")]
+		type Helper: BenchmarkHelper<Self::OffchainPublic, Self::OffchainSignature>;
+
 		/// Weight information for extrinsics in this pallet.
 		type WeightInfo: WeightInfo;
 	}
@@ -204,6 +240,68 @@ pub mod pallet {
 		OptionQuery,
 	>;
 
+	#[pallet::storage]
+	/// The price of asset instances which are for sale, the only account allowed to buy them,
+	/// if any, and the royalty rate of their class at the time the price was set.
+	pub(super) type InstancePriceOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::ClassId,
+		Blake2_128Concat,
+		T::InstanceId,
+		(InstancePrice<T, I>, Option<T::AccountId>, Perbill),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// The royalty paid on every sale of an instance of an asset class.
+	pub(super) type ClassRoyaltyOf<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::ClassId, ClassRoyalty<T::AccountId>, OptionQuery>;
+
+	#[pallet::storage]
+	/// Offers to swap an asset instance for an instance of another class.
+	pub(super) type PendingSwapOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::ClassId,
+		Blake2_128Concat,
+		T::InstanceId,
+		PendingSwap<T::ClassId, T::InstanceId>,
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// The conditions under which instances of an asset class may be minted.
+	pub(super) type ClassMintSettings<T: Config<I>, I: 'static = ()> =
+		StorageMap<_, Blake2_128Concat, T::ClassId, MintSettingsFor<T, I>, OptionQuery>;
+
+	#[pallet::storage]
+	/// The instances of a `MintType::HolderOf` class that were already used to mint an instance
+	/// of another class. An entry is removed together with the instance minted with it.
+	pub(super) type HolderMinted<T: Config<I>, I: 'static = ()> = StorageNMap<
+		_,
+		(
+			NMapKey<Blake2_128Concat, T::ClassId>, // minted class
+			NMapKey<Blake2_128Concat, T::ClassId>, // held class
+			NMapKey<Blake2_128Concat, T::InstanceId>,
+		),
+		(),
+		OptionQuery,
+	>;
+
+	#[pallet::storage]
+	/// The held class and instance an asset instance was minted with through
+	/// `MintType::HolderOf`.
+	pub(super) type HolderMintOf<T: Config<I>, I: 'static = ()> = StorageDoubleMap<
+		_,
+		Blake2_128Concat,
+		T::ClassId,
+		Blake2_128Concat,
+		T::InstanceId,
+		(T::ClassId, T::InstanceId),
+		OptionQuery,
+	>;
+
 	#[pallet::event]
 	#[pallet::generate_deposit(pub(super) fn deposit_event)]
 	pub enum Event<T: Config<I>, I: 'static = ()> {
@@ -291,6 +389,48 @@ pub mod pallet {
 			maybe_instance: Option<T::InstanceId>,
 			key: BoundedVec<u8, T::KeyLimit>,
 		},
+		/// The price for the asset `instance` was set.
+		InstancePriceSet {
+			class: T::ClassId,
+			instance: T::InstanceId,
+			price: InstancePrice<T, I>,
+			whitelisted_buyer: Option<T::AccountId>,
+		},
+		/// The price for the asset `instance` was removed.
+		InstancePriceRemoved { class: T::ClassId, instance: T::InstanceId },
+		/// An asset `instance` was bought.
+		InstanceBought {
+			class: T::ClassId,
+			instance: T::InstanceId,
+			price: InstancePrice<T, I>,
+			seller: T::AccountId,
+			buyer: T::AccountId,
+		},
+		/// The royalty of an asset `class` was set.
+		RoyaltySet { class: T::ClassId, recipient: T::AccountId, rate: Perbill },
+		/// The royalty of an asset `class` was removed.
+		RoyaltyRemoved { class: T::ClassId },
+		/// A swap of the asset `instance` for an instance of `desired_class` was offered.
+		SwapCreated {
+			class: T::ClassId,
+			instance: T::InstanceId,
+			desired_class: T::ClassId,
+			desired_instance: Option<T::InstanceId>,
+		},
+		/// The swap offered for the asset `instance` was cancelled.
+		SwapCancelled { class: T::ClassId, instance: T::InstanceId },
+		/// The swap offered for the asset `instance` was claimed by handing over
+		/// `exchanged_instance` of `exchanged_class`.
+		SwapClaimed {
+			class: T::ClassId,
+			instance: T::InstanceId,
+			exchanged_class: T::ClassId,
+			exchanged_instance: T::InstanceId,
+			offerer: T::AccountId,
+			claimer: T::AccountId,
+		},
+		/// The mint settings of an asset `class` were changed.
+		MintSettingsSet { class: T::ClassId, settings: MintSettingsFor<T, I> },
 	}
 
 	#[pallet::error]
@@ -315,6 +455,33 @@ pub mod pallet {
 		NoDelegate,
 		/// No approval exists that would allow the transfer.
 		Unapproved,
+		/// The asset instance is not for sale.
+		NotForSale,
+		/// The bid is lower than the price of the asset instance.
+		BidTooLow,
+		/// No swap is offered for the asset instance or the swap does not accept the instance
+		/// offered in exchange.
+		UnknownSwap,
+		/// The asset class does not allow this account to mint.
+		MintNotAllowed,
+		/// Minting has not started yet.
+		MintNotStarted,
+		/// Minting has ended.
+		MintEnded,
+		/// The maximum supply of the asset class has been reached.
+		MaxSupplyReached,
+		/// The maximum supply is lower than the number of outstanding instances.
+		MaxSupplyTooSmall,
+		/// The held instance was already used to mint an instance of this class.
+		AlreadyClaimed,
+		/// The signature of a pre-signed mint is invalid.
+		WrongSignature,
+		/// The deadline of a pre-signed mint has passed.
+		DeadlineExpired,
+		/// A pre-signed mint contains too many attributes.
+		MaxAttributesLimitReached,
+		/// A pre-signed mint contains a key, value or metadata that is too long.
+		IncorrectData,
 	}
 
 	impl<T: Config<I>, I: 'static> Pallet<T, I> {
@@ -967,40 +1134,7 @@ pub mod pallet {
 				.map(|_| None)
 				.or_else(|origin| ensure_signed(origin).map(Some))?;
 
-			let mut class_details = Class::<T, I>::get(&class).ok_or(Error::<T, I>::Unknown)?;
-			if let Some(check_owner) = &maybe_check_owner {
-				ensure!(check_owner == &class_details.owner, Error::<T, I>::NoPermission);
-			}
-			let maybe_is_frozen = match maybe_instance {
-				None => ClassMetadataOf::<T, I>::get(class).map(|v| v.is_frozen),
-				Some(instance) =>
-					InstanceMetadataOf::<T, I>::get(class, instance).map(|v| v.is_frozen),
-			};
-			ensure!(!maybe_is_frozen.unwrap_or(false), Error::<T, I>::Frozen);
-
-			let attribute = Attribute::<T, I>::get((class, maybe_instance, &key));
-			if attribute.is_none() {
-				class_details.attributes.saturating_inc();
-			}
-			let old_deposit = attribute.map_or(Zero::zero(), |m| m.1);
-			class_details.total_deposit.saturating_reduce(old_deposit);
-			let mut deposit = Zero::zero();
-			if !class_details.free_holding && maybe_check_owner.is_some() {
-				deposit = T::DepositPerByte::get()
-					.saturating_mul(((key.len() + value.len()) as u32).into())
-					.saturating_add(T::AttributeDepositBase::get());
-			}
-			class_details.total_deposit.saturating_accrue(deposit);
-			if deposit > old_deposit {
-				T::Currency::reserve(&class_details.owner, deposit - old_deposit)?;
-			} else if deposit < old_deposit {
-				T::Currency::unreserve(&class_details.owner, old_deposit - deposit);
-			}
-
-			Attribute::<T, I>::insert((&class, maybe_instance, &key), (&value, deposit));
-			Class::<T, I>::insert(class, &class_details);
-			Self::deposit_event(Event::AttributeSet { class, maybe_instance, key, value });
-			Ok(())
+			Self::do_set_attribute(class, maybe_instance, key, value, maybe_check_owner, None)
 		}
 
 		/// Set an attribute for an asset class or instance.
@@ -1081,40 +1215,14 @@ pub mod pallet {
 				.map(|_| None)
 				.or_else(|origin| ensure_signed(origin).map(Some))?;
 
-			let mut class_details = Class::<T, I>::get(&class).ok_or(Error::<T, I>::Unknown)?;
-
-			if let Some(check_owner) = &maybe_check_owner {
-				ensure!(check_owner == &class_details.owner, Error::<T, I>::NoPermission);
-			}
-
-			InstanceMetadataOf::<T, I>::try_mutate_exists(class, instance, |metadata| {
-				let was_frozen = metadata.as_ref().map_or(false, |m| m.is_frozen);
-				ensure!(maybe_check_owner.is_none() || !was_frozen, Error::<T, I>::Frozen);
-
-				if metadata.is_none() {
-					class_details.instance_metadatas.saturating_inc();
-				}
-				let old_deposit = metadata.take().map_or(Zero::zero(), |m| m.deposit);
-				class_details.total_deposit.saturating_reduce(old_deposit);
-				let mut deposit = Zero::zero();
-				if !class_details.free_holding && maybe_check_owner.is_some() {
-					deposit = T::DepositPerByte::get()
-						.saturating_mul(((data.len()) as u32).into())
-						.saturating_add(T::MetadataDepositBase::get());
-				}
-				if deposit > old_deposit {
-					T::Currency::reserve(&class_details.owner, deposit - old_deposit)?;
-				} else if deposit < old_deposit {
-					T::Currency::unreserve(&class_details.owner, old_deposit - deposit);
-				}
-				class_details.total_deposit.saturating_accrue(deposit);
-
-				*metadata = Some(InstanceMetadata { deposit, data: data.clone(), is_frozen });
-
-				Class::<T, I>::insert(&class, &class_details);
-				Self::deposit_event(Event::MetadataSet { class, instance, data, is_frozen });
-				Ok(())
-			})
+			Self::do_set_instance_metadata(
+				class,
+				instance,
+				data,
+				is_frozen,
+				maybe_check_owner,
+				None,
+			)
 		}
 
 		/// Clear the metadata for an asset instance.
@@ -1258,5 +1366,255 @@ pub mod pallet {
 				Ok(())
 			})
 		}
+
+		/// Set (or reset) the price for an asset instance.
+		///
+		/// Origin must be Signed and must be the owner of the asset `instance`.
+		///
+		/// - `class`: The class of the asset to be priced.
+		/// - `instance`: The instance of the asset to be priced.
+		/// - `price`: The price for the asset. If `None`, the asset is no longer for sale.
+		/// - `whitelisted_buyer`: Restricts the buy operation to a specific account.
+		///
+		/// Emits `InstancePriceSet` on success if the price is not `None`.
+		/// Emits `InstancePriceRemoved` on success if the price is `None`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_price())]
+		pub fn set_price(
+			origin: OriginFor<T>,
+			#[pallet::compact] class: T::ClassId,
+			#[pallet::compact] instance: T::InstanceId,
+			price: Option<InstancePrice<T, I>>,
+			whitelisted_buyer: Option<<T::Lookup as StaticLookup>::Source>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let whitelisted_buyer = whitelisted_buyer.map(T::Lookup::lookup).transpose()?;
+			Self::do_set_price(class, instance, origin, price, whitelisted_buyer)
+		}
+
+		/// Buy an asset instance if it's up for sale.
+		///
+		/// Origin must be Signed and must not be the owner of the asset `instance`.
+		///
+		/// The price is paid to the owner of the asset `instance`, less the royalty configured
+		/// for the asset `class` which is paid to the royalty recipient. The royalty rate never
+		/// exceeds the one in force when the price was set.
+		///
+		/// - `class`: The class of the asset to be bought.
+		/// - `instance`: The instance of the asset to be bought.
+		/// - `bid_price`: The maximum price the buyer is willing to pay.
+		///
+		/// Emits `InstanceBought` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::buy_item())]
+		pub fn buy_item(
+			origin: OriginFor<T>,
+			#[pallet::compact] class: T::ClassId,
+			#[pallet::compact] instance: T::InstanceId,
+			bid_price: InstancePrice<T, I>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_buy_item(class, instance, origin, bid_price)
+		}
+
+		/// Set (or remove) the royalty paid on every sale of an instance of an asset class.
+		///
+		/// Origin must be either `ForceOrigin` or `Signed` and the sender should be the Owner of
+		/// the asset `class`.
+		///
+		/// - `class`: The identifier of the asset class whose royalty to update.
+		/// - `royalty`: The account receiving the royalty and the share of the sale price it
+		///   receives. If `None`, no royalty is paid.
+		///
+		/// Raising the rate does not affect asset instances that are already for sale. Those are
+		/// sold with the rate in force when their price was set.
+		///
+		/// Emits `RoyaltySet` or `RoyaltyRemoved`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_royalty())]
+		pub fn set_royalty(
+			origin: OriginFor<T>,
+			#[pallet::compact] class: T::ClassId,
+			royalty: Option<(<T::Lookup as StaticLookup>::Source, Perbill)>,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some))?;
+
+			let details = Class::<T, I>::get(&class).ok_or(Error::<T, I>::Unknown)?;
+			if let Some(check_owner) = &maybe_check_owner {
+				ensure!(check_owner == &details.owner, Error::<T, I>::NoPermission);
+			}
+
+			match royalty {
+				Some((recipient, rate)) => {
+					let recipient = T::Lookup::lookup(recipient)?;
+					ClassRoyaltyOf::<T, I>::insert(
+						&class,
+						ClassRoyalty { recipient: recipient.clone(), rate },
+					);
+					Self::deposit_event(Event::RoyaltySet { class, recipient, rate });
+				},
+				None => {
+					ClassRoyaltyOf::<T, I>::remove(&class);
+					Self::deposit_event(Event::RoyaltyRemoved { class });
+				},
+			}
+			Ok(())
+		}
+
+		/// Offer to swap an asset instance for an instance of another asset class.
+		///
+		/// Origin must be Signed and must be the owner of the asset `instance`. The offer is
+		/// removed when the asset instance is transferred or burned.
+		///
+		/// - `class`: The class of the asset offered.
+		/// - `instance`: The instance of the asset offered.
+		/// - `desired_class`: The class of the asset asked for in exchange.
+		/// - `desired_instance`: The instance of the asset asked for in exchange. If `None`, any
+		///   instance of `desired_class` is accepted.
+		///
+		/// Emits `SwapCreated` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::create_swap())]
+		pub fn create_swap(
+			origin: OriginFor<T>,
+			#[pallet::compact] class: T::ClassId,
+			#[pallet::compact] instance: T::InstanceId,
+			#[pallet::compact] desired_class: T::ClassId,
+			desired_instance: Option<T::InstanceId>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_create_swap(class, instance, origin, desired_class, desired_instance)
+		}
+
+		/// Cancel the swap offered for an asset instance.
+		///
+		/// Origin must be Signed and must be the owner of the asset `instance`.
+		///
+		/// - `class`: The class of the asset offered.
+		/// - `instance`: The instance of the asset offered.
+		///
+		/// Emits `SwapCancelled` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::cancel_swap())]
+		pub fn cancel_swap(
+			origin: OriginFor<T>,
+			#[pallet::compact] class: T::ClassId,
+			#[pallet::compact] instance: T::InstanceId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_cancel_swap(class, instance, origin)
+		}
+
+		/// Claim a swap by exchanging an owned asset instance for the offered one.
+		///
+		/// Origin must be Signed and must be the owner of the asset `send_instance`.
+		///
+		/// - `send_class`: The class of the asset given in exchange.
+		/// - `send_instance`: The instance of the asset given in exchange.
+		/// - `receive_class`: The class of the asset offered.
+		/// - `receive_instance`: The instance of the asset offered.
+		///
+		/// Emits `SwapClaimed` on success.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::claim_swap())]
+		pub fn claim_swap(
+			origin: OriginFor<T>,
+			#[pallet::compact] send_class: T::ClassId,
+			#[pallet::compact] send_instance: T::InstanceId,
+			#[pallet::compact] receive_class: T::ClassId,
+			#[pallet::compact] receive_instance: T::InstanceId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_claim_swap(send_class, send_instance, receive_class, receive_instance, origin)
+		}
+
+		/// Set the conditions under which instances of an asset class may be minted.
+		///
+		/// Origin must be either `ForceOrigin` or `Signed` and the sender should be the Owner of
+		/// the asset `class`.
+		///
+		/// - `class`: The identifier of the asset class whose mint settings to update.
+		/// - `settings`: The new mint settings. `max_supply` must not be lower than the number of
+		///   outstanding instances.
+		///
+		/// Emits `MintSettingsSet`.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::set_mint_settings())]
+		pub fn set_mint_settings(
+			origin: OriginFor<T>,
+			#[pallet::compact] class: T::ClassId,
+			settings: MintSettingsFor<T, I>,
+		) -> DispatchResult {
+			let maybe_check_owner = T::ForceOrigin::try_origin(origin)
+				.map(|_| None)
+				.or_else(|origin| ensure_signed(origin).map(Some))?;
+
+			Self::do_set_mint_settings(class, settings, maybe_check_owner)
+		}
+
+		/// Mint an asset instance of a class which allows minting by accounts other than its
+		/// issuer.
+		///
+		/// Origin must be Signed and is allowed to mint according to the mint settings of the
+		/// asset `class`. The instance is minted to the origin which pays the mint price, if any,
+		/// to the owner of the class. The instance deposit is reserved from the class owner.
+		///
+		/// - `class`: The class of the asset to be minted.
+		/// - `instance`: The instance value of the asset to be minted.
+		/// - `holder_instance`: An instance of the class required by `MintType::HolderOf` owned by
+		///   the origin. Each held instance can only be used once per class, until the instance
+		///   minted with it is burned.
+		///
+		/// Emits `Issued` event when successful.
+		///
+		/// Weight: `O(1)`
+		#[pallet::weight(T::WeightInfo::public_mint())]
+		pub fn public_mint(
+			origin: OriginFor<T>,
+			#[pallet::compact] class: T::ClassId,
+			#[pallet::compact] instance: T::InstanceId,
+			holder_instance: Option<T::InstanceId>,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			Self::do_public_mint(class, instance, origin, holder_instance)
+		}
+
+		/// Mint an asset instance using data signed off-chain by the issuer of its class.
+		///
+		/// Origin must be Signed and is the account the instance is minted to. The attributes and
+		/// metadata of the pre-signed mint are set on the instance. Their deposits are paid by the
+		/// origin and held by the class owner, like all other deposits of the class, which gets
+		/// them back when the data is cleared. The instance deposit is reserved from the class
+		/// owner.
+		///
+		/// - `mint_data`: The instance to mint and the data to set on it.
+		/// - `signature`: The signature of `mint_data.signing_payload()`, that is the SCALE encoded
+		///   `mint_data` prefixed with `PRE_SIGNED_MINT_CONTEXT`.
+		/// - `signer`: The account which signed `mint_data`. Must be the issuer of the class.
+		///
+		/// Emits `Issued` event when successful.
+		///
+		/// Weight: `O(a)` where `a = mint_data.attributes.len()`
+		#[pallet::weight(T::WeightInfo::mint_pre_signed(mint_data.attributes.len() as u32))]
+		pub fn mint_pre_signed(
+			origin: OriginFor<T>,
+			mint_data: Box<PreSignedMintOf<T, I>>,
+			signature: T::OffchainSignature,
+			signer: T::AccountId,
+		) -> DispatchResult {
+			let origin = ensure_signed(origin)?;
+			let msg = mint_data.signing_payload();
+			ensure!(signature.verify(&*msg, &signer), Error::<T, I>::WrongSignature);
+			Self::do_mint_pre_signed(origin, *mint_data, signer)
+		}
 	}
 }
@@ -19,8 +19,12 @@
 
 use super::*;
 use crate::mock::*;
-use frame_support::{assert_noop, assert_ok, traits::Currency};
+use frame_support::{
+	assert_noop, assert_ok,
+	traits::{tokens::nonfungibles::Trading, Currency},
+};
 use pallet_balances::Error as BalancesError;
+use sp_runtime::testing::TestSignature;
 use sp_std::prelude::*;
 
 fn assets() -> Vec<(u64, u32, u32)> {
@@ -597,3 +601,348 @@ fn cancel_approval_works_with_force() {
 		);
 	});
 }
+
+#[test]
+fn set_price_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 42, 2));
+
+		assert_noop!(
+			Uniques::set_price(Origin::signed(1), 0, 42, Some(100), None),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Uniques::set_price(Origin::signed(2), 0, 42, Some(100), Some(3)));
+		assert_eq!(InstancePriceOf::<Test>::get(0, 42), Some((100, Some(3), Perbill::zero())));
+		assert_eq!(<Uniques as Trading<_, _>>::instance_price(&0, &42), Some(100));
+
+		assert_ok!(Uniques::set_price(Origin::signed(2), 0, 42, None, None));
+		assert!(!InstancePriceOf::<Test>::contains_key(0, 42));
+
+		// the price is cleared when the instance changes hands
+		assert_ok!(Uniques::set_price(Origin::signed(2), 0, 42, Some(100), None));
+		assert_ok!(Uniques::transfer(Origin::signed(2), 0, 42, 3));
+		assert!(!InstancePriceOf::<Test>::contains_key(0, 42));
+	});
+}
+
+#[test]
+fn buy_item_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&3, 1000);
+		Balances::make_free_balance_be(&4, 1000);
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 42, 2));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 43, 2));
+		assert_ok!(Uniques::set_royalty(
+			Origin::signed(1),
+			0,
+			Some((5, Perbill::from_percent(10)))
+		));
+
+		assert_noop!(Uniques::buy_item(Origin::signed(3), 0, 42, 100), Error::<Test>::NotForSale);
+
+		assert_ok!(Uniques::set_price(Origin::signed(2), 0, 42, Some(100), None));
+		assert_ok!(Uniques::set_price(Origin::signed(2), 0, 43, Some(100), Some(4)));
+		assert_noop!(Uniques::buy_item(Origin::signed(3), 0, 42, 99), Error::<Test>::BidTooLow);
+		assert_noop!(Uniques::buy_item(Origin::signed(2), 0, 42, 100), Error::<Test>::NoPermission);
+		assert_noop!(Uniques::buy_item(Origin::signed(3), 0, 43, 100), Error::<Test>::NoPermission);
+
+		// the buyer pays the price and not the bid
+		assert_ok!(Uniques::buy_item(Origin::signed(3), 0, 42, 150));
+		assert_eq!(Balances::total_balance(&3), 900);
+		assert_eq!(Balances::total_balance(&2), 90);
+		assert_eq!(Balances::total_balance(&5), 10);
+		assert!(!InstancePriceOf::<Test>::contains_key(0, 42));
+		System::assert_last_event(mock::Event::Uniques(crate::Event::InstanceBought {
+			class: 0,
+			instance: 42,
+			price: 100,
+			seller: 2,
+			buyer: 3,
+		}));
+
+		// frozen instances can not be bought
+		assert_ok!(Uniques::freeze(Origin::signed(1), 0, 43));
+		assert_noop!(Uniques::buy_item(Origin::signed(4), 0, 43, 100), Error::<Test>::Frozen);
+		assert_ok!(Uniques::thaw(Origin::signed(1), 0, 43));
+		assert_ok!(Uniques::buy_item(Origin::signed(4), 0, 43, 100));
+
+		assert_eq!(assets(), vec![(3, 0, 42), (4, 0, 43)]);
+	});
+}
+
+#[test]
+fn royalty_can_not_be_raised_for_listed_instances() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&3, 1000);
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 42, 2));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 43, 2));
+		assert_ok!(Uniques::set_royalty(
+			Origin::signed(1),
+			0,
+			Some((5, Perbill::from_percent(10)))
+		));
+		assert_ok!(Uniques::set_price(Origin::signed(2), 0, 42, Some(100), None));
+		assert_ok!(Uniques::set_price(Origin::signed(2), 0, 43, Some(100), None));
+
+		// raising the royalty does not affect the existing listing
+		assert_ok!(Uniques::set_royalty(Origin::signed(1), 0, Some((5, Perbill::one()))));
+		assert_ok!(Uniques::buy_item(Origin::signed(3), 0, 42, 100));
+		assert_eq!(Balances::total_balance(&2), 90);
+		assert_eq!(Balances::total_balance(&5), 10);
+
+		// lowering it does
+		assert_ok!(Uniques::set_royalty(Origin::signed(1), 0, Some((5, Perbill::from_percent(5)))));
+		assert_ok!(Uniques::buy_item(Origin::signed(3), 0, 43, 100));
+		assert_eq!(Balances::total_balance(&2), 185);
+		assert_eq!(Balances::total_balance(&5), 15);
+	});
+}
+
+#[test]
+fn set_royalty_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, true));
+
+		assert_noop!(
+			Uniques::set_royalty(Origin::signed(2), 0, Some((2, Perbill::from_percent(10)))),
+			Error::<Test>::NoPermission
+		);
+		assert_ok!(Uniques::set_royalty(
+			Origin::signed(1),
+			0,
+			Some((2, Perbill::from_percent(10)))
+		));
+		assert_eq!(<Uniques as Trading<_, _>>::royalty(&0), Some((2, Perbill::from_percent(10))));
+
+		assert_ok!(Uniques::set_royalty(Origin::root(), 0, None));
+		assert_eq!(<Uniques as Trading<_, _>>::royalty(&0), None);
+	});
+}
+
+#[test]
+fn swap_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, true));
+		assert_ok!(Uniques::force_create(Origin::root(), 1, 1, true));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 42, 2));
+		assert_ok!(Uniques::mint(Origin::signed(1), 1, 7, 3));
+		assert_ok!(Uniques::mint(Origin::signed(1), 1, 8, 3));
+
+		assert_noop!(
+			Uniques::create_swap(Origin::signed(3), 0, 42, 1, None),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Uniques::create_swap(Origin::signed(2), 0, 42, 1, Some(9)),
+			Error::<Test>::Unknown
+		);
+		assert_ok!(Uniques::create_swap(Origin::signed(2), 0, 42, 1, Some(8)));
+
+		// only the desired instance is accepted
+		assert_noop!(
+			Uniques::claim_swap(Origin::signed(3), 1, 7, 0, 42),
+			Error::<Test>::UnknownSwap
+		);
+		assert_noop!(
+			Uniques::claim_swap(Origin::signed(2), 1, 8, 0, 42),
+			Error::<Test>::NoPermission
+		);
+
+		assert_noop!(Uniques::cancel_swap(Origin::signed(3), 0, 42), Error::<Test>::NoPermission);
+		assert_ok!(Uniques::cancel_swap(Origin::signed(2), 0, 42));
+		assert_noop!(Uniques::cancel_swap(Origin::signed(2), 0, 42), Error::<Test>::UnknownSwap);
+
+		// any instance of the desired class is accepted
+		assert_ok!(Uniques::create_swap(Origin::signed(2), 0, 42, 1, None));
+		assert_ok!(Uniques::claim_swap(Origin::signed(3), 1, 7, 0, 42));
+		assert_eq!(assets(), vec![(2, 1, 7), (3, 0, 42), (3, 1, 8)]);
+		assert!(!PendingSwapOf::<Test>::contains_key(0, 42));
+		System::assert_last_event(mock::Event::Uniques(crate::Event::SwapClaimed {
+			class: 0,
+			instance: 42,
+			exchanged_class: 1,
+			exchanged_instance: 7,
+			offerer: 2,
+			claimer: 3,
+		}));
+	});
+}
+
+#[test]
+fn set_mint_settings_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, true));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 42, 1));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 43, 1));
+
+		let settings = MintSettings { max_supply: Some(2), ..Default::default() };
+		assert_noop!(
+			Uniques::set_mint_settings(Origin::signed(2), 0, settings),
+			Error::<Test>::NoPermission
+		);
+		assert_noop!(
+			Uniques::set_mint_settings(
+				Origin::signed(1),
+				0,
+				MintSettings { max_supply: Some(1), ..Default::default() }
+			),
+			Error::<Test>::MaxSupplyTooSmall
+		);
+		assert_ok!(Uniques::set_mint_settings(Origin::signed(1), 0, settings));
+
+		// the maximum supply also applies to the issuer
+		assert_noop!(Uniques::mint(Origin::signed(1), 0, 44, 1), Error::<Test>::MaxSupplyReached);
+		assert_ok!(Uniques::burn(Origin::signed(1), 0, 43, None));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 44, 1));
+	});
+}
+
+#[test]
+fn public_mint_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, true));
+
+		assert_noop!(
+			Uniques::public_mint(Origin::signed(2), 0, 42, None),
+			Error::<Test>::MintNotAllowed
+		);
+
+		let settings = MintSettings {
+			mint_type: MintType::Public,
+			price: Some(10),
+			start_block: Some(2),
+			end_block: Some(5),
+			max_supply: None,
+		};
+		assert_ok!(Uniques::set_mint_settings(Origin::signed(1), 0, settings));
+		assert_noop!(
+			Uniques::public_mint(Origin::signed(2), 0, 42, None),
+			Error::<Test>::MintNotStarted
+		);
+
+		System::set_block_number(3);
+		assert_ok!(Uniques::public_mint(Origin::signed(2), 0, 42, None));
+		assert_eq!(assets(), vec![(2, 0, 42)]);
+		assert_eq!(Balances::free_balance(&1), 10);
+		assert_eq!(Balances::free_balance(&2), 90);
+
+		System::set_block_number(6);
+		assert_noop!(
+			Uniques::public_mint(Origin::signed(2), 0, 43, None),
+			Error::<Test>::MintEnded
+		);
+	});
+}
+
+#[test]
+fn holder_mint_should_work() {
+	new_test_ext().execute_with(|| {
+		assert_ok!(Uniques::force_create(Origin::root(), 0, 1, true));
+		assert_ok!(Uniques::force_create(Origin::root(), 1, 1, true));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 42, 2));
+		assert_ok!(Uniques::mint(Origin::signed(1), 0, 43, 3));
+
+		let settings = MintSettings { mint_type: MintType::HolderOf(0), ..Default::default() };
+		assert_ok!(Uniques::set_mint_settings(Origin::signed(1), 1, settings));
+
+		assert_noop!(
+			Uniques::public_mint(Origin::signed(2), 1, 0, None),
+			Error::<Test>::BadWitness
+		);
+		assert_noop!(
+			Uniques::public_mint(Origin::signed(2), 1, 0, Some(43)),
+			Error::<Test>::MintNotAllowed
+		);
+		assert_ok!(Uniques::public_mint(Origin::signed(2), 1, 0, Some(42)));
+
+		// every held instance can only be used once
+		assert_ok!(Uniques::transfer(Origin::signed(2), 0, 42, 3));
+		assert_noop!(
+			Uniques::public_mint(Origin::signed(3), 1, 1, Some(42)),
+			Error::<Test>::AlreadyClaimed
+		);
+		assert_ok!(Uniques::public_mint(Origin::signed(3), 1, 1, Some(43)));
+		assert_eq!(assets(), vec![(2, 1, 0), (3, 0, 42), (3, 0, 43), (3, 1, 1)]);
+
+		// burning the minted instance releases the held instance
+		assert_ok!(Uniques::burn(Origin::signed(2), 1, 0, None));
+		assert_ok!(Uniques::public_mint(Origin::signed(3), 1, 2, Some(42)));
+
+		// destroying the class removes everything stored for its instances
+		assert_ok!(Uniques::set_price(Origin::signed(3), 1, 2, Some(100), None));
+		assert_ok!(Uniques::create_swap(Origin::signed(3), 1, 1, 0, None));
+		let w = Class::<Test>::get(1).unwrap().destroy_witness();
+		assert_ok!(Uniques::destroy(Origin::signed(1), 1, w));
+		assert_eq!(InstancePriceOf::<Test>::iter().count(), 0);
+		assert_eq!(PendingSwapOf::<Test>::iter().count(), 0);
+		assert_eq!(HolderMintOf::<Test>::iter().count(), 0);
+		assert_eq!(HolderMinted::<Test>::iter().count(), 0);
+	});
+}
+
+#[test]
+fn mint_pre_signed_should_work() {
+	new_test_ext().execute_with(|| {
+		Balances::make_free_balance_be(&1, 100);
+		Balances::make_free_balance_be(&2, 100);
+		assert_ok!(Uniques::create(Origin::signed(1), 0, 1));
+
+		let mint_data = PreSignedMint {
+			class: 0,
+			instance: 42,
+			attributes: vec![(vec![0], vec![1]), (vec![2], vec![3])],
+			metadata: vec![4, 5],
+			only_account: Some(2),
+			deadline: 10,
+		};
+		let sign = |signer: u64, data: &PreSignedMintOf<Test, ()>| {
+			TestSignature(signer, data.signing_payload())
+		};
+		let mint = |who: u64, data: &PreSignedMintOf<Test, ()>, signature, signer: u64| {
+			Uniques::mint_pre_signed(Origin::signed(who), Box::new(data.clone()), signature, signer)
+		};
+
+		// the signature needs to match the signer and the data
+		assert_noop!(mint(2, &mint_data, sign(1, &mint_data), 3), Error::<Test>::WrongSignature);
+		let other_data = PreSignedMint { instance: 43, ..mint_data.clone() };
+		assert_noop!(mint(2, &mint_data, sign(1, &other_data), 1), Error::<Test>::WrongSignature);
+		// the signed message is bound to pre-signed mints
+		let unprefixed = TestSignature(1, mint_data.encode());
+		assert_noop!(mint(2, &mint_data, unprefixed, 1), Error::<Test>::WrongSignature);
+		// only the issuer may sign
+		assert_noop!(mint(2, &mint_data, sign(3, &mint_data), 3), Error::<Test>::NoPermission);
+		// only the given account may mint
+		assert_noop!(mint(3, &mint_data, sign(1, &mint_data), 1), Error::<Test>::NoPermission);
+		let too_many = PreSignedMint {
+			attributes: vec![(vec![0], vec![1]), (vec![2], vec![3]), (vec![4], vec![5])],
+			..mint_data.clone()
+		};
+		assert_noop!(
+			mint(2, &too_many, sign(1, &too_many), 1),
+			Error::<Test>::MaxAttributesLimitReached
+		);
+
+		assert_ok!(mint(2, &mint_data, sign(1, &mint_data), 1));
+		assert_eq!(assets(), vec![(2, 0, 42)]);
+		assert_eq!(attributes(0), vec![(Some(42), vec![0], vec![1]), (Some(42), vec![2], vec![3])]);
+		assert_eq!(InstanceMetadataOf::<Test>::get(0, 42).unwrap().data, vec![4, 5]);
+		// class deposit, instance deposit, two attributes and the metadata
+		assert_eq!(Balances::reserved_balance(&1), 2 + 1 + 3 + 3 + 3);
+		// the deposits of the attributes and the metadata are paid by the minter
+		assert_eq!(Balances::total_balance(&1), 100 + 3 + 3 + 3);
+		assert_eq!(Balances::total_balance(&2), 100 - 3 - 3 - 3);
+		let details = Class::<Test>::get(0).unwrap();
+		assert_eq!((details.attributes, details.instance_metadatas), (2, 1));
+
+		// the same mint can not be used twice
+		assert_noop!(mint(2, &mint_data, sign(1, &mint_data), 1), Error::<Test>::AlreadyExists);
+
+		System::set_block_number(11);
+		let expired = PreSignedMint { instance: 43, ..mint_data };
+		assert_noop!(mint(2, &expired, sign(1, &expired), 1), Error::<Test>::DeadlineExpired);
+	});
+}
@@ -21,12 +21,25 @@ use super::*;
 use frame_support::{traits::Get, BoundedVec};
 use scale_info::TypeInfo;
 
+/// The prefix of the message signed by the issuer of a [`PreSignedMint`].
+pub const PRE_SIGNED_MINT_CONTEXT: &[u8] = b"pallet-uniques/pre-signed-mint:";
+
 pub(super) type DepositBalanceOf<T, I = ()> =
 	<<T as Config<I>>::Currency as Currency<<T as SystemConfig>::AccountId>>::Balance;
 pub(super) type ClassDetailsFor<T, I> =
 	ClassDetails<<T as SystemConfig>::AccountId, DepositBalanceOf<T, I>>;
 pub(super) type InstanceDetailsFor<T, I> =
 	InstanceDetails<<T as SystemConfig>::AccountId, DepositBalanceOf<T, I>>;
+pub(super) type InstancePrice<T, I = ()> =
+	<<T as Config<I>>::Currency as Currency<<T as SystemConfig>::AccountId>>::Balance;
+pub(super) type MintSettingsFor<T, I> =
+	MintSettings<InstancePrice<T, I>, <T as SystemConfig>::BlockNumber, <T as Config<I>>::ClassId>;
+pub(super) type PreSignedMintOf<T, I> = PreSignedMint<
+	<T as Config<I>>::ClassId,
+	<T as Config<I>>::InstanceId,
+	<T as SystemConfig>::AccountId,
+	<T as SystemConfig>::BlockNumber,
+>;
 
 #[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
 pub struct ClassDetails<AccountId, DepositBalance> {
@@ -120,3 +133,96 @@ pub struct InstanceMetadata<DepositBalance, StringLimit: Get<u32>> {
 	/// Whether the asset metadata may be changed by a non Force origin.
 	pub(super) is_frozen: bool,
 }
+
+/// The royalty paid on every sale of an instance of an asset class.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct ClassRoyalty<AccountId> {
+	/// The account which receives the royalty.
+	pub(super) recipient: AccountId,
+	/// The share of the sale price that is paid to `recipient`.
+	pub(super) rate: Perbill,
+}
+
+/// An offer to swap an asset instance for an instance of `desired_class`.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct PendingSwap<ClassId, InstanceId> {
+	/// The class of the instance that is asked for in exchange.
+	pub(super) desired_class: ClassId,
+	/// The instance that is asked for in exchange. If `None`, any instance of `desired_class` is
+	/// accepted.
+	pub(super) desired_instance: Option<InstanceId>,
+}
+
+/// Who, besides the issuer, may mint instances of an asset class.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub enum MintType<ClassId> {
+	/// Only the issuer of the class may mint.
+	Issuer,
+	/// Anyone may mint.
+	Public,
+	/// Only holders of an instance of the given class may mint, once per held instance for as
+	/// long as the minted instance exists.
+	HolderOf(ClassId),
+}
+
+/// The conditions under which instances of an asset class may be minted.
+#[derive(Clone, Copy, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct MintSettings<Price, BlockNumber, ClassId> {
+	/// Who may mint instances through `public_mint`.
+	pub mint_type: MintType<ClassId>,
+	/// The price paid to the class owner for every instance minted through `public_mint`.
+	pub price: Option<Price>,
+	/// The first block in which instances can be minted through `public_mint`.
+	pub start_block: Option<BlockNumber>,
+	/// The last block in which instances can be minted through `public_mint`.
+	pub end_block: Option<BlockNumber>,
+	/// The maximum number of outstanding instances of the class.
+	///
+	/// This applies to all ways of minting, including those by the issuer.
+	pub max_supply: Option<u32>,
+}
+
+impl<Price, BlockNumber, ClassId> Default for MintSettings<Price, BlockNumber, ClassId> {
+	fn default() -> Self {
+		Self {
+			mint_type: MintType::Issuer,
+			price: None,
+			start_block: None,
+			end_block: None,
+			max_supply: None,
+		}
+	}
+}
+
+/// The data of an instance minted by `mint_pre_signed`, signed off-chain by the class issuer.
+#[derive(Clone, Encode, Decode, Eq, PartialEq, RuntimeDebug, TypeInfo)]
+pub struct PreSignedMint<ClassId, InstanceId, AccountId, Deadline> {
+	/// The class of the instance to be minted.
+	pub class: ClassId,
+	/// The instance to be minted.
+	pub instance: InstanceId,
+	/// The attributes set on the instance.
+	pub attributes: Vec<(Vec<u8>, Vec<u8>)>,
+	/// The metadata set on the instance. If empty, no metadata is set.
+	pub metadata: Vec<u8>,
+	/// Restricts the mint to a specific account.
+	pub only_account: Option<AccountId>,
+	/// The last block in which the instance can be minted.
+	pub deadline: Deadline,
+}
+
+impl<ClassId, InstanceId, AccountId, Deadline>
+	PreSignedMint<ClassId, InstanceId, AccountId, Deadline>
+where
+	Self: Encode,
+{
+	/// The message the issuer signs for this mint.
+	///
+	/// This is the SCALE encoded mint prefixed with [`PRE_SIGNED_MINT_CONTEXT`], so that the
+	/// signature can not be mistaken for one of any other data.
+	pub fn signing_payload(&self) -> Vec<u8> {
+		let mut payload = PRE_SIGNED_MINT_CONTEXT.to_vec();
+		self.encode_to(&mut payload);
+		payload
+	}
+}
@@ -68,6 +68,15 @@ pub trait WeightInfo {
 	fn clear_class_metadata() -> Weight;
 	fn approve_transfer() -> Weight;
 	fn cancel_approval() -> Weight;
+	fn set_price() -> Weight;
+	fn buy_item() -> Weight;
+	fn set_royalty() -> Weight;
+	fn create_swap() -> Weight;
+	fn cancel_swap() -> Weight;
+	fn claim_swap() -> Weight;
+	fn set_mint_settings() -> Weight;
+	fn public_mint() -> Weight;
+	fn mint_pre_signed(n: u32, ) -> Weight;
 }
 
 /// Weights for pallet_uniques using the Substrate node and recommended hardware.
@@ -87,6 +96,8 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(1 as Weight))
 			.saturating_add(T::DbWeight::get().writes(2 as Weight))
 	}
+	// NOTE: the storage accesses were updated for the listings, swaps and holder mints removed
+	// with the instances, the execution time still needs to be re-benchmarked.
 	// Storage: Uniques Class (r:1 w:1)
 	// Storage: Uniques Asset (r:1 w:0)
 	// Storage: Uniques ClassAccount (r:0 w:1)
@@ -94,6 +105,12 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 	// Storage: Uniques ClassMetadataOf (r:0 w:1)
 	// Storage: Uniques InstanceMetadataOf (r:0 w:1000)
 	// Storage: Uniques Account (r:0 w:20)
+	// Storage: Uniques InstancePriceOf (r:0 w:20)
+	// Storage: Uniques PendingSwapOf (r:0 w:20)
+	// Storage: Uniques HolderMintOf (r:20 w:20)
+	// Storage: Uniques HolderMinted (r:0 w:20)
+	// Storage: Uniques ClassRoyaltyOf (r:0 w:1)
+	// Storage: Uniques ClassMintSettings (r:0 w:1)
 	fn destroy(n: u32, m: u32, a: u32, ) -> Weight {
 		(0 as Weight)
 			// Standard Error: 14_000
@@ -103,9 +120,9 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			// Standard Error: 14_000
 			.saturating_add((758_000 as Weight).saturating_mul(a as Weight))
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
-			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes((6 as Weight).saturating_mul(n as Weight)))
 			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(m as Weight)))
 			.saturating_add(T::DbWeight::get().writes((1 as Weight).saturating_mul(a as Weight)))
 	}
@@ -117,13 +134,19 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(3 as Weight))
 	}
+	// NOTE: the storage accesses were updated for the listing, swap and holder mint removed with
+	// the instance, the execution time still needs to be re-benchmarked.
 	// Storage: Uniques Class (r:1 w:1)
 	// Storage: Uniques Asset (r:1 w:1)
 	// Storage: Uniques Account (r:0 w:1)
+	// Storage: Uniques InstancePriceOf (r:0 w:1)
+	// Storage: Uniques PendingSwapOf (r:0 w:1)
+	// Storage: Uniques HolderMintOf (r:1 w:1)
+	// Storage: Uniques HolderMinted (r:0 w:1)
 	fn burn() -> Weight {
 		(31_603_000 as Weight)
-			.saturating_add(T::DbWeight::get().reads(2 as Weight))
-			.saturating_add(T::DbWeight::get().writes(3 as Weight))
+			.saturating_add(T::DbWeight::get().reads(3 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
 	}
 	// Storage: Uniques Class (r:1 w:0)
 	// Storage: Uniques Asset (r:1 w:1)
@@ -249,6 +272,97 @@ impl<T: frame_system::Config> WeightInfo for SubstrateWeight<T> {
 			.saturating_add(T::DbWeight::get().reads(2 as Weight))
 			.saturating_add(T::DbWeight::get().writes(1 as Weight))
 	}
+	// NOTE: the weights of the listing, royalty and swap calls are placeholders until they are
+	// benchmarked.
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: Uniques ClassRoyaltyOf (r:1 w:0)
+	// Storage: Uniques InstancePriceOf (r:0 w:1)
+	fn set_price() -> Weight {
+		(19_844_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques InstancePriceOf (r:1 w:1)
+	// Storage: Uniques ClassRoyaltyOf (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: Uniques PendingSwapOf (r:0 w:1)
+	fn buy_item() -> Weight {
+		(62_314_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes(8 as Weight))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques ClassRoyaltyOf (r:0 w:1)
+	fn set_royalty() -> Weight {
+		(18_760_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Uniques Asset (r:2 w:0)
+	// Storage: Uniques PendingSwapOf (r:0 w:1)
+	fn create_swap() -> Weight {
+		(21_398_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: Uniques PendingSwapOf (r:1 w:1)
+	fn cancel_swap() -> Weight {
+		(20_115_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(2 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Uniques Asset (r:2 w:2)
+	// Storage: Uniques PendingSwapOf (r:1 w:2)
+	// Storage: Uniques Class (r:2 w:0)
+	// Storage: Uniques Account (r:0 w:4)
+	// Storage: Uniques InstancePriceOf (r:0 w:2)
+	fn claim_swap() -> Weight {
+		(49_871_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(5 as Weight))
+			.saturating_add(T::DbWeight::get().writes(10 as Weight))
+	}
+	// NOTE: the weights of `set_mint_settings` and `public_mint` are placeholders until they are
+	// benchmarked.
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques ClassMintSettings (r:0 w:1)
+	fn set_mint_settings() -> Weight {
+		(18_934_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(1 as Weight))
+			.saturating_add(T::DbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Uniques ClassMintSettings (r:2 w:0)
+	// Storage: Uniques Account (r:1 w:1)
+	// Storage: Uniques HolderMinted (r:1 w:1)
+	// Storage: Uniques HolderMintOf (r:0 w:1)
+	// Storage: Uniques Class (r:2 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Uniques Asset (r:1 w:1)
+	fn public_mint() -> Weight {
+		(51_207_000 as Weight)
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+	}
+	// Storage: Uniques Class (r:2 w:1)
+	// Storage: Uniques ClassMintSettings (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques InstanceMetadataOf (r:2 w:1)
+	// Storage: Uniques Attribute (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:1)
+	// Storage: System Account (r:2 w:2)
+	// NOTE: this weight is a placeholder until it is benchmarked with the deposits paid by the
+	// minter.
+	fn mint_pre_signed(n: u32, ) -> Weight {
+		(69_425_000 as Weight)
+			.saturating_add((12_718_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(T::DbWeight::get().reads(9 as Weight))
+			.saturating_add(T::DbWeight::get().reads((3 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(T::DbWeight::get().writes(7 as Weight))
+			.saturating_add(T::DbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
 }
 
 // For backwards compatibility and tests
@@ -267,6 +381,8 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(2 as Weight))
 	}
+	// NOTE: the storage accesses were updated for the listings, swaps and holder mints removed
+	// with the instances, the execution time still needs to be re-benchmarked.
 	// Storage: Uniques Class (r:1 w:1)
 	// Storage: Uniques Asset (r:1 w:0)
 	// Storage: Uniques ClassAccount (r:0 w:1)
@@ -274,6 +390,12 @@ impl WeightInfo for () {
 	// Storage: Uniques ClassMetadataOf (r:0 w:1)
 	// Storage: Uniques InstanceMetadataOf (r:0 w:1000)
 	// Storage: Uniques Account (r:0 w:20)
+	// Storage: Uniques InstancePriceOf (r:0 w:20)
+	// Storage: Uniques PendingSwapOf (r:0 w:20)
+	// Storage: Uniques HolderMintOf (r:20 w:20)
+	// Storage: Uniques HolderMinted (r:0 w:20)
+	// Storage: Uniques ClassRoyaltyOf (r:0 w:1)
+	// Storage: Uniques ClassMintSettings (r:0 w:1)
 	fn destroy(n: u32, m: u32, a: u32, ) -> Weight {
 		(0 as Weight)
 			// Standard Error: 14_000
@@ -283,9 +405,9 @@ impl WeightInfo for () {
 			// Standard Error: 14_000
 			.saturating_add((758_000 as Weight).saturating_mul(a as Weight))
 			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().reads((1 as Weight).saturating_mul(n as Weight)))
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
-			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().reads((2 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((6 as Weight).saturating_mul(n as Weight)))
 			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(m as Weight)))
 			.saturating_add(RocksDbWeight::get().writes((1 as Weight).saturating_mul(a as Weight)))
 	}
@@ -297,13 +419,19 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
 	}
+	// NOTE: the storage accesses were updated for the listing, swap and holder mint removed with
+	// the instance, the execution time still needs to be re-benchmarked.
 	// Storage: Uniques Class (r:1 w:1)
 	// Storage: Uniques Asset (r:1 w:1)
 	// Storage: Uniques Account (r:0 w:1)
+	// Storage: Uniques InstancePriceOf (r:0 w:1)
+	// Storage: Uniques PendingSwapOf (r:0 w:1)
+	// Storage: Uniques HolderMintOf (r:1 w:1)
+	// Storage: Uniques HolderMinted (r:0 w:1)
 	fn burn() -> Weight {
 		(31_603_000 as Weight)
-			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
-			.saturating_add(RocksDbWeight::get().writes(3 as Weight))
+			.saturating_add(RocksDbWeight::get().reads(3 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
 	}
 	// Storage: Uniques Class (r:1 w:0)
 	// Storage: Uniques Asset (r:1 w:1)
@@ -429,4 +557,95 @@ impl WeightInfo for () {
 			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
 			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
 	}
+	// NOTE: the weights of the listing, royalty and swap calls are placeholders until they are
+	// benchmarked.
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: Uniques ClassRoyaltyOf (r:1 w:0)
+	// Storage: Uniques InstancePriceOf (r:0 w:1)
+	fn set_price() -> Weight {
+		(19_844_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques InstancePriceOf (r:1 w:1)
+	// Storage: Uniques ClassRoyaltyOf (r:1 w:0)
+	// Storage: System Account (r:3 w:3)
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques Account (r:0 w:2)
+	// Storage: Uniques PendingSwapOf (r:0 w:1)
+	fn buy_item() -> Weight {
+		(62_314_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(8 as Weight))
+	}
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques ClassRoyaltyOf (r:0 w:1)
+	fn set_royalty() -> Weight {
+		(18_760_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Uniques Asset (r:2 w:0)
+	// Storage: Uniques PendingSwapOf (r:0 w:1)
+	fn create_swap() -> Weight {
+		(21_398_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Uniques Asset (r:1 w:0)
+	// Storage: Uniques PendingSwapOf (r:1 w:1)
+	fn cancel_swap() -> Weight {
+		(20_115_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(2 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Uniques Asset (r:2 w:2)
+	// Storage: Uniques PendingSwapOf (r:1 w:2)
+	// Storage: Uniques Class (r:2 w:0)
+	// Storage: Uniques Account (r:0 w:4)
+	// Storage: Uniques InstancePriceOf (r:0 w:2)
+	fn claim_swap() -> Weight {
+		(49_871_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(5 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(10 as Weight))
+	}
+	// NOTE: the weights of `set_mint_settings` and `public_mint` are placeholders until they are
+	// benchmarked.
+	// Storage: Uniques Class (r:1 w:0)
+	// Storage: Uniques ClassMintSettings (r:0 w:1)
+	fn set_mint_settings() -> Weight {
+		(18_934_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(1 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(1 as Weight))
+	}
+	// Storage: Uniques ClassMintSettings (r:2 w:0)
+	// Storage: Uniques Account (r:1 w:1)
+	// Storage: Uniques HolderMinted (r:1 w:1)
+	// Storage: Uniques HolderMintOf (r:0 w:1)
+	// Storage: Uniques Class (r:2 w:1)
+	// Storage: System Account (r:2 w:2)
+	// Storage: Uniques Asset (r:1 w:1)
+	fn public_mint() -> Weight {
+		(51_207_000 as Weight)
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+	}
+	// Storage: Uniques Class (r:2 w:1)
+	// Storage: Uniques ClassMintSettings (r:1 w:0)
+	// Storage: Uniques Asset (r:1 w:1)
+	// Storage: Uniques InstanceMetadataOf (r:2 w:1)
+	// Storage: Uniques Attribute (r:1 w:1)
+	// Storage: Uniques Account (r:0 w:1)
+	// Storage: System Account (r:2 w:2)
+	// NOTE: this weight is a placeholder until it is benchmarked with the deposits paid by the
+	// minter.
+	fn mint_pre_signed(n: u32, ) -> Weight {
+		(69_425_000 as Weight)
+			.saturating_add((12_718_000 as Weight).saturating_mul(n as Weight))
+			.saturating_add(RocksDbWeight::get().reads(9 as Weight))
+			.saturating_add(RocksDbWeight::get().reads((3 as Weight).saturating_mul(n as Weight)))
+			.saturating_add(RocksDbWeight::get().writes(7 as Weight))
+			.saturating_add(RocksDbWeight::get().writes((2 as Weight).saturating_mul(n as Weight)))
+	}
 }
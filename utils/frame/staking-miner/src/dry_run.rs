@@ -0,0 +1,97 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The dry-run command: mine a solution at a single block, and dry-run its submission.
+
+use crate::{
+	rpc::{self, RpcApiClient},
+	signer::{self, ChainInfo},
+	DryRunConfig, Error, Signer, LOG_TARGET,
+};
+use codec::{Decode, Encode};
+use frame_support::traits::PalletInfoAccess;
+use jsonrpsee::ws_client::WsClient;
+use node_runtime::{BagsList, Staking};
+use sp_runtime::ApplyExtrinsicResult;
+use std::sync::Arc;
+
+pub(crate) async fn run(
+	client: Arc<WsClient>,
+	config: DryRunConfig,
+	signer: Signer,
+) -> Result<(), Error> {
+	let at = match config.at {
+		Some(at) => at,
+		None => client.finalized_head().await?,
+	};
+	let header = client
+		.header(Some(at))
+		.await?
+		.ok_or_else(|| Error::BlockNotFound(format!("{:?}", at)))?;
+
+	// the snapshot can only be created from the state of the staking and voter list pallets.
+	let additional = if config.force_snapshot {
+		vec![
+			<Staking as PalletInfoAccess>::name().into(),
+			<BagsList as PalletInfoAccess>::name().into(),
+		]
+	} else {
+		vec![]
+	};
+	let mut ext = crate::create_election_ext(client.clone(), Some(at), additional).await?;
+	if config.force_snapshot {
+		crate::force_create_snapshot(&mut ext)?;
+	}
+
+	let (raw_solution, witness) = crate::mine_with(&config.solver, &mut ext)?;
+	log::info!(
+		target: LOG_TARGET,
+		"mined a feasible solution with score {:?} and {} bytes at #{}",
+		raw_solution.score,
+		raw_solution.encoded_size(),
+		header.number,
+	);
+	if let Err(e) = crate::ensure_no_better_solution(&mut ext, raw_solution.score, &signer.account)
+	{
+		log::warn!(target: LOG_TARGET, "the solution would not win: {}", e);
+	}
+
+	if config.force_snapshot {
+		log::info!(target: LOG_TARGET, "not dry-running the submission against a forced snapshot");
+		return Ok(())
+	}
+
+	let version = client.runtime_version(Some(at)).await?;
+	let info = ChainInfo {
+		genesis_hash: rpc::genesis_hash(&client).await?,
+		spec_version: version.spec_version,
+		transaction_version: version.transaction_version,
+	};
+	let nonce = client.account_next_index(signer.account.clone()).await?;
+	let uxt =
+		signer::create_uxt(raw_solution, witness, &signer, nonce, 0, (header.number, at), &info);
+
+	let outcome = client.dry_run(uxt.encode().into(), Some(at)).await?;
+	let outcome = ApplyExtrinsicResult::decode(&mut &*outcome.0)?;
+	log::info!(target: LOG_TARGET, "the submission dry-runs to {:?}", outcome);
+
+	match outcome {
+		Ok(Ok(())) => Ok(()),
+		Ok(Err(e)) => Err(Error::DryRun(format!("{:?}", e))),
+		Err(e) => Err(Error::DryRun(format!("{:?}", e))),
+	}
+}
@@ -0,0 +1,328 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! # Staking Miner.
+//!
+//! A bot that follows a node-runtime based chain over RPC, mines NPoS election solutions off-chain
+//! and submits them to `pallet-election-provider-multi-phase` as signed submissions. See `--help`
+//! for the available commands.
+//!
+//! ## Implementation Notes
+//!
+//! - The miner scrapes the state of the election provider pallet via `remote-externalities` and
+//!   runs the very same mining code that the pallet's offchain worker uses, i.e.
+//!   [`pallet_election_provider_multi_phase::Pallet::mine_solution`]. This means that the solution
+//!   is trimmed to `MinerMaxWeight` and `MinerMaxLength` exactly the same way as on-chain.
+//! - The types of the chain are taken from `node-runtime` at compile time. The miner refuses to run
+//!   against a node whose `spec_version` differs from the one it was compiled with.
+//! - Each submission reserves a deposit from the signing account. Don't use an account with large
+//!   funds.
+
+mod dry_run;
+mod monitor;
+mod rpc;
+mod signer;
+
+use clap::{Args, Parser, Subcommand};
+use frame_election_provider_support::{PhragMMS, SequentialPhragmen};
+use frame_support::traits::{Get, PalletInfoAccess};
+use jsonrpsee::ws_client::{WsClient, WsClientBuilder};
+use node_primitives::{AccountId, Balance, Hash};
+use node_runtime::{Block, ElectionProviderMultiPhase, Runtime};
+use pallet_election_provider_multi_phase::{
+	unsigned::MinerError, Config as EPMConfig, ElectionCompute, FeasibilityError, RawSolution,
+	SolutionAccuracyOf, SolutionOf,
+};
+use remote_externalities::{Builder, Mode, OnlineConfig, TestExternalities};
+use sp_npos_elections::{is_score_better, ElectionScore, ExtendedBalance};
+use std::sync::{
+	atomic::{AtomicUsize, Ordering},
+	Arc,
+};
+
+pub(crate) use signer::Signer;
+
+pub(crate) const LOG_TARGET: &str = "staking-miner";
+
+/// The node to connect to by default: a local dev node.
+const DEFAULT_URI: &str = "ws://localhost:9944";
+
+/// The number of balancing iterations of the configured [`Solver`].
+static BALANCE_ITERATIONS: AtomicUsize = AtomicUsize::new(0);
+
+/// The balancing configuration of the solvers, as set from the command line.
+pub(crate) struct Balancing;
+impl Get<Option<(usize, ExtendedBalance)>> for Balancing {
+	fn get() -> Option<(usize, ExtendedBalance)> {
+		Some((BALANCE_ITERATIONS.load(Ordering::Relaxed), 0))
+	}
+}
+
+/// Error type of the miner.
+#[derive(thiserror::Error, Debug)]
+pub(crate) enum Error {
+	#[error("RPC error: `{0:?}`")]
+	Rpc(#[from] jsonrpsee::core::Error),
+	#[error("codec error: `{0:?}`")]
+	Codec(#[from] codec::Error),
+	#[error("failed to build the remote externalities: `{0}`")]
+	RemoteExternalities(&'static str),
+	#[error("invalid seed: `{0}`")]
+	InvalidSeed(String),
+	#[error("the node runs spec version {node}, but the miner was compiled for {miner}")]
+	IncompatibleRuntime { node: u32, miner: u32 },
+	#[error("block {0} was not found")]
+	BlockNotFound(String),
+	#[error("failed to create the snapshot: `{0}`")]
+	Snapshot(String),
+	#[error("mining failed: `{0:?}`")]
+	Mining(MinerError<Runtime>),
+	#[error("the mined solution is not feasible: `{0:?}`")]
+	Feasibility(FeasibilityError),
+	#[error("a solution with a better score is already queued or submitted")]
+	BetterScoreExists,
+	#[error("the account has already submitted a solution in this round")]
+	AlreadySubmitted,
+	#[error("the submission failed to dry-run: `{0}`")]
+	DryRun(String),
+}
+
+/// The NPoS solvers that the miner can use.
+#[derive(Debug, Clone, Subcommand)]
+pub(crate) enum Solver {
+	/// Sequential phragmen, followed by balancing.
+	SeqPhragmen {
+		/// The number of balancing iterations.
+		#[clap(long, default_value = "10")]
+		iterations: usize,
+	},
+	/// PhragMMS, followed by balancing.
+	#[clap(name = "phragmms")]
+	PhragMMS {
+		/// The number of balancing iterations.
+		#[clap(long, default_value = "10")]
+		iterations: usize,
+	},
+}
+
+/// The kind of blocks to follow.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ArgEnum)]
+pub(crate) enum Listen {
+	/// Follow the best blocks.
+	Head,
+	/// Follow the finalized blocks.
+	Finalized,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct MonitorConfig {
+	/// The kind of blocks to follow.
+	///
+	/// Following finalized blocks is safer, but slower. It is only recommended if the signed
+	/// phase is considerably longer than the finality lag of the chain.
+	#[clap(long, arg_enum, default_value = "head")]
+	listen: Listen,
+
+	/// The tip to attach to each submission.
+	#[clap(long, default_value = "0")]
+	tip: Balance,
+
+	/// The solver to mine with.
+	#[clap(subcommand)]
+	solver: Solver,
+}
+
+#[derive(Debug, Clone, Args)]
+pub(crate) struct DryRunConfig {
+	/// The block at which to mine. Defaults to the latest finalized block.
+	#[clap(long)]
+	at: Option<Hash>,
+
+	/// Create a snapshot if none exists at `at`, e.g. outside of the signed phase.
+	///
+	/// The submission itself is then not dry-run against the node, since the node would reject it
+	/// for lack of a snapshot.
+	#[clap(long)]
+	force_snapshot: bool,
+
+	/// The solver to mine with.
+	#[clap(subcommand)]
+	solver: Solver,
+}
+
+#[derive(Debug, Clone, Subcommand)]
+enum Command {
+	/// Follow the chain and submit a solution in every signed phase.
+	Monitor(MonitorConfig),
+	/// Mine a solution at a single block and dry-run its submission against the node.
+	DryRun(DryRunConfig),
+}
+
+#[derive(Debug, Clone, Parser)]
+#[clap(author, version, about)]
+struct Opt {
+	/// The `ws` endpoint of the node to connect to.
+	#[clap(long, short, default_value = DEFAULT_URI, env = "URI")]
+	uri: String,
+
+	/// The secret URI of the account that signs the submissions, or the path to a file that
+	/// contains it.
+	///
+	/// The account must be able to pay the deposit and fees of a signed submission.
+	#[clap(long, short, env = "SEED")]
+	seed_or_path: String,
+
+	#[clap(subcommand)]
+	command: Command,
+}
+
+/// Build the externalities of the election provider pallet at block `at`.
+///
+/// `additional` pallets are scraped as well, if given.
+pub(crate) async fn create_election_ext(
+	client: Arc<WsClient>,
+	at: Option<Hash>,
+	additional: Vec<String>,
+) -> Result<TestExternalities, Error> {
+	let mut pallets = vec![<ElectionProviderMultiPhase as PalletInfoAccess>::name().to_string()];
+	pallets.extend(additional);
+
+	Builder::<Block>::new()
+		.mode(Mode::Online(OnlineConfig {
+			at,
+			pallets,
+			transport: client.into(),
+			..Default::default()
+		}))
+		.build()
+		.await
+		.map_err(Error::RemoteExternalities)
+}
+
+/// Mine a solution with `solver` in `ext`, and make sure that it is feasible.
+///
+/// Returns the solution and the witness of `submit`, i.e. the current number of signed
+/// submissions.
+pub(crate) fn mine_with(
+	solver: &Solver,
+	ext: &mut TestExternalities,
+) -> Result<(RawSolution<SolutionOf<Runtime>>, u32), Error> {
+	ext.execute_with(|| {
+		let (raw_solution, _) = match *solver {
+			Solver::SeqPhragmen { iterations } => {
+				BALANCE_ITERATIONS.store(iterations, Ordering::Relaxed);
+				ElectionProviderMultiPhase::mine_solution::<
+					SequentialPhragmen<AccountId, SolutionAccuracyOf<Runtime>, Balancing>,
+				>()
+			},
+			Solver::PhragMMS { iterations } => {
+				BALANCE_ITERATIONS.store(iterations, Ordering::Relaxed);
+				ElectionProviderMultiPhase::mine_solution::<
+					PhragMMS<AccountId, SolutionAccuracyOf<Runtime>, Balancing>,
+				>()
+			},
+		}
+		.map_err(Error::Mining)?;
+
+		ElectionProviderMultiPhase::feasibility_check(
+			raw_solution.clone(),
+			ElectionCompute::Signed,
+		)
+		.map_err(Error::Feasibility)?;
+
+		Ok((raw_solution, ElectionProviderMultiPhase::signed_submissions().len() as u32))
+	})
+}
+
+/// Ensure that a solution with `score`, submitted by `who`, can win the election.
+///
+/// That is, it must sufficiently improve the queued solution and every signed submission, and
+/// `who` must not have submitted in this round already.
+pub(crate) fn ensure_no_better_solution(
+	ext: &mut TestExternalities,
+	score: ElectionScore,
+	who: &AccountId,
+) -> Result<(), Error> {
+	ext.execute_with(|| {
+		let threshold = <Runtime as EPMConfig>::SolutionImprovementThreshold::get();
+		if ElectionProviderMultiPhase::queued_solution()
+			.map_or(false, |q| !is_score_better(score, q.score, threshold))
+		{
+			return Err(Error::BetterScoreExists)
+		}
+
+		for submission in ElectionProviderMultiPhase::signed_submissions().iter() {
+			if &submission.who == who {
+				return Err(Error::AlreadySubmitted)
+			}
+			if !is_score_better(score, submission.raw_solution.score, threshold) {
+				return Err(Error::BetterScoreExists)
+			}
+		}
+
+		Ok(())
+	})
+}
+
+/// Create the snapshot in `ext`, if none exists.
+///
+/// `ext` must contain the state of the staking and voter list pallets.
+pub(crate) fn force_create_snapshot(ext: &mut TestExternalities) -> Result<(), Error> {
+	ext.execute_with(|| {
+		if ElectionProviderMultiPhase::snapshot().is_some() {
+			return Ok(())
+		}
+
+		log::info!(target: LOG_TARGET, "no snapshot exists, creating one");
+		ElectionProviderMultiPhase::create_snapshot()
+			.map_err(|e| Error::Snapshot(format!("{:?}", e)))
+	})
+}
+
+async fn run(opt: Opt) -> Result<(), Error> {
+	let Opt { uri, seed_or_path, command } = opt;
+	let signer = signer::signer_from_seed_or_path(&seed_or_path)?;
+	log::info!(target: LOG_TARGET, "submitting as account {}", signer.account);
+
+	log::debug!(target: LOG_TARGET, "connecting to {}", uri);
+	let client =
+		Arc::new(WsClientBuilder::default().max_request_body_size(u32::MAX).build(&uri).await?);
+
+	let version = rpc::RpcApiClient::runtime_version(&*client, None).await?;
+	let miner = node_runtime::VERSION.spec_version;
+	if version.spec_version != miner {
+		return Err(Error::IncompatibleRuntime { node: version.spec_version, miner })
+	}
+
+	match command {
+		Command::Monitor(config) => monitor::run(client, config, signer).await,
+		Command::DryRun(config) => dry_run::run(client, config, signer).await,
+	}
+}
+
+#[tokio::main]
+async fn main() {
+	env_logger::Builder::new()
+		.filter_module(LOG_TARGET, log::LevelFilter::Info)
+		.parse_default_env()
+		.init();
+
+	if let Err(e) = run(Opt::parse()).await {
+		log::error!(target: LOG_TARGET, "{}", e);
+		std::process::exit(1);
+	}
+}
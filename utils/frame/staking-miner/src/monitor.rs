@@ -0,0 +1,135 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The monitor command: follow the chain, and submit a solution in every signed phase.
+
+use crate::{
+	rpc::{self, RpcApiClient},
+	signer::{self, ChainInfo},
+	Error, Listen, MonitorConfig, Signer, LOG_TARGET,
+};
+use codec::Encode;
+use jsonrpsee::ws_client::WsClient;
+use node_primitives::{BlockNumber, Hash, Header};
+use node_runtime::Runtime;
+use pallet_election_provider_multi_phase::{CurrentPhase, Phase, Round};
+use sp_runtime::traits::Header as HeaderT;
+use std::sync::Arc;
+
+pub(crate) async fn run(
+	client: Arc<WsClient>,
+	config: MonitorConfig,
+	signer: Signer,
+) -> Result<(), Error> {
+	let genesis_hash = rpc::genesis_hash(&client).await?;
+	let mut subscription =
+		rpc::subscribe_headers(&client, config.listen == Listen::Finalized).await?;
+	log::info!(target: LOG_TARGET, "following {:?} blocks", config.listen);
+
+	// the round in which we last submitted a solution.
+	let mut submitted_round = None;
+	while let Some(header) = subscription.next().await {
+		let header = header?;
+		match mine_and_submit(
+			&client,
+			&header,
+			&config,
+			&signer,
+			genesis_hash,
+			&mut submitted_round,
+		)
+		.await
+		{
+			Ok(()) => (),
+			Err(e @ Error::BetterScoreExists) | Err(e @ Error::AlreadySubmitted) =>
+				log::info!(target: LOG_TARGET, "not submitting at #{}: {}", header.number, e),
+			Err(e) => log::error!(target: LOG_TARGET, "failed at #{}: {}", header.number, e),
+		}
+	}
+
+	log::warn!(target: LOG_TARGET, "the subscription to the headers of the node has ended");
+	Ok(())
+}
+
+/// Mine and submit a solution at `header`, if the signed phase is open and we have not yet
+/// submitted in this round.
+async fn mine_and_submit(
+	client: &Arc<WsClient>,
+	header: &Header,
+	config: &MonitorConfig,
+	signer: &Signer,
+	genesis_hash: Hash,
+	submitted_round: &mut Option<u32>,
+) -> Result<(), Error> {
+	let hash = header.hash();
+
+	// check the phase and round first, which is a lot cheaper than scraping the whole pallet.
+	let phase = rpc::get_storage::<Phase<BlockNumber>>(
+		client,
+		CurrentPhase::<Runtime>::hashed_key().to_vec(),
+		Some(hash),
+	)
+	.await?
+	.unwrap_or_default();
+	if !phase.is_signed() {
+		log::trace!(target: LOG_TARGET, "phase at #{} is {:?}", header.number, phase);
+		return Ok(())
+	}
+
+	let round =
+		rpc::get_storage::<u32>(client, Round::<Runtime>::hashed_key().to_vec(), Some(hash))
+			.await?
+			.unwrap_or(1);
+	if *submitted_round == Some(round) {
+		return Ok(())
+	}
+
+	let mut ext = crate::create_election_ext(client.clone(), Some(hash), vec![]).await?;
+	let (raw_solution, witness) = crate::mine_with(&config.solver, &mut ext)?;
+	let score = raw_solution.score;
+	log::info!(target: LOG_TARGET, "mined a solution with score {:?} at #{}", score, header.number);
+	crate::ensure_no_better_solution(&mut ext, score, &signer.account)?;
+
+	let version = client.runtime_version(Some(hash)).await?;
+	let info = ChainInfo {
+		genesis_hash,
+		spec_version: version.spec_version,
+		transaction_version: version.transaction_version,
+	};
+	let nonce = client.account_next_index(signer.account.clone()).await?;
+	let uxt = signer::create_uxt(
+		raw_solution,
+		witness,
+		signer,
+		nonce,
+		config.tip,
+		(header.number, hash),
+		&info,
+	);
+
+	let tx_hash = client.submit_extrinsic(uxt.encode().into()).await?;
+	*submitted_round = Some(round);
+	log::info!(
+		target: LOG_TARGET,
+		"submitted a solution with score {:?} in round {} as {:?}",
+		score,
+		round,
+		tx_hash
+	);
+
+	Ok(())
+}
@@ -0,0 +1,94 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The RPC methods of the node that the miner uses.
+
+use crate::Error;
+use codec::Decode;
+use jsonrpsee::{
+	core::{
+		client::{Subscription, SubscriptionClientT},
+		Error as RpcError,
+	},
+	proc_macros::rpc,
+	ws_client::WsClient,
+};
+use node_primitives::{AccountId, BlockNumber, Hash, Header, Index};
+use sp_core::{
+	storage::{StorageData, StorageKey},
+	Bytes,
+};
+use sp_version::RuntimeVersion;
+
+#[rpc(client)]
+pub(crate) trait RpcApi {
+	#[method(name = "system_accountNextIndex")]
+	fn account_next_index(&self, account: AccountId) -> Result<Index, RpcError>;
+
+	#[method(name = "system_dryRun")]
+	fn dry_run(&self, extrinsic: Bytes, at: Option<Hash>) -> Result<Bytes, RpcError>;
+
+	#[method(name = "author_submitExtrinsic")]
+	fn submit_extrinsic(&self, extrinsic: Bytes) -> Result<Hash, RpcError>;
+
+	#[method(name = "state_getStorage")]
+	fn storage(&self, key: StorageKey, at: Option<Hash>) -> Result<Option<StorageData>, RpcError>;
+
+	#[method(name = "state_getRuntimeVersion")]
+	fn runtime_version(&self, at: Option<Hash>) -> Result<RuntimeVersion, RpcError>;
+
+	#[method(name = "chain_getBlockHash")]
+	fn block_hash(&self, number: Option<BlockNumber>) -> Result<Option<Hash>, RpcError>;
+
+	#[method(name = "chain_getHeader")]
+	fn header(&self, at: Option<Hash>) -> Result<Option<Header>, RpcError>;
+
+	#[method(name = "chain_getFinalizedHead")]
+	fn finalized_head(&self) -> Result<Hash, RpcError>;
+}
+
+/// Read and decode the storage value at `key` at block `at`.
+pub(crate) async fn get_storage<T: Decode>(
+	client: &WsClient,
+	key: Vec<u8>,
+	at: Option<Hash>,
+) -> Result<Option<T>, Error> {
+	let maybe_data = client.storage(StorageKey(key), at).await?;
+	maybe_data.map(|data| T::decode(&mut &*data.0)).transpose().map_err(Into::into)
+}
+
+/// Get the hash of the genesis block.
+pub(crate) async fn genesis_hash(client: &WsClient) -> Result<Hash, Error> {
+	client
+		.block_hash(Some(0))
+		.await?
+		.ok_or_else(|| Error::BlockNotFound("#0".into()))
+}
+
+/// Subscribe to the headers of either the best or the finalized blocks.
+pub(crate) async fn subscribe_headers(
+	client: &WsClient,
+	finalized: bool,
+) -> Result<Subscription<Header>, Error> {
+	let (subscribe, unsubscribe) = if finalized {
+		("chain_subscribeFinalizedHeads", "chain_unsubscribeFinalizedHeads")
+	} else {
+		("chain_subscribeNewHeads", "chain_unsubscribeNewHeads")
+	};
+
+	client.subscribe(subscribe, None, unsubscribe).await.map_err(Into::into)
+}
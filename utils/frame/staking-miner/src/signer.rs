@@ -0,0 +1,108 @@
+// This file is part of Substrate.
+
+// Copyright (C) 2022 Parity Technologies (UK) Ltd.
+// SPDX-License-Identifier: Apache-2.0
+
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// 	http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! The account of the miner, and the signed extrinsics that it submits.
+
+use crate::Error;
+use codec::Encode;
+use node_primitives::{AccountId, Balance, BlockNumber, Hash, Index};
+use node_runtime::{
+	Address, BlockHashCount, Call, Runtime, SignedExtra, SignedPayload, UncheckedExtrinsic,
+};
+use pallet_election_provider_multi_phase::{RawSolution, SolutionOf};
+use sp_core::{sr25519, Pair};
+use sp_runtime::{generic::Era, traits::IdentifyAccount, MultiSigner};
+
+/// The account that signs the submissions.
+pub(crate) struct Signer {
+	/// The account id of the signer.
+	pub(crate) account: AccountId,
+	/// The key pair of the signer.
+	pair: sr25519::Pair,
+}
+
+/// Read the signer from `seed_or_path`, which is either a secret URI or the path to a file that
+/// contains one.
+pub(crate) fn signer_from_seed_or_path(seed_or_path: &str) -> Result<Signer, Error> {
+	let seed_or_path = seed_or_path.trim();
+	let seed = std::fs::read_to_string(seed_or_path).unwrap_or_else(|_| seed_or_path.to_string());
+
+	let pair = sr25519::Pair::from_string(seed.trim(), None)
+		.map_err(|e| Error::InvalidSeed(format!("{:?}", e)))?;
+	let account = MultiSigner::from(pair.public()).into_account();
+
+	Ok(Signer { account, pair })
+}
+
+/// The parts of the chain's state that a signed extrinsic commits to.
+pub(crate) struct ChainInfo {
+	/// The hash of the genesis block.
+	pub(crate) genesis_hash: Hash,
+	/// The spec version of the runtime.
+	pub(crate) spec_version: u32,
+	/// The transaction version of the runtime.
+	pub(crate) transaction_version: u32,
+}
+
+/// Create a signed `submit` extrinsic, mortal from block `number` with hash `hash` onwards.
+pub(crate) fn create_uxt(
+	raw_solution: RawSolution<SolutionOf<Runtime>>,
+	witness: u32,
+	signer: &Signer,
+	nonce: Index,
+	tip: Balance,
+	(number, hash): (BlockNumber, Hash),
+	info: &ChainInfo,
+) -> UncheckedExtrinsic {
+	let call: Call = pallet_election_provider_multi_phase::Call::<Runtime>::submit {
+		raw_solution: Box::new(raw_solution),
+		num_signed_submissions: witness,
+	}
+	.into();
+
+	// take the biggest period possible, like the runtime does for its own transactions.
+	let period =
+		BlockHashCount::get().checked_next_power_of_two().map(|c| c / 2).unwrap_or(2) as u64;
+	let era = Era::mortal(period, number as u64);
+	// the extrinsic commits to the hash of the block at which its era begins.
+	debug_assert_eq!(era.birth(number as u64), number as u64);
+
+	let extra: SignedExtra = (
+		frame_system::CheckNonZeroSender::<Runtime>::new(),
+		frame_system::CheckSpecVersion::<Runtime>::new(),
+		frame_system::CheckTxVersion::<Runtime>::new(),
+		frame_system::CheckGenesis::<Runtime>::new(),
+		frame_system::CheckEra::<Runtime>::from(era),
+		frame_system::CheckNonce::<Runtime>::from(nonce),
+		frame_system::CheckWeight::<Runtime>::new(),
+		pallet_asset_tx_payment::ChargeAssetTxPayment::<Runtime>::from(tip, None),
+		pallet_recovery::TrackActivity::<Runtime>::new(),
+	);
+	let additional_signed =
+		((), info.spec_version, info.transaction_version, info.genesis_hash, hash, (), (), (), ());
+
+	let raw_payload = SignedPayload::from_raw(call, extra, additional_signed);
+	let signature = raw_payload.using_encoded(|payload| signer.pair.sign(payload));
+	let (call, extra, _) = raw_payload.deconstruct();
+
+	UncheckedExtrinsic::new_signed(
+		call,
+		Address::Id(signer.account.clone()),
+		signature.into(),
+		extra,
+	)
+}